@@ -1,8 +1,15 @@
 #![cfg(test)]
 use crate::polkadot_test_net::*;
-use frame_support::assert_ok;
+use frame_support::{
+	assert_ok,
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	sp_runtime::traits::SignedExtension,
+};
 use frame_system::RawOrigin;
-use hydradx_runtime::{Currencies, Omnipool, Referrals, Runtime, RuntimeOrigin, Staking, Tokens};
+use hydradx_runtime::{
+	Currencies, LinkReferralCode, Omnipool, Referrals, ReferralsTransactionFeeDiscount, Runtime, RuntimeCall,
+	RuntimeOrigin, Staking, Tokens,
+};
 use orml_traits::MultiCurrency;
 use pallet_referrals::{FeeDistribution, ReferralCode};
 use primitives::AccountId;
@@ -555,3 +562,170 @@ fn verify_preregisters_codes() {
 		}
 	});
 }
+
+fn charge_fee(who: AccountId, call: &RuntimeCall) -> Balance {
+	let info = call.get_dispatch_info();
+	let post_info = PostDispatchInfo::default();
+	let len: usize = 146;
+	let balance_before = Currencies::free_balance(HDX, &who);
+
+	let pre_payment = pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0)
+		.pre_dispatch(&who, call, &info, len)
+		.unwrap();
+
+	assert_ok!(
+		pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::post_dispatch(
+			Some(pre_payment),
+			&info,
+			&post_info,
+			len,
+			&Ok(())
+		)
+	);
+
+	balance_before - Currencies::free_balance(HDX, &who)
+}
+
+#[test]
+fn linked_account_should_pay_less_fee_for_swap_than_unlinked_account() {
+	Hydra::execute_with(|| {
+		// Arrange
+		let discount = Permill::from_percent(10);
+		ReferralsTransactionFeeDiscount::set(&discount);
+		let code =
+			ReferralCode::<<Runtime as pallet_referrals::Config>::CodeLength>::truncate_from(b"BALLS69".to_vec());
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE.into()),
+			code.clone()
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB.into()), code));
+
+		let call = RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount: 1_000_000_000_000,
+			min_buy_amount: 0,
+		});
+
+		// Act
+		let linked_fee = charge_fee(BOB.into(), &call);
+		let unlinked_fee = charge_fee(CHARLIE.into(), &call);
+
+		// Assert
+		assert!(linked_fee < unlinked_fee);
+		assert_eq!(linked_fee, unlinked_fee - discount.mul_floor(unlinked_fee));
+	});
+}
+
+#[test]
+fn referral_discount_should_not_be_applied_when_discount_is_not_set() {
+	Hydra::execute_with(|| {
+		// Arrange
+		let code =
+			ReferralCode::<<Runtime as pallet_referrals::Config>::CodeLength>::truncate_from(b"BALLS69".to_vec());
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE.into()),
+			code.clone()
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB.into()), code));
+
+		let call = RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount: 1_000_000_000_000,
+			min_buy_amount: 0,
+		});
+
+		// Act
+		let linked_fee = charge_fee(BOB.into(), &call);
+		let unlinked_fee = charge_fee(CHARLIE.into(), &call);
+
+		// Assert
+		assert_eq!(linked_fee, unlinked_fee);
+	});
+}
+
+#[test]
+fn referral_discount_should_not_be_applied_when_call_is_not_trading() {
+	Hydra::execute_with(|| {
+		// Arrange
+		ReferralsTransactionFeeDiscount::set(&Permill::from_percent(10));
+		let code =
+			ReferralCode::<<Runtime as pallet_referrals::Config>::CodeLength>::truncate_from(b"BALLS69".to_vec());
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE.into()),
+			code.clone()
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB.into()), code));
+
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+
+		// Act
+		let linked_fee = charge_fee(BOB.into(), &call);
+		let unlinked_fee = charge_fee(CHARLIE.into(), &call);
+
+		// Assert
+		assert_eq!(linked_fee, unlinked_fee);
+	});
+}
+
+#[test]
+fn first_trade_with_referral_code_should_link_account() {
+	Hydra::execute_with(|| {
+		// Arrange
+		let code =
+			ReferralCode::<<Runtime as pallet_referrals::Config>::CodeLength>::truncate_from(b"BALLS69".to_vec());
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE.into()),
+			code.clone()
+		));
+		let call = RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount: 1_000_000_000_000,
+			min_buy_amount: 0,
+		});
+		let info = call.get_dispatch_info();
+
+		// Act
+		assert_ok!(LinkReferralCode(Some(code)).pre_dispatch(&BOB.into(), &call, &info, 146));
+
+		// Assert
+		let bob: AccountId = BOB.into();
+		assert_eq!(Referrals::linked_referral_account(bob), Some(ALICE.into()));
+	});
+}
+
+#[test]
+fn second_trade_with_referral_code_should_not_relink_account() {
+	Hydra::execute_with(|| {
+		// Arrange
+		let code =
+			ReferralCode::<<Runtime as pallet_referrals::Config>::CodeLength>::truncate_from(b"BALLS69".to_vec());
+		let other_code =
+			ReferralCode::<<Runtime as pallet_referrals::Config>::CodeLength>::truncate_from(b"OTHER69".to_vec());
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE.into()),
+			code.clone()
+		));
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			other_code.clone()
+		));
+		let call = RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount: 1_000_000_000_000,
+			min_buy_amount: 0,
+		});
+		let info = call.get_dispatch_info();
+		assert_ok!(LinkReferralCode(Some(code)).pre_dispatch(&BOB.into(), &call, &info, 146));
+
+		// Act
+		assert_ok!(LinkReferralCode(Some(other_code)).pre_dispatch(&BOB.into(), &call, &info, 146));
+
+		// Assert
+		let bob: AccountId = BOB.into();
+		assert_eq!(Referrals::linked_referral_account(bob), Some(ALICE.into()));
+	});
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the bonds pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query bonds metadata.
+	pub trait BondsApi<AssetId, Moment, Balance> where
+		AssetId: Codec,
+		Moment: Codec,
+		Balance: Codec,
+	{
+		/// Return metadata URI of the bonds.
+		/// Returns `None` if the metadata URI is not set.
+		fn metadata_uri(bond_id: AssetId) -> Option<Vec<u8>>;
+
+		/// Return the supply-weighted average remaining time to maturity in milliseconds of the outstanding bonds
+		/// of the underlying asset.
+		/// Returns `None` if there are no outstanding bonds of the asset.
+		fn weighted_average_maturity(asset_id: AssetId) -> Option<Moment>;
+
+		/// Return total protocol fees collected from the issuance of bonds of the underlying asset.
+		fn cumulative_fees(asset_id: AssetId) -> Balance;
+
+		/// Return IDs of the bonds with outstanding supply of the underlying asset, in the order of issuance.
+		fn bonds_of_asset(asset_id: AssetId) -> Vec<AssetId>;
+	}
+}
@@ -19,9 +19,10 @@
 
 use super::*;
 
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{account, benchmarks};
 use frame_support::{assert_ok, traits::EnsureOrigin};
 use frame_system::RawOrigin;
+use sp_runtime::traits::TrailingZeroInput;
 
 use orml_traits::MultiCurrency;
 use primitives::{constants::time::unix_time::MONTH, AssetId, Balance};
@@ -79,5 +80,518 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(bond_id, &issuer), 0u32.into());
 	}
 
+	issue_locked {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+
+	}: _(RawOrigin::Signed(issuer), HDX, (100 * ONE).into(), maturity)
+	verify {
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+		assert!(TransferLocked::<T>::contains_key(bond_id));
+	}
+
+	unlock {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		T::Currency::deposit(HDX, &issuer, amount)?;
+
+		let maturity = NOW + MONTH;
+
+		assert_ok!(crate::Pallet::<T>::issue_locked(RawOrigin::Signed(issuer).into(), HDX, (100 * ONE).into(), maturity));
+
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+		let authority = T::AuthorityOrigin::try_successful_origin().unwrap();
+
+	}: _<T::RuntimeOrigin>(authority, bond_id)
+	verify {
+		assert!(!TransferLocked::<T>::contains_key(bond_id));
+	}
+
+	redeem_many {
+		let n in 1 .. T::MaxBatch::get();
+
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		//NOTE: bonds are insufficient so issuer must ED for it
+		T::Currency::deposit(HDX, &issuer, amount.saturating_mul(n.into()) + (100 * ONE).into())?;
+
+		let fee = <T as Config>::ProtocolFee::get().mul_ceil(amount);
+		let amount_without_fee: T::Balance = amount.checked_sub(&fee).unwrap();
+
+		let mut redemptions = Vec::new();
+		for i in 0..n {
+			let maturity = NOW + MONTH + i as Moment;
+			assert_ok!(crate::Pallet::<T>::issue(RawOrigin::Signed(issuer.clone()).into(), HDX, amount, maturity));
+			let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+			redemptions.push((bond_id, amount_without_fee));
+		}
+
+		pallet_timestamp::Pallet::<T>::set_timestamp((NOW + 2 * MONTH).into());
+
+	}: _(RawOrigin::Signed(issuer.clone()), redemptions.clone(), true)
+	verify {
+		for (bond_id, _) in redemptions {
+			assert_eq!(T::Currency::free_balance(bond_id, &issuer), 0u32.into());
+		}
+	}
+
+	cancel_queued_redemption {
+		let (issuer, bond_id, amount) = queue_redemption::<T>()?;
+		let id = RedemptionQueueHead::<T>::get();
+
+	}: _(RawOrigin::Signed(issuer.clone()), id)
+	verify {
+		assert!(RedemptionQueue::<T>::get(id).is_none());
+		assert_eq!(T::Currency::free_balance(bond_id, &issuer), amount);
+	}
+
+	process_queued_redemption {
+		let (issuer, bond_id, amount) = queue_redemption::<T>()?;
+		let id = RedemptionQueueHead::<T>::get();
+
+		let (underlying_asset_id, _) = crate::Pallet::<T>::bond(bond_id).unwrap();
+		T::Currency::deposit(underlying_asset_id, &crate::Pallet::<T>::pallet_account_id(), amount)?;
+
+	}: {
+		crate::Pallet::<T>::process_redemption_queue();
+	}
+	verify {
+		assert!(RedemptionQueue::<T>::get(id).is_none());
+	}
+
+	issue_managed {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+		let manager: T::AccountId = account("manager", 0, 0);
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+
+	}: _(RawOrigin::Signed(issuer), HDX, (100 * ONE).into(), maturity, manager.clone())
+	verify {
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+		assert_eq!(BondManagers::<T>::get(bond_id), Some(manager));
+	}
+
+	manager_redeem {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		//NOTE: bonds are insufficient so issuer must ED for it
+		T::Currency::deposit(HDX, &issuer, amount + (100 * ONE).into())?;
+
+		let maturity = NOW + MONTH;
+		let manager: T::AccountId = account("manager", 0, 0);
+		let dest: T::AccountId = account("dest", 0, 0);
+
+		assert_ok!(crate::Pallet::<T>::issue_managed(RawOrigin::Signed(issuer.clone()).into(), HDX, amount, maturity, manager.clone()));
+
+		let fee = <T as Config>::ProtocolFee::get().mul_ceil(amount);
+		let amount_without_fee: T::Balance = amount.checked_sub(&fee).unwrap();
+
+		pallet_timestamp::Pallet::<T>::set_timestamp((NOW + 2 * MONTH).into());
+
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+
+	}: _(RawOrigin::Signed(manager), bond_id, issuer.clone(), amount_without_fee, dest.clone())
+	verify {
+		assert_eq!(T::Currency::free_balance(bond_id, &issuer), 0u32.into());
+		assert_eq!(T::Currency::free_balance(HDX, &dest), amount_without_fee);
+	}
+
+	issue_discounted {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+
+	}: _(RawOrigin::Signed(issuer), HDX, (100 * ONE).into(), (10 * ONE).into(), maturity)
+	verify {
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+		assert!(BondDiscounts::<T>::contains_key(bond_id));
+	}
+
+	approve_issuance {
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let owner = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let spender: T::AccountId = account("spender", 0, 0);
+		let amount: T::Balance = (100 * ONE).into();
+
+	}: _(RawOrigin::Signed(owner.clone()), spender.clone(), amount)
+	verify {
+		assert_eq!(IssuanceAllowance::<T>::get(&owner, &spender), amount);
+	}
+
+	issue_from {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let owner = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let spender: T::AccountId = account("spender", 0, 0);
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &owner, amount)?;
+		IssuanceAllowance::<T>::insert(&owner, &spender, T::Balance::from(100 * ONE));
+
+	}: _(RawOrigin::Signed(spender.clone()), owner.clone(), HDX, (100 * ONE).into(), maturity)
+	verify {
+		assert!(BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).is_some());
+		assert!(!IssuanceAllowance::<T>::contains_key(&owner, &spender));
+	}
+
+	check_maturity_warning {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+		assert_ok!(crate::Pallet::<T>::issue(RawOrigin::Signed(issuer).into(), HDX, (100 * ONE).into(), maturity));
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+
+	}: {
+		let (_, maturity) = crate::Pallet::<T>::bond(bond_id).unwrap();
+		crate::Pallet::<T>::check_maturity_warning(bond_id, maturity, NOW, MONTH);
+	}
+	verify {
+		assert!(MaturityWarned::<T>::contains_key(bond_id));
+	}
+
+	emit_outstanding_supply {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+		assert_ok!(crate::Pallet::<T>::issue(RawOrigin::Signed(issuer).into(), HDX, (100 * ONE).into(), maturity));
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+
+	}: {
+		crate::Pallet::<T>::bond(bond_id).unwrap();
+		crate::Pallet::<T>::emit_outstanding_supply(bond_id);
+	}
+	verify {
+		assert!(!T::Currency::total_issuance(bond_id).is_zero());
+	}
+
+	set_metadata_uri {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+		assert_ok!(crate::Pallet::<T>::issue(RawOrigin::Signed(issuer.clone()).into(), HDX, (100 * ONE).into(), maturity));
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+
+		let uri = sp_std::vec![b'u'; T::MaxUriLen::get() as usize];
+	}: _(RawOrigin::Signed(issuer), bond_id, uri.clone())
+	verify {
+		assert_eq!(BondMetadataUri::<T>::get(bond_id).map(|uri| uri.into_inner()), Some(uri));
+	}
+
+	clawback {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		//NOTE: bonds are insufficient so issuer must ED for it
+		T::Currency::deposit(HDX, &issuer, amount + (100 * ONE).into())?;
+
+		let maturity = NOW + MONTH;
+		let dest: T::AccountId = account("dest", 0, 0);
+
+		assert_ok!(crate::Pallet::<T>::issue(RawOrigin::Signed(issuer.clone()).into(), HDX, amount, maturity));
+
+		let fee = <T as Config>::ProtocolFee::get().mul_ceil(amount);
+		let amount_without_fee: T::Balance = amount.checked_sub(&fee).unwrap();
+
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+		let clawback_origin = T::ClawbackOrigin::try_successful_origin().unwrap();
+
+	}: _<T::RuntimeOrigin>(clawback_origin, bond_id, issuer.clone(), amount_without_fee, dest.clone())
+	verify {
+		assert_eq!(T::Currency::free_balance(bond_id, &issuer), 0u32.into());
+		assert_eq!(T::Currency::free_balance(HDX, &dest), amount_without_fee);
+	}
+
+	bid {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let maturity = NOW + MONTH;
+		let amount: T::Balance = (100 * ONE).into();
+		let max_price = Permill::from_percent(90);
+		let escrow_account = crate::Pallet::<T>::bid_escrow_account_id();
+
+		// all other bids have a lower price, so the new bid is placed first in the full book
+		for i in 1..T::MaxBidsPerBook::get() {
+			let bidder: T::AccountId = account("bidder", i, 0);
+			T::Currency::deposit(HDX, &bidder, amount)?;
+			assert_ok!(crate::Pallet::<T>::bid(RawOrigin::Signed(bidder).into(), HDX, maturity, Permill::from_percent(80), amount));
+		}
+
+		let bidder: T::AccountId = account("bidder", 0, 0);
+		T::Currency::deposit(HDX, &bidder, amount)?;
+		let escrowed = T::Currency::free_balance(HDX, &escrow_account);
+	}: _(RawOrigin::Signed(bidder), HDX, maturity, max_price, amount)
+	verify {
+		assert_eq!(T::Currency::free_balance(HDX, &escrow_account), escrowed + max_price.mul_ceil(amount));
+	}
+
+	cancel_bid {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let maturity = NOW + MONTH;
+		let amount: T::Balance = (100 * ONE).into();
+
+		for i in 0..T::MaxBidsPerBook::get() {
+			let bidder: T::AccountId = account("bidder", i, 0);
+			T::Currency::deposit(HDX, &bidder, amount)?;
+			assert_ok!(crate::Pallet::<T>::bid(RawOrigin::Signed(bidder).into(), HDX, maturity, Permill::from_percent(90), amount));
+		}
+
+		let bidder: T::AccountId = account("bidder", T::MaxBidsPerBook::get() - 1, 0);
+		let id = NextBidId::<T>::get() - 1;
+	}: _(RawOrigin::Signed(bidder.clone()), HDX, maturity, id)
+	verify {
+		assert_eq!(T::Currency::free_balance(HDX, &bidder), amount);
+	}
+
+	fill_bids {
+		let n in 1 .. T::MaxBidsPerBook::get();
+
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let maturity = NOW + MONTH;
+		let amount: T::Balance = (100 * ONE).into();
+
+		let mut bidders = Vec::new();
+		for i in 0..n {
+			let bidder: T::AccountId = account("bidder", i, 0);
+			T::Currency::deposit(HDX, &bidder, amount)?;
+			assert_ok!(crate::Pallet::<T>::bid(RawOrigin::Signed(bidder.clone()).into(), HDX, maturity, Permill::from_percent(90), amount));
+			bidders.push(bidder);
+		}
+
+		let issued_amount = amount.saturating_mul(n.into());
+		//NOTE: bonds are insufficient so issuer must ED for it
+		T::Currency::deposit(HDX, &issuer, issued_amount + (100 * ONE).into())?;
+	}: _(RawOrigin::Signed(issuer), HDX, maturity, issued_amount)
+	verify {
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+		assert!(T::Currency::free_balance(bond_id, &bidders[0]) > 0u32.into());
+	}
+
+	set_maturity_freeze {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let authority = T::AuthorityOrigin::try_successful_origin().unwrap();
+
+	}: _<T::RuntimeOrigin>(authority, true)
+	verify {
+		assert_eq!(GlobalMaturityFreeze::<T>::get(), Some(NOW));
+	}
+
+	approve_transfer {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let owner = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let spender: T::AccountId = account("spender", 0, 0);
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &owner, amount)?;
+		assert_ok!(crate::Pallet::<T>::issue(RawOrigin::Signed(owner.clone()).into(), HDX, (100 * ONE).into(), maturity));
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+
+	}: _(RawOrigin::Signed(owner.clone()), spender.clone(), bond_id, (50 * ONE).into())
+	verify {
+		assert_eq!(TransferAllowance::<T>::get((&owner, &spender, bond_id)), (50 * ONE).into());
+	}
+
+	transfer_from {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let owner = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let spender: T::AccountId = account("spender", 0, 0);
+		let dest: T::AccountId = account("dest", 1, 0);
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &owner, amount)?;
+		assert_ok!(crate::Pallet::<T>::issue_locked(RawOrigin::Signed(owner.clone()).into(), HDX, (100 * ONE).into(), maturity));
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+		TransferAllowance::<T>::insert((&owner, &spender, bond_id), T::Balance::from(50 * ONE));
+
+		pallet_timestamp::Pallet::<T>::set_timestamp(maturity.into());
+
+	}: _(RawOrigin::Signed(spender.clone()), owner.clone(), dest.clone(), bond_id, (50 * ONE).into())
+	verify {
+		assert!(!TransferAllowance::<T>::contains_key((&owner, &spender, bond_id)));
+		assert_eq!(T::Currency::free_balance(bond_id, &dest), (50 * ONE).into());
+	}
+
+	top_up {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+		assert_ok!(crate::Pallet::<T>::issue(RawOrigin::Signed(issuer.clone()).into(), HDX, (100 * ONE).into(), maturity));
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+
+	}: _(RawOrigin::Signed(issuer), bond_id, (50 * ONE).into())
+	verify {
+		assert_eq!(BondDiscounts::<T>::get(bond_id).map(|(_, discount)| discount), Some((50 * ONE).into()));
+	}
+
+	issue_vested {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let vesting = VestingInfo {
+			locked: (50 * ONE).into(),
+			per_block: ONE.into(),
+			starting_block: frame_system::Pallet::<T>::block_number(),
+		};
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+
+	}: _(RawOrigin::Signed(issuer), HDX, (100 * ONE).into(), maturity, recipient.clone(), vesting)
+	verify {
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+		assert_eq!(BondVesting::<T>::get(bond_id, &recipient), Some(vesting));
+	}
+
+	issue_with_redemption_fee {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+
+	}: _(RawOrigin::Signed(issuer), HDX, (100 * ONE).into(), maturity, Permill::from_percent(1))
+	verify {
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+		assert_eq!(RedemptionFees::<T>::get(bond_id), Some(Permill::from_percent(1)));
+	}
+
+	issue_conditional {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+		let condition = T::RedemptionCondition::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+
+	}: _(RawOrigin::Signed(issuer), HDX, (100 * ONE).into(), maturity, condition.clone())
+	verify {
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+		assert_eq!(RedemptionConditions::<T>::get(bond_id), Some(condition));
+	}
+
+	issue_with_yield {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + MONTH;
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+
+	}: _(RawOrigin::Signed(issuer), HDX, (100 * ONE).into(), (10 * ONE).into(), maturity)
+	verify {
+		let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+		assert!(BondDiscounts::<T>::contains_key(bond_id));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
+
+/// Issue bonds and queue a redemption of them by emptying the pallet account.
+fn queue_redemption<T: Config + pallet_timestamp::Config>() -> Result<(T::AccountId, AssetId, T::Balance), DispatchError>
+where
+	T::Balance: From<u128>,
+	T::Moment: From<u64>,
+{
+	pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+	let origin = T::IssueOrigin::try_successful_origin().unwrap();
+	let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+	let amount: T::Balance = (200 * ONE).into();
+	//NOTE: bonds are insufficient so issuer must ED for it
+	T::Currency::deposit(HDX, &issuer, amount + (100 * ONE).into())?;
+
+	let maturity = NOW + MONTH;
+	assert_ok!(crate::Pallet::<T>::issue(
+		RawOrigin::Signed(issuer.clone()).into(),
+		HDX,
+		amount,
+		maturity
+	));
+
+	let fee = <T as Config>::ProtocolFee::get().mul_ceil(amount);
+	let amount_without_fee: T::Balance = amount.checked_sub(&fee).unwrap();
+
+	let pallet_account = crate::Pallet::<T>::pallet_account_id();
+	T::Currency::withdraw(HDX, &pallet_account, T::Currency::free_balance(HDX, &pallet_account))?;
+
+	pallet_timestamp::Pallet::<T>::set_timestamp((NOW + 2 * MONTH).into());
+
+	let bond_id = BondIds::<T>::get::<(AssetId, Moment)>((HDX, maturity)).unwrap();
+	assert_ok!(crate::Pallet::<T>::redeem(
+		RawOrigin::Signed(issuer.clone()).into(),
+		bond_id,
+		amount_without_fee
+	));
+
+	Ok((issuer, bond_id, amount_without_fee))
+}
@@ -27,40 +27,136 @@
 //! * New amount of bonds is issued when the underlying asset and maturity matches already registered bonds.
 //! * It's possible to create multiple bonds for the same underlying asset.
 //! * Bonds can be issued for all available asset types permitted by `AssetTypeWhitelist`.
+//!   This includes share assets of liquidity pools (e.g. `XYK` and `StableSwap` shares), which are handled as any other
+//!   underlying asset.
 //! * The existential deposit of the bonds is the same as of the underlying asset.
 //! * A user receives the same amount of bonds as the amount of the underlying asset he provided, minus the protocol fee.
 //! * Maturity of bonds is represented using the Unix time in milliseconds.
+//! * Requested maturity is rounded down to a multiple of `MaturityGranularity`.
 //! * Underlying assets are stored in the pallet account until redeemed.
 //! * Protocol fee is applied to the amount of the underlying asset and transferred to the fee receiver.
+//!   Total fees collected per underlying asset are tracked in `CumulativeFees`.
 //! * It's possible to issue new bonds for bonds that are already mature.
+//! * The account which registered the bonds is stored as the issuer of the bonds.
+//! * The amount of the underlying assets locked in outstanding bonds of an issuer is limited by `MaxIssuancePerIssuer`.
+//! * Bonds issued with `issue_discounted` are issued below the face value locked in the pallet account.
+//!   The discount is paid out pro rata on redemption, so the bonds are redeemed at the face value.
+//! * Bonds issued with `issue_with_yield` have the yield deposited by the issuer together with the principal.
+//!   The yield is paid out pro rata on redemption the same way as the discount.
+//! * The issuer can add yield to outstanding bonds with `top_up`. The yield is paid out pro rata on redemption
+//!   the same way as the prefunded yield, and such bonds can't be issued again.
+//! * If the pallet account doesn't hold enough of the underlying asset to pay the share of the discount or the yield,
+//!   the redemption pays the principal and the rest of the reserve, and `RedemptionShortfall` event is emitted.
+//! * An issuer can allow a spender to issue bonds on its behalf with `approve_issuance`. The spender issues
+//!   the bonds with `issue_from` using the issuer's funds, up to the approved amount.
+//! * A holder can allow a spender to transfer its bonds with `approve_transfer`. The spender transfers
+//!   the bonds with `transfer_from`, up to the approved amount.
+//! * Bonds can have a metadata URI linking to external documentation, e.g. a prospectus. The URI is set
+//!   with `issue_with_metadata_uri` or later by the issuer with `set_metadata_uri`.
+//!
+//! ## Primary issuance auction
+//! * Buyers place bids for bonds of an underlying asset and maturity with `bid`. A bid states the amount of the bonds
+//!   and the maximum price as a share of the face value. The maximum cost of the bid is escrowed until the bid
+//!   is filled or cancelled with `cancel_bid`.
+//! * At most `MaxBidsPerBook` bids can be placed for the same underlying asset and maturity.
+//! * An issuer issues new bonds with `fill_bids` and sells them to the highest bids first. All filled bids pay
+//!   the clearing price, which is the maximum price of the lowest filled bid. The rest of the escrow is refunded.
+//! * A bid can be filled partially, the remainder of the bid stays in the book.
+//!   Bonds not sold to bids stay with the issuer.
 //!
 //! ## Redeeming of new bonds
 //! * Bonds can be both partially or fully redeemed.
 //! * The amount of the underlying asset an account receives is 1:1 to the `amount` of the bonds redeemed.
 //! * Anyone who holds the bonds is able to redeem them.
+//! * Multiple bonds can be redeemed in one call with `redeem_many`.
+//! * If `DustRedemption` is enabled and a redemption leaves less than `BondDustThreshold` of the bonds
+//!   to the holder, the remainder is redeemed in the same call.
+//! * A redemption of less than `MinRedemptionAmount` of the bonds is rejected, unless it redeems
+//!   the whole bond balance of the holder.
+//! * If the underlying asset was removed from the asset registry, the redemption is paid 1:1 in
+//!   `UnderlyingFallbackAsset`, or fails with `UnderlyingRemoved` if no fallback asset is configured.
+//!   The same applies to `manager_redeem` and `clawback`.
+//!   Such redemption is never queued.
+//! * If the pallet account doesn't hold enough of the underlying asset, the redemption is queued.
+//!   Queued redemptions are processed in FIFO order per underlying asset in `on_initialize` once the pallet account
+//!   is refilled. Redemptions of other underlying assets are not blocked by a redemption which can't be covered.
+//!   The queue is scanned in passes of up to `MaxQueuedRedemptionsPerBlock` redemptions per block, continuing
+//!   from `RedemptionQueueCursor` in the next block, so redemptions anywhere in the queue are eventually reached.
+//!   If the underlying asset was removed, queued redemptions are paid in `UnderlyingFallbackAsset`.
+//! * Queued redemption can be cancelled by its owner with `cancel_queued_redemption`, which returns the bonds.
+//! * `BondMaturingSoon` event is emitted once per bond when the bond enters `MaturityWarningWindow` before maturity.
+//!   At most `MaxMaturityChecksPerBlock` bonds are checked in `on_initialize`, continuing in the next block.
+//! * `OutstandingSupply` event is emitted for every bond with nonzero supply in `on_finalize` every `GaugeInterval`
+//!   blocks, and `UnderlyingOutstandingSupply` event with the total supply of the bonds of each underlying asset.
+//!   At most `MaxAssetsPerGauge` bonds are checked, continuing from `GaugeCursor` in the next gauge block.
+//! * Bonds can be redeemed for a substitute asset with `redeem_as`. The underlying asset is sold for the substitute
+//!   asset via `Router`. Such redemption is never queued.
+//! * Bonds of the stakeable asset of `Staking` can be redeemed and staked in one call with `redeem_and_stake`.
+//!   Such redemption is never queued.
+//! * Bonds issued with `issue_managed` have a manager, who can redeem the bonds of any holder to any account
+//!   with `manager_redeem`. Such redemption is never queued.
+//!
+//! * Bonds issued with `issue_with_redemption_fee` charge the redemption fee on the amount paid out by redemptions.
+//!   The fee is transferred to `FeeReceiver` and is independent of the protocol fee charged on issuance.
+//!
+//! * Bonds issued with `issue_conditional` have a redemption condition, e.g. a minimum price of an asset.
+//!   The condition is checked by `ConditionOracle` on every redemption, which fails with `ConditionNotMet`
+//!   while the condition is not met. The condition can't be changed after the issuance.
+//!
+//! * Bonds with outstanding supply are indexed by the underlying asset in `BondsByAsset`, at most
+//!   `MaxBondsPerAsset` bonds per asset. Fully redeemed bonds are removed from the index and added again
+//!   when they are issued again or their queued redemption is cancelled. The index is used only for queries,
+//!   so bonds issued after the bound was reached are not indexed, but their issuance doesn't fail.
+//!
+//! * `AuthorityOrigin` can freeze the maturity of all bonds with `set_maturity_freeze`, e.g. during an incident.
+//!   While frozen, redemptions consider the time the freeze was set as the current time, so no new bonds mature.
+//!
+//! ## Transfer locked bonds
+//! * Bonds issued with `issue_locked` can't be transferred until they are mature.
+//! * The transfer lock is enforced by `OnTransfer` implementation, which needs to be used as a pre-transfer hook of the currency.
+//! * `AuthorityOrigin` can release the transfer lock before maturity by calling `unlock`, or within
+//!   `PostMaturityGrace` after the maturity.
+//!
+//! ## Vested bonds
+//! * Bonds issued with `issue_vested` are transferred to the recipient and locked in its account by a vesting schedule,
+//!   which unlocks `per_block` bonds every block after `starting_block`.
+//! * The schedule is enforced by `OnTransfer` implementation and by redemptions, which can't reduce the bond balance
+//!   below the amount still locked by the schedule.
+//!
+//! ## Clawback
+//! * `ClawbackOrigin` can burn bonds of a holder before maturity with `clawback` in confirmed fraud cases.
+//!   The underlying assets of the bonds are transferred to the provided account, e.g. the treasury.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
 use frame_support::{
-	ensure,
-	pallet_prelude::{DispatchResult, Get},
+	defensive, ensure,
+	pallet_prelude::{BoundedVec, ConstU32, DispatchResult, Get, Weight},
 	sp_runtime::{
-		traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub},
-		DispatchError, Permill, Saturating,
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub, UniqueSaturatedInto, Zero},
+		ArithmeticError, DispatchError, Permill, Rounding, Saturating, TransactionOutcome,
 	},
+	storage::with_transaction,
 	traits::{Contains, Time},
-	PalletId,
+	PalletId, RuntimeDebug,
 };
-use frame_system::{ensure_signed, pallet_prelude::OriginFor};
-use sp_core::MaxEncodedLen;
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+};
+use sp_core::{MaxEncodedLen, U256};
 use sp_std::{mem, vec::Vec};
 
 use hydradx_traits::{
 	registry::{Create, Inspect},
+	router::{AmmTradeWeights, AmountInAndOut, AssetPair, RouteProvider, RouterT, Trade},
 	AssetKind,
 };
-use orml_traits::{GetByKey, MultiCurrency};
+use orml_traits::{currency::OnTransfer, GetByKey, MultiCurrency};
 use primitives::{AssetId, Moment};
+use scale_info::TypeInfo;
 
 #[cfg(test)]
 mod tests;
@@ -68,17 +164,86 @@ mod tests;
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarks;
 
+pub mod migration;
 pub mod weights;
 
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// Maximum number of underlying assets with a queued redemption which couldn't be covered,
+/// tracked in one pass over the redemption queue.
+pub const MAX_BLOCKED_REDEMPTION_ASSETS: u32 = 32;
+
+/// Bid for bonds placed in the primary issuance auction.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Bid<AccountId, Balance> {
+	/// Bid ID
+	pub id: u64,
+	/// Account which placed the bid
+	pub who: AccountId,
+	/// Maximum price of the bonds as a share of the face value
+	pub max_price: Permill,
+	/// Unfilled amount of the bonds
+	pub amount: Balance,
+	/// Amount of the underlying asset escrowed for the unfilled amount
+	pub escrowed: Balance,
+}
+
+/// Vesting schedule of bonds issued with `issue_vested`, same as `VestingInfo` of `pallet_vesting`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VestingInfo<Balance, BlockNumber> {
+	/// Amount of the bonds locked at `starting_block`
+	pub locked: Balance,
+	/// Amount of the bonds unlocked every block after `starting_block`
+	pub per_block: Balance,
+	/// Block number the bonds start unlocking at
+	pub starting_block: BlockNumber,
+}
+
+impl<Balance, BlockNumber> VestingInfo<Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy + From<u128>,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// Amount of the bonds still locked at block `n`.
+	pub fn locked_at(&self, n: BlockNumber) -> Balance {
+		let vested_blocks: u128 = n.saturating_sub(self.starting_block).unique_saturated_into();
+		self.locked
+			.saturating_sub(self.per_block.saturating_mul(Balance::from(vested_blocks)))
+	}
+}
+
+/// Oracle checking the redemption conditions of bonds issued with `issue_conditional`.
+pub trait RedemptionConditionOracle<Condition> {
+	/// Return `true` if the redemption condition is met.
+	fn is_met(condition: &Condition) -> bool;
+
+	/// Weight of `is_met`.
+	fn is_met_weight() -> Weight;
+}
+
+/// Staking of the underlying asset redeemed with `redeem_and_stake`.
+pub trait StakeHandler<AccountId, Balance> {
+	/// Asset that can be staked.
+	fn stakeable_asset() -> AssetId;
+
+	/// Stake `amount` of the stakeable asset of `who`.
+	fn stake(who: &AccountId, amount: Balance) -> DispatchResult;
+
+	/// Weight of `stake`.
+	fn stake_weight() -> Weight;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -118,6 +283,78 @@ pub mod pallet {
 		/// The origin which can issue new bonds.
 		type IssueOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
+		/// The origin which can release transfer locks of bonds.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin which can claw back bonds of a holder before maturity, e.g. in fraud cases.
+		type ClawbackOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Time window in milliseconds after the maturity in which `AuthorityOrigin` can still act on bonds.
+		#[pallet::constant]
+		type PostMaturityGrace: Get<Moment>;
+
+		/// Granularity of bond maturities in milliseconds.
+		/// Requested maturity is rounded down to a multiple of the granularity, so bonds with nearly
+		/// identical maturities coalesce. Granularity of zero or one disables the rounding.
+		#[pallet::constant]
+		type MaturityGranularity: Get<Moment>;
+
+		/// Maximum number of redemptions in one `redeem_many` call.
+		#[pallet::constant]
+		type MaxBatch: Get<u32>;
+
+		/// Maximum number of queued redemptions processed in `on_initialize`.
+		#[pallet::constant]
+		type MaxQueuedRedemptionsPerBlock: Get<u32>;
+
+		/// Time window in milliseconds before the maturity in which `BondMaturingSoon` event is emitted.
+		/// Window of zero disables the warnings.
+		#[pallet::constant]
+		type MaturityWarningWindow: Get<Moment>;
+
+		/// Maximum number of bonds checked for the maturity warning in `on_initialize`.
+		#[pallet::constant]
+		type MaxMaturityChecksPerBlock: Get<u32>;
+
+		/// Number of blocks between `OutstandingSupply` events. Interval of zero disables the events.
+		#[pallet::constant]
+		type GaugeInterval: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of bonds checked for `OutstandingSupply` events in one block.
+		#[pallet::constant]
+		type MaxAssetsPerGauge: Get<u32>;
+
+		/// Maximum amount of the underlying assets locked in outstanding bonds of one issuer.
+		#[pallet::constant]
+		type MaxIssuancePerIssuer: Get<Self::Balance>;
+
+		/// Maximum length of the metadata URI of bonds.
+		#[pallet::constant]
+		type MaxUriLen: Get<u32>;
+
+		/// Maximum number of bids for the same underlying asset and maturity.
+		#[pallet::constant]
+		type MaxBidsPerBook: Get<u32>;
+
+		/// Redeem the remainder of the bonds of a holder together with a partial redemption
+		/// when the remainder would be below `BondDustThreshold`.
+		#[pallet::constant]
+		type DustRedemption: Get<bool>;
+
+		/// Bond balance below which the remainder of a partial redemption is considered dust.
+		#[pallet::constant]
+		type BondDustThreshold: Get<Self::Balance>;
+
+		/// Minimum amount of the bonds that can be redeemed, unless the redemption closes out
+		/// the whole bond balance of the holder.
+		#[pallet::constant]
+		type MinRedemptionAmount: Get<Self::Balance>;
+
+		/// Asset paid out 1:1 instead of the underlying asset when the underlying asset was removed
+		/// from the asset registry. If not set, redemptions of such bonds fail with `UnderlyingRemoved`.
+		#[pallet::constant]
+		type UnderlyingFallbackAsset: Get<Option<AssetId>>;
+
 		/// Asset types that are permitted to be used as underlying assets.
 		type AssetTypeWhitelist: Contains<AssetKind>;
 
@@ -129,8 +366,29 @@ pub mod pallet {
 		#[pallet::constant]
 		type FeeReceiver: Get<Self::AccountId>;
 
+		/// Router implementation used to sell the underlying asset in `redeem_as`.
+		type Router: RouteProvider<AssetId>
+			+ RouterT<Self::RuntimeOrigin, AssetId, Self::Balance, Trade<AssetId>, AmountInAndOut<Self::Balance>>;
+
+		/// Staking implementation used to stake the underlying asset in `redeem_and_stake`.
+		type Staking: StakeHandler<Self::AccountId, Self::Balance>;
+
+		/// Redemption condition of bonds issued with `issue_conditional`.
+		type RedemptionCondition: Parameter + MaxEncodedLen;
+
+		/// Oracle checking the redemption conditions at redemption time.
+		type ConditionOracle: RedemptionConditionOracle<Self::RedemptionCondition>;
+
+		/// Maximum number of bonds with outstanding supply of one underlying asset in `BondsByAsset`.
+		/// Bonds over the bound are issued, but not indexed.
+		#[pallet::constant]
+		type MaxBondsPerAsset: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// Router weight information.
+		type RouterWeightInfo: AmmTradeWeights<Trade<AssetId>>;
 	}
 
 	#[pallet::storage]
@@ -145,6 +403,182 @@ pub mod pallet {
 	#[pallet::getter(fn bond)]
 	pub(super) type Bonds<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, (AssetId, Moment)>;
 
+	#[pallet::storage]
+	/// Bonds with outstanding supply of an underlying asset, in the order of issuance.
+	/// Fully redeemed bonds are removed and added again when they are issued again.
+	/// Maps underlying asset ID -> bond IDs
+	#[pallet::getter(fn bonds_of_asset)]
+	pub(super) type BondsByAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, BoundedVec<AssetId, T::MaxBondsPerAsset>, ValueQuery>;
+
+	#[pallet::storage]
+	/// Issuers of registered bonds. The issuer is the account which registered the bonds.
+	/// Maps bond ID -> issuer account ID
+	#[pallet::getter(fn bond_issuer)]
+	pub(super) type BondIssuers<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, T::AccountId>;
+
+	#[pallet::storage]
+	/// Managers of registered bonds. The manager can redeem the bonds on behalf of the holders.
+	/// Maps bond ID -> manager account ID
+	#[pallet::getter(fn bond_manager)]
+	pub(super) type BondManagers<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, T::AccountId>;
+
+	#[pallet::storage]
+	/// Outstanding discount of bonds issued below the face value, or prefunded yield of bonds issued with a yield.
+	/// Redemptions of the bonds are paid their share of the discount on top of the amount of the bonds.
+	/// Maps bond ID -> (outstanding amount of the bonds, outstanding discount)
+	#[pallet::getter(fn bond_discount)]
+	pub(super) type BondDiscounts<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, (T::Balance, T::Balance)>;
+
+	#[pallet::storage]
+	/// Amount of the underlying assets locked in outstanding bonds per issuer.
+	/// Bonds are accounted to the account which issued them, also when the bonds were registered by another account.
+	/// Dev note: bonds issued before the storage was introduced are not included.
+	#[pallet::getter(fn issued_by_issuer)]
+	pub(super) type IssuedByIssuer<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// Amount of the underlying assets locked in outstanding bonds per bond and issuer.
+	/// Used to release the issuance of the issuers of the bonds on redemption.
+	/// Maps (bond ID, issuer account ID) -> amount
+	#[pallet::getter(fn issued_by_issuer_per_bond)]
+	pub(super) type IssuedByIssuerPerBond<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AssetId, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// Amount of the underlying assets a spender is allowed to issue bonds with on behalf of an owner.
+	/// Maps (owner account ID, spender account ID) -> amount
+	#[pallet::getter(fn issuance_allowance)]
+	pub(super) type IssuanceAllowance<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// Amount of bonds a spender is allowed to transfer on behalf of an owner.
+	/// Maps (owner account ID, spender account ID, bond ID) -> amount
+	#[pallet::getter(fn transfer_allowance)]
+	pub(super) type TransferAllowance<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, AssetId>,
+		),
+		T::Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// URI of the external documentation of registered bonds, e.g. a prospectus.
+	/// Maps bond ID -> metadata URI
+	#[pallet::getter(fn bond_metadata_uri)]
+	pub(super) type BondMetadataUri<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, BoundedVec<u8, T::MaxUriLen>>;
+
+	#[pallet::storage]
+	/// Unfilled bids of the primary issuance auction, highest maximum price first.
+	/// Bids with the same maximum price are ordered by the time they were placed.
+	/// Maps (underlying asset ID, maturity) -> bids
+	#[pallet::getter(fn bid_book)]
+	pub(super) type BidBooks<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(AssetId, Moment),
+		BoundedVec<Bid<T::AccountId, T::Balance>, T::MaxBidsPerBook>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// ID assigned to the next bid.
+	pub(super) type NextBidId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	/// Bonds that can't be transferred until mature.
+	/// Maps bond ID -> ()
+	pub(super) type TransferLocked<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, ()>;
+
+	#[pallet::storage]
+	/// Bonds for which `BondMaturingSoon` event was already emitted.
+	/// Maps bond ID -> ()
+	pub(super) type MaturityWarned<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, ()>;
+
+	#[pallet::storage]
+	/// ID of the last bond checked for the maturity warning.
+	/// Next check continues with the following bond, or from the beginning if not set.
+	pub(super) type MaturityCheckCursor<T: Config> = StorageValue<_, AssetId>;
+
+	#[pallet::storage]
+	/// Underlying asset in progress of the gauge, number of its bonds already checked
+	/// and their outstanding supply.
+	/// Next gauge continues with the following bond of the asset, or from the beginning of `BondsByAsset` if not set.
+	/// Dev note: bonds added or removed from `BondsByAsset` while the asset is in progress can be skipped or counted
+	/// twice by the gauge.
+	pub(super) type GaugeCursor<T: Config> = StorageValue<_, (AssetId, u32, T::Balance)>;
+
+	#[pallet::storage]
+	/// Redemptions waiting for the pallet account to have enough of the underlying asset.
+	/// Maps queue ID -> (account ID, bond ID, amount)
+	#[pallet::getter(fn queued_redemption)]
+	pub(super) type RedemptionQueue<T: Config> =
+		StorageMap<_, Blake2_128Concat, u64, (T::AccountId, AssetId, T::Balance)>;
+
+	#[pallet::storage]
+	/// ID of the oldest queued redemption which has not been processed yet.
+	/// Redemptions after the head can be already processed, if the head couldn't be covered.
+	pub(super) type RedemptionQueueHead<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	/// Position of the pass over the redemption queue which continues in the next block.
+	/// (ID of the next queued redemption to scan, underlying assets blocked in this pass)
+	/// Queued redemptions of blocked assets are skipped until the pass completes, so they stay in FIFO order.
+	/// The next pass starts from `RedemptionQueueHead`.
+	pub(super) type RedemptionQueueCursor<T: Config> =
+		StorageValue<_, (u64, BoundedVec<AssetId, ConstU32<MAX_BLOCKED_REDEMPTION_ASSETS>>)>;
+
+	#[pallet::storage]
+	/// ID assigned to the next queued redemption.
+	pub(super) type NextQueuedRedemptionId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	/// Total amount of queued redemptions per underlying asset.
+	/// Maps underlying asset ID -> amount
+	#[pallet::getter(fn queued_amount)]
+	pub(super) type QueuedAmounts<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, T::Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// Total protocol fees collected from the issuance of bonds.
+	/// Maps underlying asset ID -> amount
+	#[pallet::getter(fn cumulative_fees)]
+	pub(super) type CumulativeFees<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, T::Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// Time the maturity of all bonds was frozen at. Redemptions use it as the current time while set.
+	#[pallet::getter(fn global_maturity_freeze)]
+	pub(super) type GlobalMaturityFreeze<T: Config> = StorageValue<_, Moment>;
+
+	#[pallet::storage]
+	/// Fee charged on the amount paid out by redemptions of bonds issued with `issue_with_redemption_fee`.
+	/// Maps bond ID -> redemption fee
+	#[pallet::getter(fn bond_redemption_fee)]
+	pub(super) type RedemptionFees<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, Permill>;
+
+	#[pallet::storage]
+	/// Redemption conditions of bonds issued with `issue_conditional`.
+	/// Maps bond ID -> redemption condition
+	#[pallet::getter(fn bond_redemption_condition)]
+	pub(super) type RedemptionConditions<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, T::RedemptionCondition>;
+
+	#[pallet::storage]
+	/// Vesting schedules of bonds issued with `issue_vested`.
+	/// Maps (bond ID, account ID) -> vesting schedule
+	#[pallet::getter(fn bond_vesting)]
+	pub(super) type BondVesting<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		VestingInfo<T::Balance, BlockNumberFor<T>>,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -161,6 +595,7 @@ pub mod pallet {
 			bond_id: AssetId,
 			amount: T::Balance,
 			fee: T::Balance,
+			maturity: Moment,
 		},
 		/// Bonds were redeemed
 		Redeemed {
@@ -168,6 +603,169 @@ pub mod pallet {
 			bond_id: AssetId,
 			amount: T::Balance,
 		},
+		/// Dust remainder of the bonds was redeemed together with a redemption
+		DustRedeemed {
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		},
+		/// Transfer lock of bonds was released
+		Unlocked { bond_id: AssetId },
+		/// Redemption of bonds in a batch failed and was skipped
+		RedeemSkipped {
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+			error: DispatchError,
+		},
+		/// Redemption was queued because the pallet account doesn't have enough of the underlying asset
+		RedemptionQueued {
+			id: u64,
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		},
+		/// Queued redemption was cancelled and the bonds were returned
+		QueuedRedemptionCancelled {
+			id: u64,
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		},
+		/// Bonds were redeemed and the underlying asset was sold for the substitute asset
+		RedeemedAs {
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+			asset_out: AssetId,
+			amount_out: T::Balance,
+		},
+		/// Bonds were redeemed and the underlying asset was staked
+		RedeemedAndStaked {
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+			staked: T::Balance,
+		},
+		/// Bonds of the holder were redeemed by the manager of the bonds
+		ManagerRedeemed {
+			manager: T::AccountId,
+			holder: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+			dest: T::AccountId,
+		},
+		/// Spender was allowed to issue bonds on behalf of the owner
+		IssuanceApproved {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Spender was allowed to transfer bonds on behalf of the owner
+		TransferApproved {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		},
+		/// Bonds were transferred by a spender on behalf of the owner
+		TransferredFrom {
+			spender: T::AccountId,
+			owner: T::AccountId,
+			dest: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		},
+		/// Issuer added yield to outstanding bonds
+		ToppedUp {
+			issuer: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		},
+		/// Bonds entered the maturity warning window
+		BondMaturingSoon { bond_id: AssetId, maturity: Moment },
+		/// Outstanding supply of bonds, emitted every `GaugeInterval` blocks
+		OutstandingSupply { asset: AssetId, amount: T::Balance },
+		/// Outstanding supply of all bonds of an underlying asset, emitted once all its bonds were checked
+		UnderlyingOutstandingSupply { asset: AssetId, amount: T::Balance },
+		/// Bonds were clawed back from a holder
+		Clawback {
+			bond_id: AssetId,
+			holder: T::AccountId,
+			amount: T::Balance,
+			dest: T::AccountId,
+			underlying_amount: T::Balance,
+		},
+		/// Metadata URI of bonds was set
+		MetadataUriSet {
+			bond_id: AssetId,
+			uri: BoundedVec<u8, T::MaxUriLen>,
+		},
+		/// Bid for bonds was placed
+		BidPlaced {
+			id: u64,
+			who: T::AccountId,
+			asset_id: AssetId,
+			maturity: Moment,
+			max_price: Permill,
+			amount: T::Balance,
+		},
+		/// Bid was cancelled and the escrow was refunded
+		BidCancelled {
+			id: u64,
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Bid was filled, fully or partially, at the clearing price
+		BidFilled {
+			id: u64,
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+			price: Permill,
+		},
+		/// Bids were filled with newly issued bonds
+		BidsFilled {
+			issuer: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+			clearing_price: Permill,
+		},
+		/// Maturity of all bonds was frozen at the time or unfrozen
+		MaturityFreezeSet { frozen_at: Option<Moment> },
+		/// Reserve of the underlying asset didn't cover the share of the yield of the redeemed bonds.
+		/// The principal was paid in full and the yield only up to the reserve.
+		RedemptionShortfall {
+			bond_id: AssetId,
+			holder: T::AccountId,
+			expected_yield: T::Balance,
+			paid_yield: T::Balance,
+		},
+		/// Underlying asset of the redeemed bonds was removed from the asset registry
+		/// and the redemption was paid in `UnderlyingFallbackAsset`.
+		RedeemedInFallbackAsset {
+			who: T::AccountId,
+			bond_id: AssetId,
+			asset_id: AssetId,
+			amount: T::Balance,
+		},
+		/// Issued bonds were locked by a vesting schedule
+		VestingScheduleSet {
+			who: T::AccountId,
+			bond_id: AssetId,
+			schedule: VestingInfo<T::Balance, BlockNumberFor<T>>,
+		},
+		/// Redemption fee of the bonds was paid to `FeeReceiver`
+		RedemptionFeePaid {
+			who: T::AccountId,
+			bond_id: AssetId,
+			fee: T::Balance,
+		},
+		/// Redemption condition of the bonds was set
+		RedemptionConditionSet {
+			bond_id: AssetId,
+			condition: T::RedemptionCondition,
+		},
 	}
 
 	#[pallet::error]
@@ -187,6 +785,98 @@ pub mod pallet {
 		InvalidBondName,
 		/// Bond's name parsing was now successful
 		FailToParseName,
+		/// Bonds can't be transferred until mature
+		TransferLocked,
+		/// Transfer lock can be set only for new bonds
+		TransferLockNotAllowed,
+		/// Bonds are not transfer locked
+		NotTransferLocked,
+		/// Bonds are mature and the grace window has passed
+		AlreadyMatured,
+		/// Number of redemptions exceeds `MaxBatch`
+		TooManyRedemptions,
+		/// Queued redemption not found
+		QueuedRedemptionNotFound,
+		/// Queued redemption is owned by another account
+		NotQueuedRedemptionOwner,
+		/// Substitute asset is the underlying asset or the payout asset of the bonds
+		InvalidSubstituteAsset,
+		/// Issuance exceeds `MaxIssuancePerIssuer` of the issuer
+		IssuerCapExceeded,
+		/// Pallet account doesn't hold enough of the underlying asset to redeem the bonds immediately
+		InsufficientReserve,
+		/// Account is not the manager of the bonds
+		NotManager,
+		/// Manager can be set only for new bonds
+		ManagerNotAllowed,
+		/// Discount can be set only for new bonds and discounted bonds can't be issued again
+		DiscountNotAllowed,
+		/// Discount is not lower than the amount of the underlying asset without the protocol fee
+		InvalidDiscount,
+		/// Yield is not lower than the principal and the yield without the protocol fee
+		YieldTooHigh,
+		/// Amount exceeds the issuance or transfer allowance of the spender
+		InsufficientAllowance,
+		/// Account is not the issuer of the bonds
+		NotIssuer,
+		/// Metadata URI is empty
+		EmptyMetadataUri,
+		/// Metadata URI is longer than `MaxUriLen`
+		MetadataUriTooLong,
+		/// Bid amount or maximum price is zero
+		InvalidBid,
+		/// Number of bids exceeds `MaxBidsPerBook`
+		BidBookFull,
+		/// Bid not found
+		BidNotFound,
+		/// Bid is owned by another account
+		NotBidOwner,
+		/// There are no bids to fill or no bonds to fill them with
+		NothingToFill,
+		/// Redeemed amount is below `MinRedemptionAmount` and doesn't close out the bond balance
+		RedemptionTooSmall,
+		/// Underlying asset of the bonds is not the stakeable asset
+		NotStakeable,
+		/// Top-up amount is zero or there are no outstanding bonds
+		NothingToTopUp,
+		/// Underlying asset was removed from the asset registry and no fallback asset is configured
+		UnderlyingRemoved,
+		/// Vesting schedule locks nothing, unlocks nothing per block or locks more than the issued bonds
+		InvalidVestingSchedule,
+		/// Account already has a vesting schedule for the bonds
+		VestingScheduleExists,
+		/// Bonds are locked by the vesting schedule of the account
+		VestingLocked,
+		/// Redemption fee can be set only for new bonds
+		RedemptionFeeNotAllowed,
+		/// Redemption condition can be set only for new bonds
+		RedemptionConditionNotAllowed,
+		/// Redemption condition of the bonds is not met
+		ConditionNotMet,
+		/// Maturity of the bonds is already frozen
+		MaturityAlreadyFrozen,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let gauge_weight = if Self::is_gauge_block(n) {
+				// OutstandingSupply events are emitted in on_finalize
+				<T as Config>::WeightInfo::emit_outstanding_supply().saturating_mul(T::MaxAssetsPerGauge::get().into())
+			} else {
+				Weight::zero()
+			};
+
+			Self::process_redemption_queue()
+				.saturating_add(Self::check_maturity_warnings())
+				.saturating_add(gauge_weight)
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			if Self::is_gauge_block(n) {
+				Self::emit_outstanding_supply_gauge();
+			}
+		}
 	}
 
 	#[pallet::call]
@@ -208,6 +898,7 @@ pub mod pallet {
 		/// - `asset_id`: underlying asset id
 		/// - `amount`: the amount of the underlying asset
 		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
 		///
 		/// Emits `BondTokenCreated` event when successful and new bonds were registered.
 		/// Emits `BondsIssued` event when successful.
@@ -217,68 +908,16 @@ pub mod pallet {
 		pub fn issue(origin: OriginFor<T>, asset_id: AssetId, amount: T::Balance, maturity: Moment) -> DispatchResult {
 			let who = T::IssueOrigin::ensure_origin(origin)?;
 
-			ensure!(
-				T::AssetTypeWhitelist::contains(
-					&T::AssetRegistry::asset_type(asset_id).ok_or(Error::<T>::AssetNotFound)?
-				),
-				Error::<T>::DisallowedAsset
-			);
-
-			let fee = T::ProtocolFee::get().mul_ceil(amount);
-			let amount_without_fee = amount.saturating_sub(fee);
-			let pallet_account = Self::pallet_account_id();
-
-			let bond_id = match BondIds::<T>::get((asset_id, maturity)) {
-				Some(bond_id) => bond_id,
-				None => {
-					// register new bonds
-					ensure!(maturity >= T::TimestampProvider::now(), Error::<T>::InvalidMaturity);
-
-					let ed = T::ExistentialDeposits::get(&asset_id);
-					let b_name = Self::bond_name(asset_id, maturity);
-					let bond_id = T::AssetRegistry::register_insufficient_asset(
-						None,
-						Some(b_name.try_into().map_err(|_| Error::<T>::InvalidBondName)?),
-						AssetKind::Bond,
-						Some(ed),
-						None,
-						None,
-						None,
-						None,
-					)?;
-
-					Bonds::<T>::insert(bond_id, (asset_id, maturity));
-					BondIds::<T>::insert((asset_id, maturity), bond_id);
-
-					Self::deposit_event(Event::TokenCreated {
-						issuer: who.clone(),
-						asset_id,
-						bond_id,
-						maturity,
-					});
-
-					bond_id
-				}
-			};
-
-			T::Currency::transfer(asset_id, &who, &pallet_account, amount_without_fee)?;
-			T::Currency::transfer(asset_id, &who, &T::FeeReceiver::get(), fee)?;
-			T::Currency::deposit(bond_id, &who, amount_without_fee)?;
-
-			Self::deposit_event(Event::Issued {
-				issuer: who,
-				bond_id,
-				amount: amount_without_fee,
-				fee,
-			});
-
-			Ok(())
+			Self::do_issue(who, asset_id, amount, maturity, false, None, Zero::zero())
 		}
 
 		/// Redeem bonds for the underlying asset.
 		/// The amount of the underlying asset the `origin` receives is 1:1 to the `amount` of the bonds.
 		/// Anyone who holds the bonds is able to redeem them.
 		/// Bonds can be both partially or fully redeemed.
+		/// If `T::DustRedemption` is enabled, a remainder below `T::BondDustThreshold` is redeemed as well.
+		/// Redemption below `T::MinRedemptionAmount` is allowed only when it closes out the bond balance.
+		/// If the underlying asset was removed from the asset registry, `T::UnderlyingFallbackAsset` is paid instead.
 		///
 		/// Parameters:
 		/// - `origin`: account id
@@ -286,41 +925,1863 @@ pub mod pallet {
 		/// - `amount`: the amount of the bonds to redeem for the underlying asset
 		///
 		/// Emits `BondsRedeemed` event when successful.
+		/// Emits `DustRedeemed` event when the dust remainder was redeemed.
+		/// Emits `RedeemedInFallbackAsset` event when the fallback asset was paid.
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::redeem())]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem().saturating_add(T::ConditionOracle::is_met_weight()))]
 		pub fn redeem(origin: OriginFor<T>, bond_id: AssetId, amount: T::Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			Self::do_redeem(who, bond_id, amount)
+		}
 
-			let now = T::TimestampProvider::now();
-			ensure!(now >= maturity, Error::<T>::NotMature);
+		/// Issue new fungible bonds which can't be transferred until mature.
+		/// Works the same way as `issue`, except that newly registered bonds are marked as transfer locked.
+		/// Already registered bonds can be issued only if they are transfer locked as well.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `amount`: the amount of the underlying asset
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		///
+		/// Emits `TokenCreated` event when successful and new bonds were registered.
+		/// Emits `Issued` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_locked())]
+		pub fn issue_locked(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: T::Balance,
+			maturity: Moment,
+		) -> DispatchResult {
+			let who = T::IssueOrigin::ensure_origin(origin)?;
 
-			T::Currency::withdraw(bond_id, &who, amount)?;
+			Self::do_issue(who, asset_id, amount, maturity, true, None, Zero::zero())
+		}
 
-			let pallet_account = Self::pallet_account_id();
-			T::Currency::transfer(underlying_asset_id, &pallet_account, &who, amount)?;
+		/// Release the transfer lock of bonds before maturity.
+		/// Can be called only before the maturity or within `T::PostMaturityGrace` after it.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`
+		/// - `bond_id`: bond asset id
+		///
+		/// Emits `Unlocked` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::unlock())]
+		pub fn unlock(origin: OriginFor<T>, bond_id: AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let (_, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			Self::ensure_authority_can_act(maturity)?;
+
+			ensure!(
+				TransferLocked::<T>::take(bond_id).is_some(),
+				Error::<T>::NotTransferLocked
+			);
 
-			Self::deposit_event(Event::Redeemed { who, bond_id, amount });
+			Self::deposit_event(Event::Unlocked { bond_id });
 
 			Ok(())
 		}
-	}
-}
-
-impl<T: Config> Pallet<T> {
-	/// The account ID of the bonds pallet.
-	///
-	/// This actually does computation. If you need to keep using it, then make sure you cache the
-	/// value and only call this once.
-	pub fn pallet_account_id() -> T::AccountId {
-		T::PalletId::get().into_account_truncating()
-	}
 
-	/// Return bond token name
-	pub fn bond_name(asset_id: AssetId, when: Moment) -> Vec<u8> {
-		let mut buf: Vec<u8> = Vec::new();
+		/// Redeem multiple bonds for the underlying assets in one call.
+		/// Each redemption works the same way as `redeem`.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `redemptions`: list of bond asset ids and amounts of the bonds to redeem, limited by `T::MaxBatch`
+		/// - `stop_on_error`: if true, the whole call fails when any redemption fails. Otherwise failed redemptions
+		///   are skipped.
+		///
+		/// Emits `Redeemed` event for each successful redemption.
+		/// Emits `RedeemSkipped` event for each skipped redemption.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem_many(redemptions.len() as u32)
+			.saturating_add(T::ConditionOracle::is_met_weight().saturating_mul(redemptions.len() as u64)))]
+		pub fn redeem_many(
+			origin: OriginFor<T>,
+			redemptions: Vec<(AssetId, T::Balance)>,
+			stop_on_error: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				redemptions.len() <= T::MaxBatch::get() as usize,
+				Error::<T>::TooManyRedemptions
+			);
+
+			for (bond_id, amount) in redemptions {
+				let result = with_transaction(|| {
+					let r = Self::do_redeem(who.clone(), bond_id, amount);
+					match r {
+						Ok(()) => TransactionOutcome::Commit(r),
+						Err(_) => TransactionOutcome::Rollback(r),
+					}
+				});
+
+				if let Err(error) = result {
+					if stop_on_error {
+						return Err(error);
+					}
+					Self::deposit_event(Event::RedeemSkipped {
+						who: who.clone(),
+						bond_id,
+						amount,
+						error,
+					});
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Cancel a queued redemption.
+		/// The bonds of the queued redemption are returned to the `origin`.
+		///
+		/// Parameters:
+		/// - `origin`: account id which queued the redemption
+		/// - `id`: queued redemption id
+		///
+		/// Emits `QueuedRedemptionCancelled` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_queued_redemption())]
+		pub fn cancel_queued_redemption(origin: OriginFor<T>, id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (owner, bond_id, amount) = RedemptionQueue::<T>::get(id).ok_or(Error::<T>::QueuedRedemptionNotFound)?;
+			ensure!(owner == who, Error::<T>::NotQueuedRedemptionOwner);
+
+			let (underlying_asset_id, _) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+
+			RedemptionQueue::<T>::remove(id);
+			QueuedAmounts::<T>::mutate(underlying_asset_id, |queued| *queued = queued.saturating_sub(amount));
+
+			T::Currency::deposit(bond_id, &who, amount)?;
+			// the bonds were removed from the index when they were burned on queueing
+			Self::index_bond(underlying_asset_id, bond_id);
+
+			Self::deposit_event(Event::QueuedRedemptionCancelled {
+				id,
+				who,
+				bond_id,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Redeem bonds and sell the underlying asset for a substitute asset.
+		/// The underlying asset is redeemed the same way as in `redeem` and sold for `out_asset`
+		/// via `T::Router`, using the route provided by the router.
+		/// The whole amount paid out by the redemption is sold, including the redeemed dust remainder.
+		/// If the redemption is paid in `UnderlyingFallbackAsset`, the fallback asset is sold instead.
+		/// The redemption can't be queued, so the pallet account needs to hold enough of the payout asset.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `bond_id`: bond asset id
+		/// - `amount`: the amount of the bonds to redeem
+		/// - `out_asset`: substitute asset id the payout asset is sold for
+		/// - `min_out`: minimum amount of `out_asset` to receive
+		///
+		/// Emits `Redeemed` and `RedeemedAs` events when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem()
+			.saturating_add(Pallet::<T>::redeem_as_router_weight(*bond_id, *out_asset))
+			.saturating_add(T::ConditionOracle::is_met_weight()))]
+		pub fn redeem_as(
+			origin: OriginFor<T>,
+			bond_id: AssetId,
+			amount: T::Balance,
+			out_asset: AssetId,
+			min_out: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let (underlying_asset_id, _) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			ensure!(out_asset != underlying_asset_id, Error::<T>::InvalidSubstituteAsset);
+			let payout_asset_id = Self::payout_asset(underlying_asset_id)?;
+			ensure!(out_asset != payout_asset_id, Error::<T>::InvalidSubstituteAsset);
+
+			let redeemed = amount.saturating_add(Self::dust_remainder(&who, bond_id, amount));
+			let required =
+				QueuedAmounts::<T>::get(payout_asset_id).saturating_add(Self::redemption_amount(bond_id, redeemed));
+			ensure!(
+				T::Currency::free_balance(payout_asset_id, &Self::pallet_account_id()) >= required,
+				Error::<T>::InsufficientReserve
+			);
+
+			let payout_before = T::Currency::free_balance(payout_asset_id, &who);
+			Self::do_redeem(who.clone(), bond_id, amount)?;
+			// the amount actually paid out, without the redemption fee
+			let amount_in = T::Currency::free_balance(payout_asset_id, &who).saturating_sub(payout_before);
+
+			let balance_before = T::Currency::free_balance(out_asset, &who);
+			let route = T::Router::get_route(AssetPair::new(payout_asset_id, out_asset));
+			T::Router::sell(origin, payout_asset_id, out_asset, amount_in, min_out, route)?;
+			let amount_out = T::Currency::free_balance(out_asset, &who).saturating_sub(balance_before);
+
+			Self::deposit_event(Event::RedeemedAs {
+				who,
+				bond_id,
+				amount,
+				asset_out: out_asset,
+				amount_out,
+			});
+
+			Ok(())
+		}
+
+		/// Issue new fungible bonds with a manager.
+		/// Works the same way as `issue`, except that newly registered bonds are assigned the `manager`,
+		/// who can redeem the bonds on behalf of the holders via `manager_redeem`.
+		/// Already registered bonds can be issued only if they have the same manager.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `amount`: the amount of the underlying asset
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		/// - `manager`: manager of the bonds
+		///
+		/// Emits `TokenCreated` event when successful and new bonds were registered.
+		/// Emits `Issued` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_managed())]
+		pub fn issue_managed(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: T::Balance,
+			maturity: Moment,
+			manager: T::AccountId,
+		) -> DispatchResult {
+			let who = T::IssueOrigin::ensure_origin(origin)?;
+
+			Self::do_issue(who, asset_id, amount, maturity, false, Some(manager), Zero::zero())
+		}
+
+		/// Redeem bonds of a holder on behalf of the holder.
+		/// The bonds are withdrawn from the `holder` and the underlying asset is transferred to `dest`.
+		/// The redemption can't be queued, so the pallet account needs to hold enough of the underlying asset.
+		/// If the underlying asset was removed from the asset registry, `UnderlyingFallbackAsset` is transferred instead.
+		///
+		/// Parameters:
+		/// - `origin`: manager of the bonds
+		/// - `bond_id`: bond asset id
+		/// - `holder`: account holding the bonds
+		/// - `amount`: the amount of the bonds to redeem
+		/// - `dest`: account receiving the underlying asset
+		///
+		/// Emits `Redeemed` and `ManagerRedeemed` events when successful.
+		/// Emits `RedeemedInFallbackAsset` event when the fallback asset was paid.
+		///
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::manager_redeem().saturating_add(T::ConditionOracle::is_met_weight()))]
+		pub fn manager_redeem(
+			origin: OriginFor<T>,
+			bond_id: AssetId,
+			holder: T::AccountId,
+			amount: T::Balance,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			ensure!(Self::bond_manager(bond_id) == Some(who.clone()), Error::<T>::NotManager);
+
+			ensure!(Self::maturity_now() >= maturity, Error::<T>::NotMature);
+			Self::ensure_condition_met(bond_id)?;
+			Self::ensure_vested(bond_id, &holder, amount)?;
+
+			let payout_asset_id = Self::payout_asset(underlying_asset_id)?;
+
+			let pallet_account = Self::pallet_account_id();
+			let amount_out = Self::redemption_amount(bond_id, amount);
+			let required = QueuedAmounts::<T>::get(payout_asset_id).saturating_add(amount_out);
+			ensure!(
+				T::Currency::free_balance(payout_asset_id, &pallet_account) >= required,
+				Error::<T>::InsufficientReserve
+			);
+
+			T::Currency::withdraw(bond_id, &holder, amount)?;
+			Self::unindex_if_redeemed(underlying_asset_id, bond_id);
+			Self::release_discount(bond_id, amount);
+			Self::pay_redemption(payout_asset_id, bond_id, &dest, amount_out)?;
+			Self::release_issuance(bond_id, &holder, amount_out);
+
+			Self::deposit_event(Event::Redeemed {
+				who: holder.clone(),
+				bond_id,
+				amount,
+			});
+			if payout_asset_id != underlying_asset_id {
+				Self::deposit_event(Event::RedeemedInFallbackAsset {
+					who: holder.clone(),
+					bond_id,
+					asset_id: payout_asset_id,
+					amount: amount_out,
+				});
+			}
+			Self::deposit_event(Event::ManagerRedeemed {
+				manager: who,
+				holder,
+				bond_id,
+				amount,
+				dest,
+			});
+
+			Ok(())
+		}
+
+		/// Issue new fungible bonds below the face value.
+		/// Works the same way as `issue`, except that the issuer receives the amount of the bonds reduced
+		/// by the `discount`, while the whole `face_value` is locked in the pallet account.
+		/// Redemptions of the bonds are paid their share of the discount, so redemption of all bonds pays out
+		/// the face value. Only new bonds can be issued with a discount and discounted bonds can't be issued again.
+		/// Redemption of discounted bonds is never queued.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `face_value`: the amount of the underlying asset
+		/// - `discount`: the amount the bonds are issued below the face value.
+		///   Must be lower than the face value without the protocol fee.
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		///
+		/// Emits `TokenCreated` and `Issued` events when successful.
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_discounted())]
+		pub fn issue_discounted(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			face_value: T::Balance,
+			discount: T::Balance,
+			maturity: Moment,
+		) -> DispatchResult {
+			let who = T::IssueOrigin::ensure_origin(origin)?;
+
+			Self::do_issue(who, asset_id, face_value, maturity, false, None, discount)
+		}
+
+		/// Allow a spender to issue bonds on behalf of the `origin`.
+		/// The allowance replaces the previous allowance of the spender. Zero amount removes the allowance.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the funds, needs to be `T::IssueOrigin`
+		/// - `spender`: account allowed to issue bonds on behalf of the owner
+		/// - `amount`: the amount of the underlying assets the spender can issue bonds with
+		///
+		/// Emits `IssuanceApproved` event when successful.
+		///
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_issuance())]
+		pub fn approve_issuance(origin: OriginFor<T>, spender: T::AccountId, amount: T::Balance) -> DispatchResult {
+			let owner = T::IssueOrigin::ensure_origin(origin)?;
+
+			if amount.is_zero() {
+				IssuanceAllowance::<T>::remove(&owner, &spender);
+			} else {
+				IssuanceAllowance::<T>::insert(&owner, &spender, amount);
+			}
+
+			Self::deposit_event(Event::IssuanceApproved { owner, spender, amount });
+
+			Ok(())
+		}
+
+		/// Issue new fungible bonds on behalf of the owner.
+		/// Works the same way as `issue`, except that the underlying asset is taken from the `owner`,
+		/// the `owner` receives the bonds and becomes the issuer of new bonds.
+		/// The `amount` is deducted from the issuance allowance of the `origin`.
+		///
+		/// Parameters:
+		/// - `origin`: spender approved by the owner with `approve_issuance`
+		/// - `owner`: account the underlying asset is taken from
+		/// - `asset_id`: underlying asset id
+		/// - `amount`: the amount of the underlying asset
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		///
+		/// Emits `TokenCreated` event when successful and new bonds were registered.
+		/// Emits `Issued` event when successful.
+		///
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_from())]
+		pub fn issue_from(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			asset_id: AssetId,
+			amount: T::Balance,
+			maturity: Moment,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+
+			IssuanceAllowance::<T>::try_mutate_exists(&owner, &spender, |maybe_allowance| -> DispatchResult {
+				let allowance = maybe_allowance
+					.unwrap_or_default()
+					.checked_sub(&amount)
+					.ok_or(Error::<T>::InsufficientAllowance)?;
+				*maybe_allowance = if allowance.is_zero() { None } else { Some(allowance) };
+				Ok(())
+			})?;
+
+			Self::do_issue(owner, asset_id, amount, maturity, false, None, Zero::zero())
+		}
+
+		/// Issue new fungible bonds with a metadata URI.
+		/// Works the same way as `issue`, and sets the metadata URI of the bonds.
+		/// Metadata URI of already registered bonds can be set only by the issuer of the bonds.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `amount`: the amount of the underlying asset
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		/// - `uri`: non-empty URI of the external documentation, at most `T::MaxUriLen` long
+		///
+		/// Emits `TokenCreated` event when successful and new bonds were registered.
+		/// Emits `Issued` and `MetadataUriSet` events when successful.
+		///
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue()
+			.saturating_add(<T as Config>::WeightInfo::set_metadata_uri()))]
+		pub fn issue_with_metadata_uri(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: T::Balance,
+			maturity: Moment,
+			uri: Vec<u8>,
+		) -> DispatchResult {
+			let who = T::IssueOrigin::ensure_origin(origin)?;
+			let uri = Self::validate_metadata_uri(uri)?;
+
+			Self::do_issue(who.clone(), asset_id, amount, maturity, false, None, Zero::zero())?;
+
+			let bond_id = Self::bond_id((asset_id, Self::round_maturity(maturity))).ok_or(Error::<T>::NotRegistered)?;
+			Self::do_set_metadata_uri(&who, bond_id, uri)
+		}
+
+		/// Set the metadata URI of bonds.
+		/// The URI replaces the previous metadata URI of the bonds.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of the bonds
+		/// - `bond_id`: bond asset id
+		/// - `uri`: non-empty URI of the external documentation, at most `T::MaxUriLen` long
+		///
+		/// Emits `MetadataUriSet` event when successful.
+		///
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_metadata_uri())]
+		pub fn set_metadata_uri(origin: OriginFor<T>, bond_id: AssetId, uri: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let uri = Self::validate_metadata_uri(uri)?;
+			ensure!(Self::bond(bond_id).is_some(), Error::<T>::NotRegistered);
+
+			Self::do_set_metadata_uri(&who, bond_id, uri)
+		}
+
+		/// Claw back bonds of a holder before maturity.
+		/// The bonds are burned from the `holder` and the underlying asset is transferred to `dest`.
+		/// Meant to be used as an emergency power in confirmed fraud cases.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::ClawbackOrigin`
+		/// - `bond_id`: bond asset id
+		/// - `holder`: account holding the bonds
+		/// - `amount`: the amount of the bonds to claw back
+		/// - `dest`: account receiving the underlying asset, e.g. the treasury
+		///
+		/// If the underlying asset was removed from the asset registry, `UnderlyingFallbackAsset` is transferred instead.
+		///
+		/// Emits `Clawback` event when successful.
+		/// Emits `RedeemedInFallbackAsset` event when the fallback asset was paid.
+		///
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::clawback())]
+		pub fn clawback(
+			origin: OriginFor<T>,
+			bond_id: AssetId,
+			holder: T::AccountId,
+			amount: T::Balance,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			T::ClawbackOrigin::ensure_origin(origin)?;
+
+			let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+
+			let now = T::TimestampProvider::now();
+			ensure!(now < maturity, Error::<T>::AlreadyMatured);
+
+			let payout_asset_id = Self::payout_asset(underlying_asset_id)?;
+
+			let pallet_account = Self::pallet_account_id();
+			let underlying_amount = Self::redemption_amount(bond_id, amount);
+			let required = QueuedAmounts::<T>::get(payout_asset_id).saturating_add(underlying_amount);
+			ensure!(
+				T::Currency::free_balance(payout_asset_id, &pallet_account) >= required,
+				Error::<T>::InsufficientReserve
+			);
+
+			T::Currency::withdraw(bond_id, &holder, amount)?;
+			Self::unindex_if_redeemed(underlying_asset_id, bond_id);
+			Self::release_discount(bond_id, amount);
+			T::Currency::transfer(payout_asset_id, &pallet_account, &dest, underlying_amount)?;
+			Self::release_issuance(bond_id, &holder, underlying_amount);
+
+			Self::deposit_event(Event::Clawback {
+				bond_id,
+				holder: holder.clone(),
+				amount,
+				dest,
+				underlying_amount,
+			});
+			if payout_asset_id != underlying_asset_id {
+				Self::deposit_event(Event::RedeemedInFallbackAsset {
+					who: holder,
+					bond_id,
+					asset_id: payout_asset_id,
+					amount: underlying_amount,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Place a bid for bonds in the primary issuance auction.
+		/// The maximum cost of the bid is transferred to the escrow account until the bid is filled or cancelled.
+		///
+		/// Parameters:
+		/// - `origin`: account placing the bid
+		/// - `asset_id`: underlying asset id
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		/// - `max_price`: the maximum price of the bonds as a share of the face value
+		/// - `amount`: the amount of the bonds
+		///
+		/// Emits `BidPlaced` event when successful.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			maturity: Moment,
+			max_price: Permill,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero() && !max_price.is_zero(), Error::<T>::InvalidBid);
+
+			let maturity = Self::round_maturity(maturity);
+			ensure!(maturity >= T::TimestampProvider::now(), Error::<T>::InvalidMaturity);
+
+			let id = NextBidId::<T>::mutate(|next_id| {
+				let id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				id
+			});
+			let escrowed = max_price.mul_ceil(amount);
+
+			BidBooks::<T>::try_mutate((asset_id, maturity), |book| -> DispatchResult {
+				let position = book
+					.iter()
+					.position(|bid| bid.max_price < max_price)
+					.unwrap_or(book.len());
+				book.try_insert(
+					position,
+					Bid {
+						id,
+						who: who.clone(),
+						max_price,
+						amount,
+						escrowed,
+					},
+				)
+				.map_err(|_| Error::<T>::BidBookFull)?;
+				Ok(())
+			})?;
+
+			T::Currency::transfer(asset_id, &who, &Self::bid_escrow_account_id(), escrowed)?;
+
+			Self::deposit_event(Event::BidPlaced {
+				id,
+				who,
+				asset_id,
+				maturity,
+				max_price,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel an unfilled bid and refund the escrow.
+		///
+		/// Parameters:
+		/// - `origin`: account which placed the bid
+		/// - `asset_id`: underlying asset id
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		/// - `id`: bid id
+		///
+		/// Emits `BidCancelled` event when successful.
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_bid())]
+		pub fn cancel_bid(origin: OriginFor<T>, asset_id: AssetId, maturity: Moment, id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let key = (asset_id, Self::round_maturity(maturity));
+
+			let mut book = BidBooks::<T>::get(key);
+			let position = book
+				.iter()
+				.position(|bid| bid.id == id)
+				.ok_or(Error::<T>::BidNotFound)?;
+			ensure!(book[position].who == who, Error::<T>::NotBidOwner);
+			let bid = book.remove(position);
+			if book.is_empty() {
+				BidBooks::<T>::remove(key);
+			} else {
+				BidBooks::<T>::insert(key, book);
+			}
+
+			T::Currency::transfer(asset_id, &Self::bid_escrow_account_id(), &who, bid.escrowed)?;
+
+			Self::deposit_event(Event::BidCancelled {
+				id,
+				who,
+				amount: bid.amount,
+			});
+
+			Ok(())
+		}
+
+		/// Issue new fungible bonds and sell them to the bids of the primary issuance auction.
+		/// Bonds are issued the same way as with `issue` and sold to the highest bids first.
+		/// All filled bids pay the clearing price, which is the maximum price of the lowest filled bid.
+		/// The payment is transferred to the issuer and the rest of the escrow is refunded to the bidders.
+		/// Bonds not sold to the bids stay with the issuer.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		/// - `amount`: the amount of the underlying asset
+		///
+		/// Emits `TokenCreated` event when successful and new bonds were registered.
+		/// Emits `Issued`, `BidFilled` and `BidsFilled` events when successful.
+		///
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::fill_bids(T::MaxBidsPerBook::get()))]
+		pub fn fill_bids(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			maturity: Moment,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let issuer = T::IssueOrigin::ensure_origin(origin)?;
+			let maturity = Self::round_maturity(maturity);
+
+			let mut book = BidBooks::<T>::take((asset_id, maturity));
+			ensure!(!book.is_empty(), Error::<T>::NothingToFill);
+
+			Self::do_issue(issuer.clone(), asset_id, amount, maturity, false, None, Zero::zero())?;
+			let bond_id = Self::bond_id((asset_id, maturity)).ok_or(Error::<T>::NotRegistered)?;
+
+			let mut remaining = amount.saturating_sub(T::ProtocolFee::get().mul_ceil(amount));
+			let mut fills = Vec::new();
+			let mut clearing_price = None;
+			for bid in book.iter() {
+				if remaining.is_zero() {
+					break;
+				}
+				let filled = bid.amount.min(remaining);
+				remaining = remaining.saturating_sub(filled);
+				fills.push(filled);
+				clearing_price = Some(bid.max_price);
+			}
+			let clearing_price = clearing_price.ok_or(Error::<T>::NothingToFill)?;
+
+			let escrow_account = Self::bid_escrow_account_id();
+			let mut total_filled = T::Balance::zero();
+			for (bid, filled) in book.iter_mut().zip(fills) {
+				let cost = clearing_price.mul_floor(filled);
+				let released = Self::escrow_share(bid, filled);
+
+				T::Currency::transfer(bond_id, &issuer, &bid.who, filled)?;
+				T::Currency::transfer(asset_id, &escrow_account, &issuer, cost)?;
+				T::Currency::transfer(asset_id, &escrow_account, &bid.who, released.saturating_sub(cost))?;
+
+				bid.amount = bid.amount.saturating_sub(filled);
+				bid.escrowed = bid.escrowed.saturating_sub(released);
+				total_filled = total_filled.saturating_add(filled);
+
+				Self::deposit_event(Event::BidFilled {
+					id: bid.id,
+					who: bid.who.clone(),
+					bond_id,
+					amount: filled,
+					price: clearing_price,
+				});
+			}
+
+			book.retain(|bid| !bid.amount.is_zero());
+			if !book.is_empty() {
+				BidBooks::<T>::insert((asset_id, maturity), book);
+			}
+
+			Self::deposit_event(Event::BidsFilled {
+				issuer,
+				bond_id,
+				amount: total_filled,
+				clearing_price,
+			});
+
+			Ok(())
+		}
+
+		/// Issue new fungible bonds with the yield funded up front.
+		/// Works the same way as `issue`, except that the issuer deposits `yield_amount` in the pallet account
+		/// together with the `principal`. The issuer receives the amount of the bonds for the `principal`.
+		/// Redemptions of the bonds are paid their share of the yield, so redemption of all bonds pays out
+		/// the principal and the yield. The protocol fee is applied to the whole deposited amount.
+		/// Only new bonds can be issued with a yield and such bonds can't be issued again.
+		/// Redemption of the bonds is never queued. If the reserve doesn't cover the share of the yield,
+		/// the principal is paid and the yield only up to the reserve.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `principal`: the amount of the underlying asset the bonds are issued for
+		/// - `yield_amount`: the amount of the underlying asset paid to the holders on top of the principal
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		///
+		/// Emits `TokenCreated` and `Issued` events when successful.
+		///
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_with_yield())]
+		pub fn issue_with_yield(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			principal: T::Balance,
+			yield_amount: T::Balance,
+			maturity: Moment,
+		) -> DispatchResult {
+			let who = T::IssueOrigin::ensure_origin(origin)?;
+			let face_value = principal.checked_add(&yield_amount).ok_or(ArithmeticError::Overflow)?;
+			let fee = T::ProtocolFee::get().mul_ceil(face_value);
+			ensure!(yield_amount < face_value.saturating_sub(fee), Error::<T>::YieldTooHigh);
+
+			Self::do_issue(who, asset_id, face_value, maturity, false, None, yield_amount)
+		}
+
+		/// Redeem bonds and stake the underlying asset.
+		/// The underlying asset is redeemed the same way as in `redeem` and staked via `T::Staking`.
+		/// The underlying asset of the bonds needs to be the stakeable asset of `T::Staking`.
+		/// The whole amount paid out by the redemption is staked, including the redeemed dust remainder.
+		/// The redemption can't be queued or paid in `UnderlyingFallbackAsset`, so the underlying asset needs
+		/// to be registered and the pallet account needs to hold enough of it.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `bond_id`: bond asset id
+		/// - `amount`: the amount of the bonds to redeem
+		///
+		/// Emits `Redeemed` and `RedeemedAndStaked` events when successful.
+		///
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem()
+			.saturating_add(T::Staking::stake_weight())
+			.saturating_add(T::ConditionOracle::is_met_weight()))]
+		pub fn redeem_and_stake(origin: OriginFor<T>, bond_id: AssetId, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (underlying_asset_id, _) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			ensure!(
+				underlying_asset_id == T::Staking::stakeable_asset(),
+				Error::<T>::NotStakeable
+			);
+
+			ensure!(
+				Self::payout_asset(underlying_asset_id)? == underlying_asset_id,
+				Error::<T>::UnderlyingRemoved
+			);
+
+			let redeemed = amount.saturating_add(Self::dust_remainder(&who, bond_id, amount));
+			let amount_out = Self::redemption_amount(bond_id, redeemed);
+			let required = QueuedAmounts::<T>::get(underlying_asset_id).saturating_add(amount_out);
+			ensure!(
+				T::Currency::free_balance(underlying_asset_id, &Self::pallet_account_id()) >= required,
+				Error::<T>::InsufficientReserve
+			);
+
+			let balance_before = T::Currency::free_balance(underlying_asset_id, &who);
+			Self::do_redeem(who.clone(), bond_id, amount)?;
+			let staked = T::Currency::free_balance(underlying_asset_id, &who).saturating_sub(balance_before);
+			// the redemption must have been paid out in full, not queued
+			ensure!(
+				!staked.is_zero()
+					&& staked == amount_out.saturating_sub(Self::redemption_fee_amount(bond_id, amount_out)),
+				Error::<T>::InsufficientReserve
+			);
+
+			T::Staking::stake(&who, staked)?;
+
+			Self::deposit_event(Event::RedeemedAndStaked {
+				who,
+				bond_id,
+				amount,
+				staked,
+			});
+
+			Ok(())
+		}
+
+		/// Freeze the maturity of all bonds at the current time, or unfreeze it.
+		/// While frozen, bonds maturing after the time of the freeze can't be redeemed.
+		/// Freezing fails while the maturity is frozen, so the time of the freeze is never moved forward.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`
+		/// - `frozen`: `true` freezes the maturity at the current time, `false` removes the freeze
+		///
+		/// Emits `MaturityFreezeSet` event when successful.
+		///
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_maturity_freeze())]
+		pub fn set_maturity_freeze(origin: OriginFor<T>, frozen: bool) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			ensure!(
+				!frozen || GlobalMaturityFreeze::<T>::get().is_none(),
+				Error::<T>::MaturityAlreadyFrozen
+			);
+
+			let frozen_at = frozen.then(T::TimestampProvider::now);
+			GlobalMaturityFreeze::<T>::set(frozen_at);
+
+			Self::deposit_event(Event::MaturityFreezeSet { frozen_at });
+
+			Ok(())
+		}
+
+		/// Allow a spender to transfer bonds on behalf of the `origin`.
+		/// The allowance replaces the previous allowance of the spender for the bonds. Zero amount removes the allowance.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the bonds
+		/// - `spender`: account allowed to transfer the bonds on behalf of the owner
+		/// - `bond_id`: id of the bonds
+		/// - `amount`: the amount of the bonds the spender can transfer
+		///
+		/// Emits `TransferApproved` event when successful.
+		///
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_transfer())]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			spender: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(Self::bond(bond_id).is_some(), Error::<T>::NotRegistered);
+
+			if amount.is_zero() {
+				TransferAllowance::<T>::remove((&owner, &spender, bond_id));
+			} else {
+				TransferAllowance::<T>::insert((&owner, &spender, bond_id), amount);
+			}
+
+			Self::deposit_event(Event::TransferApproved {
+				owner,
+				spender,
+				bond_id,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Transfer bonds on behalf of the owner.
+		/// The `amount` is deducted from the transfer allowance of the `origin`.
+		/// Transfer locked bonds can't be transferred until mature.
+		///
+		/// Parameters:
+		/// - `origin`: spender approved by the owner with `approve_transfer`
+		/// - `owner`: account the bonds are taken from
+		/// - `dest`: account receiving the bonds
+		/// - `bond_id`: id of the bonds
+		/// - `amount`: the amount of the bonds to transfer
+		///
+		/// Emits `TransferredFrom` event when successful.
+		///
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			dest: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+
+			TransferAllowance::<T>::try_mutate_exists(
+				(&owner, &spender, bond_id),
+				|maybe_allowance| -> DispatchResult {
+					let allowance = maybe_allowance
+						.unwrap_or_default()
+						.checked_sub(&amount)
+						.ok_or(Error::<T>::InsufficientAllowance)?;
+					*maybe_allowance = if allowance.is_zero() { None } else { Some(allowance) };
+					Ok(())
+				},
+			)?;
+
+			T::Currency::transfer(bond_id, &owner, &dest, amount)?;
+
+			Self::deposit_event(Event::TransferredFrom {
+				spender,
+				owner,
+				dest,
+				bond_id,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Add yield to outstanding bonds.
+		/// The `extra` amount of the underlying asset is transferred to the pallet account without issuing new bonds,
+		/// so each redeemed bond is paid its share of the yield on top of the principal.
+		/// Bonds with yield added can't be issued again.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of the bonds
+		/// - `bond_id`: id of the bonds
+		/// - `extra`: the amount of the underlying asset added to the yield of the bonds
+		///
+		/// Emits `ToppedUp` event when successful.
+		///
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::top_up())]
+		pub fn top_up(origin: OriginFor<T>, bond_id: AssetId, extra: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (underlying_asset_id, _) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			ensure!(Self::bond_issuer(bond_id).as_ref() == Some(&who), Error::<T>::NotIssuer);
+
+			let (outstanding, discount) =
+				Self::bond_discount(bond_id).unwrap_or_else(|| (T::Currency::total_issuance(bond_id), Zero::zero()));
+			ensure!(!extra.is_zero() && !outstanding.is_zero(), Error::<T>::NothingToTopUp);
+
+			Self::charge_issuance(bond_id, &who, extra)?;
+
+			T::Currency::transfer(underlying_asset_id, &who, &Self::pallet_account_id(), extra)?;
+			BondDiscounts::<T>::insert(
+				bond_id,
+				(
+					outstanding,
+					discount.checked_add(&extra).ok_or(ArithmeticError::Overflow)?,
+				),
+			);
+
+			Self::deposit_event(Event::ToppedUp {
+				issuer: who,
+				bond_id,
+				amount: extra,
+			});
+
+			Ok(())
+		}
+
+		/// Issue new fungible bonds locked by a vesting schedule.
+		/// Works the same way as `issue`, except that the issued bonds are transferred to `recipient`,
+		/// locked in its account and unlock over blocks according to `vesting`.
+		/// The locked bonds can't be transferred or redeemed.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `amount`: the amount of the underlying asset
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		/// - `recipient`: account receiving the issued bonds
+		/// - `vesting`: vesting schedule of the issued bonds, can't lock more than the issued amount
+		///
+		/// Emits `TokenCreated` event when successful and new bonds were registered.
+		/// Emits `Issued` event when successful.
+		/// Emits `VestingScheduleSet` event when successful.
+		///
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_vested())]
+		pub fn issue_vested(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: T::Balance,
+			maturity: Moment,
+			recipient: T::AccountId,
+			vesting: VestingInfo<T::Balance, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = T::IssueOrigin::ensure_origin(origin)?;
+			ensure!(
+				!vesting.locked.is_zero() && !vesting.per_block.is_zero(),
+				Error::<T>::InvalidVestingSchedule
+			);
+
+			let balance_before = Self::bond_id((asset_id, Self::round_maturity(maturity)))
+				.map(|bond_id| T::Currency::free_balance(bond_id, &who))
+				.unwrap_or_else(Zero::zero);
+
+			Self::do_issue(who.clone(), asset_id, amount, maturity, false, None, Zero::zero())?;
+
+			let bond_id = Self::bond_id((asset_id, Self::round_maturity(maturity))).ok_or(Error::<T>::NotRegistered)?;
+			ensure!(
+				!BondVesting::<T>::contains_key(bond_id, &recipient),
+				Error::<T>::VestingScheduleExists
+			);
+			let issued = T::Currency::free_balance(bond_id, &who).saturating_sub(balance_before);
+			ensure!(vesting.locked <= issued, Error::<T>::InvalidVestingSchedule);
+
+			if recipient != who {
+				T::Currency::transfer(bond_id, &who, &recipient, issued)?;
+			}
+
+			BondVesting::<T>::insert(bond_id, &recipient, vesting);
+
+			Self::deposit_event(Event::VestingScheduleSet {
+				who: recipient,
+				bond_id,
+				schedule: vesting,
+			});
+
+			Ok(())
+		}
+
+		/// Issue new fungible bonds with a redemption fee.
+		/// Works the same way as `issue`, and sets the fee charged on the amount paid out by redemptions
+		/// of the bonds. The redemption fee is transferred to `T::FeeReceiver`.
+		/// Only new bonds can be issued with a redemption fee.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `amount`: the amount of the underlying asset
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		/// - `redemption_fee`: fee charged on the amount paid out by redemptions
+		///
+		/// Emits `TokenCreated` and `Issued` events when successful.
+		///
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_with_redemption_fee())]
+		pub fn issue_with_redemption_fee(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: T::Balance,
+			maturity: Moment,
+			redemption_fee: Permill,
+		) -> DispatchResult {
+			let who = T::IssueOrigin::ensure_origin(origin)?;
+			ensure!(
+				Self::bond_id((asset_id, Self::round_maturity(maturity))).is_none(),
+				Error::<T>::RedemptionFeeNotAllowed
+			);
+
+			Self::do_issue(who, asset_id, amount, maturity, false, None, Zero::zero())?;
+
+			let bond_id = Self::bond_id((asset_id, Self::round_maturity(maturity))).ok_or(Error::<T>::NotRegistered)?;
+			if !redemption_fee.is_zero() {
+				RedemptionFees::<T>::insert(bond_id, redemption_fee);
+			}
+
+			Ok(())
+		}
+
+		/// Issue new fungible bonds with a redemption condition.
+		/// Works the same way as `issue`, and sets the condition checked by `T::ConditionOracle`
+		/// on every redemption of the bonds. The condition can't be changed after the issuance.
+		/// Only new bonds can be issued with a redemption condition.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `amount`: the amount of the underlying asset
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		///   Rounded down to a multiple of `T::MaturityGranularity`.
+		/// - `condition`: condition which needs to be met to redeem the bonds
+		///
+		/// Emits `TokenCreated`, `Issued` and `RedemptionConditionSet` events when successful.
+		///
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_conditional())]
+		pub fn issue_conditional(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: T::Balance,
+			maturity: Moment,
+			condition: T::RedemptionCondition,
+		) -> DispatchResult {
+			let who = T::IssueOrigin::ensure_origin(origin)?;
+			ensure!(
+				Self::bond_id((asset_id, Self::round_maturity(maturity))).is_none(),
+				Error::<T>::RedemptionConditionNotAllowed
+			);
+
+			Self::do_issue(who, asset_id, amount, maturity, false, None, Zero::zero())?;
+
+			let bond_id = Self::bond_id((asset_id, Self::round_maturity(maturity))).ok_or(Error::<T>::NotRegistered)?;
+			RedemptionConditions::<T>::insert(bond_id, condition.clone());
+
+			Self::deposit_event(Event::RedemptionConditionSet { bond_id, condition });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account ID of the bonds pallet.
+	///
+	/// This actually does computation. If you need to keep using it, then make sure you cache the
+	/// value and only call this once.
+	pub fn pallet_account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The account ID escrowing the bids of the primary issuance auction.
+	pub fn bid_escrow_account_id() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"bids")
+	}
+
+	/// Issue new bonds. Optionally, newly registered bonds are transfer locked until mature,
+	/// assigned a manager and issued below the face value.
+	fn do_issue(
+		who: T::AccountId,
+		asset_id: AssetId,
+		amount: T::Balance,
+		maturity: Moment,
+		transfer_locked: bool,
+		manager: Option<T::AccountId>,
+		discount: T::Balance,
+	) -> DispatchResult {
+		ensure!(
+			T::AssetTypeWhitelist::contains(&T::AssetRegistry::asset_type(asset_id).ok_or(Error::<T>::AssetNotFound)?),
+			Error::<T>::DisallowedAsset
+		);
+
+		let fee = T::ProtocolFee::get().mul_ceil(amount);
+		let amount_without_fee = amount.saturating_sub(fee);
+		ensure!(
+			discount.is_zero() || discount < amount_without_fee,
+			Error::<T>::InvalidDiscount
+		);
+		let bond_amount = amount_without_fee.saturating_sub(discount);
+		let pallet_account = Self::pallet_account_id();
+		let maturity = Self::round_maturity(maturity);
+
+		let bond_id = match BondIds::<T>::get((asset_id, maturity)) {
+			Some(bond_id) => {
+				ensure!(
+					!transfer_locked || TransferLocked::<T>::contains_key(bond_id),
+					Error::<T>::TransferLockNotAllowed
+				);
+				ensure!(
+					manager.is_none() || Self::bond_manager(bond_id) == manager,
+					Error::<T>::ManagerNotAllowed
+				);
+				ensure!(
+					discount.is_zero() && !BondDiscounts::<T>::contains_key(bond_id),
+					Error::<T>::DiscountNotAllowed
+				);
+				bond_id
+			}
+			None => {
+				// register new bonds
+				ensure!(maturity >= T::TimestampProvider::now(), Error::<T>::InvalidMaturity);
+
+				let ed = T::ExistentialDeposits::get(&asset_id);
+				let b_name = Self::bond_name(asset_id, maturity);
+				let bond_id = T::AssetRegistry::register_insufficient_asset(
+					None,
+					Some(b_name.try_into().map_err(|_| Error::<T>::InvalidBondName)?),
+					AssetKind::Bond,
+					Some(ed),
+					None,
+					None,
+					None,
+					None,
+				)?;
+
+				Bonds::<T>::insert(bond_id, (asset_id, maturity));
+				BondIds::<T>::insert((asset_id, maturity), bond_id);
+				BondIssuers::<T>::insert(bond_id, &who);
+				if transfer_locked {
+					TransferLocked::<T>::insert(bond_id, ());
+				}
+				if let Some(manager) = manager {
+					BondManagers::<T>::insert(bond_id, manager);
+				}
+				if !discount.is_zero() {
+					BondDiscounts::<T>::insert(bond_id, (bond_amount, discount));
+				}
+
+				Self::deposit_event(Event::TokenCreated {
+					issuer: who.clone(),
+					asset_id,
+					bond_id,
+					maturity,
+				});
+
+				bond_id
+			}
+		};
+
+		Self::charge_issuance(bond_id, &who, amount_without_fee)?;
+
+		Self::index_bond(asset_id, bond_id);
+
+		T::Currency::transfer(asset_id, &who, &pallet_account, amount_without_fee)?;
+		T::Currency::transfer(asset_id, &who, &T::FeeReceiver::get(), fee)?;
+		T::Currency::deposit(bond_id, &who, bond_amount)?;
+		if !fee.is_zero() {
+			CumulativeFees::<T>::mutate(asset_id, |total| *total = total.saturating_add(fee));
+		}
+
+		Self::deposit_event(Event::Issued {
+			issuer: who,
+			bond_id,
+			amount: bond_amount,
+			fee,
+			maturity,
+		});
+
+		Ok(())
+	}
+
+	fn do_redeem(who: T::AccountId, bond_id: AssetId, amount: T::Balance) -> DispatchResult {
+		let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+
+		ensure!(Self::maturity_now() >= maturity, Error::<T>::NotMature);
+		Self::ensure_condition_met(bond_id)?;
+
+		let payout_asset_id = Self::payout_asset(underlying_asset_id)?;
+
+		let dust = Self::dust_remainder(&who, bond_id, amount);
+		let amount = amount.saturating_add(dust);
+
+		ensure!(
+			amount >= T::MinRedemptionAmount::get() || amount == T::Currency::free_balance(bond_id, &who),
+			Error::<T>::RedemptionTooSmall
+		);
+
+		Self::ensure_vested(bond_id, &who, amount)?;
+		T::Currency::withdraw(bond_id, &who, amount)?;
+		Self::unindex_if_redeemed(underlying_asset_id, bond_id);
+
+		if !dust.is_zero() {
+			Self::deposit_event(Event::DustRedeemed {
+				who: who.clone(),
+				bond_id,
+				amount: dust,
+			});
+		}
+
+		let pallet_account = Self::pallet_account_id();
+		let amount_out = Self::redemption_amount(bond_id, amount);
+
+		// Queued redemptions are served first.
+		let available = T::Currency::free_balance(payout_asset_id, &pallet_account)
+			.saturating_sub(QueuedAmounts::<T>::get(payout_asset_id));
+		let mut paid_out = amount_out;
+		if available < amount_out {
+			// Queued redemptions are paid in the underlying asset, so redemptions in the fallback asset can't be queued.
+			if !BondDiscounts::<T>::contains_key(bond_id) && payout_asset_id == underlying_asset_id {
+				Self::queue_redemption(who, underlying_asset_id, bond_id, amount);
+				return Ok(());
+			}
+			// The principal is paid in full, the share of the yield only up to the reserve.
+			ensure!(available >= amount, Error::<T>::InsufficientReserve);
+			paid_out = available;
+		}
+
+		Self::release_discount(bond_id, amount);
+		Self::pay_redemption(payout_asset_id, bond_id, &who, paid_out)?;
+		Self::release_issuance(bond_id, &who, amount_out);
+
+		Self::deposit_event(Event::Redeemed {
+			who: who.clone(),
+			bond_id,
+			amount,
+		});
+
+		if payout_asset_id != underlying_asset_id {
+			Self::deposit_event(Event::RedeemedInFallbackAsset {
+				who: who.clone(),
+				bond_id,
+				asset_id: payout_asset_id,
+				amount: paid_out,
+			});
+		}
+
+		if paid_out < amount_out {
+			Self::deposit_event(Event::RedemptionShortfall {
+				bond_id,
+				holder: who,
+				expected_yield: amount_out.saturating_sub(amount),
+				paid_yield: paid_out.saturating_sub(amount),
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Transfer `amount` of the asset from the pallet account to `who`, minus the redemption fee of the bonds,
+	/// which is transferred to `T::FeeReceiver`.
+	fn pay_redemption(asset_id: AssetId, bond_id: AssetId, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		let pallet_account = Self::pallet_account_id();
+		let fee = Self::redemption_fee_amount(bond_id, amount);
+
+		T::Currency::transfer(asset_id, &pallet_account, who, amount.saturating_sub(fee))?;
+		if !fee.is_zero() {
+			T::Currency::transfer(asset_id, &pallet_account, &T::FeeReceiver::get(), fee)?;
+			Self::deposit_event(Event::RedemptionFeePaid {
+				who: who.clone(),
+				bond_id,
+				fee,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Return the redemption fee of the bonds charged on `amount` paid out by a redemption.
+	fn redemption_fee_amount(bond_id: AssetId, amount: T::Balance) -> T::Balance {
+		Self::bond_redemption_fee(bond_id)
+			.map(|fee| fee.mul_floor(amount))
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Return the asset the redemption of bonds of the underlying asset is paid in.
+	/// If the underlying asset was removed from the asset registry, `UnderlyingFallbackAsset` is paid instead.
+	fn payout_asset(underlying_asset_id: AssetId) -> Result<AssetId, DispatchError> {
+		if T::AssetRegistry::exists(underlying_asset_id) {
+			return Ok(underlying_asset_id);
+		}
+		T::UnderlyingFallbackAsset::get().ok_or_else(|| Error::<T>::UnderlyingRemoved.into())
+	}
+
+	/// Return the bond balance of `who` left after redeeming `amount` of the bonds if it is dust
+	/// to be redeemed together with the amount.
+	fn dust_remainder(who: &T::AccountId, bond_id: AssetId, amount: T::Balance) -> T::Balance {
+		if !T::DustRedemption::get() {
+			return Zero::zero();
+		}
+
+		let remainder = T::Currency::free_balance(bond_id, who).saturating_sub(amount);
+		if remainder < T::BondDustThreshold::get() {
+			remainder
+		} else {
+			Zero::zero()
+		}
+	}
+
+	/// Weight of selling the payout asset of the bonds for `out_asset` via the router.
+	fn redeem_as_router_weight(bond_id: AssetId, out_asset: AssetId) -> Weight {
+		let Some((underlying_asset_id, _)) = Self::bond(bond_id) else {
+			return T::RouterWeightInfo::get_route_weight();
+		};
+		let payout_asset_id = Self::payout_asset(underlying_asset_id).unwrap_or(underlying_asset_id);
+		let route = T::Router::get_route(AssetPair::new(payout_asset_id, out_asset));
+
+		T::RouterWeightInfo::get_route_weight().saturating_add(T::RouterWeightInfo::sell_weight(&route))
+	}
+
+	/// Return the amount of the underlying asset paid for `amount` of the bonds.
+	/// Discounted bonds are paid their share of the outstanding discount on top of the amount.
+	fn redemption_amount(bond_id: AssetId, amount: T::Balance) -> T::Balance {
+		amount.saturating_add(Self::discount_share(bond_id, amount))
+	}
+
+	/// Return the share of the outstanding discount of the bonds belonging to `amount` of the bonds.
+	/// The last redemption receives the whole remaining discount.
+	fn discount_share(bond_id: AssetId, amount: T::Balance) -> T::Balance {
+		let Some((outstanding, discount)) = Self::bond_discount(bond_id) else {
+			return Zero::zero();
+		};
+		if amount >= outstanding {
+			return discount;
+		}
+
+		multiply_by_rational_with_rounding(
+			discount.unique_saturated_into(),
+			amount.unique_saturated_into(),
+			outstanding.unique_saturated_into(),
+			Rounding::Down,
+		)
+		.map(Into::into)
+		.unwrap_or_else(Zero::zero)
+	}
+
+	/// Return the share of the escrow of the bid belonging to the `filled` amount of the bonds.
+	/// Filling the whole bid releases the whole escrow.
+	fn escrow_share(bid: &Bid<T::AccountId, T::Balance>, filled: T::Balance) -> T::Balance {
+		if filled >= bid.amount {
+			return bid.escrowed;
+		}
+
+		multiply_by_rational_with_rounding(
+			bid.escrowed.unique_saturated_into(),
+			filled.unique_saturated_into(),
+			bid.amount.unique_saturated_into(),
+			Rounding::Down,
+		)
+		.map(Into::into)
+		.unwrap_or_else(Zero::zero)
+	}
+
+	/// Subtract redeemed `amount` of the bonds and its share of the discount from the outstanding discount.
+	fn release_discount(bond_id: AssetId, amount: T::Balance) {
+		let share = Self::discount_share(bond_id, amount);
+		BondDiscounts::<T>::mutate_exists(bond_id, |maybe_discount| {
+			*maybe_discount = maybe_discount
+				.map(|(outstanding, discount)| (outstanding.saturating_sub(amount), discount.saturating_sub(share)))
+				.filter(|(outstanding, _)| !outstanding.is_zero());
+		});
+	}
+
+	/// Add the bonds to `BondsByAsset` of the underlying asset if they are not indexed yet.
+	/// The index is used only for queries, so the bonds are left out of the index instead of failing
+	/// when the underlying asset has `MaxBondsPerAsset` bonds indexed already.
+	fn index_bond(underlying_asset_id: AssetId, bond_id: AssetId) {
+		let _ = BondsByAsset::<T>::try_mutate(underlying_asset_id, |bonds| -> Result<(), ()> {
+			if !bonds.contains(&bond_id) {
+				bonds.try_push(bond_id).map_err(|_| {
+					log::warn!(target: "runtime::bonds", "Bond {:?} of asset {:?} not indexed", bond_id, underlying_asset_id);
+				})?;
+			}
+			Ok(())
+		});
+	}
+
+	/// Remove the bonds from `BondsByAsset` of the underlying asset if all bonds were redeemed.
+	fn unindex_if_redeemed(underlying_asset_id: AssetId, bond_id: AssetId) {
+		if !T::Currency::total_issuance(bond_id).is_zero() {
+			return;
+		}
+		BondsByAsset::<T>::mutate_exists(underlying_asset_id, |maybe_bonds| {
+			if let Some(bonds) = maybe_bonds.as_mut() {
+				bonds.retain(|id| *id != bond_id);
+				if bonds.is_empty() {
+					*maybe_bonds = None;
+				}
+			}
+		});
+	}
+
+	/// Add issued amount to the issuance of the issuer, fails if the issuer would exceed `T::MaxIssuancePerIssuer`.
+	fn charge_issuance(bond_id: AssetId, issuer: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		IssuedByIssuer::<T>::try_mutate(issuer, |issued| -> DispatchResult {
+			*issued = issued.saturating_add(amount);
+			ensure!(*issued <= T::MaxIssuancePerIssuer::get(), Error::<T>::IssuerCapExceeded);
+			Ok(())
+		})?;
+		IssuedByIssuerPerBond::<T>::mutate(bond_id, issuer, |issued| *issued = issued.saturating_add(amount));
+		Ok(())
+	}
+
+	/// Subtract redeemed amount from the issuance of the issuers of the bonds.
+	/// The issuance of the holder is released first if the holder issued the bonds,
+	/// the rest of the amount is released from the other issuers of the bonds.
+	fn release_issuance(bond_id: AssetId, holder: &T::AccountId, amount: T::Balance) {
+		let mut remaining = Self::release_issuance_of(bond_id, holder, amount);
+		if remaining.is_zero() {
+			return;
+		}
+
+		let issuers: Vec<T::AccountId> = IssuedByIssuerPerBond::<T>::iter_key_prefix(bond_id).collect();
+		for issuer in issuers {
+			remaining = Self::release_issuance_of(bond_id, &issuer, remaining);
+			if remaining.is_zero() {
+				break;
+			}
+		}
+	}
+
+	/// Release up to `amount` of the issuance of the issuer of the bonds. Returns the amount left to release.
+	fn release_issuance_of(bond_id: AssetId, issuer: &T::AccountId, amount: T::Balance) -> T::Balance {
+		let issued = IssuedByIssuerPerBond::<T>::take(bond_id, issuer);
+		if issued.is_zero() {
+			return amount;
+		}
+
+		let released = issued.min(amount);
+		let rest = issued.saturating_sub(released);
+		if !rest.is_zero() {
+			IssuedByIssuerPerBond::<T>::insert(bond_id, issuer, rest);
+		}
+		IssuedByIssuer::<T>::mutate_exists(issuer, |maybe_issued| {
+			*maybe_issued = maybe_issued
+				.map(|issued| issued.saturating_sub(released))
+				.filter(|issued| !issued.is_zero());
+		});
+
+		amount.saturating_sub(released)
+	}
+
+	/// Round the maturity down to a multiple of `T::MaturityGranularity`.
+	pub fn round_maturity(maturity: Moment) -> Moment {
+		let granularity = T::MaturityGranularity::get();
+		if granularity <= 1 {
+			return maturity;
+		}
+		maturity.saturating_sub(maturity % granularity)
+	}
+
+	fn queue_redemption(who: T::AccountId, underlying_asset_id: AssetId, bond_id: AssetId, amount: T::Balance) {
+		let id = NextQueuedRedemptionId::<T>::mutate(|next_id| {
+			let id = *next_id;
+			*next_id = next_id.saturating_add(1);
+			id
+		});
+
+		RedemptionQueue::<T>::insert(id, (&who, bond_id, amount));
+		QueuedAmounts::<T>::mutate(underlying_asset_id, |queued| *queued = queued.saturating_add(amount));
+
+		Self::deposit_event(Event::RedemptionQueued {
+			id,
+			who,
+			bond_id,
+			amount,
+		});
+	}
+
+	/// Process queued redemptions in FIFO order per underlying asset.
+	/// Redemption the pallet account can't cover is kept in the queue together with the following redemptions of
+	/// the same underlying asset, redemptions of other assets are processed.
+	/// Redemptions are paid in `payout_asset`, i.e. in the fallback asset if the underlying asset was removed.
+	///
+	/// The queue is scanned in passes which continue across blocks from `RedemptionQueueCursor`, so redemptions
+	/// behind more than `MaxQueuedRedemptionsPerBlock` redemptions which can't be covered are reached as well.
+	/// If more than `MAX_BLOCKED_REDEMPTION_ASSETS` assets are blocked, the pass ends and the next one starts
+	/// from the head.
+	fn process_redemption_queue() -> Weight {
+		let mut head = RedemptionQueueHead::<T>::get();
+		let next_id = NextQueuedRedemptionId::<T>::get();
+		let mut weight = T::DbWeight::get().reads(3);
+
+		if head == next_id {
+			RedemptionQueueCursor::<T>::kill();
+			return weight.saturating_add(T::DbWeight::get().writes(1));
+		}
+
+		let pallet_account = Self::pallet_account_id();
+		// underlying assets with a queued redemption which couldn't be covered in this pass
+		let (mut id, mut blocked) = RedemptionQueueCursor::<T>::get().unwrap_or((head, BoundedVec::new()));
+		id = id.max(head);
+		let mut pass_complete = false;
+
+		for _ in 0..T::MaxQueuedRedemptionsPerBlock::get() {
+			if id >= next_id {
+				pass_complete = true;
+				break;
+			}
+
+			weight.saturating_accrue(<T as Config>::WeightInfo::process_queued_redemption());
+			let current = id;
+			id = id.saturating_add(1);
+
+			// cancelled or processed redemption
+			let Some((who, bond_id, amount)) = RedemptionQueue::<T>::get(current) else {
+				if current == head {
+					head = id;
+				}
+				continue;
+			};
+
+			let Some((underlying_asset_id, _)) = Self::bond(bond_id) else {
+				defensive!("Bonds of queued redemption not found");
+				RedemptionQueue::<T>::remove(current);
+				if current == head {
+					head = id;
+				}
+				continue;
+			};
+
+			if blocked.contains(&underlying_asset_id) {
+				continue;
+			}
+
+			let paid = Self::payout_asset(underlying_asset_id).ok().filter(|payout_asset_id| {
+				// queued redemptions of bonds of the fallback asset are served first
+				let reserved = if *payout_asset_id == underlying_asset_id {
+					Zero::zero()
+				} else {
+					QueuedAmounts::<T>::get(payout_asset_id)
+				};
+				T::Currency::free_balance(*payout_asset_id, &pallet_account).saturating_sub(reserved) >= amount
+					&& with_transaction(|| {
+						let r = Self::pay_redemption(*payout_asset_id, bond_id, &who, amount);
+						match r {
+							Ok(()) => TransactionOutcome::Commit(r),
+							Err(_) => TransactionOutcome::Rollback(r),
+						}
+					})
+					.is_ok()
+			});
+			let Some(payout_asset_id) = paid else {
+				if blocked.try_push(underlying_asset_id).is_err() {
+					// the following redemptions of the asset can't be skipped, so the pass starts over
+					pass_complete = true;
+					break;
+				}
+				continue;
+			};
+
+			RedemptionQueue::<T>::remove(current);
+			QueuedAmounts::<T>::mutate(underlying_asset_id, |queued| *queued = queued.saturating_sub(amount));
+			Self::release_issuance(bond_id, &who, amount);
+			if current == head {
+				head = id;
+			}
+
+			Self::deposit_event(Event::Redeemed {
+				who: who.clone(),
+				bond_id,
+				amount,
+			});
+			if payout_asset_id != underlying_asset_id {
+				Self::deposit_event(Event::RedeemedInFallbackAsset {
+					who,
+					bond_id,
+					asset_id: payout_asset_id,
+					amount,
+				});
+			}
+		}
+
+		RedemptionQueueHead::<T>::put(head);
+		if pass_complete || id >= next_id {
+			RedemptionQueueCursor::<T>::kill();
+		} else {
+			RedemptionQueueCursor::<T>::put((id, blocked));
+		}
+		weight.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Emit `BondMaturingSoon` event once for every bond which entered the maturity warning window.
+	/// At most `MaxMaturityChecksPerBlock` bonds are checked, starting after the bond stored in the cursor.
+	fn check_maturity_warnings() -> Weight {
+		let window = T::MaturityWarningWindow::get();
+		if window.is_zero() {
+			return Weight::zero();
+		}
+
+		let now = T::TimestampProvider::now();
+		let mut weight = T::DbWeight::get().reads(1);
+
+		let mut bonds = match MaturityCheckCursor::<T>::get() {
+			Some(cursor) => Bonds::<T>::iter_from(Bonds::<T>::hashed_key_for(cursor)),
+			None => Bonds::<T>::iter(),
+		};
+
+		let mut cursor = None;
+		for _ in 0..T::MaxMaturityChecksPerBlock::get() {
+			// all bonds were checked, start from the beginning in the next block
+			let Some((bond_id, (_, maturity))) = bonds.next() else {
+				cursor = None;
+				break;
+			};
+
+			weight.saturating_accrue(<T as Config>::WeightInfo::check_maturity_warning());
+			cursor = Some(bond_id);
+
+			Self::check_maturity_warning(bond_id, maturity, now, window);
+		}
+
+		MaturityCheckCursor::<T>::set(cursor);
+		weight.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Emit `BondMaturingSoon` event if the bond is within `window` before its maturity
+	/// and the event hasn't been emitted for the bond yet.
+	fn check_maturity_warning(bond_id: AssetId, maturity: Moment, now: Moment, window: Moment) {
+		if now >= maturity || maturity.saturating_sub(now) > window || MaturityWarned::<T>::contains_key(bond_id) {
+			return;
+		}
+
+		MaturityWarned::<T>::insert(bond_id, ());
+		Self::deposit_event(Event::BondMaturingSoon { bond_id, maturity });
+	}
+
+	/// Return `true` if `OutstandingSupply` events are emitted in the block.
+	fn is_gauge_block(n: BlockNumberFor<T>) -> bool {
+		let interval = T::GaugeInterval::get();
+		!interval.is_zero() && (n % interval).is_zero()
+	}
+
+	/// Emit `OutstandingSupply` event for every bond with nonzero outstanding supply, and
+	/// `UnderlyingOutstandingSupply` event for every underlying asset once all its bonds were checked.
+	/// At most `MaxAssetsPerGauge` bonds are checked, starting after the position stored in the cursor.
+	fn emit_outstanding_supply_gauge() {
+		let (mut assets, mut in_progress) = match GaugeCursor::<T>::take() {
+			Some((asset, checked, amount)) => (
+				BondsByAsset::<T>::iter_from(BondsByAsset::<T>::hashed_key_for(asset)),
+				Some((asset, Self::bonds_of_asset(asset), checked, amount)),
+			),
+			None => (BondsByAsset::<T>::iter(), None),
+		};
+
+		let mut remaining = T::MaxAssetsPerGauge::get();
+		loop {
+			// all underlying assets were checked, start from the beginning in the next gauge block
+			let Some((asset, bonds, checked, mut total)) = in_progress
+				.take()
+				.or_else(|| assets.next().map(|(asset, bonds)| (asset, bonds, 0, Zero::zero())))
+			else {
+				return;
+			};
+
+			for (position, bond_id) in bonds.iter().enumerate().skip(checked as usize) {
+				if remaining.is_zero() {
+					GaugeCursor::<T>::put((asset, position as u32, total));
+					return;
+				}
+				remaining.saturating_dec();
+				total = total.saturating_add(Self::emit_outstanding_supply(*bond_id));
+			}
+
+			if !total.is_zero() {
+				Self::deposit_event(Event::UnderlyingOutstandingSupply { asset, amount: total });
+			}
+		}
+	}
+
+	/// Emit `OutstandingSupply` event for the bond if its outstanding supply is not zero.
+	/// Returns the outstanding supply.
+	fn emit_outstanding_supply(bond_id: AssetId) -> T::Balance {
+		let amount = T::Currency::total_issuance(bond_id);
+		if !amount.is_zero() {
+			Self::deposit_event(Event::OutstandingSupply { asset: bond_id, amount });
+		}
+		amount
+	}
+
+	/// Ensure that the metadata URI is not empty and is within `MaxUriLen`.
+	fn validate_metadata_uri(uri: Vec<u8>) -> Result<BoundedVec<u8, T::MaxUriLen>, DispatchError> {
+		ensure!(!uri.is_empty(), Error::<T>::EmptyMetadataUri);
+		uri.try_into().map_err(|_| Error::<T>::MetadataUriTooLong.into())
+	}
+
+	/// Set the metadata URI of the bonds if `who` is the issuer of the bonds.
+	fn do_set_metadata_uri(who: &T::AccountId, bond_id: AssetId, uri: BoundedVec<u8, T::MaxUriLen>) -> DispatchResult {
+		ensure!(Self::bond_issuer(bond_id).as_ref() == Some(who), Error::<T>::NotIssuer);
+
+		BondMetadataUri::<T>::insert(bond_id, uri.clone());
+		Self::deposit_event(Event::MetadataUriSet { bond_id, uri });
+
+		Ok(())
+	}
+
+	/// Current time used for the maturity checks of redemptions.
+	/// While `GlobalMaturityFreeze` is set, the time is capped at the time of the freeze.
+	fn maturity_now() -> Moment {
+		let now = T::TimestampProvider::now();
+		GlobalMaturityFreeze::<T>::get().map_or(now, |frozen_at| frozen_at.min(now))
+	}
+
+	/// Ensure that the bonds are not mature or are still within the post-maturity grace window.
+	fn ensure_authority_can_act(maturity: Moment) -> DispatchResult {
+		let now = T::TimestampProvider::now();
+		ensure!(
+			now <= maturity.saturating_add(T::PostMaturityGrace::get()),
+			Error::<T>::AlreadyMatured
+		);
+		Ok(())
+	}
+
+	/// Ensure that the asset is not transfer locked bond that is not mature yet.
+	pub fn ensure_transferable(asset_id: AssetId) -> DispatchResult {
+		if !TransferLocked::<T>::contains_key(asset_id) {
+			return Ok(());
+		}
+
+		if let Some((_, maturity)) = Self::bond(asset_id) {
+			ensure!(T::TimestampProvider::now() >= maturity, Error::<T>::TransferLocked);
+		}
+
+		Ok(())
+	}
+
+	/// Ensure the redemption condition of the bonds, if any, is met.
+	fn ensure_condition_met(bond_id: AssetId) -> DispatchResult {
+		if let Some(condition) = RedemptionConditions::<T>::get(bond_id) {
+			ensure!(T::ConditionOracle::is_met(&condition), Error::<T>::ConditionNotMet);
+		}
+
+		Ok(())
+	}
+
+	/// Ensure `amount` of the bonds leaving the account of `who` doesn't reduce the bond balance
+	/// below the amount still locked by the vesting schedule of the account.
+	pub fn ensure_vested(bond_id: AssetId, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		if let Some(schedule) = BondVesting::<T>::get(bond_id, who) {
+			let locked = schedule.locked_at(frame_system::Pallet::<T>::block_number());
+			ensure!(
+				T::Currency::free_balance(bond_id, who).saturating_sub(amount) >= locked,
+				Error::<T>::VestingLocked
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Return the supply-weighted average remaining time to maturity in milliseconds of the outstanding bonds
+	/// of the underlying asset. Mature bonds count with zero remaining time.
+	/// Returns `None` if there are no outstanding bonds of the asset.
+	/// Dev note: iterates all registered bonds, intended to be used off-chain via the runtime API.
+	pub fn weighted_average_maturity(asset_id: AssetId) -> Option<Moment> {
+		let now = T::TimestampProvider::now();
+
+		let mut total_supply = U256::zero();
+		let mut weighted_sum = U256::zero();
+		for (bond_id, (underlying_asset_id, maturity)) in Bonds::<T>::iter() {
+			if underlying_asset_id != asset_id {
+				continue;
+			}
+
+			let supply: u128 = T::Currency::total_issuance(bond_id).unique_saturated_into();
+			let remaining = maturity.saturating_sub(now);
+			total_supply = total_supply.saturating_add(U256::from(supply));
+			weighted_sum = weighted_sum.saturating_add(U256::from(supply).saturating_mul(U256::from(remaining)));
+		}
+
+		if total_supply.is_zero() {
+			return None;
+		}
+
+		weighted_sum.checked_div(total_supply)?.try_into().ok()
+	}
+
+	/// Return bond token name
+	pub fn bond_name(asset_id: AssetId, when: Moment) -> Vec<u8> {
+		let mut buf: Vec<u8> = Vec::new();
 
 		buf.extend_from_slice(&asset_id.to_le_bytes());
 		buf.extend_from_slice(b".");
@@ -337,3 +2798,10 @@ impl<T: Config> Pallet<T> {
 		))
 	}
 }
+
+impl<T: Config> OnTransfer<T::AccountId, AssetId, T::Balance> for Pallet<T> {
+	fn on_transfer(asset_id: AssetId, from: &T::AccountId, _to: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Self::ensure_transferable(asset_id)?;
+		Self::ensure_vested(asset_id, from, amount)
+	}
+}
@@ -0,0 +1,57 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+/// Indexes the registered bonds with outstanding supply in `BondsByAsset`.
+///
+/// Bonds above `MaxBondsPerAsset` of an underlying asset are not indexed.
+pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 1;
+		for (bond_id, (underlying_asset_id, _)) in Bonds::<T>::iter() {
+			reads.saturating_accrue(3);
+			if T::Currency::total_issuance(bond_id).is_zero() {
+				continue;
+			}
+			writes.saturating_inc();
+			BondsByAsset::<T>::mutate(underlying_asset_id, |bonds| {
+				if bonds.try_push(bond_id).is_err() {
+					log::warn!(target: "runtime::bonds", "Bond {:?} of asset {:?} not indexed", bond_id, underlying_asset_id);
+				}
+			});
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		frame_support::ensure!(
+			Pallet::<T>::on_chain_storage_version() >= 1,
+			"Storage version was not updated"
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,126 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn approve_issuance_should_set_allowance_of_spender() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(Bonds::approve_issuance(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			100 * ONE
+		));
+
+		// Assert
+		assert_eq!(Bonds::issuance_allowance(ALICE, CHARLIE), 100 * ONE);
+		expect_events(vec![Event::IssuanceApproved {
+			owner: ALICE,
+			spender: CHARLIE,
+			amount: 100 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn issue_from_should_issue_bonds_to_owner_when_spender_is_approved() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = 60 * ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::approve_issuance(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			100 * ONE
+		));
+
+		// Act
+		assert_ok!(Bonds::issue_from(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			HDX,
+			amount,
+			maturity
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), amount);
+		assert_eq!(Tokens::free_balance(bond_id, &CHARLIE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount);
+		assert_eq!(Bonds::bond_issuer(bond_id), Some(ALICE));
+		assert_eq!(Bonds::issuance_allowance(ALICE, CHARLIE), 40 * ONE);
+	});
+}
+
+#[test]
+fn issue_from_should_fail_when_amount_exceeds_allowance() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		assert_ok!(Bonds::approve_issuance(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			100 * ONE
+		));
+		assert_ok!(Bonds::issue_from(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			HDX,
+			60 * ONE,
+			maturity
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue_from(RuntimeOrigin::signed(CHARLIE), ALICE, HDX, 60 * ONE, maturity),
+			Error::<Test>::InsufficientAllowance
+		);
+		assert_noop!(
+			Bonds::issue_from(RuntimeOrigin::signed(BOB), ALICE, HDX, ONE, maturity),
+			Error::<Test>::InsufficientAllowance
+		);
+	});
+}
+
+#[test]
+fn approve_issuance_should_remove_allowance_when_amount_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::approve_issuance(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			100 * ONE
+		));
+
+		// Act
+		assert_ok!(Bonds::approve_issuance(RuntimeOrigin::signed(ALICE), CHARLIE, 0));
+
+		// Assert
+		assert!(!IssuanceAllowance::<Test>::contains_key(ALICE, CHARLIE));
+		assert_noop!(
+			Bonds::issue_from(RuntimeOrigin::signed(CHARLIE), ALICE, HDX, ONE, NOW + MONTH),
+			Error::<Test>::InsufficientAllowance
+		);
+	});
+}
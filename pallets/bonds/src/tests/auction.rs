@@ -0,0 +1,200 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn fill_bids_should_sell_bonds_to_single_bid_at_its_max_price() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, 200 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::bid(
+				RuntimeOrigin::signed(BOB),
+				HDX,
+				maturity,
+				Permill::from_percent(90),
+				100 * ONE
+			));
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::bid_escrow_account_id()), 90 * ONE);
+
+			// Act
+			assert_ok!(Bonds::fill_bids(RuntimeOrigin::signed(ALICE), HDX, maturity, 100 * ONE));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(bond_id, &BOB), 100 * ONE);
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 110 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), 990 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 100 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::bid_escrow_account_id()), 0);
+			assert!(Bonds::bid_book((HDX, maturity)).is_empty());
+			expect_events(vec![
+				Event::BidFilled {
+					id: 0,
+					who: BOB,
+					bond_id,
+					amount: 100 * ONE,
+					price: Permill::from_percent(90),
+				}
+				.into(),
+				Event::BidsFilled {
+					issuer: ALICE,
+					bond_id,
+					amount: 100 * ONE,
+					clearing_price: Permill::from_percent(90),
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn fill_bids_should_fill_bids_partially_at_clearing_price_when_supply_is_lower_than_demand() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, 200 * ONE), (CHARLIE, HDX, 200 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::bid(
+				RuntimeOrigin::signed(CHARLIE),
+				HDX,
+				maturity,
+				Permill::from_percent(90),
+				60 * ONE
+			));
+			assert_ok!(Bonds::bid(
+				RuntimeOrigin::signed(BOB),
+				HDX,
+				maturity,
+				Permill::from_percent(95),
+				60 * ONE
+			));
+
+			// Act
+			assert_ok!(Bonds::fill_bids(RuntimeOrigin::signed(ALICE), HDX, maturity, 100 * ONE));
+
+			// Assert
+			// the highest bid is filled first, both bids pay the price of the lowest filled bid
+			assert_eq!(Tokens::free_balance(bond_id, &BOB), 60 * ONE);
+			assert_eq!(Tokens::free_balance(bond_id, &CHARLIE), 40 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 146 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &CHARLIE), 146 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), 990 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::bid_escrow_account_id()), 18 * ONE);
+			assert_eq!(
+				Bonds::bid_book((HDX, maturity)).into_inner(),
+				vec![Bid {
+					id: 0,
+					who: CHARLIE,
+					max_price: Permill::from_percent(90),
+					amount: 20 * ONE,
+					escrowed: 18 * ONE,
+				}]
+			);
+			expect_events(vec![
+				Event::BidFilled {
+					id: 1,
+					who: BOB,
+					bond_id,
+					amount: 60 * ONE,
+					price: Permill::from_percent(90),
+				}
+				.into(),
+				Event::BidFilled {
+					id: 0,
+					who: CHARLIE,
+					bond_id,
+					amount: 40 * ONE,
+					price: Permill::from_percent(90),
+				}
+				.into(),
+				Event::BidsFilled {
+					issuer: ALICE,
+					bond_id,
+					amount: 100 * ONE,
+					clearing_price: Permill::from_percent(90),
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn cancel_bid_should_refund_escrow_when_bid_is_not_filled() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, 200 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			assert_ok!(Bonds::bid(
+				RuntimeOrigin::signed(BOB),
+				HDX,
+				maturity,
+				Permill::from_percent(90),
+				100 * ONE
+			));
+
+			// Act
+			assert_ok!(Bonds::cancel_bid(RuntimeOrigin::signed(BOB), HDX, maturity, 0));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 200 * ONE);
+			assert!(Bonds::bid_book((HDX, maturity)).is_empty());
+		});
+}
+
+#[test]
+fn bid_should_fail_when_book_is_full() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, 500 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			for _ in 0..3 {
+				assert_ok!(Bonds::bid(
+					RuntimeOrigin::signed(BOB),
+					HDX,
+					maturity,
+					Permill::from_percent(90),
+					100 * ONE
+				));
+			}
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::bid(
+					RuntimeOrigin::signed(BOB),
+					HDX,
+					maturity,
+					Permill::from_percent(90),
+					100 * ONE
+				),
+				Error::<Test>::BidBookFull
+			);
+		});
+}
@@ -0,0 +1,205 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::assert_ok;
+use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn issue_should_index_bonds_by_underlying_asset() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let first_bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+			let second_bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + 2 * MONTH));
+			let dai_bond_id = next_asset_id();
+
+			// Act
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, ONE, NOW + MONTH));
+
+			// Assert
+			assert_eq!(
+				Bonds::bonds_of_asset(HDX).into_inner(),
+				vec![first_bond_id, second_bond_id]
+			);
+			assert_eq!(Bonds::bonds_of_asset(DAI).into_inner(), vec![dai_bond_id]);
+		});
+}
+
+#[test]
+fn issue_should_not_index_bonds_twice_when_bonds_are_already_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+
+		// Act
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, ONE, NOW + MONTH));
+
+		// Assert
+		assert_eq!(Bonds::bonds_of_asset(HDX).into_inner(), vec![bond_id]);
+	});
+}
+
+#[test]
+fn full_redemption_should_remove_bonds_from_index_while_partial_redemption_should_keep_them() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let first_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+		let second_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH + DAY));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), second_bond_id, ONE / 2));
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), first_bond_id, ONE));
+
+		// Assert
+		assert_eq!(Bonds::bonds_of_asset(HDX).into_inner(), vec![second_bond_id]);
+
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), second_bond_id, ONE / 2));
+		assert!(Bonds::bonds_of_asset(HDX).is_empty());
+		assert!(!BondsByAsset::<Test>::contains_key(HDX));
+	});
+}
+
+#[test]
+fn bonds_should_stay_indexed_when_other_holders_still_hold_them() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, ONE, NOW + MONTH));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, ONE));
+
+		// Assert
+		assert_eq!(Bonds::bonds_of_asset(HDX).into_inner(), vec![bond_id]);
+	});
+}
+
+#[test]
+fn issue_should_index_bonds_again_when_bonds_were_clawed_back() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+		assert_ok!(Bonds::clawback(RuntimeOrigin::root(), bond_id, ALICE, ONE, CHARLIE));
+		assert!(Bonds::bonds_of_asset(HDX).is_empty());
+
+		// Act
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+
+		// Assert
+		assert_eq!(Bonds::bonds_of_asset(HDX).into_inner(), vec![bond_id]);
+	});
+}
+
+#[test]
+fn issue_should_not_index_bonds_but_succeed_when_max_bonds_per_asset_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let max_bonds = <Test as Config>::MaxBondsPerAsset::get() as u64;
+		for i in 0..max_bonds {
+			assert_ok!(Bonds::issue(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				ONE,
+				NOW + (i + 1) * DAY
+			));
+		}
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			ONE,
+			NOW + (max_bonds + 1) * DAY
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), ONE);
+		assert_eq!(Bonds::bonds_of_asset(HDX).len() as u64, max_bonds);
+		assert!(!Bonds::bonds_of_asset(HDX).contains(&bond_id));
+	});
+}
+
+#[test]
+fn cancel_queued_redemption_should_index_bonds_again() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+		let pallet_account = Bonds::pallet_account_id();
+		let reserve = Tokens::free_balance(HDX, &pallet_account);
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::withdraw(
+			HDX,
+			&pallet_account,
+			reserve
+		));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, ONE));
+		assert_eq!(Bonds::queued_redemption(0), Some((ALICE, bond_id, ONE)));
+		assert!(Bonds::bonds_of_asset(HDX).is_empty());
+
+		// Act
+		assert_ok!(Bonds::cancel_queued_redemption(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), ONE);
+		assert_eq!(Bonds::bonds_of_asset(HDX).into_inner(), vec![bond_id]);
+	});
+}
+
+#[test]
+fn migration_should_index_bonds_with_outstanding_supply() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let redeemed_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + 2 * MONTH));
+		Timestamp::set_timestamp(NOW + MONTH);
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), redeemed_bond_id, ONE));
+
+		let _ = BondsByAsset::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(0).put::<Bonds>();
+
+		// Act
+		migration::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		// Assert
+		assert_eq!(Bonds::bonds_of_asset(HDX).into_inner(), vec![bond_id]);
+		assert_eq!(StorageVersion::get::<Bonds>(), 1);
+	});
+}
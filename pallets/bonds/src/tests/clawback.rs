@@ -0,0 +1,88 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn clawback_should_burn_bonds_of_holder_and_transfer_underlying_to_dest() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, amount));
+
+		// Act
+		assert_ok!(Bonds::clawback(RuntimeOrigin::root(), bond_id, BOB, amount, CHARLIE));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &BOB), 0);
+		assert_eq!(Tokens::free_balance(HDX, &CHARLIE), amount);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		assert_eq!(Bonds::issued_by_issuer(ALICE), 0);
+		expect_events(vec![Event::Clawback {
+			bond_id,
+			holder: BOB,
+			amount,
+			dest: CHARLIE,
+			underlying_amount: amount,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn clawback_should_fail_when_origin_is_not_clawback_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::clawback(RuntimeOrigin::signed(ALICE), bond_id, ALICE, amount, CHARLIE),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn clawback_should_fail_when_bonds_are_mature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+		Timestamp::set_timestamp(maturity);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::clawback(RuntimeOrigin::root(), bond_id, ALICE, amount, CHARLIE),
+			Error::<Test>::AlreadyMatured
+		);
+	});
+}
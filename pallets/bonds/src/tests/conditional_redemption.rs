@@ -0,0 +1,129 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn issue_conditional_should_set_redemption_condition_of_new_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue_conditional(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			2 * ONE
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 100 * ONE);
+		assert_eq!(Bonds::bond_redemption_condition(bond_id), Some(2 * ONE));
+		expect_events(vec![Event::RedemptionConditionSet {
+			bond_id,
+			condition: 2 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn issue_conditional_should_fail_when_bonds_are_already_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue_conditional(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH, 2 * ONE),
+			Error::<Test>::RedemptionConditionNotAllowed
+		);
+	});
+}
+
+#[test]
+fn redeem_should_work_when_condition_is_met() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_conditional(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			2 * ONE
+		));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+		DummyConditionOracle::set_price(2 * ONE);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 100 * ONE));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn redeem_should_fail_when_condition_is_not_met() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_conditional(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			2 * ONE
+		));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+		DummyConditionOracle::set_price(2 * ONE - 1);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 100 * ONE),
+			Error::<Test>::ConditionNotMet
+		);
+		assert_noop!(
+			Bonds::redeem_as(RuntimeOrigin::signed(ALICE), bond_id, 100 * ONE, DAI, 0),
+			Error::<Test>::ConditionNotMet
+		);
+	});
+}
+
+#[test]
+fn redeem_should_not_check_condition_of_unconditional_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+
+		// Act & Assert
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 100 * ONE));
+	});
+}
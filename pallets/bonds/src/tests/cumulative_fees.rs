@@ -0,0 +1,56 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::assert_ok;
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn issue_should_accumulate_protocol_fees_per_underlying_asset() {
+	ExtBuilder::default()
+		.with_protocol_fee(Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let fee = <Test as Config>::ProtocolFee::get().mul_ceil(ONE);
+
+			// Act
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + 2 * MONTH));
+
+			// Assert
+			assert_eq!(Bonds::cumulative_fees(HDX), 2 * fee);
+			assert_eq!(
+				Tokens::free_balance(HDX, &<Test as Config>::FeeReceiver::get()),
+				2 * fee
+			);
+		});
+}
+
+#[test]
+fn issue_should_not_track_fees_when_protocol_fee_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+
+		// Assert
+		assert_eq!(Bonds::cumulative_fees(HDX), 0);
+		assert!(!CumulativeFees::<Test>::contains_key(HDX));
+	});
+}
@@ -0,0 +1,181 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn issue_discounted_should_issue_bonds_below_face_value() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let face_value = 100 * ONE;
+		let discount = 10 * ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue_discounted(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			face_value,
+			discount,
+			maturity
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), face_value - discount);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - face_value);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), face_value);
+		assert_eq!(Bonds::bond_discount(bond_id), Some((face_value - discount, discount)));
+		assert_eq!(Bonds::issued_by_issuer(ALICE), face_value);
+	});
+}
+
+#[test]
+fn redeem_should_pay_face_value_when_bonds_are_discounted() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let face_value = 100 * ONE;
+		let discount = 10 * ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_discounted(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			face_value,
+			discount,
+			maturity
+		));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(
+			RuntimeOrigin::signed(ALICE),
+			bond_id,
+			face_value - discount
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		assert_eq!(Bonds::bond_discount(bond_id), None);
+		assert_eq!(Bonds::issued_by_issuer(ALICE), 0);
+	});
+}
+
+#[test]
+fn partial_redemptions_should_total_face_value_when_bonds_are_discounted() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let face_value = 100 * ONE;
+		let discount = 10 * ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_discounted(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			face_value,
+			discount,
+			maturity
+		));
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, 30 * ONE));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, 30 * ONE));
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 20 * ONE));
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 40 * ONE));
+
+		// Assert
+		let alice_received = Tokens::free_balance(HDX, &ALICE) - (INITIAL_BALANCE - face_value);
+		let bob_received = Tokens::free_balance(HDX, &BOB);
+		assert_eq!(bob_received, 30 * ONE + 3_333_333_333_333);
+		assert_eq!(alice_received + bob_received, face_value);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		assert_eq!(Bonds::bond_discount(bond_id), None);
+	});
+}
+
+#[test]
+fn issue_discounted_should_fail_when_discount_is_not_lower_than_face_value() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Bonds::issue_discounted(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, 100 * ONE, NOW + MONTH),
+			Error::<Test>::InvalidDiscount
+		);
+	});
+}
+
+#[test]
+fn issue_should_fail_when_bonds_are_discounted() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		assert_ok!(Bonds::issue_discounted(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			10 * ONE,
+			maturity
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity),
+			Error::<Test>::DiscountNotAllowed
+		);
+		assert_noop!(
+			Bonds::issue_discounted(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, 10 * ONE, maturity),
+			Error::<Test>::DiscountNotAllowed
+		);
+	});
+}
+
+#[test]
+fn redeem_should_fail_when_discounted_bonds_would_be_queued() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_discounted(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			10 * ONE,
+			maturity
+		));
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::withdraw(
+			HDX,
+			&Bonds::pallet_account_id(),
+			50 * ONE
+		));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 90 * ONE),
+			Error::<Test>::InsufficientReserve
+		);
+	});
+}
@@ -0,0 +1,98 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::assert_ok;
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn redeem_should_redeem_dust_remainder_when_remainder_is_below_threshold() {
+	ExtBuilder::default()
+		.with_dust_redemption(3 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let amount = 10 * ONE;
+			let redeem_amount = 8 * ONE;
+
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, redeem_amount));
+
+			// Assert
+			expect_events(vec![
+				Event::DustRedeemed {
+					who: ALICE,
+					bond_id,
+					amount: amount - redeem_amount,
+				}
+				.into(),
+				Event::Redeemed {
+					who: ALICE,
+					bond_id,
+					amount,
+				}
+				.into(),
+			]);
+
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		});
+}
+
+#[test]
+fn redeem_should_keep_remainder_when_remainder_is_not_below_threshold() {
+	ExtBuilder::default()
+		.with_dust_redemption(2 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let amount = 10 * ONE;
+			let redeem_amount = 8 * ONE;
+
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, redeem_amount));
+
+			// Assert
+			expect_events(vec![Event::Redeemed {
+				who: ALICE,
+				bond_id,
+				amount: redeem_amount,
+			}
+			.into()]);
+
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), amount - redeem_amount);
+			assert_eq!(
+				Tokens::free_balance(HDX, &ALICE),
+				INITIAL_BALANCE - amount + redeem_amount
+			);
+		});
+}
@@ -0,0 +1,212 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_ok, traits::Hooks};
+pub use pretty_assertions::assert_eq;
+
+fn outstanding_supply_events() -> Vec<(AssetId, Balance)> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Bonds(Event::OutstandingSupply { asset, amount }) => Some((asset, amount)),
+			_ => None,
+		})
+		.collect()
+}
+
+fn underlying_outstanding_supply_events() -> Vec<(AssetId, Balance)> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Bonds(Event::UnderlyingOutstandingSupply { asset, amount }) => Some((asset, amount)),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn outstanding_supply_should_be_emitted_for_every_bond_when_interval_is_reached() {
+	ExtBuilder::default().with_gauge_interval(10).build().execute_with(|| {
+		// Arrange
+		let bond_id_1 = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+		let bond_id_2 = next_asset_id();
+		assert_ok!(Bonds::issue(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			2 * ONE,
+			NOW + 2 * MONTH
+		));
+
+		// Act
+		Bonds::on_finalize(10);
+
+		// Assert
+		let mut events = outstanding_supply_events();
+		events.sort();
+		assert_eq!(events, vec![(bond_id_1, ONE), (bond_id_2, 2 * ONE)]);
+	});
+}
+
+#[test]
+fn outstanding_supply_should_not_be_emitted_when_interval_is_not_reached() {
+	ExtBuilder::default().with_gauge_interval(10).build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+
+		// Act
+		Bonds::on_finalize(9);
+		Bonds::on_finalize(11);
+
+		// Assert
+		assert_eq!(outstanding_supply_events(), vec![]);
+	});
+}
+
+#[test]
+fn outstanding_supply_should_not_be_emitted_when_interval_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+
+		// Act
+		Bonds::on_finalize(10);
+
+		// Assert
+		assert_eq!(outstanding_supply_events(), vec![]);
+	});
+}
+
+#[test]
+fn outstanding_supply_should_not_be_emitted_when_bonds_are_fully_redeemed() {
+	ExtBuilder::default().with_gauge_interval(10).build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, ONE));
+
+		// Act
+		Bonds::on_finalize(10);
+
+		// Assert
+		assert_eq!(outstanding_supply_events(), vec![]);
+	});
+}
+
+#[test]
+fn outstanding_supply_should_be_emitted_for_at_most_max_assets_per_gauge_bonds() {
+	ExtBuilder::default().with_gauge_interval(10).build().execute_with(|| {
+		// Arrange
+		for i in 0..3 {
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH + i));
+		}
+
+		// Act
+		Bonds::on_finalize(20);
+
+		// Assert
+		assert_eq!(outstanding_supply_events().len(), 2);
+	});
+}
+
+#[test]
+fn outstanding_supply_should_continue_with_next_bonds_in_next_gauge_block() {
+	ExtBuilder::default().with_gauge_interval(10).build().execute_with(|| {
+		// Arrange
+		let bond_ids: Vec<AssetId> = (0..3)
+			.map(|i| {
+				let bond_id = next_asset_id();
+				assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH + i));
+				bond_id
+			})
+			.collect();
+		Bonds::on_finalize(10);
+		System::reset_events();
+
+		// Act
+		Bonds::on_finalize(20);
+
+		// Assert
+		assert_eq!(outstanding_supply_events(), vec![(bond_ids[2], ONE)]);
+		assert_eq!(GaugeCursor::<Test>::get(), None);
+
+		System::reset_events();
+		Bonds::on_finalize(30);
+		assert_eq!(
+			outstanding_supply_events(),
+			vec![(bond_ids[0], ONE), (bond_ids[1], ONE)]
+		);
+	});
+}
+
+#[test]
+fn underlying_outstanding_supply_should_be_emitted_when_all_bonds_of_asset_were_checked() {
+	ExtBuilder::default()
+		.with_gauge_interval(10)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+			assert_ok!(Bonds::issue(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				2 * ONE,
+				NOW + 2 * MONTH
+			));
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, 3 * ONE, NOW + MONTH));
+
+			// Act
+			Bonds::on_finalize(10);
+			Bonds::on_finalize(20);
+
+			// Assert
+			let mut events = underlying_outstanding_supply_events();
+			events.sort();
+			assert_eq!(events, vec![(HDX, 3 * ONE), (DAI, 3 * ONE)]);
+			assert_eq!(outstanding_supply_events().len(), 3);
+		});
+}
+
+#[test]
+fn underlying_outstanding_supply_should_include_bonds_checked_in_previous_gauge_block() {
+	ExtBuilder::default().with_gauge_interval(10).build().execute_with(|| {
+		// Arrange
+		for i in 0..3 {
+			assert_ok!(Bonds::issue(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				(i + 1) * ONE,
+				NOW + MONTH + i as u64
+			));
+		}
+
+		// Act
+		Bonds::on_finalize(10);
+
+		// Assert
+		assert_eq!(underlying_outstanding_supply_events(), vec![]);
+		assert_eq!(GaugeCursor::<Test>::get(), Some((HDX, 2, 3 * ONE)));
+
+		Bonds::on_finalize(20);
+		assert_eq!(underlying_outstanding_supply_events(), vec![(HDX, 6 * ONE)]);
+	});
+}
@@ -0,0 +1,86 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::assert_ok;
+use proptest::prelude::*;
+
+fn decimals() -> impl Strategy<Value = u32> {
+	prop_oneof![Just(6), Just(12), Just(18)]
+}
+
+fn asset_amount(max: Balance, precision: u32) -> impl Strategy<Value = Balance> {
+	let min_amount = 10u128.pow(precision);
+	let max_amount = max * 10u128.pow(precision);
+	min_amount..max_amount
+}
+
+fn protocol_fee() -> impl Strategy<Value = Permill> {
+	(0..=100_000u32).prop_map(Permill::from_parts)
+}
+
+prop_compose! {
+	fn get_amount_with_precision()
+	(
+		precision in decimals(),
+	)
+	(
+		precision in Just(precision),
+		amount in asset_amount(1_000_000, precision),
+	)
+	-> (Balance, u32) {
+		(amount, precision)
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1_000))]
+	#[test]
+	fn issue_and_redeem_should_preserve_value_minus_fee(
+		(amount, precision) in get_amount_with_precision(),
+		fee in protocol_fee(),
+	) {
+		let ed = 10u128.pow(precision) / 100;
+
+		ExtBuilder::default()
+		.with_registered_asset(DAI, ed, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, amount + ed), (TREASURY, DAI, ed)])
+		.with_protocol_fee(fee)
+		.build()
+		.execute_with(|| {
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, amount, maturity));
+
+			let fee_amount = Tokens::free_balance(DAI, &TREASURY) - ed;
+			let bond_amount = Tokens::free_balance(bond_id, &ALICE);
+
+			Timestamp::set_timestamp(maturity);
+
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, bond_amount));
+
+			assert_eq!(fee_amount, fee.mul_ceil(amount));
+			assert_eq!(bond_amount + fee_amount, amount);
+			assert_eq!(Tokens::free_balance(DAI, &ALICE), amount + ed - fee_amount);
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(DAI, &Bonds::pallet_account_id()), 0);
+		});
+	}
+}
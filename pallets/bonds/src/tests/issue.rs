@@ -47,6 +47,7 @@ fn issue_bonds_should_work_when_fee_is_zero() {
 				bond_id,
 				amount,
 				fee: 0,
+				maturity,
 			}
 			.into(),
 		]);
@@ -93,6 +94,7 @@ fn issue_bonds_should_work_when_fee_is_non_zero() {
 					bond_id,
 					amount: amount_without_fee,
 					fee,
+					maturity,
 				}
 				.into(),
 			]);
@@ -144,6 +146,7 @@ fn issue_bonds_should_issue_new_bonds_when_bonds_are_already_registered() {
 					bond_id,
 					amount: amount_without_fee,
 					fee,
+					maturity,
 				}
 				.into(),
 				Event::Issued {
@@ -151,6 +154,7 @@ fn issue_bonds_should_issue_new_bonds_when_bonds_are_already_registered() {
 					bond_id,
 					amount: amount_without_fee,
 					fee,
+					maturity,
 				}
 				.into(),
 			]);
@@ -209,6 +213,7 @@ fn issue_bonds_should_register_new_bonds_when_underlying_asset_is_different() {
 					bond_id: first_bond_id,
 					amount: amount_without_fee,
 					fee,
+					maturity,
 				}
 				.into(),
 				Event::TokenCreated {
@@ -223,6 +228,7 @@ fn issue_bonds_should_register_new_bonds_when_underlying_asset_is_different() {
 					bond_id: second_bond_id,
 					amount: amount_without_fee,
 					fee,
+					maturity,
 				}
 				.into(),
 			]);
@@ -289,6 +295,7 @@ fn issue_bonds_should_register_new_bonds_when_maturity_is_different() {
 					bond_id: first_bond_id,
 					amount: amount_without_fee,
 					fee,
+					maturity: next_month,
 				}
 				.into(),
 				Event::TokenCreated {
@@ -303,6 +310,7 @@ fn issue_bonds_should_register_new_bonds_when_maturity_is_different() {
 					bond_id: second_bond_id,
 					amount: amount_without_fee,
 					fee,
+					maturity: next_week,
 				}
 				.into(),
 			]);
@@ -359,6 +367,7 @@ fn issue_bonds_should_work_when_bonds_are_mature() {
 				bond_id,
 				amount,
 				fee: 0,
+				maturity,
 			}
 			.into(),
 			Event::Issued {
@@ -366,6 +375,7 @@ fn issue_bonds_should_work_when_bonds_are_mature() {
 				bond_id,
 				amount,
 				fee: 0,
+				maturity,
 			}
 			.into(),
 		]);
@@ -416,6 +426,7 @@ fn reissuance_of_bonds_should_work_again_when_all_bonds_were_redeemed() {
 				bond_id,
 				amount,
 				fee: 0,
+				maturity,
 			}
 			.into(),
 			Event::TokenCreated {
@@ -430,6 +441,7 @@ fn reissuance_of_bonds_should_work_again_when_all_bonds_were_redeemed() {
 				bond_id,
 				amount,
 				fee: 0,
+				maturity,
 			}
 			.into(),
 		]);
@@ -510,3 +522,74 @@ fn issue_bonds_should_fail_when_called_from_wrong_origin() {
 		);
 	});
 }
+
+#[test]
+fn issue_bonds_should_record_issuer_when_new_bonds_are_registered() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+
+			// Act
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+			// Assert
+			assert_eq!(Bonds::bond_issuer(bond_id), Some(ALICE));
+
+			// issuing more of the same bonds doesn't change the issuer
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, ONE, maturity));
+			assert_eq!(Bonds::bond_issuer(bond_id), Some(ALICE));
+		});
+}
+
+#[test]
+fn issue_bonds_should_coalesce_maturities_when_granularity_is_set() {
+	ExtBuilder::default()
+		.with_maturity_granularity(DAY)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let effective_maturity = maturity - maturity % DAY;
+			let amount: Balance = ONE;
+
+			let bond_id = next_asset_id();
+
+			// Act
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+			assert_ok!(Bonds::issue(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				amount,
+				maturity + 1_000
+			));
+
+			// Assert
+			expect_events(vec![
+				Event::TokenCreated {
+					issuer: ALICE,
+					asset_id: HDX,
+					bond_id,
+					maturity: effective_maturity,
+				}
+				.into(),
+				Event::Issued {
+					issuer: ALICE,
+					bond_id,
+					amount,
+					fee: 0,
+					maturity: effective_maturity,
+				}
+				.into(),
+			]);
+
+			assert_eq!(next_asset_id(), bond_id + 1);
+			assert_eq!(Bonds::bond(bond_id), Some((HDX, effective_maturity)));
+			assert_eq!(Bonds::bond_id((HDX, effective_maturity)), Some(bond_id));
+			assert_eq!(Bonds::bond_id((HDX, maturity)), None);
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 2 * amount);
+		});
+}
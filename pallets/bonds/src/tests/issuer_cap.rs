@@ -0,0 +1,189 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn issue_should_work_when_issuance_reaches_issuer_cap() {
+	ExtBuilder::default()
+		.with_max_issuance_per_issuer(2 * ONE)
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + 2 * MONTH));
+
+			// Assert
+			assert_eq!(Bonds::issued_by_issuer(ALICE), 2 * ONE);
+		});
+}
+
+#[test]
+fn issue_should_fail_when_issuance_exceeds_issuer_cap() {
+	ExtBuilder::default()
+		.with_max_issuance_per_issuer(2 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE + 1, NOW + 2 * MONTH),
+				Error::<Test>::IssuerCapExceeded
+			);
+			assert_noop!(
+				Bonds::issue_locked(RuntimeOrigin::signed(ALICE), HDX, ONE + 1, NOW + 2 * MONTH),
+				Error::<Test>::IssuerCapExceeded
+			);
+		});
+}
+
+#[test]
+fn issue_should_count_issuance_of_registered_bonds_to_issuing_account() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, INITIAL_BALANCE)])
+		.with_max_issuance_per_issuer(2 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+			// Act
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, 2 * ONE, maturity));
+
+			// Assert
+			assert_eq!(Bonds::issued_by_issuer(ALICE), ONE);
+			assert_eq!(Bonds::issued_by_issuer(BOB), 2 * ONE);
+			assert_eq!(Bonds::issued_by_issuer_per_bond(bond_id, ALICE), ONE);
+			assert_eq!(Bonds::issued_by_issuer_per_bond(bond_id, BOB), 2 * ONE);
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+			assert_noop!(
+				Bonds::issue(RuntimeOrigin::signed(BOB), HDX, 1, maturity),
+				Error::<Test>::IssuerCapExceeded
+			);
+		});
+}
+
+#[test]
+fn redeem_should_release_issuance_of_issuer() {
+	ExtBuilder::default()
+		.with_max_issuance_per_issuer(2 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 2 * ONE, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, ONE));
+
+			// Assert
+			assert_eq!(Bonds::issued_by_issuer(ALICE), ONE);
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + 3 * MONTH));
+			assert_noop!(
+				Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 1, NOW + 3 * MONTH),
+				Error::<Test>::IssuerCapExceeded
+			);
+		});
+}
+
+#[test]
+fn redeem_should_release_issuance_of_issuer_of_redeemed_bonds() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, INITIAL_BALANCE)])
+		.with_max_issuance_per_issuer(2 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, 2 * ONE, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, ONE));
+
+			// Assert
+			assert_eq!(Bonds::issued_by_issuer(ALICE), ONE);
+			assert_eq!(Bonds::issued_by_issuer(BOB), ONE);
+			assert_eq!(Bonds::issued_by_issuer_per_bond(bond_id, BOB), ONE);
+		});
+}
+
+#[test]
+fn redeem_should_release_issuance_of_other_issuers_when_holder_did_not_issue_the_bonds() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, INITIAL_BALANCE)])
+		.with_max_issuance_per_issuer(2 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 2 * ONE, maturity));
+			assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, 2 * ONE));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, 2 * ONE));
+
+			// Assert
+			assert_eq!(Bonds::issued_by_issuer(ALICE), 0);
+			assert_eq!(Bonds::issued_by_issuer_per_bond(bond_id, ALICE), 0);
+			assert!(!IssuedByIssuer::<Test>::contains_key(ALICE));
+		});
+}
+
+#[test]
+fn redeem_should_release_issuance_when_bonds_have_no_registered_issuer() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, INITIAL_BALANCE)])
+		.with_max_issuance_per_issuer(2 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+			// bonds registered before the issuer of the bonds was tracked
+			BondIssuers::<Test>::remove(bond_id);
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, 2 * ONE, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, 2 * ONE));
+
+			// Assert
+			assert_eq!(Bonds::issued_by_issuer(BOB), 0);
+			assert_eq!(Bonds::issued_by_issuer(ALICE), ONE);
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, 2 * ONE, maturity));
+		});
+}
@@ -0,0 +1,132 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::assert_ok;
+pub use pretty_assertions::assert_eq;
+
+const STABLESWAP_SHARES: AssetId = 100;
+const XYK_SHARES: AssetId = 101;
+
+#[test]
+fn issue_should_work_when_underlying_asset_is_stableswap_share_asset() {
+	ExtBuilder::default()
+		.with_protocol_fee(Permill::from_percent(10))
+		.with_registered_asset(STABLESWAP_SHARES, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::StableSwap)
+		.add_endowed_accounts(vec![(ALICE, STABLESWAP_SHARES, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let amount: Balance = 100 * ONE;
+			let fee = <Test as Config>::ProtocolFee::get().mul_ceil(amount);
+			let amount_without_fee: Balance = amount.checked_sub(fee).unwrap();
+			let bond_id = next_asset_id();
+
+			// Act
+			assert_ok!(Bonds::issue(
+				RuntimeOrigin::signed(ALICE),
+				STABLESWAP_SHARES,
+				amount,
+				maturity
+			));
+
+			// Assert
+			assert_eq!(fee, 10 * ONE);
+			assert_eq!(Bonds::bond(bond_id), Some((STABLESWAP_SHARES, maturity)));
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), amount_without_fee);
+			assert_eq!(
+				Tokens::free_balance(STABLESWAP_SHARES, &ALICE),
+				INITIAL_BALANCE - amount
+			);
+			assert_eq!(
+				Tokens::free_balance(STABLESWAP_SHARES, &<Test as Config>::FeeReceiver::get()),
+				fee
+			);
+			assert_eq!(
+				Tokens::free_balance(STABLESWAP_SHARES, &Bonds::pallet_account_id()),
+				amount_without_fee
+			);
+		});
+}
+
+#[test]
+fn redeem_should_return_share_asset_when_underlying_asset_is_stableswap_share_asset() {
+	ExtBuilder::default()
+		.with_protocol_fee(Permill::from_percent(10))
+		.with_registered_asset(STABLESWAP_SHARES, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::StableSwap)
+		.add_endowed_accounts(vec![(ALICE, STABLESWAP_SHARES, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let amount: Balance = 100 * ONE;
+			let fee = <Test as Config>::ProtocolFee::get().mul_ceil(amount);
+			let amount_without_fee: Balance = amount.checked_sub(fee).unwrap();
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(
+				RuntimeOrigin::signed(ALICE),
+				STABLESWAP_SHARES,
+				amount,
+				maturity
+			));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount_without_fee));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(STABLESWAP_SHARES, &ALICE), INITIAL_BALANCE - fee);
+			assert_eq!(Tokens::free_balance(STABLESWAP_SHARES, &Bonds::pallet_account_id()), 0);
+		});
+}
+
+#[test]
+fn issue_and_redeem_should_work_when_underlying_asset_is_xyk_share_asset() {
+	ExtBuilder::default()
+		.with_protocol_fee(Permill::from_percent(10))
+		.with_registered_asset(XYK_SHARES, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::XYK)
+		.add_endowed_accounts(vec![(ALICE, XYK_SHARES, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let amount: Balance = 100 * ONE;
+			let fee = <Test as Config>::ProtocolFee::get().mul_ceil(amount);
+			let amount_without_fee: Balance = amount.checked_sub(fee).unwrap();
+			let bond_id = next_asset_id();
+
+			// Act
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), XYK_SHARES, amount, maturity));
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount_without_fee));
+
+			// Assert
+			assert_eq!(Bonds::bond(bond_id), Some((XYK_SHARES, maturity)));
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(XYK_SHARES, &ALICE), INITIAL_BALANCE - fee);
+			assert_eq!(
+				Tokens::free_balance(XYK_SHARES, &<Test as Config>::FeeReceiver::get()),
+				fee
+			);
+			assert_eq!(Tokens::free_balance(XYK_SHARES, &Bonds::pallet_account_id()), 0);
+		});
+}
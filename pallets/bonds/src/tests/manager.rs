@@ -0,0 +1,153 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn issue_managed_should_set_manager_of_new_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue_managed(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			ONE,
+			maturity,
+			BOB
+		));
+
+		// Assert
+		assert_eq!(Bonds::bond_manager(bond_id), Some(BOB));
+	});
+}
+
+#[test]
+fn issue_managed_should_fail_when_existing_bonds_have_different_manager() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue_managed(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity, BOB),
+			Error::<Test>::ManagerNotAllowed
+		);
+	});
+}
+
+#[test]
+fn manager_redeem_should_redeem_bonds_of_holder_to_dest() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_managed(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			amount,
+			maturity,
+			BOB
+		));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::manager_redeem(
+			RuntimeOrigin::signed(BOB),
+			bond_id,
+			ALICE,
+			amount,
+			CHARLIE
+		));
+
+		// Assert
+		expect_events(vec![
+			Event::Redeemed {
+				who: ALICE,
+				bond_id,
+				amount,
+			}
+			.into(),
+			Event::ManagerRedeemed {
+				manager: BOB,
+				holder: ALICE,
+				bond_id,
+				amount,
+				dest: CHARLIE,
+			}
+			.into(),
+		]);
+
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount);
+		assert_eq!(Tokens::free_balance(HDX, &CHARLIE), amount);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+	});
+}
+
+#[test]
+fn manager_redeem_should_fail_when_origin_is_not_manager() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_managed(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			amount,
+			maturity,
+			BOB
+		));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::manager_redeem(RuntimeOrigin::signed(ALICE), bond_id, ALICE, amount, CHARLIE),
+			Error::<Test>::NotManager
+		);
+	});
+}
+
+#[test]
+fn manager_redeem_should_fail_when_bonds_have_no_manager() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::manager_redeem(RuntimeOrigin::signed(BOB), bond_id, ALICE, amount, CHARLIE),
+			Error::<Test>::NotManager
+		);
+	});
+}
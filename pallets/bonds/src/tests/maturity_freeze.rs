@@ -0,0 +1,144 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn set_maturity_freeze_should_store_current_time_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(Bonds::set_maturity_freeze(RuntimeOrigin::root(), true));
+
+		// Assert
+		assert_eq!(Bonds::global_maturity_freeze(), Some(NOW));
+		expect_events(vec![Event::MaturityFreezeSet { frozen_at: Some(NOW) }.into()]);
+	});
+}
+
+#[test]
+fn set_maturity_freeze_should_fail_when_maturity_is_already_frozen() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::set_maturity_freeze(RuntimeOrigin::root(), true));
+		Timestamp::set_timestamp(NOW + MONTH);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::set_maturity_freeze(RuntimeOrigin::root(), true),
+			Error::<Test>::MaturityAlreadyFrozen
+		);
+		assert_eq!(Bonds::global_maturity_freeze(), Some(NOW));
+	});
+}
+
+#[test]
+fn set_maturity_freeze_should_store_new_time_when_frozen_again_after_unfreeze() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::set_maturity_freeze(RuntimeOrigin::root(), true));
+		assert_ok!(Bonds::set_maturity_freeze(RuntimeOrigin::root(), false));
+		Timestamp::set_timestamp(NOW + MONTH);
+
+		// Act
+		assert_ok!(Bonds::set_maturity_freeze(RuntimeOrigin::root(), true));
+
+		// Assert
+		assert_eq!(Bonds::global_maturity_freeze(), Some(NOW + MONTH));
+	});
+}
+
+#[test]
+fn set_maturity_freeze_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Bonds::set_maturity_freeze(RuntimeOrigin::signed(ALICE), true),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn redeem_should_fail_when_bond_matures_after_maturity_freeze() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let amount = 10 * ONE;
+
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+		assert_ok!(Bonds::set_maturity_freeze(RuntimeOrigin::root(), true));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount),
+			Error::<Test>::NotMature
+		);
+	});
+}
+
+#[test]
+fn redeem_should_work_when_bond_matured_before_maturity_freeze() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let amount = 10 * ONE;
+
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+		assert_ok!(Bonds::set_maturity_freeze(RuntimeOrigin::root(), true));
+
+		// Act & Assert
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount));
+	});
+}
+
+#[test]
+fn redeem_should_work_when_maturity_freeze_is_removed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let amount = 10 * ONE;
+
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+		assert_ok!(Bonds::set_maturity_freeze(RuntimeOrigin::root(), true));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::set_maturity_freeze(RuntimeOrigin::root(), false));
+
+		// Assert
+		assert_eq!(Bonds::global_maturity_freeze(), None);
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount));
+		expect_events(vec![Event::Redeemed {
+			who: ALICE,
+			bond_id,
+			amount,
+		}
+		.into()]);
+	});
+}
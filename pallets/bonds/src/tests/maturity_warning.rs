@@ -0,0 +1,94 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_ok, traits::Hooks};
+pub use pretty_assertions::assert_eq;
+
+fn maturing_soon_events() -> Vec<(AssetId, Moment)> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Bonds(Event::BondMaturingSoon { bond_id, maturity }) => Some((bond_id, maturity)),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn maturity_warning_should_be_emitted_once_when_bond_enters_warning_window() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		Bonds::on_initialize(2);
+		assert_eq!(maturing_soon_events(), vec![]);
+
+		// Act
+		Timestamp::set_timestamp(maturity - WEEK);
+		Bonds::on_initialize(3);
+		Bonds::on_initialize(4);
+		Bonds::on_initialize(5);
+
+		// Assert
+		assert_eq!(maturing_soon_events(), vec![(bond_id, maturity)]);
+	});
+}
+
+#[test]
+fn maturity_warning_should_not_be_emitted_when_bond_is_mature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act
+		Timestamp::set_timestamp(maturity);
+		Bonds::on_initialize(2);
+
+		// Assert
+		assert_eq!(maturing_soon_events(), vec![]);
+	});
+}
+
+#[test]
+fn maturity_check_should_continue_from_cursor_when_max_checks_per_block_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + DAY;
+		for i in 0..3 {
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity + i));
+		}
+
+		// Act & Assert
+		Bonds::on_initialize(2);
+		assert_eq!(maturing_soon_events().len(), 2);
+		assert!(MaturityCheckCursor::<Test>::get().is_some());
+
+		Bonds::on_initialize(3);
+		assert_eq!(maturing_soon_events().len(), 3);
+
+		Bonds::on_initialize(4);
+		Bonds::on_initialize(5);
+		assert_eq!(maturing_soon_events().len(), 3);
+		assert!(crate::pallet::Bonds::<Test>::iter_keys().all(MaturityWarned::<Test>::contains_key));
+	});
+}
@@ -0,0 +1,150 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn issue_with_metadata_uri_should_set_metadata_uri_of_new_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue_with_metadata_uri(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			ONE,
+			maturity,
+			b"ipfs://prospectus".to_vec()
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), ONE);
+		assert_eq!(
+			Bonds::bond_metadata_uri(bond_id).map(|uri| uri.into_inner()),
+			Some(b"ipfs://prospectus".to_vec())
+		);
+		expect_events(vec![Event::MetadataUriSet {
+			bond_id,
+			uri: b"ipfs://prospectus".to_vec().try_into().unwrap(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_metadata_uri_should_update_metadata_uri_when_called_by_issuer() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+		assert_eq!(Bonds::bond_metadata_uri(bond_id), None);
+		assert_ok!(Bonds::set_metadata_uri(
+			RuntimeOrigin::signed(ALICE),
+			bond_id,
+			b"ipfs://v1".to_vec()
+		));
+
+		// Act
+		assert_ok!(Bonds::set_metadata_uri(
+			RuntimeOrigin::signed(ALICE),
+			bond_id,
+			b"ipfs://v2".to_vec()
+		));
+
+		// Assert
+		assert_eq!(
+			Bonds::bond_metadata_uri(bond_id).map(|uri| uri.into_inner()),
+			Some(b"ipfs://v2".to_vec())
+		);
+	});
+}
+
+#[test]
+fn set_metadata_uri_should_fail_when_called_by_non_issuer() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::set_metadata_uri(RuntimeOrigin::signed(BOB), bond_id, b"ipfs://v1".to_vec()),
+			Error::<Test>::NotIssuer
+		);
+	});
+}
+
+#[test]
+fn set_metadata_uri_should_fail_when_bonds_are_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act & Assert
+		assert_noop!(
+			Bonds::set_metadata_uri(RuntimeOrigin::signed(ALICE), next_asset_id(), b"ipfs://v1".to_vec()),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
+
+#[test]
+fn set_metadata_uri_should_fail_when_uri_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::set_metadata_uri(RuntimeOrigin::signed(ALICE), bond_id, vec![]),
+			Error::<Test>::EmptyMetadataUri
+		);
+	});
+}
+
+#[test]
+fn metadata_uri_should_be_rejected_when_longer_than_max_uri_len() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		let uri = vec![b'u'; 33];
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::set_metadata_uri(RuntimeOrigin::signed(ALICE), bond_id, uri.clone()),
+			Error::<Test>::MetadataUriTooLong
+		);
+		assert_noop!(
+			Bonds::issue_with_metadata_uri(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity, uri),
+			Error::<Test>::MetadataUriTooLong
+		);
+		assert_ok!(Bonds::set_metadata_uri(
+			RuntimeOrigin::signed(ALICE),
+			bond_id,
+			vec![b'u'; 32]
+		));
+	});
+}
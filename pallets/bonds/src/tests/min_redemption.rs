@@ -0,0 +1,77 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn redeem_should_fail_when_partial_redemption_is_below_minimum() {
+	ExtBuilder::default()
+		.with_min_redemption_amount(2 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let amount = 10 * ONE;
+
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, ONE),
+				Error::<Test>::RedemptionTooSmall
+			);
+		});
+}
+
+#[test]
+fn redeem_should_work_when_redemption_below_minimum_closes_out_balance() {
+	ExtBuilder::default()
+		.with_min_redemption_amount(2 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let amount = 10 * ONE;
+
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount - ONE));
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, ONE));
+
+			// Assert
+			expect_events(vec![Event::Redeemed {
+				who: ALICE,
+				bond_id,
+				amount: ONE,
+			}
+			.into()]);
+
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+		});
+}
@@ -19,20 +19,25 @@ use crate as pallet_bonds;
 use crate::*;
 
 use frame_support::{
-	construct_runtime, parameter_types,
+	construct_runtime,
+	dispatch::DispatchResultWithPostInfo,
+	parameter_types,
 	sp_runtime::{
 		traits::{BlakeTwo256, IdentityLookup},
 		BuildStorage,
 	},
 	traits::{ConstU32, ConstU64, Everything, SortedMembers},
 };
-use frame_system::EnsureSignedBy;
+use frame_system::{ensure_signed, EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::BoundedVec;
 use std::{cell::RefCell, collections::HashMap};
 
-use hydradx_traits::registry::{Create, Inspect};
-use orml_traits::parameter_type_with_key;
+use hydradx_traits::{
+	registry::{Create, Inspect},
+	router::{AmountInAndOut, AssetPair, RouteProvider, RouterT, Trade},
+};
+use orml_traits::{currency::MutationHooks, parameter_type_with_key};
 pub use primitives::constants::{
 	currency::NATIVE_EXISTENTIAL_DEPOSIT,
 	time::{
@@ -56,14 +61,27 @@ pub const INITIAL_BALANCE: Balance = 1_000 * ONE;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
 pub const TREASURY: AccountId = 400;
 
 pub const NOW: Moment = 1689844300000; // unix time in milliseconds
 
+/// Amount of the asset out the mock router pays for one unit of the asset in.
+pub const ROUTER_PRICE: Balance = 2;
+
 thread_local! {
 	// maps AssetId -> existential deposit
 	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, (Balance, AssetKind)>> = RefCell::new(HashMap::default());
 	pub static PROTOCOL_FEE: RefCell<Permill> = const { RefCell::new(Permill::from_percent(0)) };
+	pub static MATURITY_GRANULARITY: RefCell<Moment> = const { RefCell::new(1) };
+	pub static MAX_ISSUANCE_PER_ISSUER: RefCell<Balance> = const { RefCell::new(Balance::MAX) };
+	pub static DUST_REDEMPTION: RefCell<bool> = const { RefCell::new(false) };
+	pub static BOND_DUST_THRESHOLD: RefCell<Balance> = const { RefCell::new(0) };
+	pub static MIN_REDEMPTION_AMOUNT: RefCell<Balance> = const { RefCell::new(0) };
+	pub static GAUGE_INTERVAL: RefCell<u64> = const { RefCell::new(0) };
+	pub static UNDERLYING_FALLBACK_ASSET: RefCell<Option<AssetId>> = const { RefCell::new(None) };
+	pub static STAKED: RefCell<HashMap<AccountId, Balance>> = RefCell::new(HashMap::default());
+	pub static ORACLE_PRICE: RefCell<Balance> = const { RefCell::new(0) };
 }
 
 construct_runtime!(
@@ -80,6 +98,15 @@ parameter_types! {
 	pub ProtocolFee: Permill = PROTOCOL_FEE.with(|v| *v.borrow());
 	pub TreasuryAccount: AccountId = TREASURY;
 	pub const BondsPalletId: PalletId = PalletId(*b"pltbonds");
+	pub const PostMaturityGrace: Moment = DAY;
+	pub const MaturityWarningWindow: Moment = WEEK;
+	pub MaturityGranularity: Moment = MATURITY_GRANULARITY.with(|v| *v.borrow());
+	pub MaxIssuancePerIssuer: Balance = MAX_ISSUANCE_PER_ISSUER.with(|v| *v.borrow());
+	pub DustRedemption: bool = DUST_REDEMPTION.with(|v| *v.borrow());
+	pub BondDustThreshold: Balance = BOND_DUST_THRESHOLD.with(|v| *v.borrow());
+	pub MinRedemptionAmount: Balance = MIN_REDEMPTION_AMOUNT.with(|v| *v.borrow());
+	pub GaugeInterval: u64 = GAUGE_INTERVAL.with(|v| *v.borrow());
+	pub UnderlyingFallbackAsset: Option<AssetId> = UNDERLYING_FALLBACK_ASSET.with(|v| *v.borrow());
 }
 
 parameter_type_with_key! {
@@ -111,10 +138,33 @@ impl pallet_bonds::Config for Test {
 	type TimestampProvider = Timestamp;
 	type PalletId = BondsPalletId;
 	type IssueOrigin = EnsureSignedBy<AliceOrBob, AccountId>;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type ClawbackOrigin = EnsureRoot<AccountId>;
+	type PostMaturityGrace = PostMaturityGrace;
+	type MaturityGranularity = MaturityGranularity;
+	type MaxBatch = ConstU32<5>;
+	type MaxQueuedRedemptionsPerBlock = ConstU32<5>;
+	type MaturityWarningWindow = MaturityWarningWindow;
+	type MaxMaturityChecksPerBlock = ConstU32<2>;
+	type GaugeInterval = GaugeInterval;
+	type MaxAssetsPerGauge = ConstU32<2>;
+	type MaxIssuancePerIssuer = MaxIssuancePerIssuer;
+	type MaxUriLen = ConstU32<32>;
+	type MaxBidsPerBook = ConstU32<3>;
+	type DustRedemption = DustRedemption;
+	type BondDustThreshold = BondDustThreshold;
+	type MinRedemptionAmount = MinRedemptionAmount;
+	type UnderlyingFallbackAsset = UnderlyingFallbackAsset;
 	type AssetTypeWhitelist = AssetTypeWhitelist;
 	type ProtocolFee = ProtocolFee;
 	type FeeReceiver = TreasuryAccount;
+	type Router = DummyRouter;
+	type Staking = DummyStaking;
+	type RedemptionCondition = Balance;
+	type ConditionOracle = DummyConditionOracle;
+	type MaxBondsPerAsset = ConstU32<10>;
 	type WeightInfo = ();
+	type RouterWeightInfo = ();
 }
 
 impl frame_system::Config for Test {
@@ -156,13 +206,25 @@ impl orml_tokens::Config for Test {
 	type CurrencyId = AssetId;
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
-	type CurrencyHooks = ();
+	type CurrencyHooks = CurrencyHooks;
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = ();
 	type DustRemovalWhitelist = Everything;
 }
 
+pub struct CurrencyHooks;
+impl MutationHooks<AccountId, AssetId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = Bonds;
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
 parameter_types! {
 	pub const MinimumPeriod: u64 = SLOT_DURATION / 2;
 }
@@ -243,8 +305,8 @@ impl<T: Config> Inspect for DummyRegistry<T> {
 		REGISTERED_ASSETS.with(|v| v.borrow().get(&id).cloned()).map(|v| v.1)
 	}
 
-	fn exists(_name: AssetId) -> bool {
-		unimplemented!()
+	fn exists(id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&id))
 	}
 
 	fn is_banned(_id: Self::AssetId) -> bool {
@@ -264,10 +326,140 @@ impl<T: Config> Inspect for DummyRegistry<T> {
 	}
 }
 
+/// Staking mock which tracks the staked amount of each account.
+pub struct DummyStaking;
+
+impl DummyStaking {
+	pub fn staked(who: AccountId) -> Balance {
+		STAKED.with(|v| v.borrow().get(&who).copied().unwrap_or_default())
+	}
+}
+
+impl StakeHandler<AccountId, Balance> for DummyStaking {
+	fn stakeable_asset() -> AssetId {
+		HDX
+	}
+
+	fn stake(who: &AccountId, amount: Balance) -> DispatchResult {
+		ensure!(
+			Tokens::free_balance(HDX, who) >= Self::staked(*who).saturating_add(amount),
+			DispatchError::Other("InsufficientBalance")
+		);
+		STAKED.with(|v| *v.borrow_mut().entry(*who).or_default() += amount);
+		Ok(())
+	}
+
+	fn stake_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Condition oracle mock. The condition is the minimum price, which is met if `ORACLE_PRICE` is not lower.
+pub struct DummyConditionOracle;
+
+impl DummyConditionOracle {
+	pub fn set_price(price: Balance) {
+		ORACLE_PRICE.with(|v| *v.borrow_mut() = price);
+	}
+}
+
+impl RedemptionConditionOracle<Balance> for DummyConditionOracle {
+	fn is_met(min_price: &Balance) -> bool {
+		ORACLE_PRICE.with(|v| *v.borrow()) >= *min_price
+	}
+
+	fn is_met_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct DummyRouter;
+
+impl RouteProvider<AssetId> for DummyRouter {}
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for DummyRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		let who = ensure_signed(origin)?;
+		let amount_out = amount_in.saturating_mul(ROUTER_PRICE);
+		ensure!(
+			amount_out >= min_amount_out,
+			DispatchError::Other("TradingLimitReached")
+		);
+
+		<Tokens as MultiCurrency<AccountId>>::withdraw(asset_in, &who, amount_in)?;
+		<Tokens as MultiCurrency<AccountId>>::deposit(asset_out, &who, amount_out)
+	}
+
+	fn sell_all(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
 	registered_assets: Vec<(AssetId, (Balance, AssetKind))>,
 	protocol_fee: Permill,
+	maturity_granularity: Moment,
+	max_issuance_per_issuer: Balance,
+	dust_redemption: bool,
+	bond_dust_threshold: Balance,
+	min_redemption_amount: Balance,
+	gauge_interval: u64,
+	underlying_fallback_asset: Option<AssetId>,
 }
 
 impl Default for ExtBuilder {
@@ -275,11 +467,39 @@ impl Default for ExtBuilder {
 		PROTOCOL_FEE.with(|v| {
 			*v.borrow_mut() = Permill::from_percent(0);
 		});
+		MATURITY_GRANULARITY.with(|v| {
+			*v.borrow_mut() = 1;
+		});
+		MAX_ISSUANCE_PER_ISSUER.with(|v| {
+			*v.borrow_mut() = Balance::MAX;
+		});
+		DUST_REDEMPTION.with(|v| {
+			*v.borrow_mut() = false;
+		});
+		BOND_DUST_THRESHOLD.with(|v| {
+			*v.borrow_mut() = 0;
+		});
+		MIN_REDEMPTION_AMOUNT.with(|v| {
+			*v.borrow_mut() = 0;
+		});
+		GAUGE_INTERVAL.with(|v| {
+			*v.borrow_mut() = 0;
+		});
+		UNDERLYING_FALLBACK_ASSET.with(|v| {
+			*v.borrow_mut() = None;
+		});
 
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, 1_000 * ONE)],
 			registered_assets: vec![(HDX, (NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token))],
 			protocol_fee: Permill::from_percent(0),
+			maturity_granularity: 1,
+			max_issuance_per_issuer: Balance::MAX,
+			dust_redemption: false,
+			bond_dust_threshold: 0,
+			min_redemption_amount: 0,
+			gauge_interval: 0,
+			underlying_fallback_asset: None,
 		}
 	}
 }
@@ -299,6 +519,31 @@ impl ExtBuilder {
 		self.protocol_fee = fee;
 		self
 	}
+	pub fn with_maturity_granularity(mut self, granularity: Moment) -> Self {
+		self.maturity_granularity = granularity;
+		self
+	}
+	pub fn with_max_issuance_per_issuer(mut self, max_issuance: Balance) -> Self {
+		self.max_issuance_per_issuer = max_issuance;
+		self
+	}
+	pub fn with_dust_redemption(mut self, threshold: Balance) -> Self {
+		self.dust_redemption = true;
+		self.bond_dust_threshold = threshold;
+		self
+	}
+	pub fn with_min_redemption_amount(mut self, amount: Balance) -> Self {
+		self.min_redemption_amount = amount;
+		self
+	}
+	pub fn with_gauge_interval(mut self, interval: u64) -> Self {
+		self.gauge_interval = interval;
+		self
+	}
+	pub fn with_underlying_fallback_asset(mut self, asset: AssetId) -> Self {
+		self.underlying_fallback_asset = Some(asset);
+		self
+	}
 
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
@@ -313,6 +558,34 @@ impl ExtBuilder {
 			*v.borrow_mut() = self.protocol_fee;
 		});
 
+		MATURITY_GRANULARITY.with(|v| {
+			*v.borrow_mut() = self.maturity_granularity;
+		});
+
+		MAX_ISSUANCE_PER_ISSUER.with(|v| {
+			*v.borrow_mut() = self.max_issuance_per_issuer;
+		});
+
+		DUST_REDEMPTION.with(|v| {
+			*v.borrow_mut() = self.dust_redemption;
+		});
+
+		BOND_DUST_THRESHOLD.with(|v| {
+			*v.borrow_mut() = self.bond_dust_threshold;
+		});
+
+		MIN_REDEMPTION_AMOUNT.with(|v| {
+			*v.borrow_mut() = self.min_redemption_amount;
+		});
+
+		GAUGE_INTERVAL.with(|v| {
+			*v.borrow_mut() = self.gauge_interval;
+		});
+
+		UNDERLYING_FALLBACK_ASSET.with(|v| {
+			*v.borrow_mut() = self.underlying_fallback_asset;
+		});
+
 		orml_tokens::GenesisConfig::<Test> {
 			balances: self
 				.endowed_accounts
@@ -338,6 +611,13 @@ pub fn expect_events(e: Vec<RuntimeEvent>) {
 	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
 }
 
+/// Remove the asset from the mocked asset registry.
+pub fn remove_registered_asset(asset_id: AssetId) {
+	REGISTERED_ASSETS.with(|v| {
+		v.borrow_mut().remove(&asset_id);
+	});
+}
+
 pub fn next_asset_id() -> AssetId {
 	REGISTERED_ASSETS.with(|v| v.borrow().len().try_into().unwrap())
 }
@@ -1,5 +1,35 @@
+mod allowance;
+mod auction;
+mod bonds_by_asset;
+mod clawback;
+mod conditional_redemption;
+mod cumulative_fees;
+mod discount;
+mod dust_redemption;
+mod gauge;
+mod invariants;
 mod issue;
+mod issuer_cap;
+mod lp_shares;
+mod manager;
+mod maturity_freeze;
+mod maturity_warning;
+mod metadata_uri;
+mod min_redemption;
 pub mod mock;
+mod prefunded_yield;
 mod redeem;
+mod redeem_and_stake;
+mod redeem_as;
+mod redeem_many;
+mod redemption_fee;
+mod redemption_queue;
+mod redemption_shortfall;
 #[allow(clippy::module_inception)]
 mod tests;
+mod top_up;
+mod transfer_allowance;
+mod transfer_lock;
+mod underlying_removed;
+mod vesting;
+mod weighted_maturity;
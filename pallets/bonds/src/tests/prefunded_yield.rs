@@ -0,0 +1,121 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn issue_with_yield_should_lock_principal_and_yield() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let principal = 100 * ONE;
+		let yield_amount = 10 * ONE;
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue_with_yield(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			principal,
+			yield_amount,
+			NOW + MONTH
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), principal);
+		assert_eq!(
+			Tokens::free_balance(HDX, &ALICE),
+			INITIAL_BALANCE - principal - yield_amount
+		);
+		assert_eq!(
+			Tokens::free_balance(HDX, &Bonds::pallet_account_id()),
+			principal + yield_amount
+		);
+		assert_eq!(Bonds::bond_discount(bond_id), Some((principal, yield_amount)));
+	});
+}
+
+#[test]
+fn redemptions_should_pay_out_prefunded_yield_at_maturity() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let principal = 100 * ONE;
+		let yield_amount = 10 * ONE;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_yield(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			principal,
+			yield_amount,
+			NOW + MONTH
+		));
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, 30 * ONE));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, 30 * ONE));
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 70 * ONE));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 33 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - 30 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		assert_eq!(Bonds::bond_discount(bond_id), None);
+	});
+}
+
+#[test]
+fn issue_with_yield_should_fail_when_bonds_are_already_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue_with_yield(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, 10 * ONE, maturity),
+			Error::<Test>::DiscountNotAllowed
+		);
+	});
+}
+
+#[test]
+fn issue_with_yield_should_fail_when_principal_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Bonds::issue_with_yield(RuntimeOrigin::signed(ALICE), HDX, 0, 10 * ONE, NOW + MONTH),
+			Error::<Test>::YieldTooHigh
+		);
+	});
+}
+
+#[test]
+fn issue_with_yield_should_fail_when_yield_is_not_lower_than_amount_without_protocol_fee() {
+	ExtBuilder::default()
+		.with_protocol_fee(Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Bonds::issue_with_yield(RuntimeOrigin::signed(ALICE), HDX, ONE, 100 * ONE, NOW + MONTH),
+				Error::<Test>::YieldTooHigh
+			);
+		});
+}
@@ -0,0 +1,195 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn redeem_and_stake_should_stake_redeemed_underlying_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem_and_stake(RuntimeOrigin::signed(ALICE), bond_id, amount));
+
+		// Assert
+		expect_events(vec![
+			Event::Redeemed {
+				who: ALICE,
+				bond_id,
+				amount,
+			}
+			.into(),
+			Event::RedeemedAndStaked {
+				who: ALICE,
+				bond_id,
+				amount,
+				staked: amount,
+			}
+			.into(),
+		]);
+
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+		assert_eq!(DummyStaking::staked(ALICE), amount);
+	});
+}
+
+#[test]
+fn redeem_and_stake_should_fail_when_underlying_asset_is_not_stakeable() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, 1_000, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, amount, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::redeem_and_stake(RuntimeOrigin::signed(ALICE), bond_id, amount),
+				Error::<Test>::NotStakeable
+			);
+		});
+}
+
+#[test]
+fn redeem_and_stake_should_fail_when_bonds_are_not_mature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem_and_stake(RuntimeOrigin::signed(ALICE), bond_id, amount),
+			Error::<Test>::NotMature
+		);
+		assert_eq!(DummyStaking::staked(ALICE), 0);
+	});
+}
+
+#[test]
+fn redeem_and_stake_should_stake_dust_remainder_when_dust_is_redeemed() {
+	ExtBuilder::default()
+		.with_dust_redemption(3 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 10 * ONE;
+			let redeem_amount = 8 * ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem_and_stake(
+				RuntimeOrigin::signed(ALICE),
+				bond_id,
+				redeem_amount
+			));
+
+			// Assert
+			expect_events(vec![Event::RedeemedAndStaked {
+				who: ALICE,
+				bond_id,
+				amount: redeem_amount,
+				staked: amount,
+			}
+			.into()]);
+
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(DummyStaking::staked(ALICE), amount);
+		});
+}
+
+#[test]
+fn redeem_and_stake_should_fail_when_dust_remainder_exceeds_reserve() {
+	ExtBuilder::default()
+		.with_dust_redemption(3 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 10 * ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+			assert_ok!(<Tokens as MultiCurrency<AccountId>>::withdraw(
+				HDX,
+				&Bonds::pallet_account_id(),
+				ONE
+			));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::redeem_and_stake(RuntimeOrigin::signed(ALICE), bond_id, 8 * ONE),
+				Error::<Test>::InsufficientReserve
+			);
+			assert_eq!(Bonds::queued_amount(HDX), 0);
+		});
+}
+
+#[test]
+fn redeem_and_stake_should_fail_when_underlying_asset_was_removed() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(BOB, DAI, INITIAL_BALANCE)])
+		.with_underlying_fallback_asset(DAI)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+			assert_ok!(Tokens::transfer(
+				RuntimeOrigin::signed(BOB),
+				Bonds::pallet_account_id(),
+				DAI,
+				amount
+			));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+			remove_registered_asset(HDX);
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::redeem_and_stake(RuntimeOrigin::signed(ALICE), bond_id, amount),
+				Error::<Test>::UnderlyingRemoved
+			);
+		});
+}
@@ -0,0 +1,253 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn redeem_as_should_sell_underlying_asset_for_substitute_asset() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, 1_000, AssetKind::Token)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem_as(
+				RuntimeOrigin::signed(ALICE),
+				bond_id,
+				amount,
+				DAI,
+				ROUTER_PRICE * amount
+			));
+
+			// Assert
+			expect_events(vec![
+				Event::Redeemed {
+					who: ALICE,
+					bond_id,
+					amount,
+				}
+				.into(),
+				Event::RedeemedAs {
+					who: ALICE,
+					bond_id,
+					amount,
+					asset_out: DAI,
+					amount_out: ROUTER_PRICE * amount,
+				}
+				.into(),
+			]);
+
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount);
+			assert_eq!(Tokens::free_balance(DAI, &ALICE), ROUTER_PRICE * amount);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		});
+}
+
+#[test]
+fn redeem_as_should_fail_when_min_out_is_not_reached() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, 1_000, AssetKind::Token)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::redeem_as(
+					RuntimeOrigin::signed(ALICE),
+					bond_id,
+					amount,
+					DAI,
+					ROUTER_PRICE * amount + 1
+				),
+				DispatchError::Other("TradingLimitReached")
+			);
+		});
+}
+
+#[test]
+fn redeem_as_should_fail_when_substitute_asset_is_underlying_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem_as(RuntimeOrigin::signed(ALICE), bond_id, amount, HDX, amount),
+			Error::<Test>::InvalidSubstituteAsset
+		);
+	});
+}
+
+#[test]
+fn redeem_as_should_fail_when_reserve_is_insufficient() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, 1_000, AssetKind::Token)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+			assert_ok!(<Tokens as MultiCurrency<AccountId>>::withdraw(
+				HDX,
+				&Bonds::pallet_account_id(),
+				amount / 2
+			));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::redeem_as(RuntimeOrigin::signed(ALICE), bond_id, amount, DAI, 0),
+				Error::<Test>::InsufficientReserve
+			);
+		});
+}
+
+#[test]
+fn redeem_as_should_sell_dust_remainder_when_dust_is_redeemed() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, 1_000, AssetKind::Token)
+		.with_dust_redemption(3 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 10 * ONE;
+			let redeem_amount = 8 * ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem_as(
+				RuntimeOrigin::signed(ALICE),
+				bond_id,
+				redeem_amount,
+				DAI,
+				ROUTER_PRICE * amount
+			));
+
+			// Assert
+			expect_events(vec![Event::RedeemedAs {
+				who: ALICE,
+				bond_id,
+				amount: redeem_amount,
+				asset_out: DAI,
+				amount_out: ROUTER_PRICE * amount,
+			}
+			.into()]);
+
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount);
+			assert_eq!(Tokens::free_balance(DAI, &ALICE), ROUTER_PRICE * amount);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		});
+}
+
+#[test]
+fn redeem_as_should_sell_fallback_asset_when_underlying_asset_was_removed() {
+	const DOT: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.with_registered_asset(DOT, 1_000, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE), (BOB, HDX, INITIAL_BALANCE)])
+		.with_underlying_fallback_asset(HDX)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 100 * ONE;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, amount, NOW + MONTH));
+			assert_ok!(Tokens::transfer(
+				RuntimeOrigin::signed(BOB),
+				Bonds::pallet_account_id(),
+				HDX,
+				amount
+			));
+
+			Timestamp::set_timestamp(NOW + MONTH);
+			remove_registered_asset(DAI);
+
+			// Act
+			assert_ok!(Bonds::redeem_as(
+				RuntimeOrigin::signed(ALICE),
+				bond_id,
+				amount,
+				DOT,
+				ROUTER_PRICE * amount
+			));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), ROUTER_PRICE * amount);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+			assert_eq!(Tokens::free_balance(DAI, &Bonds::pallet_account_id()), amount);
+		});
+}
+
+#[test]
+fn redeem_as_should_fail_when_substitute_asset_is_fallback_asset() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE)])
+		.with_underlying_fallback_asset(HDX)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 100 * ONE;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, amount, NOW + MONTH));
+
+			Timestamp::set_timestamp(NOW + MONTH);
+			remove_registered_asset(DAI);
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::redeem_as(RuntimeOrigin::signed(ALICE), bond_id, amount, HDX, amount),
+				Error::<Test>::InvalidSubstituteAsset
+			);
+		});
+}
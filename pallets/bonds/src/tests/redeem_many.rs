@@ -0,0 +1,166 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn redeem_many_should_work_when_all_bonds_are_mature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let first_maturity = NOW + MONTH;
+		let first_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, first_maturity));
+		let second_maturity = NOW + 2 * MONTH;
+		let second_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, second_maturity));
+
+		Timestamp::set_timestamp(NOW + 3 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem_many(
+			RuntimeOrigin::signed(ALICE),
+			vec![(first_bond_id, amount), (second_bond_id, amount / 2)],
+			true
+		));
+
+		// Assert
+		expect_events(vec![
+			Event::Redeemed {
+				who: ALICE,
+				bond_id: first_bond_id,
+				amount,
+			}
+			.into(),
+			Event::Redeemed {
+				who: ALICE,
+				bond_id: second_bond_id,
+				amount: amount / 2,
+			}
+			.into(),
+		]);
+
+		assert_eq!(Tokens::free_balance(first_bond_id, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(second_bond_id, &ALICE), amount / 2);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount / 2);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), amount / 2);
+	});
+}
+
+#[test]
+fn redeem_many_should_skip_failed_redemptions_when_stop_on_error_is_false() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let mature_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, NOW + MONTH));
+		let not_mature_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, NOW + 3 * MONTH));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem_many(
+			RuntimeOrigin::signed(ALICE),
+			vec![
+				(mature_bond_id, amount / 2),
+				(not_mature_bond_id, amount),
+				(mature_bond_id, amount),
+			],
+			false
+		));
+
+		// Assert
+		expect_events(vec![
+			Event::Redeemed {
+				who: ALICE,
+				bond_id: mature_bond_id,
+				amount: amount / 2,
+			}
+			.into(),
+			Event::RedeemSkipped {
+				who: ALICE,
+				bond_id: not_mature_bond_id,
+				amount,
+				error: Error::<Test>::NotMature.into(),
+			}
+			.into(),
+			Event::RedeemSkipped {
+				who: ALICE,
+				bond_id: mature_bond_id,
+				amount,
+				error: orml_tokens::Error::<Test>::BalanceTooLow.into(),
+			}
+			.into(),
+		]);
+
+		assert_eq!(Tokens::free_balance(mature_bond_id, &ALICE), amount / 2);
+		assert_eq!(Tokens::free_balance(not_mature_bond_id, &ALICE), amount);
+		assert_eq!(
+			Tokens::free_balance(HDX, &ALICE),
+			INITIAL_BALANCE - 2 * amount + amount / 2
+		);
+	});
+}
+
+#[test]
+fn redeem_many_should_fail_when_stop_on_error_is_true_and_redemption_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let mature_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, NOW + MONTH));
+		let not_mature_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, NOW + 3 * MONTH));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem_many(
+				RuntimeOrigin::signed(ALICE),
+				vec![(mature_bond_id, amount), (not_mature_bond_id, amount)],
+				true
+			),
+			Error::<Test>::NotMature
+		);
+	});
+}
+
+#[test]
+fn redeem_many_should_fail_when_batch_is_too_big() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, NOW + MONTH));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		let redemptions = vec![(bond_id, 1); <Test as Config>::MaxBatch::get() as usize + 1];
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem_many(RuntimeOrigin::signed(ALICE), redemptions, false),
+			Error::<Test>::TooManyRedemptions
+		);
+	});
+}
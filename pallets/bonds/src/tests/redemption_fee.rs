@@ -0,0 +1,144 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn issue_with_redemption_fee_should_set_redemption_fee_of_new_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue_with_redemption_fee(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			Permill::from_percent(10)
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 100 * ONE);
+		assert_eq!(Bonds::bond_redemption_fee(bond_id), Some(Permill::from_percent(10)));
+	});
+}
+
+#[test]
+fn redeem_should_pay_out_net_of_redemption_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_redemption_fee(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			Permill::from_percent(10)
+		));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 100 * ONE));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - 10 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &TREASURY), 10 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		expect_events(vec![Event::RedemptionFeePaid {
+			who: ALICE,
+			bond_id,
+			fee: 10 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn redemption_fee_should_be_charged_independently_of_protocol_fee() {
+	ExtBuilder::default()
+		.with_protocol_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue_with_redemption_fee(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				100 * ONE,
+				NOW + MONTH,
+				Permill::from_percent(10)
+			));
+			assert_eq!(Tokens::free_balance(HDX, &TREASURY), ONE);
+
+			Timestamp::set_timestamp(NOW + MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 99 * ONE));
+
+			// Assert
+			let redemption_fee = Permill::from_percent(10).mul_floor(99 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &TREASURY), ONE + redemption_fee);
+			assert_eq!(
+				Tokens::free_balance(HDX, &ALICE),
+				INITIAL_BALANCE - ONE - redemption_fee
+			);
+		});
+}
+
+#[test]
+fn redeem_should_not_charge_redemption_fee_when_bonds_have_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 100 * ONE));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+		assert_eq!(Tokens::free_balance(HDX, &TREASURY), 0);
+	});
+}
+
+#[test]
+fn issue_with_redemption_fee_should_fail_when_bonds_are_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue_with_redemption_fee(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				100 * ONE,
+				NOW + MONTH,
+				Permill::from_percent(10)
+			),
+			Error::<Test>::RedemptionFeeNotAllowed
+		);
+	});
+}
@@ -0,0 +1,450 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+pub use pretty_assertions::assert_eq;
+
+fn empty_reserve(asset_id: AssetId) {
+	let pallet_account = Bonds::pallet_account_id();
+	let balance = Tokens::free_balance(asset_id, &pallet_account);
+	assert_ok!(<Tokens as MultiCurrency<AccountId>>::withdraw(
+		asset_id,
+		&pallet_account,
+		balance
+	));
+}
+
+fn refill_reserve(asset_id: AssetId, amount: Balance) {
+	assert_ok!(<Tokens as MultiCurrency<AccountId>>::deposit(
+		asset_id,
+		&Bonds::pallet_account_id(),
+		amount
+	));
+}
+
+#[test]
+fn redeem_should_queue_redemption_when_reserve_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+		empty_reserve(HDX);
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount));
+
+		// Assert
+		expect_events(vec![Event::RedemptionQueued {
+			id: 0,
+			who: ALICE,
+			bond_id,
+			amount,
+		}
+		.into()]);
+
+		assert_eq!(Bonds::queued_redemption(0), Some((ALICE, bond_id, amount)));
+		assert_eq!(Bonds::queued_amount(HDX), amount);
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount);
+	});
+}
+
+#[test]
+fn queued_redemptions_should_be_processed_in_fifo_order_when_reserve_is_refilled() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, amount, maturity));
+			empty_reserve(HDX);
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount));
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, amount));
+
+			// Act
+			refill_reserve(HDX, amount);
+			Bonds::on_initialize(2);
+
+			// Assert
+			expect_events(vec![Event::Redeemed {
+				who: ALICE,
+				bond_id,
+				amount,
+			}
+			.into()]);
+
+			assert_eq!(Bonds::queued_redemption(0), None);
+			assert_eq!(Bonds::queued_redemption(1), Some((BOB, bond_id, amount)));
+			assert_eq!(Bonds::queued_amount(HDX), amount);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+			assert_eq!(Tokens::free_balance(HDX, &BOB), INITIAL_BALANCE - amount);
+
+			// Act
+			refill_reserve(HDX, amount);
+			Bonds::on_initialize(3);
+
+			// Assert
+			expect_events(vec![Event::Redeemed {
+				who: BOB,
+				bond_id,
+				amount,
+			}
+			.into()]);
+
+			assert_eq!(Bonds::queued_redemption(1), None);
+			assert_eq!(Bonds::queued_amount(HDX), 0);
+			assert_eq!(Tokens::free_balance(HDX, &BOB), INITIAL_BALANCE);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		});
+}
+
+#[test]
+fn queued_redemptions_should_not_be_processed_when_reserve_is_not_refilled() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+		empty_reserve(HDX);
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount));
+
+		// Act
+		refill_reserve(HDX, amount / 2);
+		Bonds::on_initialize(2);
+
+		// Assert
+		assert_eq!(Bonds::queued_redemption(0), Some((ALICE, bond_id, amount)));
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount);
+	});
+}
+
+#[test]
+fn redeem_should_be_queued_behind_existing_queue_when_reserve_covers_only_new_redemption() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 2 * amount, maturity));
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, amount, maturity));
+			empty_reserve(HDX);
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 2 * amount));
+			refill_reserve(HDX, amount);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, amount));
+
+			// Assert
+			assert_eq!(Bonds::queued_redemption(1), Some((BOB, bond_id, amount)));
+			assert_eq!(Bonds::queued_amount(HDX), 3 * amount);
+			assert_eq!(Tokens::free_balance(HDX, &BOB), INITIAL_BALANCE - amount);
+		});
+}
+
+#[test]
+fn cancel_queued_redemption_should_return_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+		empty_reserve(HDX);
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount));
+
+		// Act
+		assert_ok!(Bonds::cancel_queued_redemption(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		expect_events(vec![Event::QueuedRedemptionCancelled {
+			id: 0,
+			who: ALICE,
+			bond_id,
+			amount,
+		}
+		.into()]);
+
+		assert_eq!(Bonds::queued_redemption(0), None);
+		assert_eq!(Bonds::queued_amount(HDX), 0);
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), amount);
+
+		// cancelled redemption is skipped
+		refill_reserve(HDX, amount);
+		Bonds::on_initialize(2);
+
+		assert_eq!(RedemptionQueueHead::<Test>::get(), 1);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount);
+	});
+}
+
+#[test]
+fn cancel_queued_redemption_should_fail_when_called_by_another_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = ONE;
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+		empty_reserve(HDX);
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::cancel_queued_redemption(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NotQueuedRedemptionOwner
+		);
+		assert_noop!(
+			Bonds::cancel_queued_redemption(RuntimeOrigin::signed(ALICE), 1),
+			Error::<Test>::QueuedRedemptionNotFound
+		);
+	});
+}
+
+#[test]
+fn queued_redemptions_of_other_assets_should_be_processed_when_head_is_not_covered() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, DAI, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let hdx_bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+			let dai_bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), DAI, amount, maturity));
+			empty_reserve(HDX);
+			empty_reserve(DAI);
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), hdx_bond_id, amount));
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), dai_bond_id, amount));
+
+			// Act
+			refill_reserve(DAI, amount);
+			Bonds::on_initialize(2);
+
+			// Assert
+			expect_events(vec![Event::Redeemed {
+				who: BOB,
+				bond_id: dai_bond_id,
+				amount,
+			}
+			.into()]);
+
+			assert_eq!(Bonds::queued_redemption(0), Some((ALICE, hdx_bond_id, amount)));
+			assert_eq!(Bonds::queued_redemption(1), None);
+			assert_eq!(RedemptionQueueHead::<Test>::get(), 0);
+			assert_eq!(Bonds::queued_amount(DAI), 0);
+			assert_eq!(Tokens::free_balance(DAI, &BOB), INITIAL_BALANCE);
+
+			// Act
+			refill_reserve(HDX, amount);
+			Bonds::on_initialize(3);
+
+			// Assert
+			assert_eq!(Bonds::queued_redemption(0), None);
+			assert_eq!(RedemptionQueueHead::<Test>::get(), 2);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+		});
+}
+
+#[test]
+fn queued_redemptions_behind_more_blocked_redemptions_than_processed_per_block_should_be_processed() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, DAI, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let hdx_bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 6 * amount, maturity));
+			let dai_bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), DAI, amount, maturity));
+			empty_reserve(HDX);
+			empty_reserve(DAI);
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			// more blocked redemptions than `MaxQueuedRedemptionsPerBlock` at the head of the queue
+			for _ in 0..6 {
+				assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), hdx_bond_id, amount));
+			}
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), dai_bond_id, amount));
+			refill_reserve(DAI, amount);
+
+			// Act
+			Bonds::on_initialize(2);
+
+			// Assert
+			assert_eq!(Bonds::queued_redemption(6), Some((BOB, dai_bond_id, amount)));
+			assert_eq!(RedemptionQueueCursor::<Test>::get().map(|(id, _)| id), Some(5));
+
+			// Act
+			Bonds::on_initialize(3);
+
+			// Assert
+			assert_eq!(Bonds::queued_redemption(6), None);
+			assert_eq!(Bonds::queued_redemption(0), Some((ALICE, hdx_bond_id, amount)));
+			assert_eq!(RedemptionQueueHead::<Test>::get(), 0);
+			assert_eq!(RedemptionQueueCursor::<Test>::get(), None);
+			assert_eq!(Tokens::free_balance(DAI, &BOB), INITIAL_BALANCE);
+		});
+}
+
+#[test]
+fn queued_redemptions_of_blocked_asset_should_stay_in_fifo_order_across_blocks() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 7 * amount, maturity));
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, amount, maturity));
+			empty_reserve(HDX);
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 2 * amount));
+			for _ in 0..5 {
+				assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount));
+			}
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, amount));
+			Bonds::on_initialize(2);
+
+			// Act
+			refill_reserve(HDX, amount);
+			Bonds::on_initialize(3);
+
+			// Assert
+			assert_eq!(Bonds::queued_redemption(0), Some((ALICE, bond_id, 2 * amount)));
+			assert_eq!(Bonds::queued_redemption(6), Some((BOB, bond_id, amount)));
+			assert_eq!(Tokens::free_balance(HDX, &BOB), INITIAL_BALANCE - amount);
+		});
+}
+
+#[test]
+fn queued_redemptions_of_same_asset_should_not_be_processed_before_head_is_covered() {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let maturity = NOW + MONTH;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 2 * amount, maturity));
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), HDX, amount, maturity));
+			empty_reserve(HDX);
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 2 * amount));
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, amount));
+
+			// Act
+			refill_reserve(HDX, amount);
+			Bonds::on_initialize(2);
+
+			// Assert
+			assert_eq!(Bonds::queued_redemption(0), Some((ALICE, bond_id, 2 * amount)));
+			assert_eq!(Bonds::queued_redemption(1), Some((BOB, bond_id, amount)));
+			assert_eq!(Tokens::free_balance(HDX, &BOB), INITIAL_BALANCE - amount);
+		});
+}
+
+#[test]
+fn queued_redemption_should_be_paid_in_fallback_asset_when_underlying_asset_was_removed() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(BOB, DAI, INITIAL_BALANCE)])
+		.with_underlying_fallback_asset(HDX)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = ONE;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(BOB), DAI, amount, NOW + MONTH));
+			empty_reserve(DAI);
+
+			Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, amount));
+			remove_registered_asset(DAI);
+
+			// Act
+			refill_reserve(HDX, amount);
+			Bonds::on_initialize(2);
+
+			// Assert
+			expect_events(vec![
+				Event::Redeemed {
+					who: BOB,
+					bond_id,
+					amount,
+				}
+				.into(),
+				Event::RedeemedInFallbackAsset {
+					who: BOB,
+					bond_id,
+					asset_id: HDX,
+					amount,
+				}
+				.into(),
+			]);
+
+			assert_eq!(Bonds::queued_redemption(0), None);
+			assert_eq!(Bonds::queued_amount(DAI), 0);
+			assert_eq!(Tokens::free_balance(HDX, &BOB), amount);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		});
+}
@@ -0,0 +1,129 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn redeem_should_pay_principal_and_emit_shortfall_when_reserve_does_not_cover_yield() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let principal = 100 * ONE;
+		let yield_amount = 10 * ONE;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_yield(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			principal,
+			yield_amount,
+			NOW + MONTH
+		));
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, principal));
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::withdraw(
+			HDX,
+			&Bonds::pallet_account_id(),
+			6 * ONE
+		));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, principal));
+
+		// Assert
+		expect_events(vec![
+			Event::Redeemed {
+				who: BOB,
+				bond_id,
+				amount: principal,
+			}
+			.into(),
+			Event::RedemptionShortfall {
+				bond_id,
+				holder: BOB,
+				expected_yield: yield_amount,
+				paid_yield: 4 * ONE,
+			}
+			.into(),
+		]);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), principal + 4 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		assert_eq!(Bonds::bond_discount(bond_id), None);
+	});
+}
+
+#[test]
+fn redeem_should_not_emit_shortfall_when_reserve_covers_yield() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let principal = 100 * ONE;
+		let yield_amount = 10 * ONE;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_yield(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			principal,
+			yield_amount,
+			NOW + MONTH
+		));
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, principal));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, principal));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(HDX, &BOB), principal + yield_amount);
+		assert!(!System::events()
+			.iter()
+			.any(|record| matches!(record.event, RuntimeEvent::Bonds(Event::RedemptionShortfall { .. }))));
+	});
+}
+
+#[test]
+fn redeem_should_fail_when_reserve_does_not_cover_principal() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let principal = 100 * ONE;
+		let yield_amount = 10 * ONE;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_yield(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			principal,
+			yield_amount,
+			NOW + MONTH
+		));
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::withdraw(
+			HDX,
+			&Bonds::pallet_account_id(),
+			20 * ONE
+		));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, principal),
+			Error::<Test>::InsufficientReserve
+		);
+	});
+}
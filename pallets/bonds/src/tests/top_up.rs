@@ -0,0 +1,155 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn top_up_should_transfer_extra_to_pallet_account_without_issuing_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let amount = 100 * ONE;
+		let extra = 10 * ONE;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, NOW + MONTH));
+
+		// Act
+		assert_ok!(Bonds::top_up(RuntimeOrigin::signed(ALICE), bond_id, extra));
+
+		// Assert
+		assert_eq!(Tokens::total_issuance(bond_id), amount);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount - extra);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), amount + extra);
+		assert_eq!(Bonds::bond_discount(bond_id), Some((amount, extra)));
+		expect_events(vec![Event::ToppedUp {
+			issuer: ALICE,
+			bond_id,
+			amount: extra,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn redemptions_should_pay_more_per_bond_when_topped_up() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, 30 * ONE));
+		assert_ok!(Bonds::top_up(RuntimeOrigin::signed(ALICE), bond_id, 10 * ONE));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, 30 * ONE));
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 70 * ONE));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 33 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - 30 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+		assert_eq!(Bonds::bond_discount(bond_id), None);
+	});
+}
+
+#[test]
+fn top_up_should_add_to_prefunded_yield() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_yield(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			10 * ONE,
+			NOW + MONTH
+		));
+
+		// Act
+		assert_ok!(Bonds::top_up(RuntimeOrigin::signed(ALICE), bond_id, 5 * ONE));
+
+		// Assert
+		assert_eq!(Bonds::bond_discount(bond_id), Some((100 * ONE, 15 * ONE)));
+	});
+}
+
+#[test]
+fn top_up_should_fail_when_called_by_non_issuer() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::top_up(RuntimeOrigin::signed(BOB), bond_id, 10 * ONE),
+			Error::<Test>::NotIssuer
+		);
+	});
+}
+
+#[test]
+fn top_up_should_fail_when_bond_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Bonds::top_up(RuntimeOrigin::signed(ALICE), next_asset_id(), 10 * ONE),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
+
+#[test]
+fn top_up_should_fail_when_there_are_no_outstanding_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+		Timestamp::set_timestamp(NOW + MONTH);
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 100 * ONE));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::top_up(RuntimeOrigin::signed(ALICE), bond_id, 10 * ONE),
+			Error::<Test>::NothingToTopUp
+		);
+		assert_noop!(
+			Bonds::top_up(RuntimeOrigin::signed(ALICE), bond_id, 0),
+			Error::<Test>::NothingToTopUp
+		);
+	});
+}
+
+#[test]
+fn issue_should_fail_when_bonds_were_topped_up() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, maturity));
+		assert_ok!(Bonds::top_up(RuntimeOrigin::signed(ALICE), bond_id, 10 * ONE));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, maturity),
+			Error::<Test>::DiscountNotAllowed
+		);
+	});
+}
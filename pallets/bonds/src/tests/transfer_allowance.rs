@@ -0,0 +1,175 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn approve_transfer_should_set_allowance_of_spender() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+
+		// Act
+		assert_ok!(Bonds::approve_transfer(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			bond_id,
+			60 * ONE
+		));
+
+		// Assert
+		assert_eq!(Bonds::transfer_allowance((ALICE, CHARLIE, bond_id)), 60 * ONE);
+		expect_events(vec![Event::TransferApproved {
+			owner: ALICE,
+			spender: CHARLIE,
+			bond_id,
+			amount: 60 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn transfer_from_should_transfer_bonds_of_owner_when_spender_is_approved() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+		assert_ok!(Bonds::approve_transfer(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			bond_id,
+			60 * ONE
+		));
+
+		// Act
+		assert_ok!(Bonds::transfer_from(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			BOB,
+			bond_id,
+			40 * ONE
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 60 * ONE);
+		assert_eq!(Tokens::free_balance(bond_id, &BOB), 40 * ONE);
+		assert_eq!(Tokens::free_balance(bond_id, &CHARLIE), 0);
+		assert_eq!(Bonds::transfer_allowance((ALICE, CHARLIE, bond_id)), 20 * ONE);
+		expect_events(vec![Event::TransferredFrom {
+			spender: CHARLIE,
+			owner: ALICE,
+			dest: BOB,
+			bond_id,
+			amount: 40 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn transfer_from_should_remove_allowance_when_fully_used() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+		assert_ok!(Bonds::approve_transfer(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			bond_id,
+			60 * ONE
+		));
+
+		// Act
+		assert_ok!(Bonds::transfer_from(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			BOB,
+			bond_id,
+			60 * ONE
+		));
+
+		// Assert
+		assert!(!TransferAllowance::<Test>::contains_key((ALICE, CHARLIE, bond_id)));
+	});
+}
+
+#[test]
+fn transfer_from_should_fail_when_amount_exceeds_allowance() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+		assert_ok!(Bonds::approve_transfer(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			bond_id,
+			60 * ONE
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::transfer_from(RuntimeOrigin::signed(CHARLIE), ALICE, BOB, bond_id, 61 * ONE),
+			Error::<Test>::InsufficientAllowance
+		);
+		assert_noop!(
+			Bonds::transfer_from(RuntimeOrigin::signed(BOB), ALICE, BOB, bond_id, ONE),
+			Error::<Test>::InsufficientAllowance
+		);
+	});
+}
+
+#[test]
+fn transfer_from_should_fail_when_bonds_are_locked_and_not_mature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_locked(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH
+		));
+		assert_ok!(Bonds::approve_transfer(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			bond_id,
+			60 * ONE
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::transfer_from(RuntimeOrigin::signed(CHARLIE), ALICE, BOB, bond_id, 60 * ONE),
+			Error::<Test>::TransferLocked
+		);
+	});
+}
+
+#[test]
+fn approve_transfer_should_fail_when_bonds_are_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Bonds::approve_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, next_asset_id(), 60 * ONE),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
@@ -0,0 +1,205 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn issue_locked_should_mark_new_bonds_as_transfer_locked() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue_locked(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Assert
+		assert!(TransferLocked::<Test>::contains_key(bond_id));
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), ONE);
+	});
+}
+
+#[test]
+fn transfer_should_fail_when_bonds_are_locked_and_not_mature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_locked(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, ONE),
+			Error::<Test>::TransferLocked
+		);
+	});
+}
+
+#[test]
+fn transfer_should_work_when_locked_bonds_are_mature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_locked(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		Timestamp::set_timestamp(maturity);
+
+		// Act
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, ONE));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(bond_id, &BOB), ONE);
+	});
+}
+
+#[test]
+fn transfer_should_work_when_bonds_are_not_locked() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, ONE));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &BOB), ONE);
+	});
+}
+
+#[test]
+fn issue_locked_should_fail_when_existing_bonds_are_not_locked() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue_locked(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity),
+			Error::<Test>::TransferLockNotAllowed
+		);
+	});
+}
+
+#[test]
+fn unlock_should_allow_transfer_before_maturity() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_locked(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act
+		assert_ok!(Bonds::unlock(RuntimeOrigin::root(), bond_id));
+
+		// Assert
+		expect_events(vec![Event::Unlocked { bond_id }.into()]);
+		assert!(!TransferLocked::<Test>::contains_key(bond_id));
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, ONE));
+	});
+}
+
+#[test]
+fn unlock_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_locked(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::unlock(RuntimeOrigin::signed(ALICE), bond_id),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn unlock_should_fail_when_bonds_are_not_locked() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::unlock(RuntimeOrigin::root(), bond_id),
+			Error::<Test>::NotTransferLocked
+		);
+	});
+}
+
+#[test]
+fn unlock_should_work_just_before_maturity() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_locked(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+		Timestamp::set_timestamp(maturity - 1);
+
+		// Act
+		assert_ok!(Bonds::unlock(RuntimeOrigin::root(), bond_id));
+
+		// Assert
+		assert!(!TransferLocked::<Test>::contains_key(bond_id));
+	});
+}
+
+#[test]
+fn unlock_should_work_within_post_maturity_grace() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_locked(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+		Timestamp::set_timestamp(maturity + PostMaturityGrace::get());
+
+		// Act
+		assert_ok!(Bonds::unlock(RuntimeOrigin::root(), bond_id));
+
+		// Assert
+		assert!(!TransferLocked::<Test>::contains_key(bond_id));
+	});
+}
+
+#[test]
+fn unlock_should_fail_when_post_maturity_grace_has_passed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_locked(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+		Timestamp::set_timestamp(maturity + PostMaturityGrace::get() + 1);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::unlock(RuntimeOrigin::root(), bond_id),
+			Error::<Test>::AlreadyMatured
+		);
+	});
+}
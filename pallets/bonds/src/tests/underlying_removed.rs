@@ -0,0 +1,287 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn redeem_should_fail_when_underlying_asset_was_removed_and_fallback_asset_is_not_set() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 100 * ONE;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, amount, NOW + MONTH));
+
+			Timestamp::set_timestamp(NOW + MONTH);
+			remove_registered_asset(DAI);
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount),
+				Error::<Test>::UnderlyingRemoved
+			);
+		});
+}
+
+#[test]
+fn redeem_should_pay_fallback_asset_when_underlying_asset_was_removed() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE), (BOB, HDX, INITIAL_BALANCE)])
+		.with_underlying_fallback_asset(HDX)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 100 * ONE;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, amount, NOW + MONTH));
+			assert_ok!(Tokens::transfer(
+				RuntimeOrigin::signed(BOB),
+				Bonds::pallet_account_id(),
+				HDX,
+				amount
+			));
+
+			Timestamp::set_timestamp(NOW + MONTH);
+			remove_registered_asset(DAI);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE + amount);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+			assert_eq!(Tokens::free_balance(DAI, &Bonds::pallet_account_id()), amount);
+			expect_events(vec![
+				Event::Redeemed {
+					who: ALICE,
+					bond_id,
+					amount,
+				}
+				.into(),
+				Event::RedeemedInFallbackAsset {
+					who: ALICE,
+					bond_id,
+					asset_id: HDX,
+					amount,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn redeem_should_pay_underlying_asset_when_fallback_asset_is_set_and_underlying_asset_exists() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE)])
+		.with_underlying_fallback_asset(HDX)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 100 * ONE;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, amount, NOW + MONTH));
+
+			Timestamp::set_timestamp(NOW + MONTH);
+
+			// Act
+			assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(DAI, &ALICE), INITIAL_BALANCE);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+		});
+}
+
+#[test]
+fn redeem_should_not_be_queued_when_fallback_reserve_is_insufficient() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE)])
+		.with_underlying_fallback_asset(HDX)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 100 * ONE;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, amount, NOW + MONTH));
+
+			Timestamp::set_timestamp(NOW + MONTH);
+			remove_registered_asset(DAI);
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, amount),
+				Error::<Test>::InsufficientReserve
+			);
+		});
+}
+
+#[test]
+fn manager_redeem_should_pay_fallback_asset_when_underlying_asset_was_removed() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE), (BOB, HDX, INITIAL_BALANCE)])
+		.with_underlying_fallback_asset(HDX)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 100 * ONE;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue_managed(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				amount,
+				NOW + MONTH,
+				BOB
+			));
+			assert_ok!(Tokens::transfer(
+				RuntimeOrigin::signed(BOB),
+				Bonds::pallet_account_id(),
+				HDX,
+				amount
+			));
+
+			Timestamp::set_timestamp(NOW + MONTH);
+			remove_registered_asset(DAI);
+
+			// Act
+			assert_ok!(Bonds::manager_redeem(
+				RuntimeOrigin::signed(BOB),
+				bond_id,
+				ALICE,
+				amount,
+				CHARLIE
+			));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(HDX, &CHARLIE), amount);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+			assert_eq!(Tokens::free_balance(DAI, &Bonds::pallet_account_id()), amount);
+			expect_events(vec![
+				Event::Redeemed {
+					who: ALICE,
+					bond_id,
+					amount,
+				}
+				.into(),
+				Event::RedeemedInFallbackAsset {
+					who: ALICE,
+					bond_id,
+					asset_id: HDX,
+					amount,
+				}
+				.into(),
+				Event::ManagerRedeemed {
+					manager: BOB,
+					holder: ALICE,
+					bond_id,
+					amount,
+					dest: CHARLIE,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn manager_redeem_should_fail_when_underlying_asset_was_removed_and_fallback_asset_is_not_set() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 100 * ONE;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue_managed(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				amount,
+				NOW + MONTH,
+				BOB
+			));
+
+			Timestamp::set_timestamp(NOW + MONTH);
+			remove_registered_asset(DAI);
+
+			// Act & Assert
+			assert_noop!(
+				Bonds::manager_redeem(RuntimeOrigin::signed(BOB), bond_id, ALICE, amount, CHARLIE),
+				Error::<Test>::UnderlyingRemoved
+			);
+		});
+}
+
+#[test]
+fn clawback_should_transfer_fallback_asset_when_underlying_asset_was_removed() {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(ALICE, DAI, INITIAL_BALANCE), (BOB, HDX, INITIAL_BALANCE)])
+		.with_underlying_fallback_asset(HDX)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let amount = 100 * ONE;
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), DAI, amount, NOW + MONTH));
+			assert_ok!(Tokens::transfer(
+				RuntimeOrigin::signed(BOB),
+				Bonds::pallet_account_id(),
+				HDX,
+				amount
+			));
+
+			remove_registered_asset(DAI);
+
+			// Act
+			assert_ok!(Bonds::clawback(RuntimeOrigin::root(), bond_id, ALICE, amount, CHARLIE));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(HDX, &CHARLIE), amount);
+			assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+			assert_eq!(Tokens::free_balance(DAI, &Bonds::pallet_account_id()), amount);
+			expect_events(vec![
+				Event::Clawback {
+					bond_id,
+					holder: ALICE,
+					amount,
+					dest: CHARLIE,
+					underlying_amount: amount,
+				}
+				.into(),
+				Event::RedeemedInFallbackAsset {
+					who: ALICE,
+					bond_id,
+					asset_id: HDX,
+					amount,
+				}
+				.into(),
+			]);
+		});
+}
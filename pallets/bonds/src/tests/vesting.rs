@@ -0,0 +1,261 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+const VESTING: VestingInfo<Balance, u64> = VestingInfo {
+	locked: 100 * ONE,
+	per_block: 10 * ONE,
+	starting_block: 1,
+};
+
+#[test]
+fn issue_vested_should_lock_issued_bonds_by_vesting_schedule() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue_vested(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			ALICE,
+			VESTING
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 100 * ONE);
+		assert_eq!(Bonds::bond_vesting(bond_id, ALICE), Some(VESTING));
+		expect_events(vec![Event::VestingScheduleSet {
+			who: ALICE,
+			bond_id,
+			schedule: VESTING,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn transfer_should_fail_when_bonds_are_locked_by_vesting_schedule() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_vested(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			ALICE,
+			VESTING
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, ONE),
+			Error::<Test>::VestingLocked
+		);
+	});
+}
+
+#[test]
+fn transfer_should_work_for_bonds_unlocked_over_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_vested(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			ALICE,
+			VESTING
+		));
+
+		System::set_block_number(4);
+
+		// Act & Assert
+		assert_noop!(
+			Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, 31 * ONE),
+			Error::<Test>::VestingLocked
+		);
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, 30 * ONE));
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 70 * ONE);
+		assert_eq!(Tokens::free_balance(bond_id, &BOB), 30 * ONE);
+
+		System::set_block_number(11);
+
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, 70 * ONE));
+		assert_eq!(Tokens::free_balance(bond_id, &BOB), 100 * ONE);
+	});
+}
+
+#[test]
+fn redeem_should_fail_when_bonds_are_locked_by_vesting_schedule() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_vested(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			ALICE,
+			VESTING
+		));
+
+		Timestamp::set_timestamp(NOW + MONTH);
+		System::set_block_number(6);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 60 * ONE),
+			Error::<Test>::VestingLocked
+		);
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 50 * ONE));
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 50 * ONE);
+	});
+}
+
+#[test]
+fn issue_vested_should_fail_when_schedule_locks_more_than_issued_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue_vested(RuntimeOrigin::signed(ALICE), HDX, 50 * ONE, NOW + MONTH, ALICE, VESTING),
+			Error::<Test>::InvalidVestingSchedule
+		);
+	});
+}
+
+#[test]
+fn issue_vested_should_fail_when_schedule_unlocks_nothing_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let vesting = VestingInfo {
+			per_block: 0,
+			..VESTING
+		};
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue_vested(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				100 * ONE,
+				NOW + MONTH,
+				ALICE,
+				vesting
+			),
+			Error::<Test>::InvalidVestingSchedule
+		);
+	});
+}
+
+#[test]
+fn issue_vested_should_fail_when_account_already_has_vesting_schedule() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::issue_vested(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			ALICE,
+			VESTING
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::issue_vested(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				100 * ONE,
+				NOW + MONTH,
+				ALICE,
+				VESTING
+			),
+			Error::<Test>::VestingScheduleExists
+		);
+	});
+}
+
+#[test]
+fn issue_vested_should_transfer_issued_bonds_to_recipient_and_lock_them_by_vesting_schedule() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue_vested(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			BOB,
+			VESTING
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(bond_id, &BOB), 100 * ONE);
+		assert_eq!(Bonds::bond_vesting(bond_id, ALICE), None);
+		assert_eq!(Bonds::bond_vesting(bond_id, BOB), Some(VESTING));
+		expect_events(vec![Event::VestingScheduleSet {
+			who: BOB,
+			bond_id,
+			schedule: VESTING,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn transfer_should_work_for_bonds_of_recipient_unlocked_over_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_vested(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			BOB,
+			VESTING
+		));
+
+		System::set_block_number(4);
+
+		// Act & Assert
+		assert_noop!(
+			Tokens::transfer(RuntimeOrigin::signed(BOB), CHARLIE, bond_id, 31 * ONE),
+			Error::<Test>::VestingLocked
+		);
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(BOB), CHARLIE, bond_id, 30 * ONE));
+		assert_eq!(Tokens::free_balance(bond_id, &BOB), 70 * ONE);
+
+		System::set_block_number(11);
+
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(BOB), CHARLIE, bond_id, 70 * ONE));
+		assert_eq!(Tokens::free_balance(bond_id, &CHARLIE), 100 * ONE);
+	});
+}
@@ -0,0 +1,67 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::assert_ok;
+pub use pretty_assertions::assert_eq;
+
+#[test]
+fn weighted_average_maturity_should_weight_remaining_maturity_by_supply() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+		assert_ok!(Bonds::issue(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			300 * ONE,
+			NOW + 3 * MONTH
+		));
+
+		// Act & Assert
+		// (100 * MONTH + 300 * 3 * MONTH) / 400
+		assert_eq!(Bonds::weighted_average_maturity(HDX), Some(5 * MONTH / 2));
+	});
+}
+
+#[test]
+fn weighted_average_maturity_should_count_mature_bonds_with_zero_remaining_time() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, NOW + MONTH));
+		assert_ok!(Bonds::issue(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			300 * ONE,
+			NOW + 3 * MONTH
+		));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act & Assert
+		// (100 * 0 + 300 * MONTH) / 400
+		assert_eq!(Bonds::weighted_average_maturity(HDX), Some(3 * MONTH / 4));
+	});
+}
+
+#[test]
+fn weighted_average_maturity_should_return_none_when_asset_has_no_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Bonds::weighted_average_maturity(HDX), None);
+	});
+}
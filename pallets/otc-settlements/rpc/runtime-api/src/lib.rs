@@ -0,0 +1,43 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the OTC settlements pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the state of the OTC settlements offchain worker.
+	pub trait OtcSettlementsApi<OrderId, Balance, SettlementPreview, SettlementOutcome> where
+		OrderId: Codec,
+		Balance: Codec,
+		SettlementPreview: Codec,
+		SettlementOutcome: Codec,
+	{
+		/// Simulate the settlement of `amount` of the OTC order and return the profit and the spot price
+		/// of the route before and after the settlement.
+		/// Returns `None` if the settlement would fail.
+		fn preview_settlement(otc_id: OrderId, amount: Balance) -> Option<SettlementPreview>;
+
+		/// Return at most `limit` OTC orders that can be settled and the estimated profit of their settlement.
+		fn settleable_orders(limit: u32) -> Vec<(OrderId, Balance)>;
+
+		/// Simulate the settlements of the OTC orders one after another and return the outcome of each settlement.
+		/// Each settlement sees the state changes of the previous settlements in the batch.
+		fn simulate_batch(order_ids: Vec<OrderId>) -> Vec<(OrderId, SettlementOutcome)>;
+	}
+}
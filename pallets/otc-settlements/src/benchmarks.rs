@@ -43,7 +43,148 @@ benchmarks! {
 			asset_out: HDX.into(),
 		});
 
-  }:  _(RawOrigin::None, 0u32, 2 * ONE, route)
+		let keeper: T::AccountId = account("keeper", 2, 1);
+
+  }:  _(RawOrigin::Signed(keeper), 0u32, 2 * ONE, route, false, None)
+
+	settle_otc_order_skipped {
+		let account: T::AccountId = account("acc", 1, 1);
+
+		<T as crate::Config>::Currency::mint_into(DAI.into(), &account, 1_000_000_000 * ONE)?;
+
+		assert_ok!(
+			pallet_otc::Pallet::<T>::place_order(RawOrigin::Signed(account).into(), HDX.into(), DAI.into(), 100_000_000 * ONE, 202_020_001 * ONE, true)
+		);
+
+		let route = <T as crate::Config>::Router::get_route(AssetPair {
+			asset_in: DAI.into(),
+			asset_out: HDX.into(),
+		});
+
+		let other_keeper: T::AccountId = account("keeper", 3, 1);
+		assert_ok!(Pallet::<T>::reserve_order(RawOrigin::Signed(other_keeper).into(), 0u32));
+
+		let keeper: T::AccountId = account("keeper", 2, 1);
+	}: {
+		assert!(Pallet::<T>::settle_otc_order(RawOrigin::Signed(keeper).into(), 0u32, 2 * ONE, route, false, None).is_err());
+	}
+
+	settle_otc_pair {
+		let account_a: T::AccountId = account("acc", 1, 1);
+		let account_b: T::AccountId = account("acc", 3, 1);
+
+		<T as crate::Config>::Currency::mint_into(DAI.into(), &account_a, 1_000_000_000 * ONE)?;
+		<T as crate::Config>::Currency::mint_into(HDX.into(), &account_b, 1_000_000_000 * ONE)?;
+
+		assert_ok!(
+			pallet_otc::Pallet::<T>::place_order(RawOrigin::Signed(account_a).into(), HDX.into(), DAI.into(), 100_000_000 * ONE, 220_000_000 * ONE, true)
+		);
+		assert_ok!(
+			pallet_otc::Pallet::<T>::place_order(RawOrigin::Signed(account_b).into(), DAI.into(), HDX.into(), 200_000_000 * ONE, 110_000_000 * ONE, true)
+		);
+
+		let keeper: T::AccountId = account("keeper", 2, 1);
+	}: _(RawOrigin::Signed(keeper), 0u32, 1u32)
+	verify {
+		assert!(!<pallet_otc::Orders<T>>::contains_key(0u32));
+		assert!(!<pallet_otc::Orders<T>>::contains_key(1u32));
+	}
+
+	set_asset_excluded {
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(origin, DAI.into(), true)
+	verify {
+		assert!(ExcludedAssets::<T>::contains_key(AssetIdOf::<T>::from(DAI)));
+	}
+
+	reserve_order {
+		let account: T::AccountId = account("acc", 1, 1);
+
+		<T as crate::Config>::Currency::mint_into(DAI.into(), &account, 1_000_000_000 * ONE)?;
+
+		assert_ok!(
+			pallet_otc::Pallet::<T>::place_order(RawOrigin::Signed(account).into(), HDX.into(), DAI.into(), 100_000_000 * ONE, 202_020_001 * ONE, true)
+		);
+
+		let keeper: T::AccountId = account("keeper", 2, 1);
+	}: _(RawOrigin::Signed(keeper.clone()), 0u32)
+	verify {
+		assert!(OrderReservations::<T>::get(0u32).is_some());
+	}
+
+	set_order_profit_target {
+		let account: T::AccountId = account("acc", 1, 1);
+
+		<T as crate::Config>::Currency::mint_into(DAI.into(), &account, 1_000_000_000 * ONE)?;
+
+		assert_ok!(
+			pallet_otc::Pallet::<T>::place_order(RawOrigin::Signed(account).into(), HDX.into(), DAI.into(), 100_000_000 * ONE, 202_020_001 * ONE, true)
+		);
+
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(origin, 0u32, Some(ONE))
+	verify {
+		assert_eq!(OrderProfitTarget::<T>::get(0u32), Some(ONE));
+	}
+
+	set_asset_min_profit {
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(origin, HDX.into(), Some(ONE))
+	verify {
+		assert_eq!(MinProfitPerAsset::<T>::get(AssetIdOf::<T>::from(HDX)), Some(ONE));
+	}
+
+	expire_order {
+		let account: T::AccountId = account("acc", 1, 1);
+
+		<T as crate::Config>::Currency::mint_into(DAI.into(), &account, 1_000_000_000 * ONE)?;
+
+		assert_ok!(
+			pallet_otc::Pallet::<T>::place_order(RawOrigin::Signed(account).into(), HDX.into(), DAI.into(), 100_000_000 * ONE, 202_020_001 * ONE, true)
+		);
+
+		let keeper: T::AccountId = account("keeper", 2, 1);
+		assert_ok!(Pallet::<T>::reserve_order(RawOrigin::Signed(keeper.clone()).into(), 0u32));
+		OrderProfitTarget::<T>::insert(0u32, ONE);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::OrderTtl::get()));
+	}: _(RawOrigin::Signed(keeper), 0u32)
+	verify {
+		assert!(!<pallet_otc::Orders<T>>::contains_key(0u32));
+		assert!(OrderReservations::<T>::get(0u32).is_none());
+	}
+
+	set_pool_allowed {
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(origin, PoolType::Omnipool, true)
+	verify {
+		assert!(AllowedPools::<T>::contains_key(PoolType::<AssetIdOf<T>>::Omnipool));
+	}
+
+	set_profit_split {
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+
+		let beneficiaries = T::MaxProfitBeneficiaries::get();
+		let share = Permill::from_rational(1, beneficiaries);
+		let mut split: Vec<(T::AccountId, Permill)> = (1..beneficiaries).map(|i| (account("beneficiary", i, 1), share)).collect();
+		split.push((account("beneficiary", 0, 1), Permill::from_parts(Permill::one().deconstruct() - share.deconstruct() * (beneficiaries - 1))));
+		let split: BoundedVec<(T::AccountId, Permill), T::MaxProfitBeneficiaries> = split.try_into().unwrap();
+	}: _<T::RuntimeOrigin>(origin, split.clone())
+	verify {
+		assert_eq!(ProfitSplit::<T>::get(), split);
+	}
+
+	reset_settlement_breaker {
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+
+		SettlementHalted::<T>::put(true);
+		ConsecutiveFailures::<T>::put(1);
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert!(!SettlementHalted::<T>::get());
+		assert_eq!(ConsecutiveFailures::<T>::get(), 0);
+	}
 }
 
 #[cfg(test)]
@@ -27,45 +27,98 @@
 //! aligns the OTC and the Omnipool prices. Executing this trade needs to be profitable, but we are not trying to maximize
 //! the profit. If the pallet couldn't find the amount that closes the arb, the amount that reduces the size of the arb is used.
 //! In the case of not partially fillable OTC orders, the pallet tries to maximize the profit.
+//! When the extrinsic is called with signed origin, the caller receives `KeeperBounty` share of the profit.
+//! If `MaxOracleDeviation` is set, the settlement fails when the spot price of the route deviates from the oracle price
+//! by more than `MaxOracleDeviation`.
+//! The offchain worker scans at most `MaxOrdersScannedPerBlock` orders in one block and continues with the next orders
+//! in the following block. The position of the scan is kept in the offchain storage of the node under
+//! `OFFCHAIN_WORKER_SCAN_CURSOR`, so it's not available to the runtime API. It can be read with the
+//! `offchain_localStorageGet` RPC method, using the `PERSISTENT` storage kind and the hex-encoded key.
+//! Orders that fail to settle `FailuresBeforeBackoff` times in a row are skipped by the offchain worker for
+//! an exponentially growing number of scans, up to `MaxBackoffScans`. The count resets on a successful settlement.
+//! One settlement can't buy more than `MaxPoolDrain` of the liquidity of the last pool of the route. Larger partially
+//! fillable orders are settled partially and `PartiallySettled` event reports the unfilled amount.
+//! Settlements can be simulated with `preview_settlement`, which returns the profit and the spot price of the route
+//! before and after the settlement without changing the state. `simulate_batch` simulates the settlements of several
+//! orders one after another, so each settlement sees the state changes of the previous ones.
+//! OTC orders can't be settled before `MinOrderAge` blocks have passed since they were placed.
+//! If `OrderTtl` is not zero, OTC orders expire `OrderTtl` blocks after they were placed. Expired orders can't be
+//! settled and anyone can close them with `expire_order`, which returns the reserved amount to the maker.
+//! Two complementary OTC orders with crossing prices can be settled against each other with `settle_otc_pair`
+//! without trading in any pool. The surplus of both assets is the profit of the settlement.
+//! Total profit of the OTC settlements in one block, valued in the native asset, is capped by `MaxProfitPerBlock`.
+//! If `ConvertProfitToNative` is set, profit in an asset other than the native asset is sold for the native asset
+//! before it is transferred to `ProfitReceiver`. Profit already in the native asset is transferred without conversion.
+//! If `ProfitSplit` is set, the profit is split between its beneficiaries by their shares instead of being transferred
+//! to `ProfitReceiver`.
+//! If `AllowedPools` is not empty, the route of a settlement can only trade in the allowed pools.
+//! Executed settlements need to improve the limit price of the OTC order by at least `MinPriceImprovement`.
+//! `AuthorityOrigin` can set the minimum profit in an asset with `set_asset_min_profit`. It's used for the orders
+//! with the asset as their asset in instead of `MinProfitPercentage`, unless the order has its own profit target.
+//! The keeper of the last signed settlement of an OTC order is recorded in `SettledBy` and the number of
+//! the settlements of each keeper is counted in `KeeperSettlements`, e.g. for keeper rewards.
+//! If `FailureBreakerThreshold` is not zero, failed unsigned settlements of the offchain worker are reported by
+//! `SettlementFailed` event instead of failing the extrinsic and counted in `ConsecutiveFailures`. Once the count reaches the threshold, `SettlementHalted`
+//! is set and no OTC order can be settled until `AuthorityOrigin` resets it with `reset_settlement_breaker`.
 //!
 //! ## Dispatachable functions
 //! * `settle_otc_order` -  Executes a trade between an OTC order and some route.
+//! * `settle_otc_order_with_route` -  Executes a trade between an OTC order and the provided route.
+//! * `set_asset_excluded` -  Excludes an asset from the settlement or includes it back.
+//! * `reserve_order` -  Reserves an OTC order for the keeper for `ReservationPeriod` blocks.
+//! * `set_order_profit_target` -  Sets the minimum profit of an OTC order instead of `MinProfitPercentage`.
+//! * `settle_otc_pair` -  Fills two complementary OTC orders against each other.
+//! * `set_pool_allowed` -  Adds a pool to the pools allowed in the routes of the settlements or removes it.
+//! * `set_profit_split` -  Sets the beneficiaries of the profit and their shares.
+//! * `reset_settlement_breaker` -  Resumes the settlement halted by the circuit breaker.
+//! * `set_asset_min_profit` -  Sets the minimum profit in an asset instead of `MinProfitPercentage`.
+//! * `expire_order` -  Closes an expired OTC order and returns the reserved amount to the maker.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[cfg(not(feature = "runtime-benchmarks"))]
 use frame_system::RawOrigin;
 
 use frame_support::{
+	dispatch::WithPostDispatchInfo,
 	pallet_prelude::*,
+	storage::with_transaction,
 	traits::{
 		fungibles::{Inspect, Mutate},
-		tokens::{Fortitude, Precision, Preservation},
+		tokens::{DepositConsequence, Fortitude, Precision, Preservation, Provenance},
 	},
-	transactional, PalletId,
+	transactional,
+	weights::WeightToFee,
+	PalletId,
 };
 use frame_system::{
+	ensure_signed,
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::{BlockNumberFor, OriginFor},
 };
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::price::PriceProvider;
 use hydradx_traits::router::{
-	AmmTradeWeights, AmountInAndOut, AssetPair, RouteProvider, RouteSpotPriceProvider, RouterT, Trade,
+	AmmTradeWeights, AmountInAndOut, AssetPair, PoolType, RouteProvider, RouteSpotPriceProvider, RouterT, Trade,
+	TradeExecution,
 };
+use orml_traits::NamedMultiReservableCurrency;
 use pallet_otc::weights::WeightInfo as OtcWeightInfo;
 pub use pallet_otc::OrderId;
 use sp_arithmetic::{
-	traits::{CheckedMul, Saturating},
+	traits::{CheckedMul, One, Saturating, Zero},
 	ArithmeticError, FixedPointNumber, FixedU128,
 };
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
 	offchain::{
 		storage::StorageValueRef,
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
 	traits::AccountIdConversion,
-	Perbill,
+	Perbill, Permill, Rounding, TransactionOutcome,
 };
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -93,16 +146,84 @@ pub const PALLET_ID: PalletId = PalletId(*b"otcsettl");
 // value taken from https://github.com/substrate-developer-hub/recipes/blob/master/pallets/ocw-demo/src/lib.rs
 pub const UNSIGNED_TXS_PRIORITY: u64 = 100;
 
-/// Vector of `SortedOtcsStorageType`
+/// Vector of `SortedOtcsStorageType`. Kept until the offchain worker completes a pass over it.
 pub const OFFCHAIN_WORKER_DATA: &[u8] = b"hydradx/otc-settlements/data/";
 /// Last block number when we updated the `OFFCHAIN_WORKER_DATA`
 pub const OFFCHAIN_WORKER_DATA_LAST_UPDATE: &[u8] = b"hydradx/otc-settlements/data-last-update/";
+/// `SettlementScanSummary` of the last run of the offchain worker
+pub const OFFCHAIN_WORKER_SCAN_SUMMARY: &[u8] = b"hydradx/otc-settlements/scan-summary/";
+/// SCALE-encoded `u32` position in the sorted list of OTCs where the next run of the offchain worker starts scanning.
+/// Cleared after a full pass over the list.
+pub const OFFCHAIN_WORKER_SCAN_CURSOR: &[u8] = b"hydradx/otc-settlements/scan-cursor/";
+/// `FailureCountsStorageType` of OTCs that failed to settle in the previous runs of the offchain worker
+pub const OFFCHAIN_WORKER_FAILURE_COUNTS: &[u8] = b"hydradx/otc-settlements/failure-counts/";
 pub const SORTED_ORDERS_LOCK: &[u8] = b"hydradx/otc-settlements/lock/";
 pub const LOCK_TIMEOUT_EXPIRATION: u64 = 5_000; // 5 seconds
 pub const FILL_SEARCH_ITERATIONS: u32 = 40;
 
 pub type AssetIdOf<T> = <T as pallet_otc::Config>::AssetId;
 type SortedOtcsStorageType = OrderId;
+/// Maps OTC order ID -> (number of consecutive failures, number of scans left to skip the order)
+type FailureCountsStorageType = BTreeMap<OrderId, (u32, u32)>;
+
+/// Outcome of one pass of the offchain worker over the sorted list of OTCs.
+///
+/// Offchain workers can't deposit events, so the summary is logged and stored in the offchain storage
+/// under `OFFCHAIN_WORKER_SCAN_SUMMARY` instead.
+#[derive(Encode, Decode, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SettlementScanSummary {
+	/// Number of OTCs with an arbitrage opportunity that were checked.
+	pub scanned: u32,
+	/// Number of OTCs for which a settlement transaction was submitted.
+	pub settled: u32,
+	/// Number of OTCs that were skipped or for which no profitable amount was found.
+	pub skipped: u32,
+}
+
+/// Outcome of a simulated settlement of an OTC order, returned by `preview_settlement`.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct SettlementPreview<AssetId> {
+	/// Profit of the settlement for the beneficiaries of the profit, without the keeper bounty.
+	/// If `ConvertProfitToNative` is set, it's the amount sold for the native asset.
+	pub profit: Balance,
+	/// Asset the profit is paid in. It's the asset in of the OTC order.
+	pub profit_asset: AssetId,
+	/// Spot price of the route before the settlement.
+	pub pool_price_before: FixedU128,
+	/// Spot price of the route after the settlement.
+	pub pool_price_after: FixedU128,
+}
+
+/// Reason why a simulated settlement of an OTC order was skipped by `simulate_batch`.
+#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum SkipReason {
+	/// OTC order doesn't exist.
+	OrderNotFound,
+	/// Asset of the OTC order is excluded from the settlement.
+	AssetExcluded,
+	/// OTC order is reserved by a keeper.
+	Reserved,
+	/// OTC order was placed less than `MinOrderAge` blocks ago.
+	OrderTooYoung,
+	/// Profit of the settlements in the block reached `MaxProfitPerBlock`.
+	BlockProfitCapReached,
+	/// No profitable amount closing or reducing the arbitrage opportunity was found.
+	NoProfitableAmount,
+	/// Settlement failed for another reason.
+	SettlementFailed,
+	/// OTC order was placed at least `OrderTtl` blocks ago.
+	OrderExpired,
+}
+
+/// Outcome of a simulated settlement of an OTC order, returned by `simulate_batch`.
+#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum SettlementOutcome {
+	/// OTC order was settled and the profit was transferred to the beneficiaries of the profit.
+	/// The profit is in the asset in of the OTC order, before the conversion to the native asset.
+	Settled(Balance),
+	/// Settlement of the OTC order was skipped.
+	Skipped(SkipReason),
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -124,14 +245,53 @@ pub mod pallet {
 			+ RouterT<Self::RuntimeOrigin, AssetIdOf<Self>, Balance, Trade<AssetIdOf<Self>>, AmountInAndOut<Balance>>
 			+ RouteSpotPriceProvider<AssetIdOf<Self>>;
 
-		/// Account who receives the profit.
+		/// Oracle price provider used to cross-check the spot price of the route before the settlement.
+		type PriceOracle: PriceProvider<AssetIdOf<Self>, Price = EmaPrice>;
+
+		/// Maximum deviation of the spot price of the route from the oracle price.
+		/// `None` disables the check.
+		#[pallet::constant]
+		type MaxOracleDeviation: Get<Option<Permill>>;
+
+		/// Handlers for AMM pools used to query the liquidity of the pools of the route.
+		type AMM: TradeExecution<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Self::AccountId,
+			AssetIdOf<Self>,
+			Balance,
+			Error = DispatchError,
+		>;
+
+		/// Maximum share of the liquidity of the last pool of the route that one settlement can buy.
+		/// Larger OTC orders are settled partially up to this amount.
+		#[pallet::constant]
+		type MaxPoolDrain: Get<Permill>;
+
+		/// Origin that can exclude assets from the settlement.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Account who receives the profit if `ProfitSplit` is empty.
 		#[pallet::constant]
 		type ProfitReceiver: Get<Self::AccountId>;
 
+		/// Maximum number of beneficiaries in `ProfitSplit`.
+		#[pallet::constant]
+		type MaxProfitBeneficiaries: Get<u32>;
+
+		/// Share of the profit paid to the signed caller of the settlement extrinsics.
+		/// The rest of the profit goes to `ProfitReceiver`.
+		#[pallet::constant]
+		type KeeperBounty: Get<Permill>;
+
 		/// Minimum profit in terms of percentage.
 		#[pallet::constant]
 		type MinProfitPercentage: Get<Perbill>;
 
+		/// Minimum improvement of the price of the router trade over the limit price of the OTC order.
+		/// Settlements fail if the router trade doesn't pay at least the limit price increased by this share.
+		#[pallet::constant]
+		type MinPriceImprovement: Get<Permill>;
+
 		/// Determines when we consider an arbitrage as closed.
 		#[pallet::constant]
 		type PricePrecision: Get<FixedU128>;
@@ -144,6 +304,60 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxIterations: Get<u32>;
 
+		/// Maximum number of OTCs scanned by the offchain worker in one block.
+		/// The next block continues scanning where the previous one stopped.
+		#[pallet::constant]
+		type MaxOrdersScannedPerBlock: Get<u32>;
+
+		/// Number of consecutive failed settlements of an OTC order after which the offchain worker starts skipping
+		/// the order. The order is skipped for an exponentially growing number of scans after each further failure.
+		#[pallet::constant]
+		type FailuresBeforeBackoff: Get<u32>;
+
+		/// Maximum number of scans an OTC order is skipped for after a failed settlement.
+		#[pallet::constant]
+		type MaxBackoffScans: Get<u32>;
+
+		/// Number of consecutive failed unsigned settlements of any OTC orders after which the settlement is halted
+		/// until `AuthorityOrigin` resets it. Zero disables the circuit breaker.
+		#[pallet::constant]
+		type FailureBreakerThreshold: Get<u32>;
+
+		/// Number of blocks an OTC order stays reserved for the keeper after calling `reserve_order`.
+		#[pallet::constant]
+		type ReservationPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks since the expiry of a reservation before the same keeper can reserve
+		/// the OTC order again.
+		#[pallet::constant]
+		type ReservationCooldown: Get<BlockNumberFor<Self>>;
+
+		/// Minimum number of blocks since the placement of an OTC order before it can be settled.
+		#[pallet::constant]
+		type MinOrderAge: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks since the placement of an OTC order after which the order expires.
+		/// Expired orders can't be settled and can be closed with `expire_order`. Zero disables the expiry.
+		#[pallet::constant]
+		type OrderTtl: Get<BlockNumberFor<Self>>;
+
+		/// Maximum total profit of the OTC settlements in one block, valued in the native asset.
+		/// Once reached, further settlements in the block are rejected. `Balance::MAX` disables the cap.
+		#[pallet::constant]
+		type MaxProfitPerBlock: Get<Balance>;
+
+		/// Native asset id. Fee calculated by `WeightToFee` is denominated in the native asset.
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetIdOf<Self>>;
+
+		/// If set, profit in an asset other than the native asset is sold for the native asset
+		/// before it is transferred to `ProfitReceiver`. Profit in the native asset is never converted.
+		#[pallet::constant]
+		type ConvertProfitToNative: Get<bool>;
+
+		/// Convert a weight of the extrinsic into the transaction fee.
+		type WeightToFee: WeightToFee<Balance = Balance>;
+
 		/// Router weight information.
 		type RouterWeightInfo: AmmTradeWeights<Trade<AssetIdOf<Self>>>;
 
@@ -151,13 +365,71 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
+	/// Assets excluded from the settlement.
+	/// OTC orders with an excluded asset can't be settled.
+	#[pallet::storage]
+	pub type ExcludedAssets<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, ()>;
+
+	/// OTC orders reserved by a keeper.
+	/// Maps OTC order ID -> (keeper, block number when the reservation expires)
+	#[pallet::storage]
+	pub type OrderReservations<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, (T::AccountId, BlockNumberFor<T>)>;
+
+	/// Minimum profit of the settlement of an OTC order, used instead of `MinProfitPercentage`.
+	/// Maps OTC order ID -> minimum profit in the asset in of the OTC order
+	#[pallet::storage]
+	pub type OrderProfitTarget<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Balance>;
+
+	/// Minimum profit of the settlements of OTC orders with the asset as their asset in,
+	/// used instead of `MinProfitPercentage`. `OrderProfitTarget` of the order takes precedence.
+	/// Maps asset ID -> minimum profit in the asset
+	#[pallet::storage]
+	pub type MinProfitPerAsset<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, Balance>;
+
+	/// Profit of the OTC settlements in the current block, valued in the native asset.
+	/// (block number, accumulated profit). The profit of an earlier block is treated as zero.
+	#[pallet::storage]
+	pub type BlockProfit<T: Config> = StorageValue<_, (BlockNumberFor<T>, Balance), OptionQuery>;
+
+	/// Pools the route of a settlement can trade in. If empty, all pools are allowed.
+	#[pallet::storage]
+	pub type AllowedPools<T: Config> = CountedStorageMap<_, Blake2_128Concat, PoolType<AssetIdOf<T>>, ()>;
+
+	/// Beneficiaries of the profit and their shares. The shares sum up to 100%.
+	/// If empty, all profit is transferred to `ProfitReceiver`.
+	#[pallet::storage]
+	pub type ProfitSplit<T: Config> =
+		StorageValue<_, BoundedVec<(T::AccountId, Permill), T::MaxProfitBeneficiaries>, ValueQuery>;
+
+	/// Keeper of the last signed settlement of an OTC order.
+	/// Maps OTC order ID -> keeper
+	#[pallet::storage]
+	pub type SettledBy<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, T::AccountId>;
+
+	/// Number of signed settlements of OTC orders per keeper.
+	/// A settlement of a pair of OTC orders counts as two settlements.
+	#[pallet::storage]
+	pub type KeeperSettlements<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Number of consecutive failed unsigned settlements of any OTC orders since the last successful one.
+	/// Counted only if `FailureBreakerThreshold` is not zero.
+	#[pallet::storage]
+	pub type ConsecutiveFailures<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Set when `ConsecutiveFailures` reaches `FailureBreakerThreshold`. No OTC order can be settled while set.
+	#[pallet::storage]
+	pub type SettlementHalted<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn offchain_worker(block_number: BlockNumberFor<T>) {
 			// limit the cases when the offchain worker run
 			if sp_io::offchain::is_validator() {
 				Self::sort_otcs(block_number);
-				Self::settle_otcs();
+				// settlements would be rejected until the circuit breaker is reset
+				if !SettlementHalted::<T>::get() {
+					Self::settle_otcs();
+				}
 			}
 		}
 	}
@@ -176,6 +448,10 @@ pub mod pallet {
 				TransactionSource::InBlock => {} // some other node included it in a block
 			};
 
+			if SettlementHalted::<T>::get() {
+				return InvalidTransaction::Call.into();
+			}
+
 			let valid_tx = |provide| {
 				ValidTransaction::with_tag_prefix("settle-otc-with-router")
 					.priority(UNSIGNED_TXS_PRIORITY)
@@ -196,7 +472,58 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A trade has been executed
-		Executed { asset_id: AssetIdOf<T>, profit: Balance },
+		Executed {
+			asset_id: AssetIdOf<T>,
+			profit: Balance,
+			bounty: Balance,
+		},
+		/// Asset was excluded from or included back to the settlement
+		AssetExclusionSet { asset_id: AssetIdOf<T>, excluded: bool },
+		/// OTC order was reserved by a keeper
+		OrderReserved {
+			otc_id: OrderId,
+			keeper: T::AccountId,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Settlement of the OTC order was capped by `MaxPoolDrain`
+		PartiallySettled {
+			otc_id: OrderId,
+			amount: Balance,
+			unfilled: Balance,
+		},
+		/// Profit target of the OTC order was set or removed
+		OrderProfitTargetSet { otc_id: OrderId, target: Option<Balance> },
+		/// Two complementary OTC orders were filled against each other
+		PairSettled { order_a: OrderId, order_b: OrderId },
+		/// Pool was added to or removed from the allowed pools
+		PoolAllowedSet {
+			pool: PoolType<AssetIdOf<T>>,
+			allowed: bool,
+		},
+		/// Beneficiaries of the profit and their shares were set
+		ProfitSplitSet { split: Vec<(T::AccountId, Permill)> },
+		/// Settlement of the OTC order failed and was counted by the circuit breaker
+		SettlementFailed {
+			otc_id: OrderId,
+			error: DispatchError,
+			failures: u32,
+		},
+		/// Settlement was halted after `FailureBreakerThreshold` consecutive failed settlements
+		SettlementHalted { failures: u32 },
+		/// Settlement halted by the circuit breaker was resumed
+		SettlementResumed,
+		/// Minimum profit in the asset was set or removed
+		AssetMinProfitSet {
+			asset_id: AssetIdOf<T>,
+			min_profit: Option<Balance>,
+		},
+		/// Expired OTC order was closed and the reserved amount was returned to the maker
+		OrderExpired {
+			otc_id: OrderId,
+			owner: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -215,6 +542,46 @@ pub mod pallet {
 		TradeAmountTooLow,
 		/// Price for a route is not available
 		PriceNotAvailable,
+		/// Assets of the route don't match the assets of the OTC order
+		AssetMismatch,
+		/// Reserved amount of the OTC order owner doesn't cover the fill
+		MakerUnderfunded,
+		/// Profit doesn't cover the transaction fee of the extrinsic
+		NotNetProfitable,
+		/// Asset of the OTC order is excluded from the settlement
+		AssetExcluded,
+		/// Spot price of the route deviates from the oracle price by more than `MaxOracleDeviation`
+		OracleDeviation,
+		/// OTC order is reserved by another keeper
+		Reserved,
+		/// Liquidity of the last pool of the route is not available
+		LiquidityNotAvailable,
+		/// Trade amount would buy more than `MaxPoolDrain` of the liquidity of the pool
+		PoolDrainLimitExceeded,
+		/// OTC order was placed less than `MinOrderAge` blocks ago
+		OrderTooYoung,
+		/// Assets of the OTC orders are not mirror images of each other
+		OrdersNotComplementary,
+		/// Amounts received from the OTC orders don't cover the amounts of the other OTC order
+		PricesNotCrossing,
+		/// Profit of the settlements in this block reached `MaxProfitPerBlock`
+		BlockProfitCapReached,
+		/// Route trades in a pool which is not allowed
+		PoolNotAllowed,
+		/// Current block is past the `valid_until` block of the settlement
+		Expired,
+		/// Shares of the profit split don't sum up to 100%
+		InvalidProfitSplit,
+		/// Price of the router trade doesn't improve the limit price of the OTC order by `MinPriceImprovement`
+		InsufficientImprovement,
+		/// Settlement is halted by the circuit breaker
+		Halted,
+		/// OTC order was placed at least `OrderTtl` blocks ago
+		OrderExpired,
+		/// OTC order hasn't expired yet
+		OrderNotExpired,
+		/// Keeper reserved the OTC order less than `ReservationCooldown` blocks ago
+		ReservationCooldown,
 	}
 
 	#[pallet::call]
@@ -225,10 +592,15 @@ pub mod pallet {
 		/// If the OTC order is partially fillable, the extrinsic fails if the existing arbitrage
 		/// opportunity is not closed or reduced after the trade.
 		/// If the OTC order is not partially fillable, fails if there is no profit after the trade.
+		/// Fails with `InsufficientImprovement` if the price of the router trade doesn't improve the limit
+		/// price of the OTC order by at least `MinPriceImprovement`.
 		///
-		/// `Origin` calling this extrinsic is not paying or receiving anything.
+		/// Signed `Origin` calling this extrinsic receives `KeeperBounty` share of the profit as a bounty.
+		/// Unsigned `Origin` is not paying or receiving anything.
 		///
-		/// The profit made by closing the arbitrage is transferred to `FeeReceiver`.
+		/// The profit made by closing the arbitrage, minus the bounty, is transferred to `ProfitReceiver`
+		/// or split between the beneficiaries of `ProfitSplit`, converted to the native asset first
+		/// if `ConvertProfitToNative` is set.
 		///
 		/// Parameters:
 		/// - `origin`: Signed or unsigned origin. Unsigned origin doesn't pay the TX fee,
@@ -236,25 +608,385 @@ pub mod pallet {
 		/// - `otc_id`: ID of the OTC order with existing arbitrage opportunity.
 		/// - `amount`: Amount necessary to close the arb.
 		/// - `route`: The route we trade against. Required for the fee calculation.
+		/// - `ensure_net_profitable`: If set, the extrinsic fails if the profit doesn't cover the transaction fee
+		/// 			calculated from the weight of the extrinsic.
+		/// - `valid_until`: If set, the extrinsic fails if the current block is past this block.
+		///
+		/// If the settlement is halted, expired, the OTC order doesn't exist, has an excluded asset, is reserved by
+		/// another keeper (signed origin only), is younger than `MinOrderAge` or `MaxProfitPerBlock` was reached in this block,
+		/// the extrinsic fails early and only the weight of these checks is charged.
+		///
+		/// If `FailureBreakerThreshold` is not zero, a failed unsigned settlement is reverted and reported
+		/// by `SettlementFailed` event instead of failing the extrinsic. Once `FailureBreakerThreshold`
+		/// unsigned settlements failed in a row, the settlement is halted. Failed signed settlements
+		/// are not counted and fail the extrinsic, so that signed callers can't halt the settlement.
 		///
 		/// Emits `Executed` event when successful.
 		///
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::settle_otc_order()
-			.saturating_add(<T as Config>::RouterWeightInfo::sell_weight(route))
-		.saturating_add(<T as Config>::RouterWeightInfo::get_route_weight())
-		.saturating_add(<T as Config>::RouterWeightInfo::calculate_spot_price_with_fee_weight(route))
-		.saturating_add(<T as pallet_otc::Config>::WeightInfo::fill_order().max(<T as pallet_otc::Config>::WeightInfo::partial_fill_order()))
-		)]
+		#[pallet::weight(Pallet::<T>::settle_otc_order_weight(route))]
 		pub fn settle_otc_order(
-			_origin: OriginFor<T>,
+			origin: OriginFor<T>,
 			otc_id: OrderId,
 			amount: Balance,
 			route: Vec<Trade<AssetIdOf<T>>>,
-		) -> DispatchResult {
+			ensure_net_profitable: bool,
+			valid_until: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let keeper = ensure_signed(origin).ok();
+
+			Self::ensure_not_halted()
+				.and_then(|_| Self::ensure_not_expired(valid_until))
+				.and_then(|_| Self::ensure_settleable(otc_id, keeper.as_ref()))
+				.and_then(|_| Self::ensure_block_profit_available())
+				.map_err(|e| e.with_weight(<T as Config>::WeightInfo::settle_otc_order_skipped()))?;
+
 			// `is_execution` is set to `true`, so both full and partial closing of arbs is allowed.
 			// If set to `false`, an arb needs to be fully closed.
-			Self::settle_otc(otc_id, amount, route, true)
+			let is_unsigned = keeper.is_none();
+			let settle =
+				|| Self::settle_otc(otc_id, amount, route, true, ensure_net_profitable, true, keeper).map(|_| ());
+			if is_unsigned {
+				Self::settle_with_breaker(otc_id, settle)?;
+			} else {
+				settle()?;
+			}
+			Ok(().into())
+		}
+
+		/// Close an existing OTC arbitrage opportunity using the provided route.
+		///
+		/// Works the same way as `settle_otc_order`, except that the route doesn't need to match the route
+		/// provided by the router. Assets of the route need to match the assets of the OTC order
+		/// and the trade needs to meet the minimum profit.
+		///
+		/// Parameters:
+		/// - `origin`: Signed origin.
+		/// - `otc_id`: ID of the OTC order with existing arbitrage opportunity.
+		/// - `amount`: Amount necessary to close the arb.
+		/// - `route`: The route to trade against.
+		///
+		/// Fails early and charges only the weight of the checks in the same cases as `settle_otc_order`.
+		///
+		/// Emits `Executed` event when successful.
+		///
+		#[pallet::call_index(1)]
+		#[pallet::weight(Pallet::<T>::settle_otc_order_weight(route))]
+		pub fn settle_otc_order_with_route(
+			origin: OriginFor<T>,
+			otc_id: OrderId,
+			amount: Balance,
+			route: Vec<Trade<AssetIdOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			let keeper = ensure_signed(origin)?;
+
+			Self::ensure_not_halted()
+				.and_then(|_| Self::ensure_settleable(otc_id, Some(&keeper)))
+				.and_then(|_| Self::ensure_block_profit_available())
+				.map_err(|e| e.with_weight(<T as Config>::WeightInfo::settle_otc_order_skipped()))?;
+
+			Self::settle_otc(otc_id, amount, route, true, false, false, Some(keeper))?;
+			Ok(().into())
+		}
+
+		/// Exclude an asset from the settlement or include it back.
+		///
+		/// OTC orders with an excluded asset can't be settled and are skipped by the offchain worker.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`.
+		/// - `asset_id`: Asset to exclude or include.
+		/// - `excluded`: If set, the asset is excluded from the settlement.
+		///
+		/// Emits `AssetExclusionSet` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_excluded())]
+		pub fn set_asset_excluded(origin: OriginFor<T>, asset_id: AssetIdOf<T>, excluded: bool) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			if excluded {
+				ExcludedAssets::<T>::insert(asset_id, ());
+			} else {
+				ExcludedAssets::<T>::remove(asset_id);
+			}
+
+			Self::deposit_event(Event::AssetExclusionSet { asset_id, excluded });
+			Ok(())
+		}
+
+		/// Reserve an OTC order for the keeper for `ReservationPeriod` blocks.
+		///
+		/// While the reservation is active, only the keeper and the unsigned settlements of the offchain worker
+		/// can settle the OTC order. The reservation expires automatically and can't be extended until it has
+		/// expired. The same keeper can reserve the OTC order again only `ReservationCooldown` blocks after
+		/// the expiry of its reservation. Successful settlement of the OTC order removes the reservation.
+		///
+		/// Parameters:
+		/// - `origin`: Signed origin of the keeper.
+		/// - `otc_id`: ID of the OTC order to reserve.
+		///
+		/// Emits `OrderReserved` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::reserve_order())]
+		pub fn reserve_order(origin: OriginFor<T>, otc_id: OrderId) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+
+			ensure!(<pallet_otc::Orders<T>>::contains_key(otc_id), Error::<T>::OrderNotFound);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some((reserved_by, expires_at)) = OrderReservations::<T>::get(otc_id) {
+				ensure!(expires_at <= now, Error::<T>::Reserved);
+				ensure!(
+					reserved_by != keeper || expires_at.saturating_add(T::ReservationCooldown::get()) <= now,
+					Error::<T>::ReservationCooldown
+				);
+			}
+
+			let expires_at = now.saturating_add(T::ReservationPeriod::get());
+			OrderReservations::<T>::insert(otc_id, (keeper.clone(), expires_at));
+
+			Self::deposit_event(Event::OrderReserved {
+				otc_id,
+				keeper,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Set the minimum profit of the settlement of an OTC order or remove it.
+		///
+		/// The profit target is used instead of `MinProfitPercentage` of the amount in of the OTC order,
+		/// so it can be both lower or higher than the global threshold.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`.
+		/// - `otc_id`: ID of the OTC order.
+		/// - `target`: Minimum profit in the asset in of the OTC order. If not set, the profit target is removed.
+		///
+		/// Emits `OrderProfitTargetSet` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_order_profit_target())]
+		pub fn set_order_profit_target(
+			origin: OriginFor<T>,
+			otc_id: OrderId,
+			target: Option<Balance>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(<pallet_otc::Orders<T>>::contains_key(otc_id), Error::<T>::OrderNotFound);
+
+			OrderProfitTarget::<T>::set(otc_id, target);
+
+			Self::deposit_event(Event::OrderProfitTargetSet { otc_id, target });
+			Ok(())
+		}
+
+		/// Settle two complementary OTC orders against each other.
+		///
+		/// `order_b` needs to offer the asset in of `order_a` for the asset out of `order_a`.
+		/// Both orders are fully filled by the pallet account, without trading in any pool.
+		/// Fails if the amount received from one order, without the OTC fee, doesn't cover the amount
+		/// the other order asks for, or if `MaxProfitPerBlock` was reached in this block.
+		///
+		/// The surplus of each asset is the profit. The caller receives `KeeperBounty` share of it
+		/// and the rest is transferred to `ProfitReceiver` or split between the beneficiaries of `ProfitSplit`.
+		/// The profit in both assets counts toward `MaxProfitPerBlock`.
+		///
+		/// Parameters:
+		/// - `origin`: Signed origin of the keeper.
+		/// - `order_a`: ID of the first OTC order.
+		/// - `order_b`: ID of the complementary OTC order.
+		///
+		/// Emits `Executed` event for each asset with profit and `PairSettled` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(Pallet::<T>::settle_otc_pair_weight(*order_a, *order_b))]
+		pub fn settle_otc_pair(origin: OriginFor<T>, order_a: OrderId, order_b: OrderId) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+
+			Self::ensure_not_halted()?;
+
+			let otc_a = Self::ensure_settleable(order_a, Some(&keeper))?;
+			let otc_b = Self::ensure_settleable(order_b, Some(&keeper))?;
+
+			ensure!(
+				order_a != order_b && otc_a.asset_in == otc_b.asset_out && otc_a.asset_out == otc_b.asset_in,
+				Error::<T>::OrdersNotComplementary
+			);
+
+			let received_from_a = otc_a
+				.amount_out
+				.saturating_sub(pallet_otc::Pallet::<T>::calculate_fee(otc_a.amount_out));
+			let received_from_b = otc_b
+				.amount_out
+				.saturating_sub(pallet_otc::Pallet::<T>::calculate_fee(otc_b.amount_out));
+			ensure!(
+				received_from_a >= otc_b.amount_in && received_from_b >= otc_a.amount_in,
+				Error::<T>::PricesNotCrossing
+			);
+
+			Self::ensure_maker_funded(&otc_a, otc_a.amount_in)?;
+			Self::ensure_maker_funded(&otc_b, otc_b.amount_in)?;
+
+			Self::ensure_block_profit_available()?;
+
+			Self::settle_pair(order_a, &otc_a, order_b, keeper.clone())?;
+
+			for otc_id in [order_a, order_b] {
+				OrderReservations::<T>::remove(otc_id);
+				OrderProfitTarget::<T>::remove(otc_id);
+				Self::record_keeper_settlement(otc_id, &keeper);
+			}
+
+			Self::deposit_event(Event::PairSettled { order_a, order_b });
+			Ok(())
+		}
+
+		/// Add a pool to the pools allowed in the routes of the settlements or remove it.
+		///
+		/// If no pools are allowed, the routes can trade in all pools.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`.
+		/// - `pool`: Pool to allow or disallow.
+		/// - `allowed`: If set, the pool is added to the allowed pools. Otherwise it's removed.
+		///
+		/// Emits `PoolAllowedSet` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_allowed())]
+		pub fn set_pool_allowed(origin: OriginFor<T>, pool: PoolType<AssetIdOf<T>>, allowed: bool) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			if allowed {
+				AllowedPools::<T>::insert(pool, ());
+			} else {
+				AllowedPools::<T>::remove(pool);
+			}
+
+			Self::deposit_event(Event::PoolAllowedSet { pool, allowed });
+			Ok(())
+		}
+
+		/// Set the beneficiaries of the profit and their shares.
+		///
+		/// The profit of each settlement, minus the keeper bounty, is split between the beneficiaries
+		/// by their shares. The last beneficiary receives the rest left after rounding.
+		/// If `split` is empty, all profit is transferred to `ProfitReceiver`.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`.
+		/// - `split`: Beneficiaries and their shares of the profit. The shares need to sum up to 100%.
+		///
+		/// Emits `ProfitSplitSet` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_profit_split())]
+		pub fn set_profit_split(
+			origin: OriginFor<T>,
+			split: BoundedVec<(T::AccountId, Permill), T::MaxProfitBeneficiaries>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let total_shares = split
+				.iter()
+				.fold(0u64, |acc, (_, share)| acc.saturating_add(share.deconstruct() as u64));
+			ensure!(
+				split.is_empty() || total_shares == Permill::one().deconstruct() as u64,
+				Error::<T>::InvalidProfitSplit
+			);
+
+			ProfitSplit::<T>::put(split.clone());
+
+			Self::deposit_event(Event::ProfitSplitSet {
+				split: split.into_inner(),
+			});
+			Ok(())
+		}
+
+		/// Resume the settlement halted by the circuit breaker.
+		///
+		/// Clears `SettlementHalted` and resets `ConsecutiveFailures`.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`.
+		///
+		/// Emits `SettlementResumed` event when successful.
+		///
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::reset_settlement_breaker())]
+		pub fn reset_settlement_breaker(origin: OriginFor<T>) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			SettlementHalted::<T>::kill();
+			ConsecutiveFailures::<T>::kill();
+
+			Self::deposit_event(Event::SettlementResumed);
+			Ok(())
+		}
+
+		/// Set the minimum profit of the settlements of OTC orders with the asset as their asset in, or remove it.
+		///
+		/// The minimum profit is used instead of `MinProfitPercentage` of the amount in of the OTC order,
+		/// e.g. to require a higher absolute profit in low-value assets. `OrderProfitTarget` of the order
+		/// takes precedence.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`.
+		/// - `asset_id`: Asset the profit is made in.
+		/// - `min_profit`: Minimum profit in the asset. If not set, the minimum profit of the asset is removed.
+		///
+		/// Emits `AssetMinProfitSet` event when successful.
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_min_profit())]
+		pub fn set_asset_min_profit(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			min_profit: Option<Balance>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			MinProfitPerAsset::<T>::set(asset_id, min_profit);
+
+			Self::deposit_event(Event::AssetMinProfitSet { asset_id, min_profit });
+			Ok(())
+		}
+
+		/// Close an expired OTC order and return the reserved amount to the maker.
+		///
+		/// OTC order expires `OrderTtl` blocks after it was placed. Orders without a recorded placement block
+		/// and all orders when `OrderTtl` is zero never expire.
+		/// The reservation and the profit target of the order are removed too.
+		///
+		/// Parameters:
+		/// - `origin`: Signed origin.
+		/// - `otc_id`: ID of the expired OTC order.
+		///
+		/// Emits `OrderExpired` event when successful.
+		///
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::expire_order())]
+		pub fn expire_order(origin: OriginFor<T>, otc_id: OrderId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(<pallet_otc::Orders<T>>::contains_key(otc_id), Error::<T>::OrderNotFound);
+			ensure!(Self::is_order_expired(otc_id), Error::<T>::OrderNotExpired);
+
+			let otc = pallet_otc::Pallet::<T>::remove_order(otc_id)?;
+			OrderReservations::<T>::remove(otc_id);
+			OrderProfitTarget::<T>::remove(otc_id);
+
+			Self::deposit_event(Event::OrderExpired {
+				otc_id,
+				owner: otc.owner,
+				asset_id: otc.asset_out,
+				amount: otc.amount_out,
+			});
+			Ok(())
 		}
 	}
 }
@@ -264,21 +996,352 @@ impl<T: Config> Pallet<T> {
 		PALLET_ID.into_account_truncating()
 	}
 
+	/// Ensure that none of the assets of the OTC order is excluded from the settlement.
+	fn ensure_assets_not_excluded(otc: &Order<T::AccountId, T::AssetId>) -> DispatchResult {
+		ensure!(
+			!ExcludedAssets::<T>::contains_key(otc.asset_in) && !ExcludedAssets::<T>::contains_key(otc.asset_out),
+			Error::<T>::AssetExcluded
+		);
+		Ok(())
+	}
+
+	/// Ensure that the settlement is not halted by the circuit breaker.
+	fn ensure_not_halted() -> DispatchResult {
+		ensure!(!SettlementHalted::<T>::get(), Error::<T>::Halted);
+		Ok(())
+	}
+
+	/// Run the settlement and count the consecutive failed settlements for the circuit breaker.
+	/// Used only for the unsigned settlements submitted by the offchain worker, because failures of signed
+	/// settlements depend on the input of the caller.
+	///
+	/// If `FailureBreakerThreshold` is zero, the result of the settlement is returned.
+	/// Otherwise the changes of a failed settlement are reverted and the failure is counted without
+	/// failing the extrinsic, so that the count is kept. A successful settlement resets the count.
+	/// The settlement is halted once the count reaches `FailureBreakerThreshold`.
+	fn settle_with_breaker(otc_id: OrderId, settle: impl FnOnce() -> DispatchResult) -> DispatchResult {
+		let threshold = T::FailureBreakerThreshold::get();
+		if threshold == 0 {
+			return settle();
+		}
+
+		let result = with_transaction(|| {
+			let result = settle();
+			if result.is_ok() {
+				TransactionOutcome::Commit(Ok::<_, DispatchError>(result))
+			} else {
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>(result))
+			}
+		})?;
+
+		match result {
+			Ok(()) => ConsecutiveFailures::<T>::kill(),
+			Err(error) => {
+				let failures = ConsecutiveFailures::<T>::mutate(|failures| {
+					*failures = failures.saturating_add(1);
+					*failures
+				});
+				Self::deposit_event(Event::SettlementFailed {
+					otc_id,
+					error,
+					failures,
+				});
+
+				if failures >= threshold {
+					SettlementHalted::<T>::put(true);
+					Self::deposit_event(Event::SettlementHalted { failures });
+				}
+			}
+		}
+		Ok(())
+	}
+
+	/// Ensure that the current block is not past the `valid_until` block of the settlement.
+	fn ensure_not_expired(valid_until: Option<BlockNumberFor<T>>) -> DispatchResult {
+		if let Some(valid_until) = valid_until {
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= valid_until,
+				Error::<T>::Expired
+			);
+		}
+		Ok(())
+	}
+
+	/// Run the cheap checks of the settlement of the OTC order and return the order.
+	/// The order has to exist, can't have an excluded asset, can't be reserved by another keeper,
+	/// has to be at least `MinOrderAge` blocks old and can't be expired.
+	fn ensure_settleable(
+		otc_id: OrderId,
+		keeper: Option<&T::AccountId>,
+	) -> Result<Order<T::AccountId, T::AssetId>, DispatchError> {
+		let otc = <pallet_otc::Orders<T>>::get(otc_id).ok_or(Error::<T>::OrderNotFound)?;
+
+		Self::ensure_assets_not_excluded(&otc)?;
+		Self::ensure_not_reserved(otc_id, keeper)?;
+		Self::ensure_order_aged(otc_id)?;
+		ensure!(!Self::is_order_expired(otc_id), Error::<T>::OrderExpired);
+
+		Ok(otc)
+	}
+
+	/// Ensure that the OTC order is not reserved by another keeper.
+	/// Unsigned settlements of the offchain worker (`keeper` is `None`) are not blocked by reservations.
+	fn ensure_not_reserved(otc_id: OrderId, keeper: Option<&T::AccountId>) -> DispatchResult {
+		let Some(keeper) = keeper else {
+			return Ok(());
+		};
+		if let Some((reserved_by, expires_at)) = OrderReservations::<T>::get(otc_id) {
+			ensure!(
+				expires_at <= frame_system::Pallet::<T>::block_number() || keeper == &reserved_by,
+				Error::<T>::Reserved
+			);
+		}
+		Ok(())
+	}
+
+	/// Ensure that the OTC order was placed at least `MinOrderAge` blocks ago.
+	/// Orders without a recorded placement block are considered old enough.
+	fn ensure_order_aged(otc_id: OrderId) -> DispatchResult {
+		if let Some(placed_at) = <pallet_otc::OrderPlacedAt<T>>::get(otc_id) {
+			ensure!(
+				placed_at.saturating_add(T::MinOrderAge::get()) <= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::OrderTooYoung
+			);
+		}
+		Ok(())
+	}
+
+	/// Check if the OTC order was placed at least `OrderTtl` blocks ago.
+	/// Orders without a recorded placement block don't expire.
+	fn is_order_expired(otc_id: OrderId) -> bool {
+		let ttl = T::OrderTtl::get();
+		if ttl.is_zero() {
+			return false;
+		}
+		<pallet_otc::OrderPlacedAt<T>>::get(otc_id).map_or(false, |placed_at| {
+			placed_at.saturating_add(ttl) <= frame_system::Pallet::<T>::block_number()
+		})
+	}
+
+	/// Ensure that the route trades only in `AllowedPools`, unless no pools are allowed explicitly.
+	fn ensure_pools_allowed(route: &[Trade<AssetIdOf<T>>]) -> DispatchResult {
+		if AllowedPools::<T>::count() == 0 {
+			return Ok(());
+		}
+		ensure!(
+			route.iter().all(|trade| AllowedPools::<T>::contains_key(trade.pool)),
+			Error::<T>::PoolNotAllowed
+		);
+		Ok(())
+	}
+
 	/// Ensure that the profit is more than some minimum amount.
-	fn ensure_min_profit(otc_amount_in: Balance, profit: Balance) -> DispatchResult {
+	/// `OrderProfitTarget` of the OTC order is used if set, then `MinProfitPerAsset` of `otc_asset_in`,
+	/// otherwise `MinProfitPercentage` of `otc_amount_in`.
+	fn ensure_min_profit(
+		otc_id: OrderId,
+		otc_asset_in: AssetIdOf<T>,
+		otc_amount_in: Balance,
+		profit: Balance,
+	) -> DispatchResult {
 		// In the benchmark we calculate the overhead of extrinsic and we doesn't make any trade.
 		// We disable this check because otherwise it would fail.
 		if cfg!(feature = "runtime-benchmarks") {
 			return Ok(());
 		}
 
-		let min_expected_profit = T::MinProfitPercentage::get().mul_floor(otc_amount_in);
+		let min_expected_profit = OrderProfitTarget::<T>::get(otc_id)
+			.or_else(|| MinProfitPerAsset::<T>::get(otc_asset_in))
+			.unwrap_or_else(|| T::MinProfitPercentage::get().mul_floor(otc_amount_in));
 		// if the next condition is not met, tell the binary search algorithm to find higher values
 		// by throwing the error.
 		ensure!(profit >= min_expected_profit, Error::<T>::TradeAmountTooLow);
 		Ok(())
 	}
 
+	/// Ensure that the price of the router trade is at least the limit price of the OTC order
+	/// increased by `MinPriceImprovement`.
+	///
+	/// Both prices are in `asset_in` of the OTC order per `asset_out` of the OTC order. `amount` is paid
+	/// to the OTC order for `otc_amount_out` and the router trade returns `amount_received` for it.
+	fn ensure_price_improvement(amount: Balance, otc_amount_out: Balance, amount_received: Balance) -> DispatchResult {
+		// The router trade is disabled in the benchmarks, so the price can't be checked.
+		if cfg!(feature = "runtime-benchmarks") {
+			return Ok(());
+		}
+
+		let limit_price = FixedU128::checked_from_rational(amount, otc_amount_out).ok_or(ArithmeticError::Overflow)?;
+		let execution_price =
+			FixedU128::checked_from_rational(amount_received, otc_amount_out).ok_or(ArithmeticError::Overflow)?;
+		let min_price = limit_price
+			.checked_mul(&FixedU128::one().saturating_add(T::MinPriceImprovement::get().into()))
+			.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(execution_price >= min_price, Error::<T>::InsufficientImprovement);
+		Ok(())
+	}
+
+	/// Maximum amount the OTC order can be filled with, so that the router trade doesn't buy more than
+	/// `MaxPoolDrain` of the liquidity of `asset_in` of the OTC order from the last pool of the route.
+	fn max_safe_fill(
+		otc: &Order<T::AccountId, T::AssetId>,
+		route: &[Trade<AssetIdOf<T>>],
+	) -> Result<Balance, DispatchError> {
+		let last_trade = route.last().ok_or(Error::<T>::AssetMismatch)?;
+		let liquidity = T::AMM::get_liquidity_depth(last_trade.pool, last_trade.asset_out, last_trade.asset_in)
+			.map_err(|_| Error::<T>::LiquidityNotAvailable)?;
+
+		Ok(T::MaxPoolDrain::get().mul_floor(liquidity).min(otc.amount_in))
+	}
+
+	/// Weight of the `settle_otc_order` extrinsic.
+	pub fn settle_otc_order_weight(route: &[Trade<AssetIdOf<T>>]) -> Weight {
+		let oracle_check_weight = if T::MaxOracleDeviation::get().is_some() {
+			// route of the oracle price, oracle entry of each trade and the spot price of the route
+			<T as Config>::RouterWeightInfo::get_route_weight()
+				.saturating_add(T::DbWeight::get().reads(route.len() as u64))
+				.saturating_add(<T as Config>::RouterWeightInfo::calculate_spot_price_with_fee_weight(
+					route,
+				))
+		} else {
+			Weight::zero()
+		};
+
+		<T as Config>::WeightInfo::settle_otc_order()
+			.saturating_add(oracle_check_weight)
+			// liquidity of the last pool of the route
+			.saturating_add(T::DbWeight::get().reads(1))
+			// allowed pools of the route
+			.saturating_add(T::DbWeight::get().reads(route.len() as u64))
+			.saturating_add(<T as Config>::RouterWeightInfo::sell_weight(route))
+			.saturating_add(<T as Config>::RouterWeightInfo::get_route_weight())
+			.saturating_add(
+				route
+					.last()
+					.map(|trade| Self::profit_conversion_weight(trade.asset_out))
+					.unwrap_or_default(),
+			)
+			.saturating_add(Self::profit_distribution_weight())
+			.saturating_add(<T as Config>::RouterWeightInfo::calculate_spot_price_with_fee_weight(
+				route,
+			))
+			.saturating_add(
+				<T as pallet_otc::Config>::WeightInfo::fill_order()
+					.max(<T as pallet_otc::Config>::WeightInfo::partial_fill_order()),
+			)
+	}
+
+	/// Ensure that the profit covers the transaction fee of the `settle_otc_order` extrinsic.
+	fn ensure_net_profit(asset_id: AssetIdOf<T>, profit: Balance, route: &[Trade<AssetIdOf<T>>]) -> DispatchResult {
+		let fee = T::WeightToFee::weight_to_fee(&Self::settle_otc_order_weight(route));
+
+		let fee_in_asset = Self::native_price(asset_id)?
+			.reciprocal()
+			.and_then(|p| p.checked_mul_int(fee))
+			.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(profit >= fee_in_asset, Error::<T>::NotNetProfitable);
+		Ok(())
+	}
+
+	/// Amount of the native asset for one unit of `asset_id`, given by the spot price of the route
+	/// between the native asset and `asset_id`.
+	fn native_price(asset_id: AssetIdOf<T>) -> Result<FixedU128, DispatchError> {
+		let native_asset_id = T::NativeAssetId::get();
+		if asset_id == native_asset_id {
+			return Ok(FixedU128::one());
+		}
+
+		let route = T::Router::get_route(AssetPair {
+			asset_in: native_asset_id,
+			asset_out: asset_id,
+		});
+		Ok(T::Router::spot_price_with_fee(&route).ok_or(Error::<T>::PriceNotAvailable)?)
+	}
+
+	/// Profit of the settlements in the current block, valued in the native asset.
+	fn block_profit() -> Balance {
+		match BlockProfit::<T>::get() {
+			Some((block_number, profit)) if block_number == frame_system::Pallet::<T>::block_number() => profit,
+			_ => 0,
+		}
+	}
+
+	/// Ensure that the profit of the settlements in the current block is below `MaxProfitPerBlock`.
+	fn ensure_block_profit_available() -> DispatchResult {
+		ensure!(
+			Self::block_profit() < T::MaxProfitPerBlock::get(),
+			Error::<T>::BlockProfitCapReached
+		);
+		Ok(())
+	}
+
+	/// Add the profit of a settlement, valued in the native asset, to the profit of the current block.
+	/// Nothing is tracked if `MaxProfitPerBlock` is disabled.
+	fn accrue_block_profit(asset_id: AssetIdOf<T>, profit: Balance) -> DispatchResult {
+		if T::MaxProfitPerBlock::get() == Balance::MAX {
+			return Ok(());
+		}
+
+		let profit_in_native = Self::native_price(asset_id)?
+			.checked_mul_int(profit)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		BlockProfit::<T>::put((
+			frame_system::Pallet::<T>::block_number(),
+			Self::block_profit().saturating_add(profit_in_native),
+		));
+		Ok(())
+	}
+
+	/// Ensure that the spot price of the route doesn't deviate from the oracle price by more than
+	/// `MaxOracleDeviation`. The check is skipped if the oracle price is not available.
+	fn ensure_oracle_price(
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		route: &[Trade<AssetIdOf<T>>],
+	) -> DispatchResult {
+		let Some(max_deviation) = T::MaxOracleDeviation::get() else {
+			return Ok(());
+		};
+		let Some(oracle_price) = T::PriceOracle::get_price(asset_in, asset_out)
+			.and_then(|price| FixedU128::checked_from_rational(price.n, price.d))
+		else {
+			return Ok(());
+		};
+
+		let spot_price = T::Router::spot_price_with_fee(route).ok_or(Error::<T>::PriceNotAvailable)?;
+		let price_diff = if spot_price > oracle_price {
+			spot_price.saturating_sub(oracle_price)
+		} else {
+			oracle_price.saturating_sub(spot_price)
+		};
+
+		let max_price_diff = oracle_price
+			.checked_mul(&FixedU128::from(max_deviation))
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(price_diff <= max_price_diff, Error::<T>::OracleDeviation);
+		Ok(())
+	}
+
+	/// Ensure that the amount reserved by the OTC order owner covers the fill of `amount_in`.
+	fn ensure_maker_funded(otc: &Order<T::AccountId, T::AssetId>, amount_in: Balance) -> DispatchResult {
+		let amount_out = if amount_in == otc.amount_in {
+			otc.amount_out
+		} else {
+			multiply_by_rational_with_rounding(otc.amount_out, amount_in, otc.amount_in, Rounding::Down)
+				.ok_or(ArithmeticError::Overflow)?
+		};
+
+		let reserved = <T as pallet_otc::Config>::Currency::reserved_balance_named(
+			&pallet_otc::NAMED_RESERVE_ID,
+			otc.asset_out,
+			&otc.owner,
+		);
+		ensure!(reserved >= amount_out, Error::<T>::MakerUnderfunded);
+		Ok(())
+	}
+
 	/// Because asset_in in a OTC order becomes asset_out in a router trade, we name
 	/// this asset just asset_a to make it less confusing.
 	///
@@ -288,26 +1351,36 @@ impl<T: Config> Pallet<T> {
 	/// opportunity is not closed after the trade and if there is no profit after the trade.
 	/// If the OTC order is partially fillable and `is_execution` is set to `true`, arbs are allowed to be partially closed.
 	/// If the OTC order is not partially fillable, fails only if there is no profit after the trade.
+	/// If `ensure_net_profitable` is set to `true`, fails if the profit doesn't cover the transaction fee of the extrinsic.
 	///
 	/// Parameters:
 	/// - `otc_id`: ID of the OTC order with existing arbitrage opportunity.
 	/// - `amount`: Amount necessary to close the arb.
 	/// - `route`: The route we trade against. Required for the fee calculation.
 	/// - `is_execution`: When enabled, test for the price precision is disabled.
+	/// - `ensure_net_profitable`: When enabled, the profit needs to cover the transaction fee.
+	/// - `validate_route`: When enabled, the route needs to match the route provided by the router.
+	/// - `keeper`: Account that receives `KeeperBounty` share of the profit.
+	///
+	/// Returns the profit of the settlement in the asset in of the OTC order and the bounty paid to the keeper.
 	#[transactional]
 	pub fn settle_otc(
 		otc_id: OrderId,
 		amount: Balance,
 		route: Vec<Trade<AssetIdOf<T>>>,
 		is_execution: bool,
-	) -> DispatchResult {
+		ensure_net_profitable: bool,
+		validate_route: bool,
+		keeper: Option<T::AccountId>,
+	) -> Result<(Balance, Balance), DispatchError> {
 		log::debug!(
 			target: "offchain_worker::settle_otc",
 			"calling settle_otc(): otc_id: {:?} amount: {:?} route: {:?}", otc_id, amount, route);
 
 		let pallet_acc = Self::account_id();
 
-		let otc = <pallet_otc::Orders<T>>::get(otc_id).ok_or(Error::<T>::OrderNotFound)?;
+		let otc = Self::ensure_settleable(otc_id, keeper.as_ref())?;
+		Self::ensure_block_profit_available()?;
 		let (asset_a, asset_b) = (otc.asset_in, otc.asset_out);
 
 		if !otc.partially_fillable {
@@ -315,15 +1388,33 @@ impl<T: Config> Pallet<T> {
 			ensure!(otc.amount_in == amount, Error::<T>::NotPartiallyFillable);
 		}
 
+		// the router trade sells asset_b received from the OTC order and buys back asset_a
 		ensure!(
-			route
-				== T::Router::get_route(AssetPair {
-					asset_in: asset_b,
-					asset_out: asset_a,
-				}),
-			Error::<T>::InvalidRoute
+			route.first().map(|trade| trade.asset_in) == Some(asset_b)
+				&& route.last().map(|trade| trade.asset_out) == Some(asset_a),
+			Error::<T>::AssetMismatch
 		);
 
+		Self::ensure_pools_allowed(&route)?;
+
+		let max_fill = Self::max_safe_fill(&otc, &route)?;
+		ensure!(amount <= max_fill, Error::<T>::PoolDrainLimitExceeded);
+
+		Self::ensure_maker_funded(&otc, amount)?;
+
+		if validate_route {
+			ensure!(
+				route
+					== T::Router::get_route(AssetPair {
+						asset_in: asset_b,
+						asset_out: asset_a,
+					}),
+				Error::<T>::InvalidRoute
+			);
+		}
+
+		Self::ensure_oracle_price(asset_b, asset_a, &route)?;
+
 		// get initial account balances
 		let asset_a_balance_before = <T as Config>::Currency::balance(asset_a, &pallet_acc);
 		let asset_b_balance_before = <T as Config>::Currency::balance(asset_b, &pallet_acc);
@@ -409,15 +1500,25 @@ impl<T: Config> Pallet<T> {
 			.and_then(|value| value.checked_sub(amount))
 			.ok_or(ArithmeticError::Overflow)?;
 
-		Self::ensure_min_profit(otc.amount_in, profit)?;
+		Self::ensure_min_profit(otc_id, otc.asset_in, otc.amount_in, profit)?;
 
-		<T as Config>::Currency::transfer(
-			asset_a,
-			&pallet_acc,
-			&T::ProfitReceiver::get(),
-			profit,
-			Preservation::Expendable,
-		)?;
+		if is_execution {
+			Self::ensure_price_improvement(amount, otc_amount_out, amount.saturating_add(profit))?;
+		}
+
+		if ensure_net_profitable {
+			Self::ensure_net_profit(asset_a, profit, &route)?;
+		}
+
+		Self::accrue_block_profit(asset_a, profit)?;
+
+		if let Some(keeper) = &keeper {
+			Self::record_keeper_settlement(otc_id, keeper);
+		}
+
+		let bounty = Self::pay_keeper_bounty(asset_a, profit, keeper)?;
+
+		Self::transfer_profit(asset_a, profit.saturating_sub(bounty))?;
 
 		<T as Config>::Currency::burn_from(asset_a, &pallet_acc, amount, Precision::Exact, Fortitude::Force)?;
 
@@ -428,19 +1529,230 @@ impl<T: Config> Pallet<T> {
 			asset_a_balance_after == asset_a_balance_before,
 			Error::<T>::BalanceInconsistency
 		);
+		// the whole amount out of the OTC is sold by the router, so no leftover of the offered asset is possible
 		ensure!(
 			asset_b_balance_after == asset_b_balance_before,
 			Error::<T>::BalanceInconsistency
 		);
 
+		OrderReservations::<T>::remove(otc_id);
+		if !<pallet_otc::Orders<T>>::contains_key(otc_id) {
+			OrderProfitTarget::<T>::remove(otc_id);
+		}
+
 		Self::deposit_event(Event::Executed {
 			asset_id: asset_a,
 			profit,
+			bounty,
+		});
+
+		if max_fill < otc.amount_in {
+			Self::deposit_event(Event::PartiallySettled {
+				otc_id,
+				amount,
+				unfilled: otc.amount_in.saturating_sub(amount),
+			});
+		}
+
+		Ok((profit, bounty))
+	}
+
+	/// Fill both OTC orders of the pair with the pallet account and distribute the surplus of both assets.
+	/// The asset in of `otc_a` is minted to fill `otc_a` and burnt once `order_b` is filled.
+	fn settle_pair(
+		order_a: OrderId,
+		otc_a: &Order<T::AccountId, T::AssetId>,
+		order_b: OrderId,
+		keeper: T::AccountId,
+	) -> DispatchResult {
+		let pallet_acc = Self::account_id();
+		let (asset_a, asset_b) = (otc_a.asset_in, otc_a.asset_out);
+
+		let asset_a_balance_before = <T as Config>::Currency::balance(asset_a, &pallet_acc);
+		let asset_b_balance_before = <T as Config>::Currency::balance(asset_b, &pallet_acc);
+
+		<T as Config>::Currency::mint_into(asset_a, &pallet_acc, otc_a.amount_in)?;
+
+		pallet_otc::Pallet::<T>::fill_order(RawOrigin::Signed(pallet_acc.clone()).into(), order_a)?;
+		pallet_otc::Pallet::<T>::fill_order(RawOrigin::Signed(pallet_acc.clone()).into(), order_b)?;
+
+		<T as Config>::Currency::burn_from(
+			asset_a,
+			&pallet_acc,
+			otc_a.amount_in,
+			Precision::Exact,
+			Fortitude::Force,
+		)?;
+
+		for (asset_id, balance_before) in [(asset_a, asset_a_balance_before), (asset_b, asset_b_balance_before)] {
+			let profit = <T as Config>::Currency::balance(asset_id, &pallet_acc)
+				.checked_sub(balance_before)
+				.ok_or(Error::<T>::BalanceInconsistency)?;
+			if profit == 0 {
+				continue;
+			}
+
+			Self::accrue_block_profit(asset_id, profit)?;
+
+			let bounty = Self::pay_keeper_bounty(asset_id, profit, Some(keeper.clone()))?;
+
+			Self::transfer_profit(asset_id, profit.saturating_sub(bounty))?;
+
+			Self::deposit_event(Event::Executed {
+				asset_id,
+				profit,
+				bounty,
+			});
+		}
+
+		ensure!(
+			<T as Config>::Currency::balance(asset_a, &pallet_acc) == asset_a_balance_before,
+			Error::<T>::BalanceInconsistency
+		);
+		ensure!(
+			<T as Config>::Currency::balance(asset_b, &pallet_acc) == asset_b_balance_before,
+			Error::<T>::BalanceInconsistency
+		);
+
+		Ok(())
+	}
+
+	/// Record the keeper of the settlement of the OTC order and count the settlement to the keeper.
+	fn record_keeper_settlement(otc_id: OrderId, keeper: &T::AccountId) {
+		SettledBy::<T>::insert(otc_id, keeper);
+		KeeperSettlements::<T>::mutate(keeper, |count| *count = count.saturating_add(1));
+	}
+
+	/// Transfer `KeeperBounty` share of the profit to the keeper and return the amount of the bounty.
+	/// The bounty is not paid if the keeper can't receive it, e.g. when it's below the existential deposit.
+	fn pay_keeper_bounty(
+		asset_id: AssetIdOf<T>,
+		profit: Balance,
+		keeper: Option<T::AccountId>,
+	) -> Result<Balance, DispatchError> {
+		let Some(keeper) = keeper else {
+			return Ok(0);
+		};
+
+		let bounty = T::KeeperBounty::get().mul_floor(profit);
+		if bounty == 0
+			|| <T as Config>::Currency::can_deposit(asset_id, &keeper, bounty, Provenance::Extant)
+				!= DepositConsequence::Success
+		{
+			return Ok(0);
+		}
+
+		<T as Config>::Currency::transfer(asset_id, &Self::account_id(), &keeper, bounty, Preservation::Expendable)?;
+
+		Ok(bounty)
+	}
+
+	/// Transfer the profit to the beneficiaries of the profit.
+	/// If `ConvertProfitToNative` is set, profit in an asset other than the native asset is sold for the native asset
+	/// first and the amount received is transferred instead. Profit in the native asset is transferred as it is.
+	fn transfer_profit(asset_id: AssetIdOf<T>, profit: Balance) -> DispatchResult {
+		let pallet_acc = Self::account_id();
+		let native_asset_id = T::NativeAssetId::get();
+
+		if profit == 0 || asset_id == native_asset_id || !T::ConvertProfitToNative::get() {
+			return Self::distribute_profit(asset_id, profit);
+		}
+
+		let native_balance_before = <T as Config>::Currency::balance(native_asset_id, &pallet_acc);
+		let route = T::Router::get_route(AssetPair {
+			asset_in: asset_id,
+			asset_out: native_asset_id,
 		});
+		T::Router::sell(
+			RawOrigin::Signed(pallet_acc.clone()).into(),
+			asset_id,
+			native_asset_id,
+			profit,
+			1,
+			route,
+		)?;
+		let converted_profit =
+			<T as Config>::Currency::balance(native_asset_id, &pallet_acc).saturating_sub(native_balance_before);
+
+		Self::distribute_profit(native_asset_id, converted_profit)
+	}
+
+	/// Split the profit between the beneficiaries of `ProfitSplit` by their shares.
+	/// The last beneficiary receives the rest left after rounding.
+	/// If `ProfitSplit` is empty, all profit is transferred to `ProfitReceiver`.
+	fn distribute_profit(asset_id: AssetIdOf<T>, profit: Balance) -> DispatchResult {
+		let pallet_acc = Self::account_id();
+		let split = ProfitSplit::<T>::get();
+
+		let Some(((last_beneficiary, _), beneficiaries)) = split.split_last() else {
+			return <T as Config>::Currency::transfer(
+				asset_id,
+				&pallet_acc,
+				&T::ProfitReceiver::get(),
+				profit,
+				Preservation::Expendable,
+			)
+			.map(|_| ());
+		};
+
+		let mut remaining = profit;
+		for (beneficiary, share) in beneficiaries {
+			let amount = share.mul_floor(profit);
+			<T as Config>::Currency::transfer(asset_id, &pallet_acc, beneficiary, amount, Preservation::Expendable)?;
+			remaining = remaining.saturating_sub(amount);
+		}
 
+		<T as Config>::Currency::transfer(
+			asset_id,
+			&pallet_acc,
+			last_beneficiary,
+			remaining,
+			Preservation::Expendable,
+		)?;
 		Ok(())
 	}
 
+	/// Weight of the transfers of the profit in one asset to the beneficiaries in `distribute_profit`.
+	fn profit_distribution_weight() -> Weight {
+		// profit split and account of each beneficiary
+		T::DbWeight::get().reads(1).saturating_add(
+			T::DbWeight::get()
+				.reads_writes(1, 1)
+				.saturating_mul(T::MaxProfitBeneficiaries::get() as u64),
+		)
+	}
+
+	/// Weight of the conversion of the profit in `asset_id` to the native asset in `transfer_profit`.
+	fn profit_conversion_weight(asset_id: AssetIdOf<T>) -> Weight {
+		let native_asset_id = T::NativeAssetId::get();
+		if asset_id == native_asset_id || !T::ConvertProfitToNative::get() {
+			return Weight::zero();
+		}
+
+		let route = T::Router::get_route(AssetPair {
+			asset_in: asset_id,
+			asset_out: native_asset_id,
+		});
+		<T as Config>::RouterWeightInfo::get_route_weight()
+			.saturating_add(<T as Config>::RouterWeightInfo::sell_weight(&route))
+	}
+
+	/// Weight of the `settle_otc_pair` extrinsic.
+	pub fn settle_otc_pair_weight(order_a: OrderId, order_b: OrderId) -> Weight {
+		[order_a, order_b]
+			.into_iter()
+			.filter_map(<pallet_otc::Orders<T>>::get)
+			.fold(
+				<T as Config>::WeightInfo::settle_otc_pair()
+					.saturating_add(<T as pallet_otc::Config>::WeightInfo::fill_order().saturating_mul(2)),
+				|weight, otc| {
+					weight
+						.saturating_add(Self::profit_conversion_weight(otc.asset_in))
+						.saturating_add(Self::profit_distribution_weight())
+				},
+			)
+	}
+
 	/// Store the latest block number in the offchain storage.
 	/// Returns `true` if `block_number` is newer than the block number stored in the storage.
 	fn try_update_last_block_storage(block_number: BlockNumberFor<T>) -> bool {
@@ -459,11 +1771,24 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Sort open OTCs orders and save a list in the offchain storage.
+	/// The list is not sorted again while a pass over it is in progress, so the scan cursor keeps pointing into it.
 	fn sort_otcs(block_number: BlockNumberFor<T>) {
 		log::debug!(
 			target: "offchain_worker::sort_otcs",
 			"sort_otcs()");
 
+		if StorageValueRef::persistent(OFFCHAIN_WORKER_SCAN_CURSOR)
+			.get::<u32>()
+			.ok()
+			.flatten()
+			.is_some()
+		{
+			log::debug!(
+				target: "offchain_worker::sort_otcs",
+				"scan in progress, keeping the sorted list");
+			return;
+		}
+
 		// acquire offchain worker lock.
 		let lock_expiration = Duration::from_millis(LOCK_TIMEOUT_EXPIRATION);
 		let mut lock = StorageLock::<'_, Time>::with_deadline(SORTED_ORDERS_LOCK, lock_expiration);
@@ -512,7 +1837,10 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Iterate over sorted list of OTCs and try to find arbitrage opportunities.
-	fn settle_otcs() {
+	/// At most `MaxOrdersScannedPerBlock` OTCs are scanned, starting at the cursor stored in the offchain storage.
+	/// The cursor wraps around to the beginning of the list after a full pass.
+	/// The list can contain OTCs that were filled or cancelled since it was sorted, these are skipped.
+	fn settle_otcs() -> SettlementScanSummary {
 		log::debug!(
 			target: "offchain_worker::settle_otcs",
 			"settle OTCs");
@@ -523,12 +1851,82 @@ impl<T: Config> Pallet<T> {
 			.unwrap_or_default()
 			.unwrap_or_default();
 
-		for otc_id in sorted_otcs.iter() {
+		let mut cursor_storage = StorageValueRef::persistent(OFFCHAIN_WORKER_SCAN_CURSOR);
+		let cursor = cursor_storage.get::<u32>().unwrap_or_default().unwrap_or_default() as usize;
+		// the list is only sorted again after a full pass, but the offchain storage can be cleared by the node
+		let start = if cursor < sorted_otcs.len() { cursor } else { 0 };
+		let end = start
+			.saturating_add(T::MaxOrdersScannedPerBlock::get() as usize)
+			.min(sorted_otcs.len());
+
+		let mut failure_counts_storage = StorageValueRef::persistent(OFFCHAIN_WORKER_FAILURE_COUNTS);
+		let mut failure_counts = failure_counts_storage
+			.get::<FailureCountsStorageType>()
+			.unwrap_or_default()
+			.unwrap_or_default();
+		// OTCs that are no longer in the list were filled, cancelled or lost the arbitrage opportunity
+		failure_counts.retain(|otc_id, _| sorted_otcs.contains(otc_id));
+
+		let mut summary = SettlementScanSummary::default();
+
+		for otc_id in sorted_otcs[start..end].iter() {
+			summary.scanned.saturating_inc();
+
+			if let Some((_, skip)) = failure_counts.get_mut(otc_id).filter(|(_, skip)| *skip > 0) {
+				log::debug!(
+				target: "offchain_worker::settle_otcs",
+					"backing off after failures, skipping OTC: {:?}", otc_id);
+				*skip = skip.saturating_sub(1);
+				summary.skipped.saturating_inc();
+				continue;
+			}
+
 			log::debug!(
 			target: "offchain_worker::settle_otcs",
 				"test OTC id {:?} ", otc_id);
 
-			let otc = <pallet_otc::Orders<T>>::get(otc_id).unwrap();
+			let Some(otc) = <pallet_otc::Orders<T>>::get(otc_id) else {
+				log::debug!(
+				target: "offchain_worker::settle_otcs",
+					"order closed, skipping OTC: {:?}", otc_id);
+				summary.skipped.saturating_inc();
+				continue;
+			};
+
+			if Self::ensure_assets_not_excluded(&otc).is_err() {
+				log::debug!(
+				target: "offchain_worker::settle_otcs",
+					"asset excluded, skipping OTC: {:?}", otc_id);
+				summary.skipped.saturating_inc();
+				continue;
+			}
+
+			if Self::ensure_order_aged(*otc_id).is_err() {
+				log::debug!(
+				target: "offchain_worker::settle_otcs",
+					"order too young, skipping OTC: {:?}", otc_id);
+				summary.skipped.saturating_inc();
+				continue;
+			}
+
+			if Self::is_order_expired(*otc_id) {
+				log::debug!(
+				target: "offchain_worker::settle_otcs",
+					"order expired, skipping OTC: {:?}", otc_id);
+				summary.skipped.saturating_inc();
+				continue;
+			}
+
+			// don't waste the work on the binary search if the order can't be filled
+			if Self::ensure_maker_funded(&otc, otc.amount_in).is_err() {
+				log::debug!(
+				target: "offchain_worker::settle_otcs",
+					"maker underfunded, skipping OTC: {:?}", otc_id);
+				Self::record_failure(&mut failure_counts, *otc_id);
+				summary.skipped.saturating_inc();
+				continue;
+			}
+
 			let route = T::Router::get_route(AssetPair {
 				asset_in: otc.asset_out,
 				asset_out: otc.asset_in,
@@ -541,14 +1939,207 @@ impl<T: Config> Pallet<T> {
 						otc_id,
 						sell_amt
 					);
+				// Unsigned transactions don't pay the fee.
 				let call = Call::settle_otc_order {
 					otc_id: *otc_id,
 					amount: sell_amt,
 					route,
+					ensure_net_profitable: false,
+					valid_until: None,
 				};
 				let _ = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
+				failure_counts.remove(otc_id);
+				summary.settled.saturating_inc();
+			} else {
+				Self::record_failure(&mut failure_counts, *otc_id);
+				summary.skipped.saturating_inc();
 			}
 		}
+
+		if failure_counts.is_empty() {
+			failure_counts_storage.clear();
+		} else {
+			failure_counts_storage.set(&failure_counts);
+		}
+
+		log::info!(
+			target: "offchain_worker::settle_otcs",
+			"settlement scan completed: scanned {:?}, settled {:?}, skipped {:?}",
+			summary.scanned,
+			summary.settled,
+			summary.skipped
+		);
+		StorageValueRef::persistent(OFFCHAIN_WORKER_SCAN_SUMMARY).set(&summary);
+
+		// the cursor is cleared after a full pass, so the next run starts from the beginning of the list
+		if end < sorted_otcs.len() {
+			cursor_storage.set(&(end as u32));
+		} else {
+			cursor_storage.clear();
+		}
+
+		summary
+	}
+
+	/// Return ID of the OTC order where the next run of the offchain worker continues scanning.
+	/// Returns `None` if the next run starts a new pass over the sorted list of OTCs.
+	///
+	/// The position is kept in the offchain storage, which is only accessible in the offchain worker.
+	/// Calling it anywhere else panics.
+	pub fn scan_position() -> Option<OrderId> {
+		let cursor = StorageValueRef::persistent(OFFCHAIN_WORKER_SCAN_CURSOR)
+			.get::<u32>()
+			.ok()
+			.flatten()?;
+		let sorted_otcs = StorageValueRef::persistent(OFFCHAIN_WORKER_DATA)
+			.get::<Vec<SortedOtcsStorageType>>()
+			.ok()
+			.flatten()?;
+
+		sorted_otcs.get(cursor as usize).copied()
+	}
+
+	/// Simulate the settlement of `amount` of the OTC order against the route provided by the router.
+	/// All state changes of the simulation are discarded.
+	/// Returns `None` if the settlement would fail or the spot price of the route is not available.
+	pub fn preview_settlement(otc_id: OrderId, amount: Balance) -> Option<SettlementPreview<AssetIdOf<T>>> {
+		let otc = <pallet_otc::Orders<T>>::get(otc_id)?;
+		let route = T::Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+		let pool_price_before = T::Router::spot_price_with_fee(&route)?;
+
+		with_transaction(|| {
+			let preview = Self::settle_otc(otc_id, amount, route.clone(), true, false, false, None)
+				.ok()
+				.and_then(|(profit, bounty)| {
+					Some(SettlementPreview {
+						profit: profit.saturating_sub(bounty),
+						profit_asset: otc.asset_in,
+						pool_price_before,
+						pool_price_after: T::Router::spot_price_with_fee(&route)?,
+					})
+				});
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(preview))
+		})
+		.ok()
+		.flatten()
+	}
+
+	/// Return at most `limit` OTC orders that can be settled and the estimated profit of their settlement.
+	///
+	/// The trade amount is found the same way as in the offchain worker and the settlement is simulated
+	/// with `preview_settlement`, so the state is not changed.
+	pub fn settleable_orders(limit: u32) -> Vec<(OrderId, Balance)> {
+		let mut orders = Vec::new();
+		for (otc_id, otc) in <pallet_otc::Orders<T>>::iter() {
+			if orders.len() >= limit as usize {
+				break;
+			}
+
+			let route = T::Router::get_route(AssetPair {
+				asset_in: otc.asset_out,
+				asset_out: otc.asset_in,
+			});
+			let maybe_amount = with_transaction(|| {
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>(Self::try_find_trade_amount(
+					otc_id, &otc, &route,
+				)))
+			})
+			.ok()
+			.flatten();
+
+			if let Some(preview) = maybe_amount.and_then(|amount| Self::preview_settlement(otc_id, amount)) {
+				orders.push((otc_id, preview.profit));
+			}
+		}
+		orders
+	}
+
+	/// Simulate the settlements of the OTC orders one after another and return the outcome of each settlement.
+	///
+	/// The trade amount is found the same way as in the offchain worker. Each settlement sees the state changes
+	/// of the previous settlements in the batch. All state changes are discarded at the end of the simulation.
+	pub fn simulate_batch(order_ids: Vec<OrderId>) -> Vec<(OrderId, SettlementOutcome)> {
+		with_transaction(|| {
+			let outcomes = order_ids
+				.into_iter()
+				.map(|otc_id| (otc_id, Self::simulate_settlement(otc_id)))
+				.collect();
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(outcomes))
+		})
+		.unwrap_or_default()
+	}
+
+	/// Settle the OTC order the same way as the offchain worker and return the outcome of the settlement.
+	/// State changes of a successful settlement are kept.
+	fn simulate_settlement(otc_id: OrderId) -> SettlementOutcome {
+		let otc = match Self::ensure_settleable(otc_id, None)
+			.and_then(|otc| Self::ensure_block_profit_available().map(|_| otc))
+		{
+			Ok(otc) => otc,
+			Err(error) => return SettlementOutcome::Skipped(Self::skip_reason(error)),
+		};
+
+		let route = T::Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+		let maybe_amount = with_transaction(|| {
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(Self::try_find_trade_amount(
+				otc_id, &otc, &route,
+			)))
+		})
+		.ok()
+		.flatten();
+		let Some(amount) = maybe_amount else {
+			return SettlementOutcome::Skipped(SkipReason::NoProfitableAmount);
+		};
+
+		match Self::settle_otc(otc_id, amount, route, true, false, true, None) {
+			Ok((profit, bounty)) => SettlementOutcome::Settled(profit.saturating_sub(bounty)),
+			Err(error) => SettlementOutcome::Skipped(Self::skip_reason(error)),
+		}
+	}
+
+	/// Map the error of a settlement to the reason why the settlement was skipped.
+	fn skip_reason(error: DispatchError) -> SkipReason {
+		[
+			(Error::<T>::OrderNotFound, SkipReason::OrderNotFound),
+			(Error::<T>::AssetExcluded, SkipReason::AssetExcluded),
+			(Error::<T>::Reserved, SkipReason::Reserved),
+			(Error::<T>::OrderTooYoung, SkipReason::OrderTooYoung),
+			(Error::<T>::BlockProfitCapReached, SkipReason::BlockProfitCapReached),
+			(Error::<T>::OrderExpired, SkipReason::OrderExpired),
+		]
+		.into_iter()
+		.find_map(|(e, reason)| (error == e.into()).then_some(reason))
+		.unwrap_or(SkipReason::SettlementFailed)
+	}
+
+	/// Return the number of consecutive failed settlements of the OTC order in the offchain worker.
+	///
+	/// The count is kept in the offchain storage under `OFFCHAIN_WORKER_FAILURE_COUNTS`, which is only accessible
+	/// in the offchain worker. Calling it anywhere else panics.
+	pub fn failure_count(otc_id: OrderId) -> u32 {
+		StorageValueRef::persistent(OFFCHAIN_WORKER_FAILURE_COUNTS)
+			.get::<FailureCountsStorageType>()
+			.ok()
+			.flatten()
+			.and_then(|failure_counts| failure_counts.get(&otc_id).map(|(failures, _)| *failures))
+			.unwrap_or_default()
+	}
+
+	/// Increment the number of consecutive failures of the OTC order and set the number of scans to skip the order.
+	/// After `FailuresBeforeBackoff` failures, the number of skipped scans doubles with every failure.
+	fn record_failure(failure_counts: &mut FailureCountsStorageType, otc_id: OrderId) {
+		let (failures, skip) = failure_counts.entry(otc_id).or_default();
+		*failures = failures.saturating_add(1);
+		*skip = match failures.checked_sub(T::FailuresBeforeBackoff::get()) {
+			Some(exponent) => 2u32.saturating_pow(exponent).min(T::MaxBackoffScans::get()),
+			None => 0,
+		};
 	}
 
 	/// Try to find the correct amount to close the arbitrage opportunity.
@@ -558,7 +2149,12 @@ impl<T: Config> Pallet<T> {
 		route: &[Trade<AssetIdOf<T>>],
 	) -> Option<Balance> {
 		// use binary search to determine the correct sell amount
-		let mut sell_amt = otc.amount_in; // start by trying to fill the whole order
+		// start by trying to fill the whole order, or as much of it as `MaxPoolDrain` allows
+		let mut sell_amt = if otc.partially_fillable {
+			Self::max_safe_fill(otc, route).ok()?
+		} else {
+			otc.amount_in
+		};
 		let mut sell_amt_up = sell_amt;
 		let mut sell_amt_down = T::MinTradingLimit::get();
 
@@ -574,7 +2170,7 @@ impl<T: Config> Pallet<T> {
 			log::debug!(
 			target: "offchain_worker::settle_otcs::binary_search",
 				"\nsell_amt: {:?}\nsell_amt_up: {:?}\nsell_amt_down: {:?}", sell_amt, sell_amt_up, sell_amt_down);
-			match Self::settle_otc(otc_id, sell_amt, route.to_vec(), false) {
+			match Self::settle_otc(otc_id, sell_amt, route.to_vec(), false, false, true, None) {
 				Ok(_) => {
 					log::debug!(
 					target: "offchain_worker::settle_otcs",
@@ -613,7 +2209,7 @@ impl<T: Config> Pallet<T> {
 		}
 		// execute with the latest min value
 		if sell_amt_down != T::MinTradingLimit::get() {
-			match Self::settle_otc(otc_id, sell_amt_down, route.to_vec(), true) {
+			match Self::settle_otc(otc_id, sell_amt_down, route.to_vec(), true, false, true, None) {
 				Ok(_) => Some(sell_amt_down),
 				Err(_) => None,
 			}
@@ -41,6 +41,7 @@ use sp_core::offchain::{
 };
 use sp_core::H256;
 use sp_std::sync::Arc;
+use std::cell::RefCell;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -81,8 +82,19 @@ parameter_types! {
 	pub ExistentialDepositMultiplier: u8 = 5;
 	pub MinProfitLimit: Balance = 10_000_000_000_000;
 	pub PricePrecision: FixedU128 = FixedU128::from_rational(1, 1_000_000);
-	pub MinProfitPercentage: Perbill = Perbill::from_rational(1u32, 100_000_u32); // 0.001%
+	pub MinProfitPercentage: Perbill = MIN_PROFIT_PERCENTAGE.with(|v| *v.borrow());
+	pub MinPriceImprovement: Permill = MIN_PRICE_IMPROVEMENT.with(|v| *v.borrow());
 	pub OtcFee: Permill = Permill::from_percent(1u32);
+	pub KeeperBounty: Permill = KEEPER_BOUNTY.with(|v| *v.borrow());
+	pub MaxOracleDeviation: Option<Permill> = MAX_ORACLE_DEVIATION.with(|v| *v.borrow());
+	pub MaxOrdersScannedPerBlock: u32 = MAX_ORDERS_SCANNED_PER_BLOCK.with(|v| *v.borrow());
+	pub MaxPoolDrain: Permill = MAX_POOL_DRAIN.with(|v| *v.borrow());
+	pub FailuresBeforeBackoff: u32 = FAILURES_BEFORE_BACKOFF.with(|v| *v.borrow());
+	pub MinOrderAge: u64 = MIN_ORDER_AGE.with(|v| *v.borrow());
+	pub OrderTtl: u64 = ORDER_TTL.with(|v| *v.borrow());
+	pub MaxProfitPerBlock: Balance = MAX_PROFIT_PER_BLOCK.with(|v| *v.borrow());
+	pub ConvertProfitToNative: bool = CONVERT_PROFIT_TO_NATIVE.with(|v| *v.borrow());
+	pub FailureBreakerThreshold: u32 = FAILURE_BREAKER_THRESHOLD.with(|v| *v.borrow());
 }
 
 parameter_type_with_key! {
@@ -95,15 +107,128 @@ impl pallet_otc_settlements::Config for Test {
 	type Currency = pallet_currencies::fungibles::FungibleCurrencies<Test>;
 	type RuntimeEvent = RuntimeEvent;
 	type Router = Router;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type ProfitReceiver = TreasuryAccount;
+	type MaxProfitBeneficiaries = ConstU32<3>;
+	type KeeperBounty = KeeperBounty;
+	type PriceOracle = SettlementPriceOracleMock;
+	type MaxOracleDeviation = MaxOracleDeviation;
+	type AMM = Omnipool;
+	type MaxPoolDrain = MaxPoolDrain;
 	type MinProfitPercentage = MinProfitPercentage;
+	type MinPriceImprovement = MinPriceImprovement;
 	type PricePrecision = PricePrecision;
 	type MinTradingLimit = MinTradingLimit;
 	type MaxIterations = ConstU32<40>;
+	type MaxOrdersScannedPerBlock = MaxOrdersScannedPerBlock;
+	type FailuresBeforeBackoff = FailuresBeforeBackoff;
+	type MaxBackoffScans = ConstU32<8>;
+	type FailureBreakerThreshold = FailureBreakerThreshold;
+	type ReservationPeriod = ConstU64<3>;
+	type ReservationCooldown = ConstU64<5>;
+	type MinOrderAge = MinOrderAge;
+	type OrderTtl = OrderTtl;
+	type MaxProfitPerBlock = MaxProfitPerBlock;
+	type NativeAssetId = HDXAssetId;
+	type ConvertProfitToNative = ConvertProfitToNative;
+	type WeightToFee = MockWeightToFee;
 	type WeightInfo = ();
 	type RouterWeightInfo = ();
 }
 
+thread_local! {
+	pub static WEIGHT_TO_FEE_MULTIPLIER: RefCell<Balance> = const { RefCell::new(0) };
+	pub static KEEPER_BOUNTY: RefCell<Permill> = const { RefCell::new(Permill::zero()) };
+	pub static MAX_ORACLE_DEVIATION: RefCell<Option<Permill>> = const { RefCell::new(None) };
+	pub static ORACLE_PRICE: RefCell<Option<EmaPrice>> = const { RefCell::new(None) };
+	pub static MAX_ORDERS_SCANNED_PER_BLOCK: RefCell<u32> = const { RefCell::new(u32::MAX) };
+	pub static MAX_POOL_DRAIN: RefCell<Permill> = const { RefCell::new(Permill::from_percent(100)) };
+	pub static FAILURES_BEFORE_BACKOFF: RefCell<u32> = const { RefCell::new(u32::MAX) };
+	pub static MIN_PROFIT_PERCENTAGE: RefCell<Perbill> = const { RefCell::new(Perbill::from_parts(10_000)) }; // 0.001%
+	pub static MIN_ORDER_AGE: RefCell<u64> = const { RefCell::new(0) };
+	pub static ORDER_TTL: RefCell<u64> = const { RefCell::new(1_000) };
+	pub static MAX_PROFIT_PER_BLOCK: RefCell<Balance> = const { RefCell::new(Balance::MAX) };
+	pub static CONVERT_PROFIT_TO_NATIVE: RefCell<bool> = const { RefCell::new(false) };
+	pub static MIN_PRICE_IMPROVEMENT: RefCell<Permill> = const { RefCell::new(Permill::zero()) };
+	pub static FAILURE_BREAKER_THRESHOLD: RefCell<u32> = const { RefCell::new(0) };
+}
+
+pub struct SettlementPriceOracleMock;
+
+impl hydradx_traits::price::PriceProvider<AssetId> for SettlementPriceOracleMock {
+	type Price = EmaPrice;
+
+	fn get_price(_asset_a: AssetId, _asset_b: AssetId) -> Option<Self::Price> {
+		ORACLE_PRICE.with(|v| *v.borrow())
+	}
+}
+
+pub struct MockWeightToFee;
+
+impl WeightToFee for MockWeightToFee {
+	type Balance = Balance;
+
+	fn weight_to_fee(weight: &Weight) -> Self::Balance {
+		(weight.ref_time() as Balance).saturating_mul(WEIGHT_TO_FEE_MULTIPLIER.with(|v| *v.borrow()))
+	}
+}
+
+pub fn set_weight_to_fee_multiplier(multiplier: Balance) {
+	WEIGHT_TO_FEE_MULTIPLIER.with(|v| *v.borrow_mut() = multiplier);
+}
+
+pub fn set_keeper_bounty(bounty: Permill) {
+	KEEPER_BOUNTY.with(|v| *v.borrow_mut() = bounty);
+}
+
+pub fn set_max_oracle_deviation(deviation: Option<Permill>) {
+	MAX_ORACLE_DEVIATION.with(|v| *v.borrow_mut() = deviation);
+}
+
+pub fn set_oracle_price(price: Option<EmaPrice>) {
+	ORACLE_PRICE.with(|v| *v.borrow_mut() = price);
+}
+
+pub fn set_max_orders_scanned_per_block(max_orders: u32) {
+	MAX_ORDERS_SCANNED_PER_BLOCK.with(|v| *v.borrow_mut() = max_orders);
+}
+
+pub fn set_max_pool_drain(max_drain: Permill) {
+	MAX_POOL_DRAIN.with(|v| *v.borrow_mut() = max_drain);
+}
+
+pub fn set_failures_before_backoff(failures: u32) {
+	FAILURES_BEFORE_BACKOFF.with(|v| *v.borrow_mut() = failures);
+}
+
+pub fn set_min_profit_percentage(percentage: Perbill) {
+	MIN_PROFIT_PERCENTAGE.with(|v| *v.borrow_mut() = percentage);
+}
+
+pub fn set_min_order_age(age: u64) {
+	MIN_ORDER_AGE.with(|v| *v.borrow_mut() = age);
+}
+
+pub fn set_order_ttl(ttl: u64) {
+	ORDER_TTL.with(|v| *v.borrow_mut() = ttl);
+}
+
+pub fn set_max_profit_per_block(max_profit: Balance) {
+	MAX_PROFIT_PER_BLOCK.with(|v| *v.borrow_mut() = max_profit);
+}
+
+pub fn set_convert_profit_to_native(convert: bool) {
+	CONVERT_PROFIT_TO_NATIVE.with(|v| *v.borrow_mut() = convert);
+}
+
+pub fn set_min_price_improvement(improvement: Permill) {
+	MIN_PRICE_IMPROVEMENT.with(|v| *v.borrow_mut() = improvement);
+}
+
+pub fn set_failure_breaker_threshold(threshold: u32) {
+	FAILURE_BREAKER_THRESHOLD.with(|v| *v.borrow_mut() = threshold);
+}
+
 impl pallet_otc::Config for Test {
 	type AssetId = AssetId;
 	type AssetRegistry = AssetRegistry;
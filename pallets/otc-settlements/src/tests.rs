@@ -21,9 +21,10 @@
 
 use super::*;
 pub use crate::mock::*;
-use frame_support::{assert_ok, assert_storage_noop};
+use frame_support::{assert_noop, assert_ok, assert_storage_noop};
+use hydradx_traits::router::PoolType;
 use hydradx_traits::Inspect;
-use orml_traits::MultiCurrency;
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
 
 pub fn expect_events(e: Vec<RuntimeEvent>) {
 	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
@@ -295,6 +296,7 @@ fn existing_arb_opportunity_should_trigger_trade_when_correct_amount_can_be_foun
 		expect_last_events(vec![Event::Executed {
 			asset_id: HDX,
 			profit: 17_736_110_470_326,
+			bounty: 0,
 		}
 		.into()]);
 	});
@@ -351,6 +353,7 @@ fn existing_arb_opportunity_should_trigger_trade_when_partially_fillable_otc_can
 		expect_last_events(vec![Event::Executed {
 			asset_id: HDX,
 			profit: 1_444_117_874_415,
+			bounty: 0,
 		}
 		.into()]);
 	});
@@ -402,6 +405,7 @@ fn existing_arb_opportunity_should_trigger_trade_when_otc_is_not_partially_filla
 		expect_last_events(vec![Event::Executed {
 			asset_id: HDX,
 			profit: 2_732_618_471_117_260,
+			bounty: 0,
 		}
 		.into()]);
 	});
@@ -456,6 +460,7 @@ fn existing_arb_opportunity_of_insufficient_asset_should_trigger_trade() {
 		expect_last_events(vec![Event::Executed {
 			asset_id: BTC,
 			profit: 245_338_363_920_576,
+			bounty: 0,
 		}
 		.into()]);
 	});
@@ -488,11 +493,13 @@ fn multiple_arb_opportunities_should_trigger_trades() {
 			Event::Executed {
 				asset_id: HDX,
 				profit: 17736110470326,
+				bounty: 0,
 			}
 			.into(),
 			Event::Executed {
 				asset_id: DOT,
 				profit: 11860096179879,
+				bounty: 0,
 			}
 			.into(),
 		]);
@@ -565,6 +572,7 @@ fn trade_should_be_triggered_when_arb_opportunity_appears() {
 		expect_last_events(vec![Event::Executed {
 			asset_id: HDX,
 			profit: 5_173_145_606_735,
+			bounty: 0,
 		}
 		.into()]);
 	});
@@ -732,11 +740,50 @@ fn test_offchain_worker_unsigned_transaction_submission() {
 				otc_id: 0,
 				amount: 2_413_749_694_825_193,
 				route,
+				ensure_net_profitable: false,
+				valid_until: None,
 			})
 		);
 	})
 }
 
+#[test]
+fn offchain_worker_unsigned_transaction_should_pass_unsigned_validation() {
+	let (mut ext, pool_state) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		let crate::mock::RuntimeCall::OtcSettlements(call) = tx.call else {
+			panic!("unexpected call submitted by the offchain worker");
+		};
+
+		assert_ok!(
+			<OtcSettlements as sp_runtime::traits::ValidateUnsigned>::validate_unsigned(
+				sp_runtime::transaction_validity::TransactionSource::Local,
+				&call
+			)
+		);
+		assert!(
+			<OtcSettlements as sp_runtime::traits::ValidateUnsigned>::validate_unsigned(
+				sp_runtime::transaction_validity::TransactionSource::External,
+				&call
+			)
+			.is_err()
+		);
+	})
+}
+
 #[test]
 fn test_offchain_worker_signed_transaction_submission() {
 	let (mut ext, _pool_state) = ExtBuilder::default().build();
@@ -762,98 +809,3226 @@ fn test_offchain_worker_signed_transaction_submission() {
 			otc_id,
 			2_413_749_694_825_193,
 			route,
+			false,
+			None,
 		));
 	})
 }
 
-fn place_orders() {
-	assert_ok!(OTC::place_order(
-		RuntimeOrigin::signed(ALICE),
-		HDX,
-		DAI,
-		1_000_000_000,
-		2_000_000_000,
-		true,
-	));
+#[test]
+fn settle_otc_order_should_fail_when_route_assets_are_reversed() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
 
-	assert_ok!(OTC::place_order(
-		RuntimeOrigin::signed(ALICE),
-		HDX,
-		LRNA,
-		1_000_000_000,
-		3_000_000_000,
-		true,
-	));
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		// route in the same direction as the OTC order instead of the opposite one
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_in,
+			asset_out: otc.asset_out,
+		});
 
-	assert_ok!(OTC::place_order(
-		RuntimeOrigin::signed(ALICE),
-		LRNA,
-		HDX,
-		4_000_000_000,
-		3_000_000_000,
-		true,
-	));
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::AssetMismatch
+		);
+	})
+}
 
-	assert_ok!(OTC::place_order(
-		RuntimeOrigin::signed(ALICE),
-		HDX,
-		LRNA,
-		7_000_000_000,
-		3_000_000_000,
-		true,
-	));
+#[test]
+fn settle_otc_order_should_fail_when_route_is_for_different_asset_pair() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
 
-	assert_ok!(OTC::place_order(
-		RuntimeOrigin::signed(ALICE),
-		KSM,
-		DAI,
-		2_000_000_000,
-		3_000_000_000,
-		true,
-	));
+		let otc_id = 0;
+		let route = Router::get_route(AssetPair {
+			asset_in: DAI,
+			asset_out: LRNA,
+		});
 
-	assert_ok!(OTC::place_order(
-		RuntimeOrigin::signed(ALICE),
-		HDX,
-		DAI,
-		2_000_000,
-		3_000_000_000,
-		true,
-	));
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::AssetMismatch
+		);
+	})
+}
 
-	assert_ok!(OTC::place_order(
-		RuntimeOrigin::signed(ALICE),
-		HDX,
-		DAI,
-		2_000_000_000,
-		3_000_000_000_000,
-		true,
-	));
+#[test]
+fn settle_otc_order_should_fail_when_route_is_empty() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
 
-	assert_ok!(OTC::place_order(
-		RuntimeOrigin::signed(ALICE),
-		DAI,
-		DOT,
-		2_000_000_000,
-		3_000_000_000_000,
-		true,
-	));
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				2_413_749_694_825_193,
+				vec![],
+				false,
+				None
+			),
+			Error::<Test>::AssetMismatch
+		);
+	})
+}
 
-	assert_ok!(OTC::place_order(
-		RuntimeOrigin::signed(ALICE),
-		HDX,
-		DOT,
-		9_000_000_000,
-		3_000_000_000,
-		true,
-	));
+#[test]
+fn settle_otc_order_should_fail_when_maker_is_underfunded() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
 
-	assert_ok!(OTC::place_order(
-		RuntimeOrigin::signed(ALICE),
-		DAI,
-		DOT,
-		2_000_000_000,
-		13_000_000_000,
-		true,
-	));
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		// the maker drains the offered asset after placing the order
+		Currencies::unreserve_named(&pallet_otc::NAMED_RESERVE_ID, DAI, &ALICE, 200_000 * ONE);
+		let free_balance = Currencies::free_balance(DAI, &ALICE);
+		assert_ok!(Currencies::transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			DAI,
+			free_balance
+		));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::MakerUnderfunded
+		);
+	})
+}
+
+#[test]
+fn offchain_worker_should_skip_order_when_maker_is_underfunded() {
+	let (mut ext, pool_state) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		// the maker drains the offered asset after placing the order
+		Currencies::unreserve_named(&pallet_otc::NAMED_RESERVE_ID, DAI, &ALICE, 200_000 * ONE);
+		let free_balance = Currencies::free_balance(DAI, &ALICE);
+		assert_ok!(Currencies::transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			DAI,
+			free_balance
+		));
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		assert!(pool_state.read().transactions.is_empty());
+	})
+}
+
+#[test]
+fn settle_otc_order_should_work_when_profit_covers_fee() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_weight_to_fee_multiplier(1);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			true,
+			None,
+		));
+	})
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_profit_does_not_cover_fee() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		// the fee estimated from the weight is higher than the gross profit
+		set_weight_to_fee_multiplier(ONE);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route.clone(),
+				true,
+				None
+			),
+			Error::<Test>::NotNetProfitable
+		);
+
+		// the check is skipped when not requested
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+#[test]
+fn settle_otc_order_with_route_should_work_when_route_is_valid() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let route = vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: DAI,
+			asset_out: HDX,
+		}];
+
+		let treasury_balance_before = Currencies::free_balance(HDX, &TreasuryAccount::get());
+
+		assert_ok!(OtcSettlements::settle_otc_order_with_route(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+		));
+
+		assert!(Currencies::free_balance(HDX, &TreasuryAccount::get()) > treasury_balance_before);
+	})
+}
+
+#[test]
+fn settle_otc_order_with_route_should_fail_when_route_assets_do_not_match_order() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let route = vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: DAI,
+			asset_out: DOT,
+		}];
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order_with_route(RuntimeOrigin::signed(ALICE), 0, 2_413_749_694_825_193, route),
+			Error::<Test>::AssetMismatch
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_with_route_should_fail_when_origin_is_unsigned() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let route = vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: DAI,
+			asset_out: HDX,
+		}];
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order_with_route(RuntimeOrigin::none(), 0, 2_413_749_694_825_193, route),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn set_asset_excluded_should_work_when_origin_is_authority() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OtcSettlements::set_asset_excluded(RuntimeOrigin::root(), DAI, true));
+		assert!(ExcludedAssets::<Test>::contains_key(DAI));
+		expect_events(vec![Event::AssetExclusionSet {
+			asset_id: DAI,
+			excluded: true,
+		}
+		.into()]);
+
+		assert_ok!(OtcSettlements::set_asset_excluded(RuntimeOrigin::root(), DAI, false));
+		assert!(!ExcludedAssets::<Test>::contains_key(DAI));
+	})
+}
+
+#[test]
+fn set_asset_excluded_should_fail_when_origin_is_not_authority() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_noop!(
+			OtcSettlements::set_asset_excluded(RuntimeOrigin::signed(ALICE), DAI, true),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_asset_is_excluded() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+		assert_ok!(OtcSettlements::set_asset_excluded(RuntimeOrigin::root(), DAI, true));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::AssetExcluded.with_weight(<Test as Config>::WeightInfo::settle_otc_order_skipped())
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_work_when_other_asset_is_excluded() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+		assert_ok!(OtcSettlements::set_asset_excluded(RuntimeOrigin::root(), DOT, true));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+#[test]
+fn offchain_worker_should_skip_order_when_asset_is_excluded() {
+	let (mut ext, pool_state) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+		assert_ok!(OtcSettlements::set_asset_excluded(RuntimeOrigin::root(), HDX, true));
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		assert!(pool_state.read().transactions.is_empty());
+	})
+}
+
+#[test]
+fn offchain_worker_should_store_scan_summary_when_order_is_settled() {
+	let (mut ext, pool_state) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		assert_eq!(pool_state.read().transactions.len(), 1);
+
+		let summary = StorageValueRef::persistent(OFFCHAIN_WORKER_SCAN_SUMMARY)
+			.get::<SettlementScanSummary>()
+			.unwrap_or_default()
+			.unwrap_or_default();
+		assert_eq!(
+			summary,
+			SettlementScanSummary {
+				scanned: 1,
+				settled: 1,
+				skipped: 0,
+			}
+		);
+	})
+}
+
+#[test]
+fn offchain_worker_should_store_scan_summary_when_no_order_is_settled() {
+	let (mut ext, pool_state) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		// the maker drains the offered asset, so the order can't be settled profitably
+		Currencies::unreserve_named(&pallet_otc::NAMED_RESERVE_ID, DAI, &ALICE, 200_000 * ONE);
+		let free_balance = Currencies::free_balance(DAI, &ALICE);
+		assert_ok!(Currencies::transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			DAI,
+			free_balance
+		));
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		assert!(pool_state.read().transactions.is_empty());
+
+		let summary = StorageValueRef::persistent(OFFCHAIN_WORKER_SCAN_SUMMARY)
+			.get::<SettlementScanSummary>()
+			.unwrap_or_default()
+			.unwrap_or_default();
+		assert_eq!(
+			summary,
+			SettlementScanSummary {
+				scanned: 1,
+				settled: 0,
+				skipped: 1,
+			}
+		);
+	})
+}
+
+fn place_orders_with_arb_opportunity() {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX, // otc asset_in
+		DAI, // otc asset_out
+		100_000 * ONE,
+		205_000 * ONE,
+		true,
+	));
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX, // otc asset_in
+		DAI, // otc asset_out
+		100_000 * ONE,
+		205_000 * ONE,
+		true,
+	));
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		DOT, // otc asset_in
+		KSM, // otc asset_out
+		100_000 * ONE,
+		102_000 * ONE,
+		true,
+	));
+}
+
+fn scan_summary_and_cursor() -> (SettlementScanSummary, u32) {
+	let summary = StorageValueRef::persistent(OFFCHAIN_WORKER_SCAN_SUMMARY)
+		.get::<SettlementScanSummary>()
+		.unwrap_or_default()
+		.unwrap_or_default();
+	let cursor = StorageValueRef::persistent(OFFCHAIN_WORKER_SCAN_CURSOR)
+		.get::<u32>()
+		.unwrap_or_default()
+		.unwrap_or_default();
+	(summary, cursor)
+}
+
+#[test]
+fn offchain_worker_should_advance_scan_cursor_when_max_orders_scanned_is_reached() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_orders_scanned_per_block(2);
+		place_orders_with_arb_opportunity();
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		let (summary, cursor) = scan_summary_and_cursor();
+		assert_eq!(summary.scanned, 2);
+		assert_eq!(cursor, 2);
+	})
+}
+
+#[test]
+fn offchain_worker_should_wrap_scan_cursor_around_after_full_pass() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_orders_scanned_per_block(2);
+		place_orders_with_arb_opportunity();
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		// the rest of the list is scanned and the cursor starts from the beginning again
+		let (summary, cursor) = scan_summary_and_cursor();
+		assert_eq!(summary.scanned, 1);
+		assert_eq!(cursor, 0);
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		let (summary, cursor) = scan_summary_and_cursor();
+		assert_eq!(summary.scanned, 2);
+		assert_eq!(cursor, 2);
+	})
+}
+
+fn sorted_otcs() -> Vec<SortedOtcsStorageType> {
+	StorageValueRef::persistent(OFFCHAIN_WORKER_DATA)
+		.get::<Vec<SortedOtcsStorageType>>()
+		.unwrap_or_default()
+		.unwrap_or_default()
+}
+
+#[test]
+fn offchain_worker_should_not_sort_orders_again_until_pass_is_completed() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_orders_scanned_per_block(2);
+		place_orders_with_arb_opportunity();
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+		let sorted_otcs_before = sorted_otcs();
+
+		// new order with an arbitrage opportunity is placed in the middle of the pass
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+		System::set_block_number(System::block_number() + 1);
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		// the pass continues over the same list
+		assert_eq!(sorted_otcs(), sorted_otcs_before);
+		let (summary, cursor) = scan_summary_and_cursor();
+		assert_eq!(summary.scanned, 1);
+		assert_eq!(cursor, 0);
+
+		System::set_block_number(System::block_number() + 1);
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		// the new pass starts with a new list
+		assert_eq!(sorted_otcs().len(), sorted_otcs_before.len() + 1);
+	})
+}
+
+#[test]
+fn offchain_worker_should_skip_order_closed_during_pass() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_orders_scanned_per_block(2);
+		place_orders_with_arb_opportunity();
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(ALICE), sorted_otcs()[2]));
+		System::set_block_number(System::block_number() + 1);
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		let (summary, cursor) = scan_summary_and_cursor();
+		assert_eq!(summary.scanned, 1);
+		assert_eq!(summary.skipped, 1);
+		assert_eq!(cursor, 0);
+	})
+}
+
+#[test]
+fn scan_position_should_return_next_order_after_partial_pass_and_reset_after_full_pass() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_orders_scanned_per_block(2);
+		place_orders_with_arb_opportunity();
+		assert_eq!(OtcSettlements::scan_position(), None);
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		let sorted_otcs = StorageValueRef::persistent(OFFCHAIN_WORKER_DATA)
+			.get::<Vec<SortedOtcsStorageType>>()
+			.unwrap_or_default()
+			.unwrap_or_default();
+		assert_eq!(OtcSettlements::scan_position(), Some(sorted_otcs[2]));
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		assert_eq!(OtcSettlements::scan_position(), None);
+	})
+}
+
+fn place_order_with_underfunded_maker() -> (OrderId, Balance) {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX, // otc asset_in
+		DAI, // otc asset_out
+		100_000 * ONE,
+		205_000 * ONE,
+		true,
+	));
+
+	// the maker drains the offered asset, so the order can't be settled
+	Currencies::unreserve_named(&pallet_otc::NAMED_RESERVE_ID, DAI, &ALICE, 200_000 * ONE);
+	let free_balance = Currencies::free_balance(DAI, &ALICE);
+	assert_ok!(Currencies::transfer(
+		RuntimeOrigin::signed(ALICE),
+		BOB,
+		DAI,
+		free_balance
+	));
+
+	(0, free_balance)
+}
+
+#[test]
+fn offchain_worker_should_increment_failure_count_when_order_fails_to_settle() {
+	let (mut ext, pool_state) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		let (otc_id, _) = place_order_with_underfunded_maker();
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+		assert_eq!(OtcSettlements::failure_count(otc_id), 1);
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+		assert_eq!(OtcSettlements::failure_count(otc_id), 2);
+
+		assert!(pool_state.read().transactions.is_empty());
+	})
+}
+
+#[test]
+fn offchain_worker_should_reset_failure_count_when_order_is_settled() {
+	let (mut ext, pool_state) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		let (otc_id, drained) = place_order_with_underfunded_maker();
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+		assert_eq!(OtcSettlements::failure_count(otc_id), 1);
+
+		// the maker funds the order again
+		assert_ok!(Currencies::transfer(RuntimeOrigin::signed(BOB), ALICE, DAI, drained));
+		assert_ok!(Currencies::reserve_named(
+			&pallet_otc::NAMED_RESERVE_ID,
+			DAI,
+			&ALICE,
+			200_000 * ONE
+		));
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		assert_eq!(pool_state.read().transactions.len(), 1);
+		assert_eq!(OtcSettlements::failure_count(otc_id), 0);
+	})
+}
+
+#[test]
+fn offchain_worker_should_skip_failing_order_when_failures_before_backoff_is_reached() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_failures_before_backoff(1);
+		let (otc_id, _) = place_order_with_underfunded_maker();
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+		assert_eq!(OtcSettlements::failure_count(otc_id), 1);
+
+		// the order is skipped in the next scan, so the failure count doesn't change
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+		assert_eq!(OtcSettlements::failure_count(otc_id), 1);
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+		assert_eq!(OtcSettlements::failure_count(otc_id), 2);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_split_profit_between_keeper_and_receiver_when_keeper_bounty_is_set() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_keeper_bounty(Permill::from_percent(20));
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		let treasury_balance_before = Currencies::free_balance(HDX, &TreasuryAccount::get());
+		let keeper_balance_before = Currencies::free_balance(HDX, &BOB);
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(BOB),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+
+		let received = Currencies::free_balance(HDX, &TreasuryAccount::get()) - treasury_balance_before;
+		let bounty = Currencies::free_balance(HDX, &BOB) - keeper_balance_before;
+		let profit = received + bounty;
+
+		assert!(bounty > 0);
+		assert_eq!(bounty, Permill::from_percent(20).mul_floor(profit));
+
+		expect_last_events(vec![Event::Executed {
+			asset_id: HDX,
+			profit,
+			bounty,
+		}
+		.into()]);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_not_pay_keeper_bounty_when_origin_is_unsigned() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_keeper_bounty(Permill::from_percent(20));
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		let treasury_balance_before = Currencies::free_balance(HDX, &TreasuryAccount::get());
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::none(),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+
+		let profit = Currencies::free_balance(HDX, &TreasuryAccount::get()) - treasury_balance_before;
+
+		assert!(profit > 0);
+		expect_last_events(vec![Event::Executed {
+			asset_id: HDX,
+			profit,
+			bounty: 0,
+		}
+		.into()]);
+	})
+}
+
+fn place_orders() {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		DAI,
+		1_000_000_000,
+		2_000_000_000,
+		true,
+	));
+
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		LRNA,
+		1_000_000_000,
+		3_000_000_000,
+		true,
+	));
+
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		LRNA,
+		HDX,
+		4_000_000_000,
+		3_000_000_000,
+		true,
+	));
+
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		LRNA,
+		7_000_000_000,
+		3_000_000_000,
+		true,
+	));
+
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		KSM,
+		DAI,
+		2_000_000_000,
+		3_000_000_000,
+		true,
+	));
+
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		DAI,
+		2_000_000,
+		3_000_000_000,
+		true,
+	));
+
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		DAI,
+		2_000_000_000,
+		3_000_000_000_000,
+		true,
+	));
+
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		DAI,
+		DOT,
+		2_000_000_000,
+		3_000_000_000_000,
+		true,
+	));
+
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		DOT,
+		9_000_000_000,
+		3_000_000_000,
+		true,
+	));
+
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		DAI,
+		DOT,
+		2_000_000_000,
+		13_000_000_000,
+		true,
+	));
+}
+
+#[test]
+fn settle_otc_order_should_work_when_spot_price_is_within_oracle_deviation() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		let spot_price = Router::spot_price_with_fee(&route).unwrap();
+		set_oracle_price(Some(EmaPrice::new(spot_price.into_inner(), FixedU128::DIV)));
+		set_max_oracle_deviation(Some(Permill::from_percent(1)));
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(BOB),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_spot_price_deviates_from_oracle_price() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		// oracle price 2% above the spot price
+		let spot_price = Router::spot_price_with_fee(&route).unwrap();
+		let oracle_price = spot_price.saturating_mul(FixedU128::from_rational(102, 100));
+		set_oracle_price(Some(EmaPrice::new(oracle_price.into_inner(), FixedU128::DIV)));
+		set_max_oracle_deviation(Some(Permill::from_percent(1)));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(BOB),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::OracleDeviation
+		);
+	})
+}
+
+#[test]
+fn reserved_order_should_be_settled_by_keeper_who_reserved_it() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::reserve_order(RuntimeOrigin::signed(BOB), otc_id));
+		expect_last_events(vec![Event::OrderReserved {
+			otc_id,
+			keeper: BOB,
+			expires_at: System::block_number() + 3,
+		}
+		.into()]);
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(BOB),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+
+		assert!(OrderReservations::<Test>::get(otc_id).is_none());
+	})
+}
+
+#[test]
+fn reserved_order_should_not_be_settled_by_others_until_reservation_expires() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::reserve_order(RuntimeOrigin::signed(BOB), otc_id));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route.clone(),
+				false,
+				None
+			),
+			Error::<Test>::Reserved.with_weight(<Test as Config>::WeightInfo::settle_otc_order_skipped())
+		);
+		assert_noop!(
+			OtcSettlements::reserve_order(RuntimeOrigin::signed(ALICE), otc_id),
+			Error::<Test>::Reserved
+		);
+
+		System::set_block_number(System::block_number() + 3);
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+#[test]
+fn reserved_order_should_be_settled_by_unsigned_settlement() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::reserve_order(RuntimeOrigin::signed(BOB), otc_id));
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::none(),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+
+		assert!(OrderReservations::<Test>::get(otc_id).is_none());
+	})
+}
+
+#[test]
+fn reserve_order_should_fail_when_same_keeper_reserves_again_within_cooldown() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		assert_ok!(OtcSettlements::reserve_order(RuntimeOrigin::signed(BOB), otc_id));
+		let (_, expires_at) = OrderReservations::<Test>::get(otc_id).unwrap();
+
+		System::set_block_number(expires_at);
+
+		assert_noop!(
+			OtcSettlements::reserve_order(RuntimeOrigin::signed(BOB), otc_id),
+			Error::<Test>::ReservationCooldown
+		);
+
+		System::set_block_number(expires_at + 4);
+
+		assert_noop!(
+			OtcSettlements::reserve_order(RuntimeOrigin::signed(BOB), otc_id),
+			Error::<Test>::ReservationCooldown
+		);
+
+		System::set_block_number(expires_at + 5);
+
+		assert_ok!(OtcSettlements::reserve_order(RuntimeOrigin::signed(BOB), otc_id));
+	})
+}
+
+#[test]
+fn reserve_order_should_allow_other_keeper_to_reserve_when_reservation_expired() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		assert_ok!(OtcSettlements::reserve_order(RuntimeOrigin::signed(BOB), otc_id));
+		let (_, expires_at) = OrderReservations::<Test>::get(otc_id).unwrap();
+
+		System::set_block_number(expires_at);
+
+		assert_ok!(OtcSettlements::reserve_order(RuntimeOrigin::signed(ALICE), otc_id));
+		assert_eq!(
+			OrderReservations::<Test>::get(otc_id).map(|(keeper, _)| keeper),
+			Some(ALICE)
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_amount_exceeds_max_pool_drain() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		// 0.1% of the HDX liquidity of the Omnipool
+		set_max_pool_drain(Permill::from_rational(1u32, 1_000u32));
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::PoolDrainLimitExceeded
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_settle_partially_when_capped_by_max_pool_drain() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_pool_drain(Permill::from_rational(1u32, 1_000u32));
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+		let hdx_liquidity = Omnipool::load_asset_state(HDX).unwrap().reserve;
+		let max_fill = Permill::from_rational(1u32, 1_000u32).mul_floor(hdx_liquidity);
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			max_fill,
+			route,
+			false,
+			None,
+		));
+
+		assert_eq!(
+			<pallet_otc::Orders<Test>>::get(otc_id).unwrap().amount_in,
+			100_000 * ONE - max_fill
+		);
+		expect_events(vec![Event::PartiallySettled {
+			otc_id,
+			amount: max_fill,
+			unfilled: 100_000 * ONE - max_fill,
+		}
+		.into()]);
+	})
+}
+
+#[test]
+fn offchain_worker_should_cap_large_order_to_partial_fill_when_max_pool_drain_is_set() {
+	let (mut ext, pool_state) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_pool_drain(Permill::from_rational(1u32, 1_000u32));
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+		let hdx_liquidity = Omnipool::load_asset_state(HDX).unwrap().reserve;
+		let max_fill = Permill::from_rational(1u32, 1_000u32).mul_floor(hdx_liquidity);
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		// the amount that closes the arb is higher, so the order is capped by the drain limit
+		assert!(max_fill < 2_413_749_694_825_193);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			crate::mock::RuntimeCall::OtcSettlements(crate::Call::settle_otc_order {
+				otc_id,
+				amount: max_fill,
+				route,
+				ensure_net_profitable: false,
+				valid_until: None,
+			})
+		);
+	})
+}
+
+#[test]
+fn set_order_profit_target_should_work_when_origin_is_authority() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		assert_ok!(OtcSettlements::set_order_profit_target(
+			RuntimeOrigin::root(),
+			0,
+			Some(ONE)
+		));
+		assert_eq!(OrderProfitTarget::<Test>::get(0), Some(ONE));
+		expect_events(vec![Event::OrderProfitTargetSet {
+			otc_id: 0,
+			target: Some(ONE),
+		}
+		.into()]);
+
+		assert_ok!(OtcSettlements::set_order_profit_target(RuntimeOrigin::root(), 0, None));
+		assert_eq!(OrderProfitTarget::<Test>::get(0), None);
+	})
+}
+
+#[test]
+fn set_order_profit_target_should_fail_when_origin_is_not_authority() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		assert_noop!(
+			OtcSettlements::set_order_profit_target(RuntimeOrigin::signed(ALICE), 0, Some(ONE)),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_profit_is_below_order_profit_target() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		// the profit meets the global threshold, but not the profit target of the order
+		assert_ok!(OtcSettlements::set_order_profit_target(
+			RuntimeOrigin::root(),
+			otc_id,
+			Some(Balance::MAX)
+		));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::TradeAmountTooLow
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_work_when_profit_meets_order_profit_target_below_global_threshold() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		// the global threshold requires the whole amount in of the order as the profit
+		set_min_profit_percentage(Perbill::one());
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route.clone(),
+				false,
+				None
+			),
+			Error::<Test>::TradeAmountTooLow
+		);
+
+		assert_ok!(OtcSettlements::set_order_profit_target(
+			RuntimeOrigin::root(),
+			otc_id,
+			Some(ONE)
+		));
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+#[test]
+fn preview_settlement_should_return_pool_price_matching_price_after_settlement() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+		let amount = 2_413_749_694_825_193;
+		let price_before = Router::spot_price_with_fee(&route).unwrap();
+
+		let preview = OtcSettlements::preview_settlement(otc_id, amount).unwrap();
+
+		// the simulation doesn't change the state
+		assert_eq!(<pallet_otc::Orders<Test>>::get(otc_id), Some(otc));
+		assert_eq!(Router::spot_price_with_fee(&route), Some(price_before));
+		assert_eq!(preview.pool_price_before, price_before);
+		assert_eq!(preview.profit_asset, HDX);
+
+		let balance_before = Currencies::free_balance(HDX, &TreasuryAccount::get());
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			amount,
+			route.clone(),
+			false,
+			None,
+		));
+
+		assert_eq!(Router::spot_price_with_fee(&route), Some(preview.pool_price_after));
+		assert_eq!(
+			Currencies::free_balance(HDX, &TreasuryAccount::get()) - balance_before,
+			preview.profit
+		);
+	})
+}
+
+#[test]
+fn preview_settlement_should_return_none_when_order_does_not_exist() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_eq!(OtcSettlements::preview_settlement(0, ONE), None);
+	})
+}
+
+#[test]
+fn ensure_price_improvement_should_reject_execution_at_limit_price() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_min_price_improvement(Permill::from_percent(1));
+
+		// 100 paid to the OTC order for 200 is the limit price
+		assert_noop!(
+			OtcSettlements::ensure_price_improvement(100 * ONE, 200 * ONE, 100 * ONE),
+			Error::<Test>::InsufficientImprovement
+		);
+		assert_noop!(
+			OtcSettlements::ensure_price_improvement(100 * ONE, 200 * ONE, 100 * ONE + ONE / 2),
+			Error::<Test>::InsufficientImprovement
+		);
+		assert_ok!(OtcSettlements::ensure_price_improvement(
+			100 * ONE,
+			200 * ONE,
+			101 * ONE
+		));
+		assert_ok!(OtcSettlements::ensure_price_improvement(
+			100 * ONE,
+			200 * ONE,
+			102 * ONE
+		));
+	});
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_price_improvement_is_insufficient() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		// the router trade would need to pay twice the limit price of the order
+		set_min_price_improvement(Permill::one());
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::InsufficientImprovement
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_work_when_price_improvement_exceeds_min_price_improvement() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_min_price_improvement(Permill::from_parts(100)); // 0.01%
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_order_is_younger_than_min_order_age() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_min_order_age(5);
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		System::set_block_number(System::block_number() + 4);
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::OrderTooYoung.with_weight(<Test as Config>::WeightInfo::settle_otc_order_skipped())
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_work_when_order_reaches_min_order_age() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_min_order_age(5);
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		System::set_block_number(System::block_number() + 5);
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+#[test]
+fn settleable_orders_should_return_profit_of_settlement_executed_by_offchain_worker() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let orders = OtcSettlements::settleable_orders(10);
+
+		assert_eq!(orders, vec![(0, 17_736_110_470_326)]);
+		assert!(<pallet_otc::Orders<Test>>::get(0).is_some());
+
+		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		expect_last_events(vec![Event::Executed {
+			asset_id: HDX,
+			profit: 17_736_110_470_326,
+			bounty: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn settleable_orders_should_return_at_most_limit_orders() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		place_orders_with_arb_opportunity();
+
+		let all_orders = OtcSettlements::settleable_orders(u32::MAX);
+		assert!(all_orders.len() > 1);
+
+		assert_eq!(OtcSettlements::settleable_orders(1), all_orders[..1].to_vec());
+		assert!(OtcSettlements::settleable_orders(0).is_empty());
+	});
+}
+
+#[test]
+fn simulate_batch_should_return_outcomes_matching_settlements_executed_in_the_same_order() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		place_orders_with_arb_opportunity();
+
+		let outcomes = OtcSettlements::simulate_batch(vec![0, 1, 2, 99]);
+
+		assert_eq!(
+			outcomes.iter().map(|(otc_id, _)| *otc_id).collect::<Vec<_>>(),
+			vec![0, 1, 2, 99]
+		);
+		assert!(matches!(outcomes[0].1, SettlementOutcome::Settled(profit) if profit > 0));
+		assert_eq!(outcomes[3].1, SettlementOutcome::Skipped(SkipReason::OrderNotFound));
+		// state changes of the simulation are discarded
+		assert!(<pallet_otc::Orders<Test>>::get(0).is_some());
+		assert_eq!(OtcSettlements::block_profit(), 0);
+
+		for (otc_id, outcome) in outcomes {
+			let Some(otc) = <pallet_otc::Orders<Test>>::get(otc_id) else {
+				assert_eq!(outcome, SettlementOutcome::Skipped(SkipReason::OrderNotFound));
+				continue;
+			};
+			let route = Router::get_route(AssetPair {
+				asset_in: otc.asset_out,
+				asset_out: otc.asset_in,
+			});
+			let maybe_amount = with_transaction(|| {
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>(OtcSettlements::try_find_trade_amount(
+					otc_id, &otc, &route,
+				)))
+			})
+			.unwrap();
+
+			match outcome {
+				SettlementOutcome::Settled(profit) => {
+					assert_ok!(OtcSettlements::settle_otc_order(
+						RuntimeOrigin::none(),
+						otc_id,
+						maybe_amount.unwrap(),
+						route,
+						false,
+						None,
+					));
+					expect_last_events(vec![Event::Executed {
+						asset_id: otc.asset_in,
+						profit,
+						bounty: 0,
+					}
+					.into()]);
+				}
+				SettlementOutcome::Skipped(reason) => {
+					assert_eq!(reason, SkipReason::NoProfitableAmount);
+					assert_eq!(maybe_amount, None);
+				}
+			}
+		}
+	});
+}
+
+#[test]
+fn settleable_orders_should_return_profit_before_conversion_when_profit_is_converted_to_native_asset() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		place_orders_with_arb_opportunity();
+		let expected = OtcSettlements::settleable_orders(10);
+		assert!(!expected.is_empty());
+		assert!(expected.iter().all(|(_, profit)| *profit > 0));
+
+		set_convert_profit_to_native(true);
+
+		assert_eq!(OtcSettlements::settleable_orders(10), expected);
+	});
+}
+
+#[test]
+fn simulate_batch_should_return_profit_before_conversion_when_profit_is_converted_to_native_asset() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		place_orders_with_arb_opportunity();
+		let expected = OtcSettlements::simulate_batch(vec![0, 1, 2]);
+		assert!(matches!(expected[0].1, SettlementOutcome::Settled(profit) if profit > 0));
+
+		set_convert_profit_to_native(true);
+
+		assert_eq!(OtcSettlements::simulate_batch(vec![0, 1, 2]), expected);
+	});
+}
+
+#[test]
+fn simulate_batch_should_skip_order_with_excluded_asset() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		place_orders_with_arb_opportunity();
+		assert_ok!(OtcSettlements::set_asset_excluded(RuntimeOrigin::root(), DAI, true));
+
+		let outcomes = OtcSettlements::simulate_batch(vec![0, 2]);
+
+		assert_eq!(outcomes[0], (0, SettlementOutcome::Skipped(SkipReason::AssetExcluded)));
+		assert!(matches!(outcomes[1], (2, SettlementOutcome::Settled(_))));
+	});
+}
+
+#[test]
+fn settle_otc_order_should_charge_only_checks_weight_when_skipped_early() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+		assert_ok!(OtcSettlements::set_asset_excluded(RuntimeOrigin::root(), DAI, true));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		let err = OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route.clone(),
+			false,
+			None,
+		)
+		.unwrap_err();
+
+		let skipped_weight = err.post_info.actual_weight.unwrap();
+		assert_eq!(skipped_weight, <Test as Config>::WeightInfo::settle_otc_order_skipped());
+		assert!(skipped_weight.ref_time() * 2 < OtcSettlements::settle_otc_order_weight(&route).ref_time());
+	})
+}
+
+#[test]
+fn settle_otc_order_should_charge_full_weight_when_settled() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		let post_info = OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		)
+		.unwrap();
+
+		assert_eq!(post_info.actual_weight, None);
+	})
+}
+
+#[test]
+fn settle_otc_pair_should_fill_both_orders_when_prices_cross() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100 * ONE,
+			205 * ONE,
+			true,
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(BOB),
+			DAI, // otc asset_in
+			HDX, // otc asset_out
+			200 * ONE,
+			102 * ONE,
+			true,
+		));
+
+		let pallet_acc = OtcSettlements::account_id();
+		let hdx_balance_before = Currencies::free_balance(HDX, &pallet_acc);
+		let dai_balance_before = Currencies::free_balance(DAI, &pallet_acc);
+
+		assert_ok!(OtcSettlements::settle_otc_pair(RuntimeOrigin::signed(ALICE), 0, 1));
+
+		assert!(<pallet_otc::Orders<Test>>::get(0).is_none());
+		assert!(<pallet_otc::Orders<Test>>::get(1).is_none());
+		assert_eq!(Currencies::free_balance(HDX, &pallet_acc), hdx_balance_before);
+		assert_eq!(Currencies::free_balance(DAI, &pallet_acc), dai_balance_before);
+
+		// 1% OTC fee is paid from the amount out of both orders
+		expect_events(vec![
+			Event::Executed {
+				asset_id: HDX,
+				profit: 102 * ONE - 1_020_000_000_000 - 100 * ONE,
+				bounty: 0,
+			}
+			.into(),
+			Event::Executed {
+				asset_id: DAI,
+				profit: 205 * ONE - 2_050_000_000_000 - 200 * ONE,
+				bounty: 0,
+			}
+			.into(),
+			Event::PairSettled { order_a: 0, order_b: 1 }.into(),
+		]);
+	});
+}
+
+#[test]
+fn settle_otc_pair_should_fail_when_prices_do_not_cross() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100 * ONE,
+			205 * ONE,
+			true,
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(BOB),
+			DAI, // otc asset_in
+			HDX, // otc asset_out
+			204 * ONE,
+			102 * ONE,
+			true,
+		));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_pair(RuntimeOrigin::signed(ALICE), 0, 1),
+			Error::<Test>::PricesNotCrossing
+		);
+	});
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_block_profit_cap_is_reached() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_profit_per_block(1);
+		place_orders_with_arb_opportunity();
+
+		let route = Router::get_route(AssetPair {
+			asset_in: DAI,
+			asset_out: HDX,
+		});
+
+		// the cap is not reached before the first settlement
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			2_413_749_694_825_193,
+			route.clone(),
+			false,
+			None,
+		));
+		assert!(BlockProfit::<Test>::get().unwrap().1 >= 1);
+
+		let err = OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			1,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		)
+		.unwrap_err();
+		assert_eq!(err.error, Error::<Test>::BlockProfitCapReached.into());
+		assert_eq!(
+			err.post_info.actual_weight,
+			Some(<Test as Config>::WeightInfo::settle_otc_order_skipped())
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_work_when_block_profit_cap_was_reached_in_previous_block() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_profit_per_block(1);
+		place_orders_with_arb_opportunity();
+
+		let route = Router::get_route(AssetPair {
+			asset_in: DAI,
+			asset_out: HDX,
+		});
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			2_413_749_694_825_193,
+			route.clone(),
+			false,
+			None,
+		));
+
+		System::set_block_number(System::block_number() + 1);
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			1,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+fn place_crossing_orders() {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX, // otc asset_in
+		DAI, // otc asset_out
+		100 * ONE,
+		205 * ONE,
+		true,
+	));
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(BOB),
+		DAI, // otc asset_in
+		HDX, // otc asset_out
+		200 * ONE,
+		102 * ONE,
+		true,
+	));
+}
+
+#[test]
+fn settle_otc_pair_should_accrue_block_profit() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_profit_per_block(Balance::MAX - 1);
+		place_crossing_orders();
+
+		assert_ok!(OtcSettlements::settle_otc_pair(RuntimeOrigin::signed(ALICE), 0, 1));
+
+		// the HDX profit alone is 102 HDX - 1% OTC fee - 100 HDX
+		let (block_number, profit) = BlockProfit::<Test>::get().unwrap();
+		assert_eq!(block_number, System::block_number());
+		assert!(profit > 102 * ONE - 1_020_000_000_000 - 100 * ONE);
+	});
+}
+
+#[test]
+fn settle_otc_pair_should_fail_when_block_profit_cap_is_reached() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_max_profit_per_block(1);
+		place_crossing_orders();
+		place_crossing_orders();
+
+		// the cap is not reached before the first settlement
+		assert_ok!(OtcSettlements::settle_otc_pair(RuntimeOrigin::signed(ALICE), 0, 1));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_pair(RuntimeOrigin::signed(ALICE), 2, 3),
+			Error::<Test>::BlockProfitCapReached
+		);
+	});
+}
+
+#[test]
+fn set_pool_allowed_should_work_when_origin_is_authority() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OtcSettlements::set_pool_allowed(
+			RuntimeOrigin::root(),
+			PoolType::Omnipool,
+			true
+		));
+		assert!(AllowedPools::<Test>::contains_key(PoolType::Omnipool));
+		expect_events(vec![Event::PoolAllowedSet {
+			pool: PoolType::Omnipool,
+			allowed: true,
+		}
+		.into()]);
+
+		assert_ok!(OtcSettlements::set_pool_allowed(
+			RuntimeOrigin::root(),
+			PoolType::Omnipool,
+			false
+		));
+		assert_eq!(AllowedPools::<Test>::count(), 0);
+	})
+}
+
+#[test]
+fn set_pool_allowed_should_fail_when_origin_is_not_authority() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_noop!(
+			OtcSettlements::set_pool_allowed(RuntimeOrigin::signed(ALICE), PoolType::Omnipool, true),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_route_trades_in_pool_not_allowed() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OtcSettlements::set_pool_allowed(
+			RuntimeOrigin::root(),
+			PoolType::XYK,
+			true
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+		assert!(route.iter().all(|trade| trade.pool == PoolType::Omnipool));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None,
+			),
+			Error::<Test>::PoolNotAllowed
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_work_when_route_trades_in_allowed_pools() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OtcSettlements::set_pool_allowed(
+			RuntimeOrigin::root(),
+			PoolType::XYK,
+			true
+		));
+		assert_ok!(OtcSettlements::set_pool_allowed(
+			RuntimeOrigin::root(),
+			PoolType::Omnipool,
+			true
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+#[test]
+fn settle_otc_order_should_work_when_included_before_deadline() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			Some(System::block_number()),
+		));
+	})
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_included_after_deadline() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		let valid_until = System::block_number();
+		System::set_block_number(valid_until + 1);
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				Some(valid_until),
+			),
+			Error::<Test>::Expired.with_weight(<Test as Config>::WeightInfo::settle_otc_order_skipped())
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_not_convert_profit_when_profit_is_in_native_asset() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_convert_profit_to_native(true);
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+		let hdx_balance_before = Currencies::free_balance(HDX, &TreasuryAccount::get());
+		let dai_balance_before = Currencies::free_balance(DAI, &TreasuryAccount::get());
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+
+		let profit = System::events()
+			.into_iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::OtcSettlements(Event::Executed { profit, .. }) => Some(profit),
+				_ => None,
+			})
+			.unwrap();
+		assert_eq!(
+			Currencies::free_balance(HDX, &TreasuryAccount::get()),
+			hdx_balance_before + profit
+		);
+		assert_eq!(
+			Currencies::free_balance(DAI, &TreasuryAccount::get()),
+			dai_balance_before
+		);
+	})
+}
+
+#[test]
+fn settle_otc_pair_should_convert_profit_to_native_asset_when_conversion_is_enabled() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_convert_profit_to_native(true);
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100 * ONE,
+			205 * ONE,
+			true,
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(BOB),
+			DAI, // otc asset_in
+			HDX, // otc asset_out
+			200 * ONE,
+			102 * ONE,
+			true,
+		));
+
+		let pallet_acc = OtcSettlements::account_id();
+		let pallet_hdx_balance_before = Currencies::free_balance(HDX, &pallet_acc);
+		let pallet_dai_balance_before = Currencies::free_balance(DAI, &pallet_acc);
+		let hdx_balance_before = Currencies::free_balance(HDX, &TreasuryAccount::get());
+		let dai_balance_before = Currencies::free_balance(DAI, &TreasuryAccount::get());
+
+		assert_ok!(OtcSettlements::settle_otc_pair(RuntimeOrigin::signed(ALICE), 0, 1));
+
+		// HDX profit is transferred as it is, DAI profit is sold for HDX
+		let hdx_profit = 102 * ONE - 1_020_000_000_000 - 100 * ONE;
+		assert!(Currencies::free_balance(HDX, &TreasuryAccount::get()) > hdx_balance_before + hdx_profit);
+		assert_eq!(
+			Currencies::free_balance(DAI, &TreasuryAccount::get()),
+			dai_balance_before
+		);
+		assert_eq!(Currencies::free_balance(HDX, &pallet_acc), pallet_hdx_balance_before);
+		assert_eq!(Currencies::free_balance(DAI, &pallet_acc), pallet_dai_balance_before);
+	})
+}
+
+#[test]
+fn settle_otc_pair_should_not_convert_profit_when_conversion_is_disabled() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100 * ONE,
+			205 * ONE,
+			true,
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(BOB),
+			DAI, // otc asset_in
+			HDX, // otc asset_out
+			200 * ONE,
+			102 * ONE,
+			true,
+		));
+
+		let hdx_balance_before = Currencies::free_balance(HDX, &TreasuryAccount::get());
+		let dai_balance_before = Currencies::free_balance(DAI, &TreasuryAccount::get());
+
+		assert_ok!(OtcSettlements::settle_otc_pair(RuntimeOrigin::signed(ALICE), 0, 1));
+
+		assert_eq!(
+			Currencies::free_balance(HDX, &TreasuryAccount::get()),
+			hdx_balance_before + 102 * ONE - 1_020_000_000_000 - 100 * ONE
+		);
+		assert_eq!(
+			Currencies::free_balance(DAI, &TreasuryAccount::get()),
+			dai_balance_before + 205 * ONE - 2_050_000_000_000 - 200 * ONE
+		);
+	})
+}
+
+#[test]
+fn settle_otc_pair_should_split_profit_between_beneficiaries_when_profit_split_is_set() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		let insurance_fund: AccountId = 4;
+		let keeper_fund: AccountId = 5;
+		assert_ok!(OtcSettlements::set_profit_split(
+			RuntimeOrigin::root(),
+			vec![
+				(TreasuryAccount::get(), Permill::from_percent(50)),
+				(insurance_fund, Permill::from_percent(30)),
+				(keeper_fund, Permill::from_percent(20)),
+			]
+			.try_into()
+			.unwrap(),
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100 * ONE,
+			205 * ONE,
+			true,
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(BOB),
+			DAI, // otc asset_in
+			HDX, // otc asset_out
+			200 * ONE,
+			102 * ONE,
+			true,
+		));
+
+		let hdx_balance_before = Currencies::free_balance(HDX, &TreasuryAccount::get());
+		let dai_balance_before = Currencies::free_balance(DAI, &TreasuryAccount::get());
+
+		assert_ok!(OtcSettlements::settle_otc_pair(RuntimeOrigin::signed(ALICE), 0, 1));
+
+		// profit is 980_000_000_000 HDX and 2_950_000_000_000 DAI
+		assert_eq!(
+			Currencies::free_balance(HDX, &TreasuryAccount::get()),
+			hdx_balance_before + 490_000_000_000
+		);
+		assert_eq!(Currencies::free_balance(HDX, &insurance_fund), 294_000_000_000);
+		assert_eq!(Currencies::free_balance(HDX, &keeper_fund), 196_000_000_000);
+		assert_eq!(
+			Currencies::free_balance(DAI, &TreasuryAccount::get()),
+			dai_balance_before + 1_475_000_000_000
+		);
+		assert_eq!(Currencies::free_balance(DAI, &insurance_fund), 885_000_000_000);
+		assert_eq!(Currencies::free_balance(DAI, &keeper_fund), 590_000_000_000);
+	})
+}
+
+#[test]
+fn set_profit_split_should_fail_when_shares_do_not_sum_to_one_hundred_percent() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_noop!(
+			OtcSettlements::set_profit_split(
+				RuntimeOrigin::root(),
+				vec![
+					(TreasuryAccount::get(), Permill::from_percent(50)),
+					(ALICE, Permill::from_percent(30)),
+					(BOB, Permill::from_percent(10)),
+				]
+				.try_into()
+				.unwrap(),
+			),
+			Error::<Test>::InvalidProfitSplit
+		);
+		assert_noop!(
+			OtcSettlements::set_profit_split(
+				RuntimeOrigin::root(),
+				vec![
+					(TreasuryAccount::get(), Permill::from_percent(80)),
+					(ALICE, Permill::from_percent(30)),
+				]
+				.try_into()
+				.unwrap(),
+			),
+			Error::<Test>::InvalidProfitSplit
+		);
+	})
+}
+
+#[test]
+fn set_profit_split_should_fail_when_called_by_non_authority_origin() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_noop!(
+			OtcSettlements::set_profit_split(
+				RuntimeOrigin::signed(ALICE),
+				vec![(ALICE, Permill::from_percent(100))].try_into().unwrap(),
+			),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn set_profit_split_should_transfer_profit_to_profit_receiver_when_split_is_empty() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OtcSettlements::set_profit_split(
+			RuntimeOrigin::root(),
+			vec![(ALICE, Permill::from_percent(100))].try_into().unwrap(),
+		));
+		assert_ok!(OtcSettlements::set_profit_split(
+			RuntimeOrigin::root(),
+			BoundedVec::default()
+		));
+		expect_events(vec![Event::ProfitSplitSet { split: vec![] }.into()]);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100 * ONE,
+			205 * ONE,
+			true,
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(BOB),
+			DAI, // otc asset_in
+			HDX, // otc asset_out
+			200 * ONE,
+			102 * ONE,
+			true,
+		));
+
+		let hdx_balance_before = Currencies::free_balance(HDX, &TreasuryAccount::get());
+
+		assert_ok!(OtcSettlements::settle_otc_pair(RuntimeOrigin::signed(ALICE), 0, 1));
+
+		assert_eq!(
+			Currencies::free_balance(HDX, &TreasuryAccount::get()),
+			hdx_balance_before + 102 * ONE - 1_020_000_000_000 - 100 * ONE
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_record_keeper_when_origin_is_signed() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(BOB),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+
+		assert_eq!(SettledBy::<Test>::get(otc_id), Some(BOB));
+		assert_eq!(KeeperSettlements::<Test>::get(BOB), 1);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_not_record_keeper_when_origin_is_unsigned() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::none(),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+
+		assert_eq!(SettledBy::<Test>::get(otc_id), None);
+		assert_eq!(KeeperSettlements::<Test>::iter().count(), 0);
+	})
+}
+
+#[test]
+fn settle_otc_pair_should_count_settlement_of_both_orders_to_keeper() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100 * ONE,
+			205 * ONE,
+			true,
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(BOB),
+			DAI, // otc asset_in
+			HDX, // otc asset_out
+			200 * ONE,
+			102 * ONE,
+			true,
+		));
+
+		assert_ok!(OtcSettlements::settle_otc_pair(RuntimeOrigin::signed(ALICE), 0, 1));
+
+		assert_eq!(SettledBy::<Test>::get(0), Some(ALICE));
+		assert_eq!(SettledBy::<Test>::get(1), Some(ALICE));
+		assert_eq!(KeeperSettlements::<Test>::get(ALICE), 2);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_halt_settlement_when_failure_breaker_threshold_is_reached() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_failure_breaker_threshold(3);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		// every settlement fails on the profit target
+		assert_ok!(OtcSettlements::set_order_profit_target(
+			RuntimeOrigin::root(),
+			otc_id,
+			Some(Balance::MAX)
+		));
+
+		for failures in 1..=3 {
+			assert_ok!(OtcSettlements::settle_otc_order(
+				RuntimeOrigin::none(),
+				otc_id,
+				2_413_749_694_825_193,
+				route.clone(),
+				false,
+				None,
+			));
+			assert_eq!(ConsecutiveFailures::<Test>::get(), failures);
+			expect_events(vec![Event::SettlementFailed {
+				otc_id,
+				error: Error::<Test>::TradeAmountTooLow.into(),
+				failures,
+			}
+			.into()]);
+		}
+
+		assert!(SettlementHalted::<Test>::get());
+		expect_events(vec![Event::SettlementHalted { failures: 3 }.into()]);
+
+		// the failed settlements were reverted
+		assert_eq!(<pallet_otc::Orders<Test>>::get(otc_id), Some(otc));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(RuntimeOrigin::none(), otc_id, 2_413_749_694_825_193, route, false, None),
+			Error::<Test>::Halted.with_weight(<Test as Config>::WeightInfo::settle_otc_order_skipped())
+		);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_reset_consecutive_failures_when_settlement_succeeds() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_failure_breaker_threshold(3);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::set_order_profit_target(
+			RuntimeOrigin::root(),
+			otc_id,
+			Some(Balance::MAX)
+		));
+
+		for _ in 0..2 {
+			assert_ok!(OtcSettlements::settle_otc_order(
+				RuntimeOrigin::none(),
+				otc_id,
+				2_413_749_694_825_193,
+				route.clone(),
+				false,
+				None,
+			));
+		}
+		assert_eq!(ConsecutiveFailures::<Test>::get(), 2);
+
+		assert_ok!(OtcSettlements::set_order_profit_target(
+			RuntimeOrigin::root(),
+			otc_id,
+			None
+		));
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::none(),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+
+		assert_eq!(ConsecutiveFailures::<Test>::get(), 0);
+		assert!(!SettlementHalted::<Test>::get());
+		assert_ne!(<pallet_otc::Orders<Test>>::get(otc_id), Some(otc));
+	})
+}
+
+#[test]
+fn settle_otc_order_should_not_count_failures_of_signed_settlements() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_failure_breaker_threshold(3);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::set_order_profit_target(
+			RuntimeOrigin::root(),
+			otc_id,
+			Some(Balance::MAX)
+		));
+
+		for _ in 0..3 {
+			assert_noop!(
+				OtcSettlements::settle_otc_order(
+					RuntimeOrigin::signed(ALICE),
+					otc_id,
+					2_413_749_694_825_193,
+					route.clone(),
+					false,
+					None
+				),
+				Error::<Test>::TradeAmountTooLow
+			);
+		}
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order_with_route(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+			),
+			Error::<Test>::TradeAmountTooLow
+		);
+
+		assert_eq!(ConsecutiveFailures::<Test>::get(), 0);
+		assert!(!SettlementHalted::<Test>::get());
+	})
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_failure_breaker_is_disabled() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		assert_ok!(OtcSettlements::set_order_profit_target(
+			RuntimeOrigin::root(),
+			otc_id,
+			Some(Balance::MAX)
+		));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::TradeAmountTooLow
+		);
+		assert_eq!(ConsecutiveFailures::<Test>::get(), 0);
+	})
+}
+
+#[test]
+fn reset_settlement_breaker_should_resume_settlement() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		SettlementHalted::<Test>::put(true);
+		ConsecutiveFailures::<Test>::put(3);
+
+		assert_noop!(
+			OtcSettlements::reset_settlement_breaker(RuntimeOrigin::signed(ALICE)),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(OtcSettlements::reset_settlement_breaker(RuntimeOrigin::root()));
+
+		assert!(!SettlementHalted::<Test>::get());
+		assert_eq!(ConsecutiveFailures::<Test>::get(), 0);
+		expect_events(vec![Event::SettlementResumed.into()]);
+	})
+}
+
+#[test]
+fn set_asset_min_profit_should_work() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_noop!(
+			OtcSettlements::set_asset_min_profit(RuntimeOrigin::signed(ALICE), HDX, Some(ONE)),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(OtcSettlements::set_asset_min_profit(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(ONE)
+		));
+		assert_eq!(MinProfitPerAsset::<Test>::get(HDX), Some(ONE));
+		expect_events(vec![Event::AssetMinProfitSet {
+			asset_id: HDX,
+			min_profit: Some(ONE),
+		}
+		.into()]);
+
+		assert_ok!(OtcSettlements::set_asset_min_profit(RuntimeOrigin::root(), HDX, None));
+		assert_eq!(MinProfitPerAsset::<Test>::get(HDX), None);
+	})
+}
+
+#[test]
+fn settle_otc_order_should_work_when_profit_meets_asset_min_profit_below_global_threshold() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		// the global threshold requires the whole amount in of the order as the profit
+		set_min_profit_percentage(Perbill::one());
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		// the minimum profit of other assets doesn't apply to the order
+		assert_ok!(OtcSettlements::set_asset_min_profit(
+			RuntimeOrigin::root(),
+			DAI,
+			Some(ONE)
+		));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route.clone(),
+				false,
+				None
+			),
+			Error::<Test>::TradeAmountTooLow
+		);
+
+		assert_ok!(OtcSettlements::set_asset_min_profit(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(ONE)
+		));
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_profit_is_below_asset_min_profit_above_global_threshold() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		// the asset threshold requires the whole amount in of the order as the profit
+		assert_ok!(OtcSettlements::set_asset_min_profit(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(100_000 * ONE)
+		));
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route.clone(),
+				false,
+				None
+			),
+			Error::<Test>::TradeAmountTooLow
+		);
+
+		// the profit target of the order takes precedence over the asset threshold
+		assert_ok!(OtcSettlements::set_order_profit_target(
+			RuntimeOrigin::root(),
+			otc_id,
+			Some(ONE)
+		));
+
+		assert_ok!(OtcSettlements::settle_otc_order(
+			RuntimeOrigin::signed(ALICE),
+			otc_id,
+			2_413_749_694_825_193,
+			route,
+			false,
+			None,
+		));
+	})
+}
+
+#[test]
+fn expire_order_should_return_reserved_amount_to_maker_when_order_expired() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_order_ttl(10);
+
+		let alice_dai_balance = Currencies::free_balance(DAI, &ALICE);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		assert_ok!(OtcSettlements::reserve_order(RuntimeOrigin::signed(BOB), otc_id));
+		assert_ok!(OtcSettlements::set_order_profit_target(
+			RuntimeOrigin::root(),
+			otc_id,
+			Some(ONE)
+		));
+		assert_eq!(
+			Currencies::reserved_balance_named(&pallet_otc::NAMED_RESERVE_ID, DAI, &ALICE),
+			205_000 * ONE
+		);
+
+		System::set_block_number(System::block_number() + 10);
+
+		assert_ok!(OtcSettlements::expire_order(RuntimeOrigin::signed(BOB), otc_id));
+
+		assert!(<pallet_otc::Orders<Test>>::get(otc_id).is_none());
+		assert!(<pallet_otc::OrderPlacedAt<Test>>::get(otc_id).is_none());
+		assert!(OrderReservations::<Test>::get(otc_id).is_none());
+		assert!(OrderProfitTarget::<Test>::get(otc_id).is_none());
+		assert_eq!(
+			Currencies::reserved_balance_named(&pallet_otc::NAMED_RESERVE_ID, DAI, &ALICE),
+			0
+		);
+		assert_eq!(Currencies::free_balance(DAI, &ALICE), alice_dai_balance);
+
+		expect_events(vec![Event::OrderExpired {
+			otc_id,
+			owner: ALICE,
+			asset_id: DAI,
+			amount: 205_000 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn expire_order_should_fail_when_order_not_expired() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_order_ttl(10);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		System::set_block_number(System::block_number() + 9);
+
+		assert_noop!(
+			OtcSettlements::expire_order(RuntimeOrigin::signed(BOB), otc_id),
+			Error::<Test>::OrderNotExpired
+		);
+		assert_noop!(
+			OtcSettlements::expire_order(RuntimeOrigin::signed(BOB), otc_id + 1),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
+
+#[test]
+fn expire_order_should_fail_when_expiry_is_disabled() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_order_ttl(0);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		System::set_block_number(System::block_number() + 1_000_000);
+
+		assert_noop!(
+			OtcSettlements::expire_order(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::OrderNotExpired
+		);
+	});
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_order_expired() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		set_order_ttl(10);
+
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+		));
+
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+
+		System::set_block_number(System::block_number() + 10);
+
+		assert_noop!(
+			OtcSettlements::settle_otc_order(
+				RuntimeOrigin::signed(ALICE),
+				otc_id,
+				2_413_749_694_825_193,
+				route,
+				false,
+				None
+			),
+			Error::<Test>::OrderExpired
+		);
+	});
 }
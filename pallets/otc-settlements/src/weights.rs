@@ -23,6 +23,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! Weights of the functions marked as estimated were not produced by the benchmark CLI
+//! and DB reads and writes of some other functions were adjusted by hand. Regenerate before release.
 
 // Executed Command:
 // target/release/hydradx
@@ -51,6 +54,16 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_otc.
 pub trait WeightInfo {
 	fn settle_otc_order() -> Weight;
+	fn set_asset_excluded() -> Weight;
+	fn reserve_order() -> Weight;
+	fn set_order_profit_target() -> Weight;
+	fn settle_otc_order_skipped() -> Weight;
+	fn settle_otc_pair() -> Weight;
+	fn set_pool_allowed() -> Weight;
+	fn set_profit_split() -> Weight;
+	fn reset_settlement_breaker() -> Weight;
+	fn set_asset_min_profit() -> Weight;
+	fn expire_order() -> Weight;
 }
 
 /// Weights for pallet_otc using the hydraDX node and recommended hardware.
@@ -59,15 +72,204 @@ impl WeightInfo for () {
 	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:1 w:0)
+	/// Storage: `Tokens::Accounts` (r:2 w:1)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::ExcludedAssets` (r:2 w:0)
+	/// Proof: `OtcSettlements::ExcludedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::OrderReservations` (r:1 w:1)
+	/// Proof: `OtcSettlements::OrderReservations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::OrderProfitTarget` (r:1 w:1)
+	/// Proof: `OtcSettlements::OrderProfitTarget` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderPlacedAt` (r:1 w:1)
+	/// Proof: `OTC::OrderPlacedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::BlockProfit` (r:1 w:1)
+	/// Proof: `OtcSettlements::BlockProfit` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::CounterForAllowedPools` (r:1 w:0)
+	/// Proof: `OtcSettlements::CounterForAllowedPools` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::KeeperSettlements` (r:1 w:1)
+	/// Proof: `OtcSettlements::KeeperSettlements` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::SettledBy` (r:0 w:1)
+	/// Proof: `OtcSettlements::SettledBy` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::SettlementHalted` (r:1 w:0)
+	/// Proof: `OtcSettlements::SettlementHalted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::ConsecutiveFailures` (r:1 w:1)
+	/// Proof: `OtcSettlements::ConsecutiveFailures` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::MinProfitPerAsset` (r:1 w:0)
+	/// Proof: `OtcSettlements::MinProfitPerAsset` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn settle_otc_order() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `747`
 		//  Estimated: `6196`
 		// Minimum execution time: 101_333_000 picoseconds.
 		Weight::from_parts(102_441_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `OtcSettlements::ExcludedAssets` (r:0 w:1)
+	/// Proof: `OtcSettlements::ExcludedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn set_asset_excluded() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_812_000 picoseconds.
+		Weight::from_parts(10_147_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OTC::Orders` (r:1 w:0)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::OrderReservations` (r:1 w:1)
+	/// Proof: `OtcSettlements::OrderReservations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn reserve_order() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `411`
+		//  Estimated: `3558`
+		// Minimum execution time: 17_406_000 picoseconds.
+		Weight::from_parts(17_893_000, 3558)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `OTC::Orders` (r:1 w:0)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::OrderProfitTarget` (r:0 w:1)
+	/// Proof: `OtcSettlements::OrderProfitTarget` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_order_profit_target() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `411`
+		//  Estimated: `3558`
+		// Minimum execution time: 14_912_000 picoseconds.
+		Weight::from_parts(15_338_000, 3558)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OTC::Orders` (r:1 w:0)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::ExcludedAssets` (r:2 w:0)
+	/// Proof: `OtcSettlements::ExcludedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::OrderReservations` (r:1 w:0)
+	/// Proof: `OtcSettlements::OrderReservations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderPlacedAt` (r:1 w:0)
+	/// Proof: `OTC::OrderPlacedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::BlockProfit` (r:1 w:0)
+	/// Proof: `OtcSettlements::BlockProfit` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::SettlementHalted` (r:1 w:0)
+	/// Proof: `OtcSettlements::SettlementHalted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn settle_otc_order_skipped() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3558`
+		// Minimum execution time: 18_204_000 picoseconds.
+		Weight::from_parts(18_689_000, 3558)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+	}
+	/// Storage: `OTC::Orders` (r:2 w:0)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::ExcludedAssets` (r:4 w:0)
+	/// Proof: `OtcSettlements::ExcludedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::OrderReservations` (r:2 w:2)
+	/// Proof: `OtcSettlements::OrderReservations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderPlacedAt` (r:2 w:0)
+	/// Proof: `OTC::OrderPlacedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:2 w:0)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::OrderProfitTarget` (r:0 w:2)
+	/// Proof: `OtcSettlements::OrderProfitTarget` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::KeeperSettlements` (r:1 w:1)
+	/// Proof: `OtcSettlements::KeeperSettlements` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::SettledBy` (r:0 w:2)
+	/// Proof: `OtcSettlements::SettledBy` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::SettlementHalted` (r:1 w:0)
+	/// Proof: `OtcSettlements::SettlementHalted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::BlockProfit` (r:1 w:1)
+	/// Proof: `OtcSettlements::BlockProfit` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn settle_otc_pair() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `1284`
+		//  Estimated: `11462`
+		// Minimum execution time: 92_518_000 picoseconds.
+		Weight::from_parts(93_906_000, 11462)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: `OtcSettlements::AllowedPools` (r:1 w:1)
+	/// Proof: `OtcSettlements::AllowedPools` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::CounterForAllowedPools` (r:1 w:1)
+	/// Proof: `OtcSettlements::CounterForAllowedPools` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_pool_allowed() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3486`
+		// Minimum execution time: 14_327_000 picoseconds.
+		Weight::from_parts(14_781_000, 3486)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OtcSettlements::ProfitSplit` (r:0 w:1)
+	/// Proof: `OtcSettlements::ProfitSplit` (`max_values`: Some(1), `max_size`: Some(181), added: 676, mode: `MaxEncodedLen`)
+	fn set_profit_split() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_812_000 picoseconds.
+		Weight::from_parts(10_164_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OtcSettlements::SettlementHalted` (r:0 w:1)
+	/// Proof: `OtcSettlements::SettlementHalted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::ConsecutiveFailures` (r:0 w:1)
+	/// Proof: `OtcSettlements::ConsecutiveFailures` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn reset_settlement_breaker() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_734_000 picoseconds.
+		Weight::from_parts(9_021_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OtcSettlements::MinProfitPerAsset` (r:0 w:1)
+	/// Proof: `OtcSettlements::MinProfitPerAsset` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_asset_min_profit() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_617_000 picoseconds.
+		Weight::from_parts(9_953_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OTC::Orders` (r:1 w:1)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderPlacedAt` (r:1 w:1)
+	/// Proof: `OTC::OrderPlacedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::OrderReservations` (r:0 w:1)
+	/// Proof: `OtcSettlements::OrderReservations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `OtcSettlements::OrderProfitTarget` (r:0 w:1)
+	/// Proof: `OtcSettlements::OrderProfitTarget` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn expire_order() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `1229`
+		//  Estimated: `4726`
+		// Minimum execution time: 45_318_000 picoseconds.
+		Weight::from_parts(45_970_000, 4726)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
@@ -36,7 +36,10 @@
 
 use codec::MaxEncodedLen;
 use frame_support::{pallet_prelude::*, require_transactional};
-use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+};
 use hydradx_traits::Inspect;
 use orml_traits::{GetByKey, MultiCurrency, NamedMultiReservableCurrency};
 use sp_core::U256;
@@ -181,6 +184,11 @@ pub mod pallet {
 	#[pallet::getter(fn orders)]
 	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId, T::AssetId>, OptionQuery>;
 
+	/// Block number in which an open order was placed
+	#[pallet::storage]
+	#[pallet::getter(fn order_placed_at)]
+	pub type OrderPlacedAt<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new OTC order
@@ -237,6 +245,7 @@ pub mod pallet {
 
 				T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
 				<Orders<T>>::insert(order_id, &order);
+				<OrderPlacedAt<T>>::insert(order_id, frame_system::Pallet::<T>::block_number());
 
 				Self::deposit_event(Event::Placed {
 					order_id,
@@ -324,6 +333,7 @@ pub mod pallet {
 
 			Self::execute_order(&order, &who, order.amount_in, order.amount_out, fee)?;
 			<Orders<T>>::remove(order_id);
+			<OrderPlacedAt<T>>::remove(order_id);
 
 			Self::deposit_event(Event::Filled {
 				order_id,
@@ -350,24 +360,33 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
 		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			<Orders<T>>::try_mutate_exists(order_id, |maybe_order| -> DispatchResult {
-				let order = maybe_order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
+			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
 
-				ensure!(order.owner == who, Error::<T>::Forbidden);
+			ensure!(order.owner == who, Error::<T>::Forbidden);
 
-				let remaining_to_unreserve =
-					T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
-				ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
-				*maybe_order = None;
+			Self::remove_order(order_id)?;
 
-				Self::deposit_event(Event::Cancelled { order_id });
-				Ok(())
-			})
+			Self::deposit_event(Event::Cancelled { order_id });
+			Ok(())
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Remove the order and unreserve its amount out for the owner of the order.
+	/// Returns the removed order.
+	#[require_transactional]
+	pub fn remove_order(order_id: OrderId) -> Result<Order<T::AccountId, T::AssetId>, DispatchError> {
+		let order = <Orders<T>>::take(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+		let remaining_to_unreserve =
+			T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
+		ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+		<OrderPlacedAt<T>>::remove(order_id);
+
+		Ok(order)
+	}
+
 	fn ensure_min_order_amount(asset: T::AssetId, amount: Balance) -> DispatchResult {
 		let min_amount = T::ExistentialDeposits::get(&asset)
 			.checked_mul(T::ExistentialDepositMultiplier::get().into())
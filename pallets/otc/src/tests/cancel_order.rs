@@ -39,6 +39,7 @@ fn cancel_order_should_work() {
 		// Assert
 		let order = OTC::orders(0);
 		assert!(order.is_none());
+		assert!(OTC::order_placed_at(0).is_none());
 
 		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
 
@@ -47,6 +47,7 @@ fn complete_fill_order_should_work() {
 		// Assert
 		let order = OTC::orders(0);
 		assert!(order.is_none());
+		assert!(OTC::order_placed_at(0).is_none());
 
 		let fee = OTC::calculate_fee(amount_out);
 
@@ -41,6 +41,7 @@ fn place_order_should_work() {
 		assert_eq!(order.amount_in, 20 * ONE);
 		assert_eq!(order.amount_out, 100 * ONE);
 		assert_eq!(order.partially_fillable, true);
+		assert_eq!(OTC::order_placed_at(0), Some(1));
 
 		expect_events(vec![Event::Placed {
 			order_id: 0,
@@ -0,0 +1,56 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the referrals pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query referral codes statistics.
+	pub trait ReferralsApi<AccountId, Balance, CodeInfo, CampaignId, RegistrationError> where
+		AccountId: Codec,
+		Balance: Codec,
+		CodeInfo: Codec,
+		CampaignId: Codec,
+		RegistrationError: Codec,
+	{
+		/// Return referral codes with the highest accrued volume, sorted in descending order.
+		fn leaderboard() -> Vec<(Vec<u8>, Balance)>;
+
+		/// Return number of registered referral codes, including aliases.
+		fn code_count() -> u32;
+
+		/// Return profile of the referral code. The code is normalized first.
+		/// Returns `None` if the code is not registered.
+		fn code_info(code: Vec<u8>) -> Option<CodeInfo>;
+
+		/// Return number of accounts linked with the referral code. The code is normalized first.
+		fn referral_count(code: Vec<u8>) -> u32;
+
+		/// Return total volume accrued by the referrers of the codes of the campaign.
+		fn campaign_volume(campaign: CampaignId) -> Balance;
+
+		/// Check whether the account can register the code, running the same checks as the registration.
+		/// Returns the reason of the first failing check.
+		fn can_register(account: AccountId, code: Vec<u8>) -> Result<(), RegistrationError>;
+
+		/// Return referral accounts up the referral chain of the account, at most `max_depth` levels.
+		/// The walk stops at an account which is not linked or at a cycle.
+		fn referral_chain(account: AccountId, max_depth: u32) -> Vec<AccountId>;
+	}
+}
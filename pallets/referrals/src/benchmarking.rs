@@ -19,6 +19,7 @@ use super::*;
 
 use frame_benchmarking::account;
 use frame_benchmarking::benchmarks;
+use frame_support::storage::StoragePrefixedMap;
 use frame_support::traits::tokens::fungibles::{Inspect, Mutate};
 use frame_system::RawOrigin;
 use sp_std::vec;
@@ -34,6 +35,8 @@ benchmarks! {
 		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
 		let (asset, fee, _) = T::RegistrationFee::get();
 		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		let pattern: ReferralCodePattern<T::CodeLength> = vec![CharClass::Any; T::CodeLength::get() as usize].try_into().unwrap();
+		Pallet::<T>::set_code_pattern(RawOrigin::Root.into(), Some(pattern))?;
 
 	}: _(RawOrigin::Signed(caller.clone()), code.clone())
 	verify {
@@ -44,6 +47,21 @@ benchmarks! {
 		assert_eq!(entry, Some(caller));
 	}
 
+	register_code_in_campaign{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		let pattern: ReferralCodePattern<T::CodeLength> = vec![CharClass::Any; T::CodeLength::get() as usize].try_into().unwrap();
+		Pallet::<T>::set_code_pattern(RawOrigin::Root.into(), Some(pattern))?;
+
+	}: _(RawOrigin::Signed(caller.clone()), code.clone(), 1)
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::referral_account(c.clone()), Some(caller));
+		assert_eq!(Pallet::<T>::code_campaign(c), Some(1));
+	}
+
 	link_code{
 		let caller: T::AccountId = account("caller", 0, 1);
 		let user: T::AccountId = account("user", 0, 1);
@@ -57,6 +75,123 @@ benchmarks! {
 		assert_eq!(entry, Some(caller));
 	}
 
+	unlink_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let user: T::AccountId = account("user", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+		Pallet::<T>::link_code(RawOrigin::Signed(user.clone()).into(), code.clone())?;
+	}: _(RawOrigin::Signed(user.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::linked_referral_account(user), None);
+		assert_eq!(Pallet::<T>::referral_count(Pallet::<T>::normalize_code(code)), 0);
+	}
+
+	offer_code_transfer{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let recipient: T::AccountId = account("recipient", 1, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+	}: _(RawOrigin::Signed(caller), code.clone(), recipient.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::code_transfer_offer(c).map(|(to, _)| to), Some(recipient));
+	}
+
+	accept_code_transfer{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let recipient: T::AccountId = account("recipient", 1, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let recipient_code: ReferralCode<T::CodeLength> = vec![b'y'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset.clone(), &caller, 2 * fee)?;
+		T::Currency::mint_into(asset, &recipient, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+		Pallet::<T>::register_code(RawOrigin::Signed(recipient.clone()).into(), recipient_code)?;
+		Pallet::<T>::offer_code_transfer(RawOrigin::Signed(caller).into(), code.clone(), recipient.clone())?;
+	}: _(RawOrigin::Signed(recipient.clone()), code.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::referral_account(c), Some(recipient));
+	}
+
+	cancel_code_transfer{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let recipient: T::AccountId = account("recipient", 1, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+		Pallet::<T>::offer_code_transfer(RawOrigin::Signed(caller).into(), code.clone(), recipient.clone())?;
+	}: _(RawOrigin::Signed(recipient), code.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::code_transfer_offer(c), None);
+	}
+
+	set_code_expiry{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller).into(), code.clone())?;
+		let expires_at = frame_system::Pallet::<T>::block_number() + T::RenewalExtension::get() + 1u32.into();
+	}: _(RawOrigin::Root, code.clone(), Some(expires_at), true)
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::code_expiry(&c), Some(expires_at));
+		assert!(AutoRenew::<T>::contains_key(c));
+	}
+
+	remove_expired_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let remover: T::AccountId = account("remover", 1, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let alias: ReferralCode<T::CodeLength> = vec![b'y'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 3 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+		Pallet::<T>::register_alias(RawOrigin::Signed(caller.clone()).into(), alias.clone())?;
+		let expires_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		Pallet::<T>::set_code_expiry(RawOrigin::Root.into(), code.clone(), Some(expires_at), true)?;
+		frame_system::Pallet::<T>::set_block_number(expires_at);
+		// The worst case is when the main code is removed and the alias becomes the main code.
+	}: _(RawOrigin::Signed(remover), code.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::referral_account(c), None);
+		assert_eq!(Pallet::<T>::referral_code(caller), Some(Pallet::<T>::normalize_code(alias)));
+	}
+
+	backfill_account_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		ReferralCodes::<T>::insert(&code, &caller);
+		AccountCodesMigrationCursor::<T>::put(BoundedVec::truncate_from(ReferralCodes::<T>::final_prefix().to_vec()));
+	}: {
+		migration::backfill_account_codes::<T>(frame_support::weights::Weight::MAX);
+	}
+	verify {
+		assert_eq!(Pallet::<T>::account_codes(caller).into_inner(), vec![code]);
+		assert!(AccountCodesMigrationCursor::<T>::get().is_none());
+	}
+
+	snapshot_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		ReferralCodes::<T>::insert(&code, &caller);
+		SnapshotCursor::<T>::put((0, BoundedVec::truncate_from(ReferralCodes::<T>::final_prefix().to_vec())));
+	}: {
+		Pallet::<T>::snapshot_codes(1u32.into());
+	}
+	verify {
+		assert!(Pallet::<T>::code_snapshot(0, code).is_some());
+	}
+
 	convert{
 		let caller: T::AccountId = account("caller", 0, 1);
 		let (asset_id, amount) = T::BenchmarkHelper::prepare_convertible_asset_and_amount();
@@ -77,7 +212,7 @@ benchmarks! {
 		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
 		let (asset, fee, _) = T::RegistrationFee::get();
 		T::Currency::mint_into(asset, &caller, 2 * fee)?;
-		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code)?;
+		Pallet::<T>::register_code_in_campaign(RawOrigin::Signed(caller.clone()).into(), code, 1)?;
 		let caller_balance = T::Currency::balance(T::RewardAsset::get(), &caller);
 
 		// The worst case is when referrer account is updated to the top tier in one call
@@ -98,6 +233,152 @@ benchmarks! {
 		assert_eq!(total, top_tier_volume);
 	}
 
+	register_alias{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let alias: ReferralCode<T::CodeLength> = vec![b'y'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 3 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code)?;
+	}: _(RawOrigin::Signed(caller.clone()), alias.clone())
+	verify {
+		let a = Pallet::<T>::normalize_code(alias);
+		let entry = Pallet::<T>::referral_account(a.clone());
+		assert_eq!(entry, Some(caller.clone()));
+		assert_eq!(Pallet::<T>::account_codes(caller).into_inner(), vec![a]);
+	}
+
+	force_register_code{
+		let account: T::AccountId = account("account", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'1'; T::CodeLength::get() as usize].try_into().unwrap();
+	}: _(RawOrigin::Root, account.clone(), code.clone())
+	verify {
+		let entry = Pallet::<T>::referral_account(code);
+		assert_eq!(entry, Some(account));
+	}
+
+	set_code_reward_override{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller).into(), code.clone())?;
+		let rate = T::MaxRewardRate::get();
+	}: _(RawOrigin::Root, code.clone(), Some(rate))
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::code_reward_override(c), Some(rate));
+	}
+
+	set_code_active{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+	}: _(RawOrigin::Signed(caller), code.clone(), false)
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert!(InactiveCodes::<T>::contains_key(c));
+	}
+
+	set_description{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+		let description = vec![b'd'; T::MaxDescLen::get() as usize];
+	}: _(RawOrigin::Signed(caller), code.clone(), description.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::code_description(c).map(|d| d.into_inner()), Some(description));
+	}
+
+	set_code_manager{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let manager: T::AccountId = account("manager", 1, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+	}: _(RawOrigin::Signed(caller), code.clone(), Some(manager.clone()))
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::code_manager(c), Some(manager));
+	}
+
+	migrate_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let new_code: ReferralCode<T::CodeLength> = vec![b'y'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+		Pallet::<T>::set_code_reward_override(RawOrigin::Root.into(), code.clone(), Some(T::MaxRewardRate::get()))?;
+		Pallet::<T>::assign_rewards(RawOrigin::Signed(caller.clone()).into(), account("payee", 0, 1))?;
+	}: _(RawOrigin::Root, code.clone(), new_code.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		let new_c = Pallet::<T>::normalize_code(new_code);
+		assert_eq!(Pallet::<T>::referral_account(c), None);
+		assert_eq!(Pallet::<T>::referral_code(caller), Some(new_c));
+	}
+
+	assign_rewards{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let payee: T::AccountId = account("payee", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+	}: _(RawOrigin::Signed(caller), payee.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::reward_payee(c), Some(payee));
+	}
+
+	set_code_pattern{
+		let pattern: ReferralCodePattern<T::CodeLength> = vec![CharClass::Any; T::CodeLength::get() as usize].try_into().unwrap();
+	}: _(RawOrigin::Root, Some(pattern.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::code_pattern(), Some(pattern));
+	}
+
+	deregister_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let alias: ReferralCode<T::CodeLength> = vec![b'y'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 3 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+		Pallet::<T>::register_alias(RawOrigin::Signed(caller.clone()).into(), alias.clone())?;
+		// The worst case is when the main code is deregistered and the alias becomes the main code.
+	}: _(RawOrigin::Signed(caller.clone()), code.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::referral_account(c), None);
+		assert_eq!(Pallet::<T>::referral_code(caller), Some(Pallet::<T>::normalize_code(alias)));
+	}
+
+	deregister_all{
+		let a in 0 .. T::MaxAliases::get();
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, (a as u128 + 2) * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code)?;
+		for i in 0..a {
+			let mut alias = vec![b'y'; T::CodeLength::get() as usize];
+			alias[0] = b'a' + i as u8;
+			Pallet::<T>::register_alias(RawOrigin::Signed(caller.clone()).into(), alias.try_into().unwrap())?;
+		}
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::referral_code(caller.clone()), None);
+		assert!(Pallet::<T>::account_codes(caller).is_empty());
+	}
+
 	set_reward_percentage{
 		let referrer_percentage = Permill::from_percent(40);
 		let trader_percentage = Permill::from_percent(30);
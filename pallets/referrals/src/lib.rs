@@ -28,10 +28,51 @@
 //! The higher level, the better reward.
 //!
 //! Rewards are accumulated in the pallet's account and if it is not RewardAsset, it is converted to RewardAsset prior to claim.
+//! If `RewardAssetForTier` provides an asset for the level of the claiming account, the claimed rewards are converted
+//! from RewardAsset to that asset before they are paid.
+//! Rewards claimed by an account are capped by `RewardCapPerPeriod` in each period of `RewardPeriod` blocks.
+//! `RewardPotShare` of the registration fee is added to the rewards, the rest goes to the beneficiary
+//! of `RegistrationFee`. Vanity codes shorter than `VanityLengthThreshold` pay `VanityFee` instead.
+//!
+//! Governance can restrict the structure of referral codes with `set_code_pattern`, e.g. three letters followed
+//! by four digits. Codes not matching the pattern can't be registered with `register_code`.
+//! `AuthorityOrigin` can link an account that already traded without a code with `force_link`.
+//! Owners can disable their codes temporarily with `set_code_active`. Accounts can't be linked to disabled codes,
+//! while the accrued rewards can still be claimed.
+//! Codes registered with `register_code_in_campaign` belong to a campaign. Volume accrued by the referrers of
+//! the codes is accumulated per campaign in `CampaignVolume`.
+//! Accrued volume is reported with `VolumeAccrued`. If `EmitVolumeEvents` is `false`, the event is emitted only
+//! when the volume accrued since the last event of the code reaches `VolumeEventThreshold`.
+//! Owners can attach a public description to their codes, e.g. a social handle, with `set_description`.
+//! Number of accounts linked with each code is tracked in `ReferralCount`.
+//! An account linked to a wrong code can unlink it with `unlink_code` within `UnlinkGracePeriod` blocks of the link.
+//! Renames are recorded in `RenamedCodes`, so that unlinking decreases the count of the renamed code.
+//! Ownership of a code is transferred in two steps. The owner offers the code with `offer_code_transfer` and
+//! the recipient accepts it with `accept_code_transfer` within `TransferOfferTtl` blocks. Either of them can cancel
+//! the offer with `cancel_code_transfer`.
+//! `AuthorityOrigin` can set an expiry of a code with `set_code_expiry`. Accounts can't be linked to expired codes
+//! and anyone can remove an expired code with `remove_expired_code`. Expiry of auto-renew codes is extended to
+//! `RenewalExtension` blocks from the current block every time the code is linked or accrues volume, so only
+//! dormant codes expire.
+//! Volume and referral count of every code are copied to `CodeSnapshots` at each boundary of `SnapshotPeriod`
+//! blocks, e.g. for reporting. At most `MaxCodesPerSnapshot` codes are copied in one block, the snapshot continues
+//! in the following blocks from `SnapshotCursor`.
+//! Aliases registered before `AccountCodes` was introduced are backfilled from `ReferralCodes` in `on_idle`,
+//! in chunks bounded by the idle weight of the block, starting from `AccountCodesMigrationCursor`.
+//!
+//! Referrers can assign the rewards to another payout account with `assign_rewards`. Rewards claimed afterwards
+//! are paid to the payee instead of the referrer.
+//!
+//! Referrers can be linked to other referrers, which forms a referral chain. Referrers up the chain receive a share
+//! of the referrer reward, decayed by `ReferralChainDecay` on each level, up to `MaxReferralDepth` levels.
+//! The shares are carved out of the referrer reward, so the chain doesn't take more of the fee than the referrer.
+//! The referral chain of an account can be queried with `referral_chain`.
 //!
 //! ### Terminology
 //!
-//! * **Referral code:**  a string of certain size that identifies the referrer. Must be alphanumeric and upper case.
+//! * **Referral code:**  a string of certain size that identifies the referrer. Must be alphanumeric. Letter case is
+//! normalized according to `NormalizeCase` (upper case by default).
+//! * **Alias:**  additional referral code registered by the referrer. Linking an alias links the trader to the same referrer.
 //! * **Referrer:**  user that registered a code
 //! * **Trader:**  user that does a trade
 //! * **Reward Asset:**  id of an asset which rewards are paid in. Usually native asset.
@@ -50,8 +91,9 @@ pub mod traits;
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::{DispatchResult, Get};
-use frame_support::traits::fungibles::Mutate;
-use frame_support::traits::tokens::Preservation;
+use frame_support::storage::StoragePrefixedMap;
+use frame_support::traits::fungibles::{Inspect, Mutate};
+use frame_support::traits::tokens::{Fortitude, Preservation};
 use frame_support::{defensive, ensure, transactional};
 use frame_system::{
 	ensure_signed,
@@ -65,10 +107,12 @@ use sp_core::U256;
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_runtime::traits::AccountIdConversion;
 use sp_runtime::Rounding;
+use sp_runtime::SaturatedConversion;
 use sp_runtime::{
-	traits::{CheckedAdd, Zero},
+	traits::{CheckedAdd, CheckedDiv, Zero},
 	ArithmeticError, DispatchError, Permill, RuntimeDebug,
 };
+use sp_std::vec::Vec;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub use crate::traits::BenchmarkHelper;
@@ -79,6 +123,18 @@ pub use weights::WeightInfo;
 
 pub type Balance = u128;
 pub type ReferralCode<S> = BoundedVec<u8, S>;
+pub type ReferralCodePattern<S> = BoundedVec<CharClass, S>;
+pub type CampaignId = u32;
+pub type PeriodIndex = u32;
+
+/// Maximum number of levels returned by `referral_chain`, regardless of the requested depth.
+pub const MAX_REFERRAL_CHAIN_QUERY_DEPTH: u32 = 32;
+
+/// Maximum length of the raw storage key kept in `AccountCodesMigrationCursor`.
+pub const MAX_MIGRATION_CURSOR_LEN: u32 = 128;
+
+/// Maximum number of renames followed in `RenamedCodes` to find the current code of a link.
+pub const MAX_RENAME_LOOKUPS: u32 = 8;
 
 /// Referrer level.
 /// Indicates current level of the referrer to determine which reward percentages are used.
@@ -123,6 +179,39 @@ impl Level {
 	}
 }
 
+/// Letter case referral codes are converted to.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum CaseMode {
+	/// Convert all characters to upper case.
+	#[default]
+	Upper,
+	/// Convert all characters to lower case.
+	Lower,
+	/// Keep the characters as they are. Codes that differ only in letter case are different codes.
+	None,
+}
+
+/// Character class of one character of a referral code pattern.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum CharClass {
+	/// ASCII letter.
+	Letter,
+	/// ASCII digit.
+	Digit,
+	/// Any alphanumeric character.
+	Any,
+}
+
+impl CharClass {
+	pub fn matches(&self, c: u8) -> bool {
+		match self {
+			Self::Letter => c.is_ascii_alphabetic(),
+			Self::Digit => c.is_ascii_digit(),
+			Self::Any => c.is_ascii_alphanumeric(),
+		}
+	}
+}
+
 #[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct FeeDistribution {
 	/// Percentage of the fee that goes to the referrer.
@@ -133,6 +222,59 @@ pub struct FeeDistribution {
 	pub external: Permill,
 }
 
+/// Stats of a referral code copied to `CodeSnapshots` at the end of a snapshot period.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CodeStats {
+	/// Volume accrued by the owner of the code.
+	pub volume: Balance,
+	/// Number of accounts linked with the code.
+	pub referral_count: u32,
+}
+
+/// Profile of a referral code.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct CodeInfo<AccountId, BlockNumber> {
+	/// Account the code is registered for.
+	pub owner: AccountId,
+	/// Current level of the owner.
+	pub level: Level,
+	/// Volume accrued by the owner, used to unlock next level.
+	pub volume: Balance,
+	/// Block number the code was registered at. `None` for codes registered before it was tracked.
+	pub registered_at: Option<BlockNumber>,
+	/// Code as submitted by the owner, with the original letter case. `None` if not stored.
+	pub display: Option<Vec<u8>>,
+	/// Public description of the code set by the owner. `None` if not set.
+	pub description: Option<Vec<u8>>,
+	/// Number of accounts linked with the code.
+	pub referral_count: u32,
+	/// Block number at which the code expires. `None` if the code doesn't expire.
+	pub expires_at: Option<BlockNumber>,
+	/// Whether the expiry of the code is extended when the code is used.
+	pub auto_renew: bool,
+}
+
+/// Reason why an account can't register a referral code, returned by `can_register`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum RegistrationError {
+	/// Referral code is longer than `CodeLength`.
+	TooLong,
+	/// Referral code is shorter than `MinCodeLength`.
+	TooShort,
+	/// Referral code contains invalid character.
+	InvalidCharacter,
+	/// Referral code consists of digits only.
+	NumericCodeReserved,
+	/// Referral code doesn't match the pattern of referral codes.
+	PatternMismatch,
+	/// The account has already a code registered.
+	AlreadyRegistered,
+	/// Referral code already exists.
+	AlreadyExists,
+	/// The account can't pay the registration fee.
+	InsufficientBalance,
+}
+
 #[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
 pub struct AssetAmount<AssetId> {
 	asset_id: AssetId,
@@ -156,7 +298,10 @@ pub mod pallet {
 	use hydra_dx_math::ema::EmaPrice;
 	use sp_runtime::traits::Zero;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -183,6 +328,9 @@ pub mod pallet {
 		#[pallet::constant]
 		type RewardAsset: Get<Self::AssetId>;
 
+		/// Asset the rewards are paid in for each level. `None` means the rewards are paid in `RewardAsset`.
+		type RewardAssetForTier: GetByKey<Level, Option<Self::AssetId>>;
+
 		/// Pallet id. Determines account which holds accumulated rewards in various assets.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -192,6 +340,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type RegistrationFee: Get<(Self::AssetId, Balance, Self::AccountId)>;
 
+		/// Share of the registration fee transferred to the reward pot.
+		/// The rest of the fee is transferred to the beneficiary account of `RegistrationFee`.
+		#[pallet::constant]
+		type RewardPotShare: Get<Permill>;
+
+		/// Registration fee of vanity codes, paid instead of the amount of `RegistrationFee`.
+		#[pallet::constant]
+		type VanityFee: Get<Balance>;
+
+		/// Normalized codes shorter than this length are vanity codes and pay `VanityFee`.
+		#[pallet::constant]
+		type VanityLengthThreshold: Get<u32>;
+
 		/// Maximum referral code length.
 		#[pallet::constant]
 		type CodeLength: Get<u32>;
@@ -210,6 +371,100 @@ pub mod pallet {
 		#[pallet::constant]
 		type SeedNativeAmount: Get<u128>;
 
+		/// Maximum number of referral codes tracked in the leaderboard.
+		#[pallet::constant]
+		type MaxLeaderboard: Get<u32>;
+
+		/// Maximum number of aliases an account can register in addition to its referral code.
+		#[pallet::constant]
+		type MaxAliases: Get<u32>;
+
+		/// Transaction fee discount of trading calls for accounts linked to a referral account.
+		#[pallet::constant]
+		type TransactionFeeDiscount: Get<Permill>;
+
+		/// Reject referral codes consisting of digits only. Such codes can be registered only by `AuthorityOrigin`.
+		#[pallet::constant]
+		type BlockNumericCodes: Get<bool>;
+
+		/// Maximum referrer reward percentage which can be set as an override for a referral code.
+		#[pallet::constant]
+		type MaxRewardRate: Get<Permill>;
+
+		/// Letter case referral codes are converted to when registered and looked up.
+		#[pallet::constant]
+		type NormalizeCase: Get<CaseMode>;
+
+		/// Maximum number of referrers in the referral chain rewarded for a trade, including the direct referrer.
+		#[pallet::constant]
+		type MaxReferralDepth: Get<u32>;
+
+		/// Share of the reward of the previous level in the referral chain paid to the next level.
+		#[pallet::constant]
+		type ReferralChainDecay: Get<Permill>;
+
+		/// Minimum volume a referrer has to accrue before it can claim rewards.
+		///
+		/// Accrued volume is the total volume of the referrer in `Referrer` together with the pending referrer rewards.
+		/// Accounts that are not referrers don't accrue volume and can claim their trader rewards without the threshold.
+		#[pallet::constant]
+		type MinVolumeForReward: Get<Balance>;
+
+		/// Emit `CodeLinked` event for every linked account.
+		/// When false, links are counted and emitted as a single `LinksBatched` event at the end of the block.
+		#[pallet::constant]
+		type EmitLinkEvents: Get<bool>;
+
+		/// Maximum amount of rewards an account can claim in one reward period.
+		/// Rewards above the cap are kept and can be claimed in the next periods.
+		#[pallet::constant]
+		type RewardCapPerPeriod: Get<Balance>;
+
+		/// Length of the reward period in blocks. Must not be zero.
+		#[pallet::constant]
+		type RewardPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum volume accrued by a referrer account.
+		/// Volume above the cap is not credited, which bounds the level the referrer can reach.
+		#[pallet::constant]
+		type MaxAccruedVolume: Get<Balance>;
+
+		/// Maximum length of the public description of a referral code.
+		#[pallet::constant]
+		type MaxDescLen: Get<u32>;
+
+		/// Number of blocks after linking during which the linked account can unlink the code with `unlink_code`.
+		#[pallet::constant]
+		type UnlinkGracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks during which the recipient can accept an offer made with `offer_code_transfer`.
+		#[pallet::constant]
+		type TransferOfferTtl: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks from the current block the expiry of an auto-renew code is extended to when the code
+		/// is linked or accrues volume.
+		#[pallet::constant]
+		type RenewalExtension: Get<BlockNumberFor<Self>>;
+
+		/// If `true`, `VolumeAccrued` is emitted every time volume is accrued by the referrer of a code.
+		/// If `false`, the volume is accumulated in `VolumeSinceLastEvent` and the event is emitted only
+		/// when the accumulated volume reaches `VolumeEventThreshold`.
+		#[pallet::constant]
+		type EmitVolumeEvents: Get<bool>;
+
+		/// Accumulated volume of a code at which `VolumeAccrued` is emitted when `EmitVolumeEvents` is `false`.
+		#[pallet::constant]
+		type VolumeEventThreshold: Get<Balance>;
+
+		/// Length of the snapshot period in blocks. Stats of the codes are copied to `CodeSnapshots`
+		/// at the end of each period. Zero disables the snapshots.
+		#[pallet::constant]
+		type SnapshotPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of referral codes copied to `CodeSnapshots` in one block.
+		#[pallet::constant]
+		type MaxCodesPerSnapshot: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -224,6 +479,51 @@ pub mod pallet {
 	pub(super) type ReferralCodes<T: Config> =
 		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, T::AccountId>;
 
+	/// Number of registered referral codes, including aliases.
+	#[pallet::storage]
+	#[pallet::getter(fn code_count)]
+	pub(super) type CodeCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Block number of the referral code registration.
+	/// Dev note: codes registered before the storage was introduced are not included.
+	#[pallet::storage]
+	#[pallet::getter(fn code_registered_at)]
+	pub(super) type CodeRegisteredAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, BlockNumberFor<T>>;
+
+	/// Referral codes as submitted in `register_code`, with the original letter case. Used for display only.
+	/// Maps normalized code -> submitted code
+	#[pallet::storage]
+	#[pallet::getter(fn code_display)]
+	pub(super) type CodeDisplay<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, ReferralCode<T::CodeLength>>;
+
+	/// Public descriptions of referral codes set by the owners, e.g. a social handle.
+	/// Maps referral code -> description
+	#[pallet::storage]
+	#[pallet::getter(fn code_description)]
+	pub(super) type CodeDescription<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, BoundedVec<u8, T::MaxDescLen>>;
+
+	/// Accounts receiving the claimed rewards instead of the owners of the referral codes.
+	/// Maps referral code -> payee account
+	#[pallet::storage]
+	#[pallet::getter(fn reward_payee)]
+	pub(super) type RewardPayee<T: Config> = StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, T::AccountId>;
+
+	/// Accounts allowed to claim the rewards of the owners of the referral codes with `claim_rewards_for`.
+	/// Maps referral code -> manager account
+	#[pallet::storage]
+	#[pallet::getter(fn code_manager)]
+	pub(super) type CodeManager<T: Config> = StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, T::AccountId>;
+
+	/// Number of accounts linked with a referral code.
+	/// Maps referral code -> count
+	#[pallet::storage]
+	#[pallet::getter(fn referral_count)]
+	pub(super) type ReferralCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, u32, ValueQuery>;
+
 	/// Referral accounts
 	/// Maps an account to a referral code.
 	#[pallet::storage]
@@ -231,12 +531,54 @@ pub mod pallet {
 	pub(super) type ReferralAccounts<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, ReferralCode<T::CodeLength>>;
 
+	/// Referral code aliases.
+	/// Maps an account to additional referral codes registered by the account.
+	/// Dev note: the main code of the account is stored in `ReferralAccounts` and it is not included here.
+	#[pallet::storage]
+	#[pallet::getter(fn account_codes)]
+	pub(super) type AccountCodes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<ReferralCode<T::CodeLength>, T::MaxAliases>,
+		ValueQuery,
+	>;
+
+	/// Raw key of the last entry of `ReferralCodes` processed by the backfill of `AccountCodes`.
+	/// Dev note: set by `migration::MigrateToV2` and removed when all referral codes were processed.
+	#[pallet::storage]
+	pub(super) type AccountCodesMigrationCursor<T: Config> =
+		StorageValue<_, BoundedVec<u8, ConstU32<MAX_MIGRATION_CURSOR_LEN>>>;
+
+	/// Referrer reward percentage overrides.
+	/// Maps a referral code to the referrer reward percentage used instead of the level and asset rewards.
+	#[pallet::storage]
+	#[pallet::getter(fn code_reward_override)]
+	pub(super) type CodeRewardOverride<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, Permill>;
+
 	/// Linked accounts.
 	/// Maps an account to a referral account.
 	#[pallet::storage]
 	#[pallet::getter(fn linked_referral_account)]
 	pub(super) type LinkedAccounts<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+	/// Block number of the link and referral code used to link an account.
+	/// Maps an account to (block number, referral code).
+	/// Dev note: links made before the storage was introduced are not included.
+	#[pallet::storage]
+	#[pallet::getter(fn linked_at)]
+	pub(super) type LinkedAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, ReferralCode<T::CodeLength>)>;
+
+	/// Renamed referral codes.
+	/// Maps a code renamed with `migrate_code` -> new code.
+	/// Used to find the current code of links made before the rename.
+	#[pallet::storage]
+	#[pallet::getter(fn renamed_code)]
+	pub(super) type RenamedCodes<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, ReferralCode<T::CodeLength>>;
+
 	/// Shares of a referral account
 	#[pallet::storage]
 	#[pallet::getter(fn referrer_shares)]
@@ -272,6 +614,88 @@ pub mod pallet {
 	#[pallet::getter(fn pending_conversions)]
 	pub(super) type PendingConversions<T: Config> = CountedStorageMap<_, Blake2_128Concat, T::AssetId, ()>;
 
+	/// Referral codes with the highest accrued volume.
+	/// Sorted by volume in descending order and bounded by `MaxLeaderboard`.
+	/// Dev note: the leaderboard is updated only when a code's volume changes, so it is an approximate top-N.
+	#[pallet::storage]
+	#[pallet::getter(fn leaderboard)]
+	pub(super) type Leaderboard<T: Config> =
+		StorageValue<_, BoundedVec<(ReferralCode<T::CodeLength>, Balance), T::MaxLeaderboard>, ValueQuery>;
+
+	/// Rewards claimed by an account in a reward period.
+	/// Maps account to (reward period, claimed amount). The amount is reset when a new reward period starts.
+	#[pallet::storage]
+	#[pallet::getter(fn rewards_claimed_in_period)]
+	pub(super) type RewardsClaimedInPeriod<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, Balance), ValueQuery>;
+
+	/// Number of accounts linked in the current block when `CodeLinked` events are not emitted.
+	#[pallet::storage]
+	#[pallet::getter(fn links_in_period)]
+	pub(super) type LinksInPeriod<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Pattern referral codes registered with `register_code` must match, one character class per character.
+	/// All codes are accepted if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn code_pattern)]
+	pub(super) type CodePattern<T: Config> = StorageValue<_, ReferralCodePattern<T::CodeLength>, OptionQuery>;
+
+	/// Campaigns of referral codes.
+	/// Maps referral code -> campaign id
+	#[pallet::storage]
+	#[pallet::getter(fn code_campaign)]
+	pub(super) type CodeCampaign<T: Config> = StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, CampaignId>;
+
+	/// Referral codes disabled by their owners. Accounts can't be linked to inactive codes.
+	/// Maps referral code -> ()
+	#[pallet::storage]
+	pub(super) type InactiveCodes<T: Config> = StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, ()>;
+
+	/// Total volume accrued by the referrers of the codes of a campaign.
+	#[pallet::storage]
+	#[pallet::getter(fn campaign_volume)]
+	pub(super) type CampaignVolume<T: Config> = StorageMap<_, Blake2_128Concat, CampaignId, Balance, ValueQuery>;
+
+	/// Volume accrued by the referrer of a code since the last `VolumeAccrued` event of the code.
+	/// Used only when `EmitVolumeEvents` is `false`.
+	#[pallet::storage]
+	#[pallet::getter(fn volume_since_last_event)]
+	pub(super) type VolumeSinceLastEvent<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, Balance, ValueQuery>;
+
+	/// Pending offers to transfer the ownership of referral codes.
+	/// Maps referral code -> (recipient, block number when the offer expires)
+	#[pallet::storage]
+	#[pallet::getter(fn code_transfer_offer)]
+	pub(super) type CodeTransferOffers<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, (T::AccountId, BlockNumberFor<T>)>;
+
+	/// Block number at which the referral code expires. Accounts can't be linked to expired codes.
+	/// Maps referral code -> block number
+	#[pallet::storage]
+	#[pallet::getter(fn code_expiry)]
+	pub(super) type CodeExpiry<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, BlockNumberFor<T>>;
+
+	/// Referral codes with the expiry extended by `RenewalExtension` when the code is used.
+	/// Maps referral code -> ()
+	#[pallet::storage]
+	pub(super) type AutoRenew<T: Config> = StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, ()>;
+
+	/// Stats of the referral codes at the end of the snapshot periods.
+	/// Maps (period index, referral code) -> stats
+	/// Dev note: codes copied in the blocks after the period boundary reflect the stats of that block.
+	#[pallet::storage]
+	#[pallet::getter(fn code_snapshot)]
+	pub(super) type CodeSnapshots<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, PeriodIndex, Blake2_128Concat, ReferralCode<T::CodeLength>, CodeStats>;
+
+	/// Period index of the snapshot in progress and raw key of the last entry of `ReferralCodes` copied to it.
+	/// Removed when all referral codes were copied.
+	#[pallet::storage]
+	pub(super) type SnapshotCursor<T: Config> =
+		StorageValue<_, (PeriodIndex, BoundedVec<u8, ConstU32<MAX_MIGRATION_CURSOR_LEN>>)>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -280,13 +704,21 @@ pub mod pallet {
 			code: ReferralCode<T::CodeLength>,
 			account: T::AccountId,
 		},
+		/// Registration fee has been paid to the reward pot and the fee collector.
+		RegistrationFeePaid {
+			account: T::AccountId,
+			fee: Balance,
+			reward_pot_amount: Balance,
+			fee_collector_amount: Balance,
+		},
 		/// Referral code has been linked to an account.
 		CodeLinked {
 			account: T::AccountId,
 			code: ReferralCode<T::CodeLength>,
 			referral_account: T::AccountId,
+			forced: bool,
 		},
-		/// Asset has been converted to RewardAsset.
+		/// Asset has been converted to RewardAsset, or claimed rewards to the reward asset of a level.
 		Converted {
 			from: AssetAmount<T::AssetId>,
 			to: AssetAmount<T::AssetId>,
@@ -305,6 +737,90 @@ pub mod pallet {
 		},
 		/// Referrer reached new level.
 		LevelUp { who: T::AccountId, level: Level },
+		/// Referral code has been deregistered.
+		CodeDeregistered {
+			code: ReferralCode<T::CodeLength>,
+			account: T::AccountId,
+		},
+		/// Referrer reward percentage override has been set or removed.
+		CodeRewardOverrideUpdated {
+			code: ReferralCode<T::CodeLength>,
+			rate: Option<Permill>,
+		},
+		/// Referral code has been renamed.
+		CodeMigrated {
+			old: ReferralCode<T::CodeLength>,
+			new: ReferralCode<T::CodeLength>,
+		},
+		/// Accounts have been linked to referral codes in the block.
+		LinksBatched { count: u32 },
+		/// Rewards above `RewardCapPerPeriod` have been deferred to the next reward period.
+		RewardDeferred { who: T::AccountId, amount: Balance },
+		/// Rewards of the referral code have been assigned to the payee.
+		RewardsAssigned {
+			code: ReferralCode<T::CodeLength>,
+			payee: T::AccountId,
+		},
+		/// Pattern of referral codes has been set or removed.
+		CodePatternSet {
+			pattern: Option<ReferralCodePattern<T::CodeLength>>,
+		},
+		/// Referral code has been registered in a campaign.
+		CodeCampaignSet {
+			code: ReferralCode<T::CodeLength>,
+			campaign: CampaignId,
+		},
+		/// Referral code has been enabled or disabled by the owner.
+		CodeActiveSet {
+			code: ReferralCode<T::CodeLength>,
+			active: bool,
+		},
+		/// Accrued volume of the referrer reached `MaxAccruedVolume`.
+		VolumeCapReached { who: T::AccountId },
+		/// Volume has been accrued by the referrer of the code.
+		/// `amount` is the volume accrued since the last event of the code, `total` is the total volume of the referrer.
+		VolumeAccrued {
+			code: ReferralCode<T::CodeLength>,
+			amount: Balance,
+			total: Balance,
+		},
+		/// Public description of the referral code has been set or removed.
+		CodeDescriptionSet {
+			code: ReferralCode<T::CodeLength>,
+			description: BoundedVec<u8, T::MaxDescLen>,
+		},
+		/// Manager of the referral code has been set or removed.
+		CodeManagerSet {
+			code: ReferralCode<T::CodeLength>,
+			manager: Option<T::AccountId>,
+		},
+		/// Account has been unlinked from the referral account.
+		CodeUnlinked {
+			account: T::AccountId,
+			code: ReferralCode<T::CodeLength>,
+			referral_account: T::AccountId,
+		},
+		/// Owner of the referral code has offered to transfer the code.
+		CodeTransferOffered {
+			code: ReferralCode<T::CodeLength>,
+			from: T::AccountId,
+			to: T::AccountId,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Ownership of the referral code has been transferred.
+		CodeTransferred {
+			code: ReferralCode<T::CodeLength>,
+			from: T::AccountId,
+			to: T::AccountId,
+		},
+		/// Offer to transfer the referral code has been cancelled.
+		CodeTransferCancelled { code: ReferralCode<T::CodeLength> },
+		/// Expiry of the referral code has been set or removed.
+		CodeExpirySet {
+			code: ReferralCode<T::CodeLength>,
+			expires_at: Option<BlockNumberFor<T>>,
+			auto_renew: bool,
+		},
 	}
 
 	#[pallet::error]
@@ -338,6 +854,52 @@ pub mod pallet {
 		ConversionMinTradingAmountNotReached,
 		/// Zero amount received from conversion.
 		ConversionZeroAmountReceived,
+		/// The account has no code registered.
+		NotRegistered,
+		/// Maximum number of aliases has been reached.
+		TooManyAliases,
+		/// Referral code is not owned by the account.
+		NotCodeOwner,
+		/// Referral codes consisting of digits only are reserved.
+		NumericCodeReserved,
+		/// Reward percentage exceeds `MaxRewardRate`.
+		RewardRateTooHigh,
+		/// Linking the account would create a cycle in the referral chain.
+		CycleDetected,
+		/// Accrued volume of the referrer is below `MinVolumeForReward`.
+		VolumeBelowThreshold,
+		/// Referral code doesn't match the pattern of referral codes.
+		PatternMismatch,
+		/// Referral code has been disabled by the owner.
+		CodeInactive,
+		/// Description is longer than `MaxDescLen`.
+		DescriptionTooLong,
+		/// Description contains characters other than printable ASCII.
+		InvalidDescription,
+		/// The account is not the manager of the referral code.
+		NotManager,
+		/// The account is not linked to any referral account.
+		NotLinked,
+		/// `UnlinkGracePeriod` since the link has passed, or the link was made before the link block was tracked.
+		UnlinkWindowClosed,
+		/// The referral code can't be offered to its owner.
+		SelfTransferNotAllowed,
+		/// There is no pending offer to transfer the referral code.
+		TransferOfferNotFound,
+		/// `TransferOfferTtl` since the offer has passed.
+		TransferOfferExpired,
+		/// The account is not the recipient of the offer.
+		NotOfferRecipient,
+		/// The account is neither the owner of the referral code nor the recipient of the offer.
+		NotOfferParty,
+		/// Referral code has expired.
+		CodeExpired,
+		/// Expiry must be after the current block.
+		InvalidExpiry,
+		/// Referral code has no expiry or the expiry has not been reached yet.
+		CodeNotExpired,
+		/// Referral code is an alias, not the main referral code of the referrer.
+		NotMainCode,
 	}
 
 	#[pallet::call]
@@ -349,7 +911,10 @@ pub mod pallet {
 		///
 		/// Length of the `code` must be at least `T::MinCodeLength`.
 		/// Maximum length is limited to `T::CodeLength`.
-		/// `code` must contain only alfa-numeric characters and all characters will be converted to upper case.
+		/// `code` must contain only alfa-numeric characters.
+		/// Letter case of all characters will be converted according to `T::NormalizeCase`.
+		/// `code` consisting of digits only is rejected if `T::BlockNumericCodes` is set.
+		/// `code` must match `CodePattern` if set.
 		///
 		/// Parameters:
 		/// - `code`: Code to register. Must follow the restrictions.
@@ -359,68 +924,29 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::register_code())]
 		pub fn register_code(origin: OriginFor<T>, code: ReferralCode<T::CodeLength>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(
-				ReferralAccounts::<T>::get(&who).is_none(),
-				Error::<T>::AlreadyRegistered
-			);
-
-			ensure!(code.len() >= T::MinCodeLength::get() as usize, Error::<T>::TooShort);
-
-			ensure!(
-				code.clone()
-					.into_inner()
-					.iter()
-					.all(|c| char::is_alphanumeric(*c as char)),
-				Error::<T>::InvalidCharacter
-			);
-
-			let code = Self::normalize_code(code);
-
-			ReferralCodes::<T>::mutate(code.clone(), |v| -> DispatchResult {
-				ensure!(v.is_none(), Error::<T>::AlreadyExists);
-
-				let (fee_asset, fee_amount, beneficiary) = T::RegistrationFee::get();
-				T::Currency::transfer(fee_asset, &who, &beneficiary, fee_amount, Preservation::Preserve)?;
-
-				*v = Some(who.clone());
-				Referrer::<T>::insert(&who, (Level::default(), Balance::zero()));
-				ReferralAccounts::<T>::insert(&who, code.clone());
-				Self::deposit_event(Event::CodeRegistered { code, account: who });
-				Ok(())
-			})
+			Self::do_register_submitted_code(who, code, None)
 		}
 
 		/// Link a code to an account.
 		///
 		/// `Code` must be valid registered code. Otherwise `InvalidCode` is returned.
+		/// Linking to a code disabled by its owner fails with `CodeInactive`.
 		///
 		/// Signer account is linked to the referral account of the code.
+		/// Linking fails with `CycleDetected` if the signer account is found in the referral chain
+		/// of the referral account within `T::MaxReferralDepth` levels.
 		///
 		/// Parameters:
 		/// - `code`: Code to use to link the signer account to.
 		///
-		/// Emits `CodeLinked` event when successful.
+		/// Emits `CodeLinked` event when successful and `T::EmitLinkEvents` is true.
+		/// Otherwise the link is counted in `LinksBatched` event emitted at the end of the block.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::link_code())]
+		#[pallet::weight(<T as Config>::WeightInfo::link_code()
+			.saturating_add(T::DbWeight::get().reads(T::MaxReferralDepth::get().into())))]
 		pub fn link_code(origin: OriginFor<T>, code: ReferralCode<T::CodeLength>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let code = Self::normalize_code(code);
-			let ref_account = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
-
-			LinkedAccounts::<T>::mutate(who.clone(), |v| -> DispatchResult {
-				ensure!(v.is_none(), Error::<T>::AlreadyLinked);
-
-				ensure!(who != ref_account, Error::<T>::LinkNotAllowed);
-
-				*v = Some(ref_account.clone());
-				Self::deposit_event(Event::CodeLinked {
-					account: who,
-					code,
-					referral_account: ref_account,
-				});
-				Ok(())
-			})?;
-			Ok(())
+			Self::do_link_code(who, code, false)
 		}
 
 		/// Convert accrued asset amount to reward currency.
@@ -460,102 +986,32 @@ pub mod pallet {
 		///
 		/// Reward amount is calculated based on the shares of the signer account.
 		///
+		/// Rewards are paid to the payee assigned to the referral code of the signer account with `assign_rewards`,
+		/// or to the signer account if there is none.
+		///
 		/// if the signer account is referrer account, total accumulated rewards is updated as well as referrer level if reached.
 		///
+		/// At most `RewardCapPerPeriod` of rewards is paid out in a reward period. Shares of the rewards above the cap
+		/// are kept and can be claimed in the next reward periods.
+		///
+		/// If `RewardAssetForTier` provides an asset for the level of the signer account, the rewards are converted
+		/// to that asset before they are paid. Amounts in the `Claimed` event are always in RewardAsset.
+		///
 		/// Emits `Claimed` event when successful.
+		/// Emits `RewardDeferred` event when part of the rewards was deferred to the next reward period.
 		#[pallet::call_index(3)]
 		#[pallet::weight( {
 			let c = PendingConversions::<T>::count() as u64;
 			let convert_weight = (<T as Config>::WeightInfo::convert()).saturating_mul(c);
 			let w  = <T as Config>::WeightInfo::claim_rewards();
 			let one_read = T::DbWeight::get().reads(1_u64);
-			w.saturating_add(convert_weight).saturating_add(one_read)
+			w.saturating_add(convert_weight)
+				.saturating_add(one_read)
+				.saturating_add(Pallet::<T>::tier_reward_conversion_weight())
 		})]
 		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			for (asset_id, _) in PendingConversions::<T>::iter() {
-				let asset_balance = T::Currency::balance(asset_id.clone(), &Self::pot_account_id());
-				let r = T::Convert::convert(
-					Self::pot_account_id(),
-					asset_id.clone(),
-					T::RewardAsset::get(),
-					asset_balance,
-				);
-				if let Err(error) = r {
-					// We allow these errors to continue claiming as the current amount of asset that needed to be converted
-					// has very low impact on the rewards.
-					if error != Error::<T>::ConversionMinTradingAmountNotReached.into()
-						&& error != Error::<T>::ConversionZeroAmountReceived.into()
-					{
-						return Err(error);
-					}
-				}
-				PendingConversions::<T>::remove(asset_id);
-			}
-			let referrer_shares = ReferrerShares::<T>::take(&who);
-			let trader_shares = TraderShares::<T>::take(&who);
-			let total_shares = referrer_shares.saturating_add(trader_shares);
-			if total_shares == Balance::zero() {
-				return Ok(());
-			}
-
-			let reward_reserve = T::Currency::balance(T::RewardAsset::get(), &Self::pot_account_id());
-			let reward_reserve = reward_reserve.saturating_sub(T::SeedNativeAmount::get());
-			let share_issuance = TotalShares::<T>::get();
-
-			let convert_shares = |to_convert: Balance| -> Option<Balance> {
-				let shares_hp = U256::from(to_convert);
-				let reward_reserve_hp = U256::from(reward_reserve);
-				let share_issuance_hp = U256::from(share_issuance);
-				let r = shares_hp
-					.checked_mul(reward_reserve_hp)?
-					.checked_div(share_issuance_hp)?;
-				Balance::try_from(r).ok()
-			};
-
-			let referrer_rewards = convert_shares(referrer_shares).ok_or(ArithmeticError::Overflow)?;
-			let trader_rewards = convert_shares(trader_shares).ok_or(ArithmeticError::Overflow)?;
-			let total_rewards = referrer_rewards
-				.checked_add(trader_rewards)
-				.ok_or(ArithmeticError::Overflow)?;
-			ensure!(total_rewards <= reward_reserve, Error::<T>::IncorrectRewardCalculation);
-
-			// Make sure that we can transfer all the rewards if all shares withdrawn.
-			let keep_pot_alive = match total_shares != share_issuance {
-				true => Preservation::Preserve,
-				false => Preservation::Expendable,
-			};
-
-			T::Currency::transfer(
-				T::RewardAsset::get(),
-				&Self::pot_account_id(),
-				&who,
-				total_rewards,
-				keep_pot_alive,
-			)?;
-			TotalShares::<T>::mutate(|v| {
-				*v = v.saturating_sub(total_shares);
-			});
-			Referrer::<T>::mutate(who.clone(), |v| {
-				if let Some((level, total)) = v {
-					*total = total.saturating_add(referrer_rewards);
-					let new_level = level.increase::<T>(*total);
-					if *level != new_level {
-						*level = new_level;
-						Self::deposit_event(Event::LevelUp {
-							who: who.clone(),
-							level: new_level,
-						});
-					}
-				}
-			});
-
-			Self::deposit_event(Event::Claimed {
-				who,
-				referrer_rewards,
-				trade_rewards: trader_rewards,
-			});
-			Ok(())
+			Self::do_claim_rewards(who)
 		}
 
 		/// Set asset reward percentages
@@ -597,47 +1053,1503 @@ pub mod pallet {
 			});
 			Ok(())
 		}
-	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
-			let convert_weight = T::WeightInfo::convert();
-			if convert_weight.is_zero() {
-				return Weight::zero();
-			}
-			let one_read = T::DbWeight::get().reads(1u64);
-			let max_converts = remaining_weight.saturating_sub(one_read).ref_time() / convert_weight.ref_time();
+		/// Register an alias of the referral code.
+		///
+		/// `origin` must have a referral code registered and pays the registration fee.
+		/// Alias follows the same restrictions as the referral code.
+		///
+		/// Maximum number of aliases is limited to `T::MaxAliases`.
+		///
+		/// Parameters:
+		/// - `code`: Alias to register. Must follow the restrictions.
+		///
+		/// Emits `CodeRegistered` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_alias())]
+		pub fn register_alias(origin: OriginFor<T>, code: ReferralCode<T::CodeLength>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(ReferralAccounts::<T>::contains_key(&who), Error::<T>::NotRegistered);
 
-			for asset_id in PendingConversions::<T>::iter_keys().take(max_converts as usize) {
-				let asset_balance = T::Currency::balance(asset_id.clone(), &Self::pot_account_id());
-				// remove the asset_id from PendingConversions even when the conversion fails
-				let _ = T::Convert::convert(
-					Self::pot_account_id(),
-					asset_id.clone(),
-					T::RewardAsset::get(),
-					asset_balance,
-				);
-				PendingConversions::<T>::remove(asset_id);
-			}
-			convert_weight.saturating_mul(max_converts).saturating_add(one_read)
-		}
-	}
-}
+			let code = Self::validate_code(code)?;
+			Self::ensure_code_not_reserved(&code)?;
 
-impl<T: Config> Pallet<T> {
-	pub fn pot_account_id() -> T::AccountId {
-		T::PalletId::get().into_account_truncating()
-	}
+			ReferralCodes::<T>::mutate(code.clone(), |v| -> DispatchResult {
+				ensure!(v.is_none(), Error::<T>::AlreadyExists);
 
-	pub(crate) fn normalize_code(code: ReferralCode<T::CodeLength>) -> ReferralCode<T::CodeLength> {
-		let r = code.into_inner().iter().map(|v| v.to_ascii_uppercase()).collect();
-		ReferralCode::<T::CodeLength>::truncate_from(r)
-	}
+				AccountCodes::<T>::try_mutate(&who, |codes| codes.try_push(code.clone()))
+					.map_err(|_| Error::<T>::TooManyAliases)?;
 
-	/// Process trader fee
-	/// `source`: account to take the fee from
-	/// `trader`: account that does the trade
+				Self::charge_registration_fee(&who, &code)?;
+
+				*v = Some(who.clone());
+				CodeCount::<T>::mutate(|count| *count = count.saturating_add(1));
+				CodeRegisteredAt::<T>::insert(&code, frame_system::Pallet::<T>::block_number());
+				Self::deposit_event(Event::CodeRegistered { code, account: who });
+				Ok(())
+			})
+		}
+
+		/// Deregister a referral code or an alias of the signer account.
+		///
+		/// If the main referral code is deregistered, the oldest alias becomes the main code.
+		///
+		/// Accounts linked to the referrer stay linked and referrer level is kept.
+		/// Registration fee is not refunded.
+		///
+		/// Parameters:
+		/// - `code`: Code to deregister.
+		///
+		/// Emits `CodeDeregistered` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::deregister_code())]
+		pub fn deregister_code(origin: OriginFor<T>, code: ReferralCode<T::CodeLength>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::normalize_code(code);
+			let owner = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+			ensure!(owner == who, Error::<T>::NotCodeOwner);
+
+			Self::do_deregister_code(who, code);
+			Ok(())
+		}
+
+		/// Deregister the referral code and all aliases of the signer account.
+		///
+		/// Accounts linked to the referrer stay linked and referrer level is kept.
+		/// Registration fees are not refunded.
+		///
+		/// Emits `CodeDeregistered` event for each deregistered code when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::deregister_all(T::MaxAliases::get()))]
+		pub fn deregister_all(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::referral_code(&who).ok_or(Error::<T>::NotRegistered)?;
+
+			// aliases go first, so the main code is not replaced by an alias when it's deregistered
+			for alias in AccountCodes::<T>::get(&who).into_inner() {
+				Self::do_deregister_code(who.clone(), alias);
+			}
+			Self::do_deregister_code(who, code);
+			Ok(())
+		}
+
+		/// Register new referral code for the given account.
+		///
+		/// Same as `register_code` but the code can consist of digits only and no registration fee is charged.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`
+		/// - `account`: Account the code is registered for.
+		/// - `code`: Code to register.
+		///
+		/// Emits `CodeRegistered` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_register_code())]
+		pub fn force_register_code(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			code: ReferralCode<T::CodeLength>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			let code = Self::validate_code(code)?;
+
+			Self::do_register_code(account, code, false)
+		}
+
+		/// Set or remove the referrer reward percentage override of a referral code.
+		///
+		/// The override applies to trades of accounts linked to the referrer whose main referral code is `code`.
+		/// It takes precedence over the asset and level reward percentages.
+		/// Overrides of aliases are not applied, so only the main referral code of the referrer can be set.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`
+		/// - `code`: Main referral code of the referrer.
+		/// - `rate`: Referrer reward percentage. Must not exceed `T::MaxRewardRate`. `None` removes the override.
+		///
+		/// Emits `CodeRewardOverrideUpdated` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_code_reward_override())]
+		pub fn set_code_reward_override(
+			origin: OriginFor<T>,
+			code: ReferralCode<T::CodeLength>,
+			rate: Option<Permill>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let code = Self::normalize_code(code);
+			let owner = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+			ensure!(
+				Self::referral_code(&owner).as_ref() == Some(&code),
+				Error::<T>::NotMainCode
+			);
+
+			if let Some(rate) = rate {
+				ensure!(rate <= T::MaxRewardRate::get(), Error::<T>::RewardRateTooHigh);
+			}
+
+			CodeRewardOverride::<T>::set(&code, rate);
+			Self::deposit_event(Event::CodeRewardOverrideUpdated { code, rate });
+			Ok(())
+		}
+
+		/// Rename a registered referral code or alias.
+		///
+		/// The owner, registration block, reward percentage override, reward payee and leaderboard position are moved
+		/// to `new_code`. `new_code` is displayed as submitted. Referrer level, accrued volume and linked accounts are
+		/// kept as they belong to the owner account. `old_code` becomes free to register.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`
+		/// - `old_code`: Registered code to rename.
+		/// - `new_code`: New code. Must follow the restrictions of `force_register_code` and must not be registered.
+		///
+		/// Emits `CodeMigrated` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_code())]
+		pub fn migrate_code(
+			origin: OriginFor<T>,
+			old_code: ReferralCode<T::CodeLength>,
+			new_code: ReferralCode<T::CodeLength>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let old_code = Self::normalize_code(old_code);
+			let display = new_code.clone();
+			let new_code = Self::validate_code(new_code)?;
+			ensure!(!ReferralCodes::<T>::contains_key(&new_code), Error::<T>::AlreadyExists);
+			let owner = ReferralCodes::<T>::take(&old_code).ok_or(Error::<T>::InvalidCode)?;
+
+			ReferralCodes::<T>::insert(&new_code, &owner);
+			if Self::referral_code(&owner).as_ref() == Some(&old_code) {
+				ReferralAccounts::<T>::insert(&owner, &new_code);
+				Self::replace_leaderboard_code(&old_code, Some(new_code.clone()));
+			} else {
+				AccountCodes::<T>::mutate(&owner, |codes| {
+					if let Some(code) = codes.iter_mut().find(|c| **c == old_code) {
+						*code = new_code.clone();
+					}
+				});
+			}
+			if let Some(registered_at) = CodeRegisteredAt::<T>::take(&old_code) {
+				CodeRegisteredAt::<T>::insert(&new_code, registered_at);
+			}
+			CodeDisplay::<T>::remove(&old_code);
+			CodeDisplay::<T>::insert(&new_code, display);
+			if let Some(description) = CodeDescription::<T>::take(&old_code) {
+				CodeDescription::<T>::insert(&new_code, description);
+			}
+			if let Some(payee) = RewardPayee::<T>::take(&old_code) {
+				RewardPayee::<T>::insert(&new_code, payee);
+			}
+			if let Some(manager) = CodeManager::<T>::take(&old_code) {
+				CodeManager::<T>::insert(&new_code, manager);
+			}
+			if let Some(rate) = CodeRewardOverride::<T>::take(&old_code) {
+				CodeRewardOverride::<T>::insert(&new_code, rate);
+			}
+			if let Some(campaign) = CodeCampaign::<T>::take(&old_code) {
+				CodeCampaign::<T>::insert(&new_code, campaign);
+			}
+			if InactiveCodes::<T>::take(&old_code).is_some() {
+				InactiveCodes::<T>::insert(&new_code, ());
+			}
+			let referral_count = ReferralCount::<T>::take(&old_code);
+			if referral_count > 0 {
+				ReferralCount::<T>::insert(&new_code, referral_count);
+			}
+			let volume_since_last_event = VolumeSinceLastEvent::<T>::take(&old_code);
+			if volume_since_last_event > 0 {
+				VolumeSinceLastEvent::<T>::insert(&new_code, volume_since_last_event);
+			}
+			if let Some(offer) = CodeTransferOffers::<T>::take(&old_code) {
+				CodeTransferOffers::<T>::insert(&new_code, offer);
+			}
+			if let Some(expires_at) = CodeExpiry::<T>::take(&old_code) {
+				CodeExpiry::<T>::insert(&new_code, expires_at);
+			}
+			if AutoRenew::<T>::take(&old_code).is_some() {
+				AutoRenew::<T>::insert(&new_code, ());
+			}
+			RenamedCodes::<T>::insert(&old_code, &new_code);
+			RenamedCodes::<T>::remove(&new_code);
+
+			Self::deposit_event(Event::CodeMigrated {
+				old: old_code,
+				new: new_code,
+			});
+			Ok(())
+		}
+
+		/// Assign the rewards of the referral code of the signer account to another account.
+		///
+		/// Rewards claimed with `claim_rewards` afterwards are paid to `dest` instead of the signer account.
+		/// Assigning the rewards to the signer account removes the payee.
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the referral code.
+		/// - `dest`: Account receiving the claimed rewards.
+		///
+		/// Emits `RewardsAssigned` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::assign_rewards())]
+		pub fn assign_rewards(origin: OriginFor<T>, dest: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::referral_code(&who).ok_or(Error::<T>::NotRegistered)?;
+
+			if dest == who {
+				RewardPayee::<T>::remove(&code);
+			} else {
+				RewardPayee::<T>::insert(&code, &dest);
+			}
+
+			Self::deposit_event(Event::RewardsAssigned { code, payee: dest });
+			Ok(())
+		}
+
+		/// Set the pattern referral codes registered with `register_code` must match, or remove it.
+		///
+		/// The pattern is a sequence of character classes, one per character of the code.
+		/// A code matches if it has the same length as the pattern and each character belongs to its class.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`
+		/// - `pattern`: Pattern of referral codes. `None` removes the pattern and all codes are accepted.
+		///
+		/// Emits `CodePatternSet` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_code_pattern())]
+		pub fn set_code_pattern(
+			origin: OriginFor<T>,
+			pattern: Option<ReferralCodePattern<T::CodeLength>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			CodePattern::<T>::set(pattern.clone());
+			Self::deposit_event(Event::CodePatternSet { pattern });
+			Ok(())
+		}
+
+		/// Link a code to an account on behalf of the account.
+		///
+		/// Same as `link_code`, used by support to resolve missed referral attributions of accounts
+		/// that already traded without a linked code.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`
+		/// - `referee`: Account to link to the referral account of the code.
+		/// - `code`: Code to use to link the account to.
+		///
+		/// Emits `CodeLinked` event with `forced` flag set when successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::link_code()
+			.saturating_add(T::DbWeight::get().reads(T::MaxReferralDepth::get().into())))]
+		pub fn force_link(
+			origin: OriginFor<T>,
+			referee: T::AccountId,
+			code: ReferralCode<T::CodeLength>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			Self::do_link_code(referee, code, true)
+		}
+
+		/// Register new referral code in a campaign.
+		///
+		/// Same as `register_code`, the code is assigned to `campaign` additionally.
+		/// Volume accrued by the referrer of the code is added to the volume of the campaign.
+		///
+		/// Parameters:
+		/// - `code`: Code to register. Must follow the restrictions of `register_code`.
+		/// - `campaign`: Campaign the code belongs to.
+		///
+		/// Emits `CodeRegistered` and `CodeCampaignSet` events when successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_code_in_campaign())]
+		pub fn register_code_in_campaign(
+			origin: OriginFor<T>,
+			code: ReferralCode<T::CodeLength>,
+			campaign: CampaignId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_register_submitted_code(who, code, Some(campaign))
+		}
+
+		/// Enable or disable a referral code of the signer account.
+		///
+		/// Accounts can't be linked to a disabled code. The code, the accounts already linked to it and
+		/// the accrued rewards are kept, so the rewards can still be claimed.
+		///
+		/// Parameters:
+		/// - `code`: Code owned by the signer account.
+		/// - `active`: `false` disables the code, `true` enables it again.
+		///
+		/// Emits `CodeActiveSet` event when successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_code_active())]
+		pub fn set_code_active(
+			origin: OriginFor<T>,
+			code: ReferralCode<T::CodeLength>,
+			active: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::normalize_code(code);
+			let owner = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+			ensure!(owner == who, Error::<T>::NotCodeOwner);
+
+			if active {
+				InactiveCodes::<T>::remove(&code);
+			} else {
+				InactiveCodes::<T>::insert(&code, ());
+			}
+
+			Self::deposit_event(Event::CodeActiveSet { code, active });
+			Ok(())
+		}
+
+		/// Set a public description of a referral code of the signer account, e.g. a social handle.
+		///
+		/// The description can contain printable ASCII characters only and can't be longer than `MaxDescLen`.
+		/// Empty description removes the existing one.
+		///
+		/// Parameters:
+		/// - `code`: Code owned by the signer account.
+		/// - `description`: Public description of the code.
+		///
+		/// Emits `CodeDescriptionSet` event when successful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_description())]
+		pub fn set_description(
+			origin: OriginFor<T>,
+			code: ReferralCode<T::CodeLength>,
+			description: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::normalize_code(code);
+			let owner = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+			ensure!(owner == who, Error::<T>::NotCodeOwner);
+
+			let description: BoundedVec<u8, T::MaxDescLen> =
+				description.try_into().map_err(|_| Error::<T>::DescriptionTooLong)?;
+			ensure!(
+				description.iter().all(|c| c.is_ascii_graphic() || *c == b' '),
+				Error::<T>::InvalidDescription
+			);
+
+			if description.is_empty() {
+				CodeDescription::<T>::remove(&code);
+			} else {
+				CodeDescription::<T>::insert(&code, &description);
+			}
+
+			Self::deposit_event(Event::CodeDescriptionSet { code, description });
+			Ok(())
+		}
+
+		/// Set the manager of a referral code of the signer account, or remove it.
+		///
+		/// The manager can claim the rewards of the signer account with `claim_rewards_for`,
+		/// e.g. a custodian distributing the rewards of its partners.
+		///
+		/// Parameters:
+		/// - `code`: Code owned by the signer account.
+		/// - `manager`: Account allowed to claim the rewards. If not set, the manager is removed.
+		///
+		/// Emits `CodeManagerSet` event when successful.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_code_manager())]
+		pub fn set_code_manager(
+			origin: OriginFor<T>,
+			code: ReferralCode<T::CodeLength>,
+			manager: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::normalize_code(code);
+			let owner = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+			ensure!(owner == who, Error::<T>::NotCodeOwner);
+
+			CodeManager::<T>::set(&code, manager.clone());
+
+			Self::deposit_event(Event::CodeManagerSet { code, manager });
+			Ok(())
+		}
+
+		/// Claim accumulated rewards of the owner of a referral code on behalf of the owner.
+		///
+		/// Works the same way as `claim_rewards` signed by the owner of the code. The rewards are paid
+		/// to the owner, or to the payee assigned with `assign_rewards`.
+		///
+		/// Parameters:
+		/// - `origin`: Manager of the code set with `set_code_manager`.
+		/// - `code`: Referral code of the owner to claim the rewards for.
+		///
+		/// Emits `Claimed` event when successful.
+		#[pallet::call_index(18)]
+		#[pallet::weight( {
+			let c = PendingConversions::<T>::count() as u64;
+			let convert_weight = (<T as Config>::WeightInfo::convert()).saturating_mul(c);
+			let w  = <T as Config>::WeightInfo::claim_rewards();
+			// pending conversions count, referral account and manager of the code
+			let reads = T::DbWeight::get().reads(3_u64);
+			w.saturating_add(convert_weight)
+				.saturating_add(reads)
+				.saturating_add(Pallet::<T>::tier_reward_conversion_weight())
+		})]
+		pub fn claim_rewards_for(origin: OriginFor<T>, code: ReferralCode<T::CodeLength>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::normalize_code(code);
+			let owner = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+			ensure!(Self::code_manager(&code) == Some(who), Error::<T>::NotManager);
+
+			Self::do_claim_rewards(owner)
+		}
+
+		/// Unlink the signer account from the referral account it was linked to.
+		///
+		/// Allowed only within `UnlinkGracePeriod` blocks of the link. The account can link a code again afterwards.
+		/// Referral count of the code used for the link is decreased. If the code was renamed since the link,
+		/// the count of the new code is decreased. If the code was transferred or deregistered, the count is kept,
+		/// as it doesn't include the link anymore.
+		///
+		/// Emits `CodeUnlinked` event when successful.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::unlink_code())]
+		pub fn unlink_code(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let referral_account = Self::linked_referral_account(&who).ok_or(Error::<T>::NotLinked)?;
+			let (linked_at, code) = Self::linked_at(&who).ok_or(Error::<T>::UnlinkWindowClosed)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= linked_at.saturating_add(T::UnlinkGracePeriod::get()),
+				Error::<T>::UnlinkWindowClosed
+			);
+
+			LinkedAccounts::<T>::remove(&who);
+			LinkedAt::<T>::remove(&who);
+			let code = Self::current_link_code(&referral_account, code);
+			if Self::referral_account(&code).as_ref() == Some(&referral_account) {
+				ReferralCount::<T>::mutate_exists(&code, |maybe_count| {
+					*maybe_count = maybe_count
+						.map(|count| count.saturating_sub(1))
+						.filter(|count| *count > 0);
+				});
+			}
+
+			Self::deposit_event(Event::CodeUnlinked {
+				account: who,
+				code,
+				referral_account,
+			});
+			Ok(())
+		}
+
+		/// Offer to transfer the ownership of a referral code or an alias of the signer account.
+		///
+		/// The ownership is transferred only when `to` accepts the offer with `accept_code_transfer`
+		/// within `T::TransferOfferTtl` blocks. A new offer replaces the pending offer of the code.
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the referral code.
+		/// - `code`: Code to transfer.
+		/// - `to`: Account the code is offered to.
+		///
+		/// Emits `CodeTransferOffered` event when successful.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::offer_code_transfer())]
+		pub fn offer_code_transfer(
+			origin: OriginFor<T>,
+			code: ReferralCode<T::CodeLength>,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::normalize_code(code);
+			let owner = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+			ensure!(owner == who, Error::<T>::NotCodeOwner);
+			ensure!(to != who, Error::<T>::SelfTransferNotAllowed);
+
+			let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(T::TransferOfferTtl::get());
+			CodeTransferOffers::<T>::insert(&code, (&to, expires_at));
+
+			Self::deposit_event(Event::CodeTransferOffered {
+				code,
+				from: who,
+				to,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Accept an offer to transfer the ownership of a referral code.
+		///
+		/// The code becomes the main code of the signer account if the account has no code registered,
+		/// or an alias otherwise. If it was the main code of the previous owner, the oldest alias of the previous
+		/// owner becomes the main code.
+		/// Accounts linked with the code stay linked to the previous owner. Reward payee, manager, referral count
+		/// and volume since the last `VolumeAccrued` event of the code are reset.
+		///
+		/// Parameters:
+		/// - `origin`: Recipient of the offer.
+		/// - `code`: Offered code.
+		///
+		/// Emits `CodeTransferred` event when successful.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_code_transfer())]
+		pub fn accept_code_transfer(origin: OriginFor<T>, code: ReferralCode<T::CodeLength>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::normalize_code(code);
+			let (to, expires_at) = Self::code_transfer_offer(&code).ok_or(Error::<T>::TransferOfferNotFound)?;
+			ensure!(to == who, Error::<T>::NotOfferRecipient);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < expires_at,
+				Error::<T>::TransferOfferExpired
+			);
+			let from = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+
+			CodeTransferOffers::<T>::remove(&code);
+			Self::do_transfer_code(&code, &from, &who)?;
+
+			Self::deposit_event(Event::CodeTransferred { code, from, to: who });
+			Ok(())
+		}
+
+		/// Cancel a pending offer to transfer the ownership of a referral code.
+		///
+		/// Can be called by the owner of the code or the recipient of the offer, also after the offer expired.
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the referral code or recipient of the offer.
+		/// - `code`: Offered code.
+		///
+		/// Emits `CodeTransferCancelled` event when successful.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_code_transfer())]
+		pub fn cancel_code_transfer(origin: OriginFor<T>, code: ReferralCode<T::CodeLength>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::normalize_code(code);
+			let (to, _) = Self::code_transfer_offer(&code).ok_or(Error::<T>::TransferOfferNotFound)?;
+			ensure!(
+				to == who || Self::referral_account(&code).as_ref() == Some(&who),
+				Error::<T>::NotOfferParty
+			);
+
+			CodeTransferOffers::<T>::remove(&code);
+
+			Self::deposit_event(Event::CodeTransferCancelled { code });
+			Ok(())
+		}
+
+		/// Set an expiry of a referral code or remove it.
+		///
+		/// Accounts can't be linked to the code from the `expires_at` block and the code can be removed
+		/// with `remove_expired_code`.
+		/// If `auto_renew` is set, the expiry is extended to `T::RenewalExtension` blocks from the current block
+		/// every time the code is linked or accrues volume, so only dormant codes expire.
+		/// Volume is accrued by the referrer, so it renews only the main code of the referrer. Aliases are renewed
+		/// when accounts are linked to them.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::AuthorityOrigin`
+		/// - `code`: Registered code.
+		/// - `expires_at`: Block number at which the code expires. Must be after the current block.
+		///    If not set, the expiry of the code is removed.
+		/// - `auto_renew`: Extend the expiry of the code when the code is used. Ignored if `expires_at` is not set.
+		///
+		/// Emits `CodeExpirySet` event when successful.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_code_expiry())]
+		pub fn set_code_expiry(
+			origin: OriginFor<T>,
+			code: ReferralCode<T::CodeLength>,
+			expires_at: Option<BlockNumberFor<T>>,
+			auto_renew: bool,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			let code = Self::normalize_code(code);
+			ensure!(ReferralCodes::<T>::contains_key(&code), Error::<T>::InvalidCode);
+
+			let auto_renew = auto_renew && expires_at.is_some();
+			match expires_at {
+				Some(expires_at) => {
+					ensure!(
+						expires_at > frame_system::Pallet::<T>::block_number(),
+						Error::<T>::InvalidExpiry
+					);
+					CodeExpiry::<T>::insert(&code, expires_at);
+				}
+				None => CodeExpiry::<T>::remove(&code),
+			}
+			if auto_renew {
+				AutoRenew::<T>::insert(&code, ());
+			} else {
+				AutoRenew::<T>::remove(&code);
+			}
+
+			Self::deposit_event(Event::CodeExpirySet {
+				code,
+				expires_at,
+				auto_renew,
+			});
+			Ok(())
+		}
+
+		/// Remove an expired referral code.
+		///
+		/// Can be called by any account. The code is deregistered the same way as with `deregister_code`.
+		/// Auto-renew codes which expired because they were not used are removed too.
+		///
+		/// Parameters:
+		/// - `code`: Expired code.
+		///
+		/// Emits `CodeDeregistered` event when successful.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_expired_code())]
+		pub fn remove_expired_code(origin: OriginFor<T>, code: ReferralCode<T::CodeLength>) -> DispatchResult {
+			ensure_signed(origin)?;
+			let code = Self::normalize_code(code);
+			let owner = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+			ensure!(Self::is_code_expired(&code), Error::<T>::CodeNotExpired);
+
+			Self::do_deregister_code(owner, code);
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let snapshot_weight = Self::snapshot_codes(n);
+			if T::EmitLinkEvents::get() {
+				snapshot_weight
+			} else {
+				// LinksInPeriod is taken in on_finalize
+				T::DbWeight::get().reads_writes(1, 1).saturating_add(snapshot_weight)
+			}
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			let count = LinksInPeriod::<T>::take();
+			if count > 0 {
+				Self::deposit_event(Event::LinksBatched { count });
+			}
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let migration_weight = migration::backfill_account_codes::<T>(remaining_weight);
+			let remaining_weight = remaining_weight.saturating_sub(migration_weight);
+
+			let convert_weight = T::WeightInfo::convert();
+			if convert_weight.is_zero() {
+				return migration_weight;
+			}
+			let one_read = T::DbWeight::get().reads(1u64);
+			let max_converts = remaining_weight.saturating_sub(one_read).ref_time() / convert_weight.ref_time();
+
+			for asset_id in PendingConversions::<T>::iter_keys().take(max_converts as usize) {
+				let asset_balance = T::Currency::balance(asset_id.clone(), &Self::pot_account_id());
+				// remove the asset_id from PendingConversions even when the conversion fails
+				let _ = T::Convert::convert(
+					Self::pot_account_id(),
+					asset_id.clone(),
+					T::RewardAsset::get(),
+					asset_balance,
+				);
+				PendingConversions::<T>::remove(asset_id);
+			}
+			convert_weight
+				.saturating_mul(max_converts)
+				.saturating_add(one_read)
+				.saturating_add(migration_weight)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn pot_account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	pub(crate) fn normalize_code(code: ReferralCode<T::CodeLength>) -> ReferralCode<T::CodeLength> {
+		let r = match T::NormalizeCase::get() {
+			CaseMode::Upper => code.into_inner().iter().map(|v| v.to_ascii_uppercase()).collect(),
+			CaseMode::Lower => code.into_inner().iter().map(|v| v.to_ascii_lowercase()).collect(),
+			CaseMode::None => return code,
+		};
+		ReferralCode::<T::CodeLength>::truncate_from(r)
+	}
+
+	/// Return the weight of the conversion of the claimed rewards to the reward asset of a level.
+	/// Zero if all levels are paid in RewardAsset.
+	fn tier_reward_conversion_weight() -> Weight {
+		let converts = [
+			Level::None,
+			Level::Tier0,
+			Level::Tier1,
+			Level::Tier2,
+			Level::Tier3,
+			Level::Tier4,
+		]
+		.iter()
+		.any(|level| T::RewardAssetForTier::get(level).is_some_and(|asset_id| asset_id != T::RewardAsset::get()));
+		if converts {
+			<T as Config>::WeightInfo::convert()
+		} else {
+			Weight::zero()
+		}
+	}
+
+	/// Return the reward period of the current block.
+	fn current_reward_period() -> BlockNumberFor<T> {
+		frame_system::Pallet::<T>::block_number()
+			.checked_div(&T::RewardPeriod::get())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Copy the stats of the referral codes to `CodeSnapshots`, at most `MaxCodesPerSnapshot` codes in one block.
+	///
+	/// A new snapshot starts at each boundary of `SnapshotPeriod`, indexed by the period that ended.
+	/// Otherwise the snapshot in progress continues after `SnapshotCursor`. A snapshot not finished by the next
+	/// boundary is left incomplete.
+	pub(crate) fn snapshot_codes(n: BlockNumberFor<T>) -> Weight {
+		let period = T::SnapshotPeriod::get();
+		if period.is_zero() {
+			return Weight::zero();
+		}
+
+		let mut weight = T::DbWeight::get().reads(1);
+		let (index, cursor) = if !n.is_zero() && (n % period).is_zero() {
+			let index = (n / period).saturated_into::<PeriodIndex>().saturating_sub(1);
+			(index, ReferralCodes::<T>::final_prefix().to_vec())
+		} else if let Some((index, cursor)) = SnapshotCursor::<T>::get() {
+			(index, cursor.into_inner())
+		} else {
+			return weight;
+		};
+		// the cursor is written back at the end of the step
+		weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+		let code_weight = T::WeightInfo::snapshot_code();
+		let mut codes = ReferralCodes::<T>::iter_from(cursor);
+		for _ in 0..T::MaxCodesPerSnapshot::get() {
+			let Some((code, owner)) = codes.next() else {
+				SnapshotCursor::<T>::kill();
+				return weight;
+			};
+			weight.saturating_accrue(code_weight);
+
+			let stats = CodeStats {
+				volume: Referrer::<T>::get(&owner).map_or(0, |(_, volume)| volume),
+				referral_count: ReferralCount::<T>::get(&code),
+			};
+			CodeSnapshots::<T>::insert(index, &code, stats);
+		}
+
+		// a key longer than the bound resumes before it and only copies some codes again
+		let last_key = BoundedVec::truncate_from(codes.last_raw_key().to_vec());
+		SnapshotCursor::<T>::put((index, last_key));
+		weight
+	}
+
+	/// Return transaction fee discount of the account.
+	/// Only accounts linked to a referral account are eligible for the discount.
+	pub fn fee_discount_for(who: &T::AccountId) -> Permill {
+		if LinkedAccounts::<T>::contains_key(who) {
+			T::TransactionFeeDiscount::get()
+		} else {
+			Permill::zero()
+		}
+	}
+
+	/// Charge the registration fee of the normalized `code` from the account.
+	/// Vanity codes shorter than `VanityLengthThreshold` pay `VanityFee` instead of the amount of `RegistrationFee`.
+	/// `RewardPotShare` of the fee is transferred to the reward pot, the rest to the fee collector.
+	fn charge_registration_fee(who: &T::AccountId, code: &ReferralCode<T::CodeLength>) -> DispatchResult {
+		let (fee_asset, _, beneficiary) = T::RegistrationFee::get();
+		let fee_amount = Self::registration_fee(code);
+		let reward_pot_amount = T::RewardPotShare::get().mul_floor(fee_amount);
+		let fee_collector_amount = fee_amount.saturating_sub(reward_pot_amount);
+
+		if !reward_pot_amount.is_zero() {
+			T::Currency::transfer(
+				fee_asset.clone(),
+				who,
+				&Self::pot_account_id(),
+				reward_pot_amount,
+				Preservation::Preserve,
+			)?;
+			if fee_asset != T::RewardAsset::get() {
+				PendingConversions::<T>::insert(fee_asset.clone(), ());
+			}
+		}
+		T::Currency::transfer(
+			fee_asset,
+			who,
+			&beneficiary,
+			fee_collector_amount,
+			Preservation::Preserve,
+		)?;
+
+		Self::deposit_event(Event::RegistrationFeePaid {
+			account: who.clone(),
+			fee: fee_amount,
+			reward_pot_amount,
+			fee_collector_amount,
+		});
+		Ok(())
+	}
+
+	/// Claim accumulated rewards of the account, see `claim_rewards`.
+	fn do_claim_rewards(who: T::AccountId) -> DispatchResult {
+		for (asset_id, _) in PendingConversions::<T>::iter() {
+			let asset_balance = T::Currency::balance(asset_id.clone(), &Self::pot_account_id());
+			let r = T::Convert::convert(
+				Self::pot_account_id(),
+				asset_id.clone(),
+				T::RewardAsset::get(),
+				asset_balance,
+			);
+			if let Err(error) = r {
+				// We allow these errors to continue claiming as the current amount of asset that needed to be converted
+				// has very low impact on the rewards.
+				if error != Error::<T>::ConversionMinTradingAmountNotReached.into()
+					&& error != Error::<T>::ConversionZeroAmountReceived.into()
+				{
+					return Err(error);
+				}
+			}
+			PendingConversions::<T>::remove(asset_id);
+		}
+		let referrer_shares = ReferrerShares::<T>::take(&who);
+		let trader_shares = TraderShares::<T>::take(&who);
+		let total_shares = referrer_shares.saturating_add(trader_shares);
+		if total_shares == Balance::zero() {
+			return Ok(());
+		}
+
+		let reward_reserve = T::Currency::balance(T::RewardAsset::get(), &Self::pot_account_id());
+		let reward_reserve = reward_reserve.saturating_sub(T::SeedNativeAmount::get());
+		let share_issuance = TotalShares::<T>::get();
+
+		let convert_shares = |to_convert: Balance| -> Option<Balance> {
+			let shares_hp = U256::from(to_convert);
+			let reward_reserve_hp = U256::from(reward_reserve);
+			let share_issuance_hp = U256::from(share_issuance);
+			let r = shares_hp
+				.checked_mul(reward_reserve_hp)?
+				.checked_div(share_issuance_hp)?;
+			Balance::try_from(r).ok()
+		};
+
+		let referrer_rewards = convert_shares(referrer_shares).ok_or(ArithmeticError::Overflow)?;
+		let trader_rewards = convert_shares(trader_shares).ok_or(ArithmeticError::Overflow)?;
+		let total_rewards = referrer_rewards
+			.checked_add(trader_rewards)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(total_rewards <= reward_reserve, Error::<T>::IncorrectRewardCalculation);
+		// Shares are kept and keep accumulating until the accrued volume of the referrer reaches the threshold.
+		// Accounts that are not referrers are not subject to the threshold.
+		if let Some((_, accrued_volume)) = Referrer::<T>::get(&who) {
+			ensure!(
+				accrued_volume.saturating_add(referrer_rewards) >= T::MinVolumeForReward::get(),
+				Error::<T>::VolumeBelowThreshold
+			);
+		}
+
+		// Only the shares of the rewards within the cap are claimed, the rest is kept for the next periods.
+		let period = Self::current_reward_period();
+		let (claimed_period, claimed_in_period) = RewardsClaimedInPeriod::<T>::get(&who);
+		let claimed_in_period = if claimed_period == period {
+			claimed_in_period
+		} else {
+			Balance::zero()
+		};
+		let claimable = T::RewardCapPerPeriod::get().saturating_sub(claimed_in_period);
+		let (referrer_shares_claimed, trader_shares_claimed, referrer_rewards, trader_rewards) =
+			if total_rewards > claimable {
+				let claimed_shares = |shares: Balance| -> Option<Balance> {
+					multiply_by_rational_with_rounding(shares, claimable, total_rewards, Rounding::Down)
+				};
+				let referrer_shares_claimed = claimed_shares(referrer_shares).ok_or(ArithmeticError::Overflow)?;
+				let trader_shares_claimed = claimed_shares(trader_shares).ok_or(ArithmeticError::Overflow)?;
+				(
+					referrer_shares_claimed,
+					trader_shares_claimed,
+					convert_shares(referrer_shares_claimed).ok_or(ArithmeticError::Overflow)?,
+					convert_shares(trader_shares_claimed).ok_or(ArithmeticError::Overflow)?,
+				)
+			} else {
+				(referrer_shares, trader_shares, referrer_rewards, trader_rewards)
+			};
+		let claimed_shares = referrer_shares_claimed.saturating_add(trader_shares_claimed);
+		let claimed_rewards = referrer_rewards.saturating_add(trader_rewards);
+
+		if referrer_shares_claimed < referrer_shares {
+			ReferrerShares::<T>::insert(&who, referrer_shares.saturating_sub(referrer_shares_claimed));
+		}
+		if trader_shares_claimed < trader_shares {
+			TraderShares::<T>::insert(&who, trader_shares.saturating_sub(trader_shares_claimed));
+		}
+
+		// Make sure that we can transfer all the rewards if all shares withdrawn.
+		let keep_pot_alive = match claimed_shares != share_issuance {
+			true => Preservation::Preserve,
+			false => Preservation::Expendable,
+		};
+
+		let payee = Self::referral_code(&who)
+			.and_then(Self::reward_payee)
+			.unwrap_or_else(|| who.clone());
+		let level = Referrer::<T>::get(&who).map_or(Level::None, |(level, _)| level);
+		match T::RewardAssetForTier::get(&level).filter(|asset_id| *asset_id != T::RewardAsset::get()) {
+			Some(reward_asset) => {
+				let converted_rewards = T::Convert::convert(
+					Self::pot_account_id(),
+					T::RewardAsset::get(),
+					reward_asset.clone(),
+					claimed_rewards,
+				)?;
+				Self::deposit_event(Event::Converted {
+					from: AssetAmount::new(T::RewardAsset::get(), claimed_rewards),
+					to: AssetAmount::new(reward_asset.clone(), converted_rewards),
+				});
+				T::Currency::transfer(
+					reward_asset,
+					&Self::pot_account_id(),
+					&payee,
+					converted_rewards,
+					Preservation::Expendable,
+				)?;
+			}
+			None => {
+				T::Currency::transfer(
+					T::RewardAsset::get(),
+					&Self::pot_account_id(),
+					&payee,
+					claimed_rewards,
+					keep_pot_alive,
+				)?;
+			}
+		}
+		TotalShares::<T>::mutate(|v| {
+			*v = v.saturating_sub(claimed_shares);
+		});
+		RewardsClaimedInPeriod::<T>::insert(&who, (period, claimed_in_period.saturating_add(claimed_rewards)));
+		Self::accrue_volume(&who, referrer_rewards);
+
+		let deferred = total_rewards.saturating_sub(claimed_rewards);
+		if !deferred.is_zero() {
+			Self::deposit_event(Event::RewardDeferred {
+				who: who.clone(),
+				amount: deferred,
+			});
+		}
+		Self::deposit_event(Event::Claimed {
+			who,
+			referrer_rewards,
+			trade_rewards: trader_rewards,
+		});
+		Ok(())
+	}
+
+	/// Registration fee of the normalized `code` in the asset of `RegistrationFee`.
+	fn registration_fee(code: &ReferralCode<T::CodeLength>) -> Balance {
+		if (code.len() as u32) < T::VanityLengthThreshold::get() {
+			T::VanityFee::get()
+		} else {
+			T::RegistrationFee::get().1
+		}
+	}
+
+	/// Check whether the account can register the code with `register_code`, without changing the state.
+	/// Runs the same checks as the registration and returns the reason of the first failing one.
+	pub fn can_register(who: &T::AccountId, code: Vec<u8>) -> Result<(), RegistrationError> {
+		let code = ReferralCode::<T::CodeLength>::try_from(code).map_err(|_| RegistrationError::TooLong)?;
+		let code = Self::validate_code(code).map_err(|e| {
+			if e == Error::<T>::TooShort.into() {
+				RegistrationError::TooShort
+			} else {
+				RegistrationError::InvalidCharacter
+			}
+		})?;
+		Self::ensure_code_not_reserved(&code).map_err(|_| RegistrationError::NumericCodeReserved)?;
+		Self::ensure_code_matches_pattern(&code).map_err(|_| RegistrationError::PatternMismatch)?;
+
+		ensure!(
+			ReferralAccounts::<T>::get(who).is_none(),
+			RegistrationError::AlreadyRegistered
+		);
+		ensure!(
+			!ReferralCodes::<T>::contains_key(&code),
+			RegistrationError::AlreadyExists
+		);
+
+		let fee_asset = T::RegistrationFee::get().0;
+		ensure!(
+			T::Currency::reducible_balance(fee_asset, who, Preservation::Preserve, Fortitude::Polite)
+				>= Self::registration_fee(&code),
+			RegistrationError::InsufficientBalance
+		);
+		Ok(())
+	}
+
+	/// Validate and register the code submitted by the account, optionally in a campaign.
+	fn do_register_submitted_code(
+		who: T::AccountId,
+		code: ReferralCode<T::CodeLength>,
+		campaign: Option<CampaignId>,
+	) -> DispatchResult {
+		let display = code.clone();
+		let code = Self::validate_code(code)?;
+		Self::ensure_code_not_reserved(&code)?;
+		Self::ensure_code_matches_pattern(&code)?;
+
+		Self::do_register_code(who, code.clone(), true)?;
+		CodeDisplay::<T>::insert(&code, display);
+
+		if let Some(campaign) = campaign {
+			CodeCampaign::<T>::insert(&code, campaign);
+			Self::deposit_event(Event::CodeCampaignSet { code, campaign });
+		}
+		Ok(())
+	}
+
+	fn do_register_code(who: T::AccountId, code: ReferralCode<T::CodeLength>, charge_fee: bool) -> DispatchResult {
+		ensure!(
+			ReferralAccounts::<T>::get(&who).is_none(),
+			Error::<T>::AlreadyRegistered
+		);
+
+		ReferralCodes::<T>::mutate(code.clone(), |v| -> DispatchResult {
+			ensure!(v.is_none(), Error::<T>::AlreadyExists);
+
+			if charge_fee {
+				Self::charge_registration_fee(&who, &code)?;
+			}
+
+			*v = Some(who.clone());
+			// Keep the level and the accumulated volume if the account registers a code again.
+			if !Referrer::<T>::contains_key(&who) {
+				Referrer::<T>::insert(&who, (Level::default(), Balance::zero()));
+			}
+			ReferralAccounts::<T>::insert(&who, code.clone());
+			CodeCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			CodeRegisteredAt::<T>::insert(&code, frame_system::Pallet::<T>::block_number());
+			Self::deposit_event(Event::CodeRegistered { code, account: who });
+			Ok(())
+		})
+	}
+
+	/// Move the ownership of the referral code from `from` to `to`.
+	fn do_transfer_code(code: &ReferralCode<T::CodeLength>, from: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+		if ReferralAccounts::<T>::contains_key(to) {
+			AccountCodes::<T>::try_mutate(to, |codes| codes.try_push(code.clone()))
+				.map_err(|_| Error::<T>::TooManyAliases)?;
+		} else {
+			ReferralAccounts::<T>::insert(to, code);
+		}
+		if !Referrer::<T>::contains_key(to) {
+			Referrer::<T>::insert(to, (Level::default(), Balance::zero()));
+		}
+
+		if Self::referral_code(from).as_ref() == Some(code) {
+			let next_code = AccountCodes::<T>::mutate_exists(from, |maybe_codes| {
+				let codes = maybe_codes.as_mut()?;
+				let next = (!codes.is_empty()).then(|| codes.remove(0));
+				if codes.is_empty() {
+					*maybe_codes = None;
+				}
+				next
+			});
+			match &next_code {
+				Some(next) => ReferralAccounts::<T>::insert(from, next),
+				None => ReferralAccounts::<T>::remove(from),
+			}
+			Self::replace_leaderboard_code(code, next_code);
+		} else {
+			AccountCodes::<T>::mutate_exists(from, |maybe_codes| {
+				if let Some(codes) = maybe_codes.as_mut() {
+					codes.retain(|c| c != code);
+					if codes.is_empty() {
+						*maybe_codes = None;
+					}
+				}
+			});
+		}
+
+		ReferralCodes::<T>::insert(code, to);
+		RewardPayee::<T>::remove(code);
+		CodeManager::<T>::remove(code);
+		ReferralCount::<T>::remove(code);
+		VolumeSinceLastEvent::<T>::remove(code);
+		Ok(())
+	}
+
+	/// Follow the renames of the code used for a link until a code owned by the referral account is found.
+	/// Returns the last code found, which is not owned by the referral account if the code was transferred
+	/// or deregistered.
+	fn current_link_code(
+		referral_account: &T::AccountId,
+		code: ReferralCode<T::CodeLength>,
+	) -> ReferralCode<T::CodeLength> {
+		let mut code = code;
+		for _ in 0..MAX_RENAME_LOOKUPS {
+			if Self::referral_account(&code).as_ref() == Some(referral_account) {
+				break;
+			}
+			match Self::renamed_code(&code) {
+				Some(new_code) => code = new_code,
+				None => break,
+			}
+		}
+		code
+	}
+
+	/// Return profile of the referral code, or `None` if the code is not registered.
+	/// The code is normalized first.
+	pub fn code_info(code: Vec<u8>) -> Option<CodeInfo<T::AccountId, BlockNumberFor<T>>> {
+		let code = Self::normalize_code(ReferralCode::<T::CodeLength>::try_from(code).ok()?);
+		let owner = Self::referral_account(&code)?;
+		let (level, volume) = Self::referrer_level(&owner).unwrap_or_default();
+
+		Some(CodeInfo {
+			owner,
+			level,
+			volume,
+			registered_at: Self::code_registered_at(&code),
+			display: Self::code_display(&code).map(|display| display.into_inner()),
+			description: Self::code_description(&code).map(|description| description.into_inner()),
+			referral_count: Self::referral_count(&code),
+			expires_at: Self::code_expiry(&code),
+			auto_renew: AutoRenew::<T>::contains_key(&code),
+		})
+	}
+
+	/// Return number of accounts linked with the referral code. The code is normalized first.
+	pub fn code_referral_count(code: Vec<u8>) -> u32 {
+		ReferralCode::<T::CodeLength>::try_from(code)
+			.map(|code| Self::referral_count(Self::normalize_code(code)))
+			.unwrap_or_default()
+	}
+
+	/// Return referral accounts up the referral chain of `who`, starting with the referral account
+	/// `who` is linked to.
+	///
+	/// The walk stops at an account which is not linked, at an account already in the chain,
+	/// or after `max_depth` levels, which is capped at `MAX_REFERRAL_CHAIN_QUERY_DEPTH`.
+	pub fn referral_chain(who: &T::AccountId, max_depth: u32) -> Vec<T::AccountId> {
+		let mut chain: Vec<T::AccountId> = Vec::new();
+		let mut current = who.clone();
+		for _ in 0..max_depth.min(MAX_REFERRAL_CHAIN_QUERY_DEPTH) {
+			let Some(next) = Self::linked_referral_account(&current) else {
+				break;
+			};
+			if next == *who || chain.contains(&next) {
+				break;
+			}
+			chain.push(next.clone());
+			current = next;
+		}
+		chain
+	}
+
+	/// Return `true` if `who` is found in the referral chain of `referrer` within `T::MaxReferralDepth` levels.
+	fn is_in_referral_chain(referrer: &T::AccountId, who: &T::AccountId) -> bool {
+		let mut current = referrer.clone();
+		for _ in 0..T::MaxReferralDepth::get() {
+			match Self::linked_referral_account(&current) {
+				Some(next) if next == *who => return true,
+				Some(next) => current = next,
+				None => return false,
+			}
+		}
+		false
+	}
+
+	/// Split the referrer reward up the referral chain of `referrer`.
+	///
+	/// Each referrer passes `T::ReferralChainDecay` of its reward to the next level and keeps the rest,
+	/// so the rewards of the chain sum up to `referrer_reward`.
+	/// Return the reward kept by `referrer` and the referrers up the chain with their rewards.
+	fn referral_chain_rewards(
+		referrer: &T::AccountId,
+		referrer_reward: Balance,
+	) -> (Balance, Vec<(T::AccountId, Balance)>) {
+		let decay = T::ReferralChainDecay::get();
+		let mut rewards: Vec<(T::AccountId, Balance)> = Vec::new();
+		let mut current = referrer.clone();
+		let mut reward = referrer_reward;
+
+		for _ in 1..T::MaxReferralDepth::get() {
+			let Some(next) = Self::linked_referral_account(&current) else {
+				break;
+			};
+			reward = decay.mul_floor(reward);
+			// cycles are rejected when linking, but don't reward an account twice
+			if reward.is_zero() || next == *referrer || rewards.iter().any(|(acc, _)| *acc == next) {
+				break;
+			}
+			rewards.push((next.clone(), reward));
+			current = next;
+		}
+
+		// Share passed to the next level is carved out of the reward of each level
+		let mut passed = Balance::zero();
+		for (_, reward) in rewards.iter_mut().rev() {
+			let level_reward = *reward;
+			*reward = level_reward.saturating_sub(passed);
+			passed = level_reward;
+		}
+		(referrer_reward.saturating_sub(passed), rewards)
+	}
+
+	/// Ensure the code is not reserved. Codes consisting of digits only are reserved if `T::BlockNumericCodes` is set.
+	fn ensure_code_not_reserved(code: &ReferralCode<T::CodeLength>) -> DispatchResult {
+		ensure!(
+			!(T::BlockNumericCodes::get() && code.iter().all(|c| c.is_ascii_digit())),
+			Error::<T>::NumericCodeReserved
+		);
+		Ok(())
+	}
+
+	/// Remove the referral code of the owner and all its data.
+	///
+	/// If the main referral code is removed, the oldest alias becomes the main code.
+	fn do_deregister_code(who: T::AccountId, code: ReferralCode<T::CodeLength>) {
+		ReferralCodes::<T>::remove(&code);
+		CodeCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+		CodeRegisteredAt::<T>::remove(&code);
+		CodeDisplay::<T>::remove(&code);
+		CodeDescription::<T>::remove(&code);
+		RewardPayee::<T>::remove(&code);
+		CodeManager::<T>::remove(&code);
+		CodeCampaign::<T>::remove(&code);
+		InactiveCodes::<T>::remove(&code);
+		ReferralCount::<T>::remove(&code);
+		VolumeSinceLastEvent::<T>::remove(&code);
+		CodeTransferOffers::<T>::remove(&code);
+		CodeExpiry::<T>::remove(&code);
+		AutoRenew::<T>::remove(&code);
+
+		if Self::referral_code(&who).as_ref() == Some(&code) {
+			let next_code = AccountCodes::<T>::mutate_exists(&who, |maybe_codes| {
+				let codes = maybe_codes.as_mut()?;
+				let next = (!codes.is_empty()).then(|| codes.remove(0));
+				if codes.is_empty() {
+					*maybe_codes = None;
+				}
+				next
+			});
+			match &next_code {
+				Some(next) => ReferralAccounts::<T>::insert(&who, next),
+				None => ReferralAccounts::<T>::remove(&who),
+			}
+			Self::replace_leaderboard_code(&code, next_code);
+		} else {
+			AccountCodes::<T>::mutate_exists(&who, |maybe_codes| {
+				if let Some(codes) = maybe_codes.as_mut() {
+					codes.retain(|c| *c != code);
+					if codes.is_empty() {
+						*maybe_codes = None;
+					}
+				}
+			});
+		}
+
+		CodeRewardOverride::<T>::remove(&code);
+		Self::deposit_event(Event::CodeDeregistered { code, account: who });
+	}
+
+	/// Return `true` if the expiry of the code has been reached.
+	fn is_code_expired(code: &ReferralCode<T::CodeLength>) -> bool {
+		Self::code_expiry(code).is_some_and(|expires_at| frame_system::Pallet::<T>::block_number() >= expires_at)
+	}
+
+	/// Extend the expiry of an auto-renew code to `RenewalExtension` blocks from the current block.
+	/// Expiry of expired codes is not extended.
+	fn renew_code(code: &ReferralCode<T::CodeLength>) {
+		if !AutoRenew::<T>::contains_key(code) {
+			return;
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let renewed = now.saturating_add(T::RenewalExtension::get());
+		CodeExpiry::<T>::mutate(code, |maybe_expiry| {
+			if let Some(expires_at) = maybe_expiry {
+				if now < *expires_at && *expires_at < renewed {
+					*expires_at = renewed;
+				}
+			}
+		});
+	}
+
+	/// Link the account to the referral account of the code.
+	/// Forced links always emit `CodeLinked` event, regardless of `T::EmitLinkEvents`.
+	/// Expiry of an auto-renew code is extended.
+	fn do_link_code(who: T::AccountId, code: ReferralCode<T::CodeLength>, forced: bool) -> DispatchResult {
+		let code = Self::normalize_code(code);
+		let ref_account = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+		ensure!(!InactiveCodes::<T>::contains_key(&code), Error::<T>::CodeInactive);
+		ensure!(!Self::is_code_expired(&code), Error::<T>::CodeExpired);
+		Self::renew_code(&code);
+
+		LinkedAccounts::<T>::mutate(who.clone(), |v| -> DispatchResult {
+			ensure!(v.is_none(), Error::<T>::AlreadyLinked);
+
+			ensure!(who != ref_account, Error::<T>::LinkNotAllowed);
+			ensure!(
+				!Self::is_in_referral_chain(&ref_account, &who),
+				Error::<T>::CycleDetected
+			);
+
+			*v = Some(ref_account.clone());
+			ReferralCount::<T>::mutate(&code, |count| *count = count.saturating_add(1));
+			LinkedAt::<T>::insert(&who, (frame_system::Pallet::<T>::block_number(), code.clone()));
+			if forced || T::EmitLinkEvents::get() {
+				Self::deposit_event(Event::CodeLinked {
+					account: who,
+					code,
+					referral_account: ref_account,
+					forced,
+				});
+			} else {
+				LinksInPeriod::<T>::mutate(|count| *count = count.saturating_add(1));
+			}
+			Ok(())
+		})
+	}
+
+	/// Ensure the code matches `CodePattern` if set.
+	fn ensure_code_matches_pattern(code: &ReferralCode<T::CodeLength>) -> DispatchResult {
+		if let Some(pattern) = Self::code_pattern() {
+			ensure!(
+				code.len() == pattern.len() && code.iter().zip(pattern.iter()).all(|(c, class)| class.matches(*c)),
+				Error::<T>::PatternMismatch
+			);
+		}
+		Ok(())
+	}
+
+	/// Check the code restrictions and return normalized code.
+	fn validate_code(code: ReferralCode<T::CodeLength>) -> Result<ReferralCode<T::CodeLength>, DispatchError> {
+		ensure!(code.len() >= T::MinCodeLength::get() as usize, Error::<T>::TooShort);
+
+		ensure!(
+			code.clone()
+				.into_inner()
+				.iter()
+				.all(|c| char::is_alphanumeric(*c as char)),
+			Error::<T>::InvalidCharacter
+		);
+
+		Ok(Self::normalize_code(code))
+	}
+
+	/// Increase total accumulated volume of the referrer account.
+	///
+	/// Referrer level is increased if required volume of the next level is reached.
+	/// The leaderboard is updated with the new volume of the referrer's code.
+	/// The amount is added to the volume of the campaign of the referrer's code, if any.
+	/// The volume is capped at `MaxAccruedVolume`, the amount above the cap is not credited.
+	/// `VolumeAccrued` is emitted according to `EmitVolumeEvents`.
+	/// Expiry of the referrer's main code is extended if it is an auto-renew code. Aliases are not renewed.
+	pub(crate) fn accrue_volume(who: &T::AccountId, amount: Balance) {
+		let max_volume = T::MaxAccruedVolume::get();
+		let mut credited = amount;
+		let maybe_total = Referrer::<T>::mutate(who, |v| {
+			let (level, total) = v.as_mut()?;
+			let new_total = total.saturating_add(amount).min(max_volume.max(*total));
+			credited = new_total.saturating_sub(*total);
+			if *total < max_volume && new_total == max_volume {
+				Self::deposit_event(Event::VolumeCapReached { who: who.clone() });
+			}
+			*total = new_total;
+			let new_level = level.increase::<T>(*total);
+			if *level != new_level {
+				*level = new_level;
+				Self::deposit_event(Event::LevelUp {
+					who: who.clone(),
+					level: new_level,
+				});
+			}
+			Some(*total)
+		});
+
+		if credited.is_zero() {
+			return;
+		}
+
+		let Some(code) = Self::referral_code(who) else {
+			return;
+		};
+		Self::renew_code(&code);
+		if let Some(campaign) = Self::code_campaign(&code) {
+			CampaignVolume::<T>::mutate(campaign, |volume| *volume = volume.saturating_add(credited));
+		}
+		if let Some(total) = maybe_total {
+			Self::update_leaderboard(code.clone(), total);
+			Self::report_accrued_volume(code, credited, total);
+		}
+	}
+
+	/// Emit `VolumeAccrued` for the volume accrued by the referrer of the code.
+	///
+	/// If `EmitVolumeEvents` is `false`, the volume is accumulated in `VolumeSinceLastEvent` and the event
+	/// with the accumulated volume is emitted only when it reaches `VolumeEventThreshold`.
+	fn report_accrued_volume(code: ReferralCode<T::CodeLength>, amount: Balance, total: Balance) {
+		if T::EmitVolumeEvents::get() {
+			Self::deposit_event(Event::VolumeAccrued { code, amount, total });
+			return;
+		}
+
+		let accumulated = VolumeSinceLastEvent::<T>::get(&code).saturating_add(amount);
+		if accumulated < T::VolumeEventThreshold::get() {
+			VolumeSinceLastEvent::<T>::insert(&code, accumulated);
+			return;
+		}
+		VolumeSinceLastEvent::<T>::remove(&code);
+		Self::deposit_event(Event::VolumeAccrued {
+			code,
+			amount: accumulated,
+			total,
+		});
+	}
+
+	/// Insert or move the code to its position in the leaderboard.
+	///
+	/// If the leaderboard is full, the code is inserted only if its volume is higher than the lowest volume,
+	/// in which case the code with the lowest volume is removed.
+	fn update_leaderboard(code: ReferralCode<T::CodeLength>, volume: Balance) {
+		Leaderboard::<T>::mutate(|board| {
+			if let Some(idx) = board.iter().position(|(c, _)| *c == code) {
+				board.remove(idx);
+			} else if board.is_full() {
+				match board.last() {
+					Some((_, lowest)) if volume > *lowest => {
+						board.pop();
+					}
+					_ => return,
+				}
+			}
+			let idx = board.iter().position(|(_, v)| *v < volume).unwrap_or(board.len());
+			// there is always enough space as one entry has been removed if the leaderboard was full.
+			let _ = board.try_insert(idx, (code, volume));
+		});
+	}
+
+	/// Replace the code in the leaderboard with new main code of the referrer.
+	/// The entry is removed if the referrer has no code anymore.
+	fn replace_leaderboard_code(code: &ReferralCode<T::CodeLength>, new_code: Option<ReferralCode<T::CodeLength>>) {
+		Leaderboard::<T>::mutate(|board| {
+			if let Some(idx) = board.iter().position(|(c, _)| c == code) {
+				match new_code {
+					Some(new_code) => board[idx].0 = new_code,
+					None => {
+						board.remove(idx);
+					}
+				}
+			}
+		});
+	}
+
+	/// Process trader fee
+	/// `source`: account to take the fee from
+	/// `trader`: account that does the trade
 	///
 	/// Returns used amount on success.
 	#[transactional]
@@ -670,8 +2582,12 @@ impl<T: Config> Pallet<T> {
 
 		// Rewards
 		let external_account = T::ExternalAccount::get();
-		let referrer_reward = if ref_account.is_some() {
-			rewards.referrer.mul_floor(amount)
+		let referrer_reward = if let Some(acc) = ref_account.as_ref() {
+			// Reward override of the referral code takes precedence.
+			Self::referral_code(acc)
+				.and_then(CodeRewardOverride::<T>::get)
+				.unwrap_or(rewards.referrer)
+				.mul_floor(amount)
 		} else {
 			0
 		};
@@ -681,7 +2597,16 @@ impl<T: Config> Pallet<T> {
 		} else {
 			0
 		};
+		let (referrer_reward, chain_rewards) = if let Some(acc) = ref_account.as_ref() {
+			Self::referral_chain_rewards(acc, referrer_reward)
+		} else {
+			(referrer_reward, Vec::new())
+		};
+		let chain_reward = chain_rewards
+			.iter()
+			.fold(Balance::zero(), |acc, (_, reward)| acc.saturating_add(*reward));
 		let total_taken = referrer_reward
+			.saturating_add(chain_reward)
 			.saturating_add(trader_reward)
 			.saturating_add(external_reward);
 		ensure!(total_taken <= amount, Error::<T>::IncorrectRewardCalculation);
@@ -707,6 +2632,14 @@ impl<T: Config> Pallet<T> {
 		} else {
 			0
 		};
+		let chain_shares = chain_rewards
+			.into_iter()
+			.map(|(acc, reward)| {
+				multiply_by_rational_with_rounding(reward, price.n, price.d, Rounding::Down)
+					.map(|shares| (acc, shares))
+					.ok_or(ArithmeticError::Overflow)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
 
 		TotalShares::<T>::mutate(|v| {
 			*v = v.saturating_add(
@@ -716,6 +2649,11 @@ impl<T: Config> Pallet<T> {
 			);
 		});
 
+		for (acc, shares) in chain_shares {
+			TotalShares::<T>::mutate(|v| *v = v.saturating_add(shares));
+			ReferrerShares::<T>::mutate(acc, |v| *v = v.saturating_add(shares));
+		}
+
 		if let Some(acc) = ref_account {
 			ReferrerShares::<T>::mutate(acc, |v| {
 				*v = v.saturating_add(referrer_shares);
@@ -14,9 +14,14 @@
 // limitations under the License.
 
 use super::*;
-use frame_support::{traits::Get, weights::Weight};
+use frame_support::{
+	storage::StoragePrefixedMap,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
 use hex_literal::hex;
 use sp_core::crypto::AccountId32;
+use sp_runtime::SaturatedConversion;
 
 pub fn preregister_parachain_codes<T: Config>() -> Weight
 where
@@ -106,9 +111,109 @@ where
 				ReferralCodes::<T>::insert(&code, &who);
 				ReferralAccounts::<T>::insert(&who, code);
 				Referrer::<T>::insert(&who, (Level::default(), Balance::zero()));
-				weight.saturating_accrue(T::DbWeight::get().writes(3));
+				CodeCount::<T>::mutate(|count| *count = count.saturating_add(1));
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 4));
 			}
 		}
 	}
 	weight
 }
+
+/// Initializes `CodeCount` from the registered referral codes.
+pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let count = ReferralCodes::<T>::iter_keys().count();
+		CodeCount::<T>::put(count.saturated_into::<u32>());
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes((count as u64).saturating_add(1), 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		frame_support::ensure!(
+			CodeCount::<T>::get() as usize == ReferralCodes::<T>::iter_keys().count(),
+			"CodeCount doesn't match the number of referral codes"
+		);
+		Ok(())
+	}
+}
+
+/// Starts the backfill of `AccountCodes` from `ReferralCodes`.
+///
+/// The codes are processed in `on_idle` by `backfill_account_codes` over several blocks.
+pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 2 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		// all entries of the map are after its prefix
+		let start = ReferralCodes::<T>::final_prefix().to_vec();
+		AccountCodesMigrationCursor::<T>::put(BoundedVec::truncate_from(start));
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		frame_support::ensure!(
+			Pallet::<T>::on_chain_storage_version() >= 2,
+			"Storage version was not updated"
+		);
+		Ok(())
+	}
+}
+
+/// Adds the referral codes after `AccountCodesMigrationCursor` to the aliases of their owners in `AccountCodes`,
+/// as many as fit in `remaining_weight`, and moves the cursor. The cursor is removed when all codes were processed.
+///
+/// Main codes of the owners are not added, because they are stored in `ReferralAccounts`. Codes already in
+/// `AccountCodes` are skipped, so processing a code again is harmless.
+pub fn backfill_account_codes<T: Config>(remaining_weight: Weight) -> Weight {
+	let mut weight = T::DbWeight::get().reads(1);
+	let Some(cursor) = AccountCodesMigrationCursor::<T>::get() else {
+		return weight;
+	};
+	let code_weight = T::WeightInfo::backfill_account_code();
+	// the cursor is written back at the end of the step
+	weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+	let mut codes = ReferralCodes::<T>::iter_from(cursor.into_inner());
+	loop {
+		if weight.saturating_add(code_weight).any_gt(remaining_weight) {
+			// a key longer than the bound resumes before it and only processes some codes again
+			let last_key = BoundedVec::truncate_from(codes.last_raw_key().to_vec());
+			AccountCodesMigrationCursor::<T>::put(last_key);
+			break;
+		}
+
+		let Some((code, owner)) = codes.next() else {
+			AccountCodesMigrationCursor::<T>::kill();
+			break;
+		};
+		weight.saturating_accrue(code_weight);
+
+		if ReferralAccounts::<T>::get(&owner).as_ref() == Some(&code) {
+			continue;
+		}
+		// aliases over `MaxAliases` are left out
+		let _ = AccountCodes::<T>::try_mutate(&owner, |aliases| {
+			if aliases.contains(&code) {
+				Ok(())
+			} else {
+				aliases.try_push(code)
+			}
+		});
+	}
+	weight
+}
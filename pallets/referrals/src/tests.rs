@@ -15,14 +15,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod active;
+mod backfill;
+mod campaign;
+mod can_register;
+mod chain;
 mod claim;
+mod code_count;
+mod code_info;
 mod convert;
+mod deregister;
+mod description;
+mod expiry;
 mod flow;
+mod leaderboard;
 mod link;
+mod manager;
+mod migrate;
 mod mock_amm;
+mod normalize;
+mod pattern;
+mod referral_count;
 mod register;
+mod reward_asset;
+mod reward_override;
+mod snapshot;
 mod tiers;
 mod trade_fee;
+mod transfer;
+mod unlink;
+mod volume_cap;
+mod volume_events;
 
 use crate as pallet_referrals;
 use crate::*;
@@ -32,7 +55,7 @@ use std::collections::HashMap;
 
 use frame_support::{
 	assert_noop, assert_ok, construct_runtime, parameter_types,
-	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup, Zero},
+	sp_runtime::traits::{BlakeTwo256, ConstBool, ConstU32, ConstU64, IdentityLookup, Zero},
 	traits::Everything,
 	PalletId,
 };
@@ -69,8 +92,20 @@ thread_local! {
 	pub static CONVERSION_RATE: RefCell<HashMap<(AssetId,AssetId), EmaPrice>> = RefCell::new(HashMap::default());
 	pub static TIER_VOLUME: RefCell<HashMap<Level, Option<Balance>>> = RefCell::new(HashMap::default());
 	pub static TIER_REWARDS: RefCell<HashMap<Level, FeeDistribution>> = RefCell::new(HashMap::default());
+	pub static TIER_REWARD_ASSET: RefCell<HashMap<Level, AssetId>> = RefCell::new(HashMap::default());
 	pub static SEED_AMOUNT: RefCell<Balance> = RefCell::new(Balance::zero());
 	pub static EXTERNAL_ACCOUNT: RefCell<Option<AccountId>> = const { RefCell::new(None) };
+	pub static NORMALIZE_CASE: RefCell<CaseMode> = const { RefCell::new(CaseMode::Upper) };
+	pub static MAX_REFERRAL_DEPTH: RefCell<u32> = const { RefCell::new(1) };
+	pub static MIN_VOLUME_FOR_REWARD: RefCell<Balance> = const { RefCell::new(0) };
+	pub static EMIT_LINK_EVENTS: RefCell<bool> = const { RefCell::new(true) };
+	pub static REWARD_CAP_PER_PERIOD: RefCell<Balance> = const { RefCell::new(Balance::MAX) };
+	pub static MAX_ACCRUED_VOLUME: RefCell<Balance> = const { RefCell::new(Balance::MAX) };
+	pub static REWARD_POT_SHARE: RefCell<Permill> = const { RefCell::new(Permill::from_percent(0)) };
+	pub static MIN_CODE_LENGTH: RefCell<u32> = const { RefCell::new(4) };
+	pub static VANITY_LENGTH_THRESHOLD: RefCell<u32> = const { RefCell::new(0) };
+	pub static EMIT_VOLUME_EVENTS: RefCell<bool> = const { RefCell::new(true) };
+	pub static VOLUME_EVENT_THRESHOLD: RefCell<Balance> = const { RefCell::new(0) };
 }
 
 construct_runtime!(
@@ -86,9 +121,24 @@ construct_runtime!(
 parameter_types! {
 	pub const RefarralPalletId: PalletId = PalletId(*b"test_ref");
 	pub const CodeLength: u32 = 10;
-	pub const MinCodeLength: u32 = 4;
+	pub MinCodeLength: u32 = MIN_CODE_LENGTH.with(|v| *v.borrow());
+	pub const TransactionFeeDiscount: Permill = Permill::from_percent(10);
+	pub const MaxRewardRate: Permill = Permill::from_percent(80);
+	pub NormalizeCase: CaseMode = NORMALIZE_CASE.with(|v| *v.borrow());
+	pub MaxReferralDepth: u32 = MAX_REFERRAL_DEPTH.with(|v| *v.borrow());
+	pub const ReferralChainDecay: Permill = Permill::from_percent(50);
+	pub MinVolumeForReward: Balance = MIN_VOLUME_FOR_REWARD.with(|v| *v.borrow());
+	pub EmitLinkEvents: bool = EMIT_LINK_EVENTS.with(|v| *v.borrow());
+	pub RewardCapPerPeriod: Balance = REWARD_CAP_PER_PERIOD.with(|v| *v.borrow());
+	pub MaxAccruedVolume: Balance = MAX_ACCRUED_VOLUME.with(|v| *v.borrow());
+	pub const RewardPeriod: u64 = 10;
 	pub const RegistrationFee: (AssetId,Balance, AccountId) = (HDX, 222 * 1_000_000_000_000, TREASURY) ;
+	pub RewardPotShare: Permill = REWARD_POT_SHARE.with(|v| *v.borrow());
+	pub const VanityFee: Balance = 500 * ONE;
+	pub VanityLengthThreshold: u32 = VANITY_LENGTH_THRESHOLD.with(|v| *v.borrow());
 	pub const RewardAsset: AssetId = HDX;
+	pub EmitVolumeEvents: bool = EMIT_VOLUME_EVENTS.with(|v| *v.borrow());
+	pub VolumeEventThreshold: Balance = VOLUME_EVENT_THRESHOLD.with(|v| *v.borrow());
 }
 
 pub struct LevelVolumeAndRewards;
@@ -111,6 +161,14 @@ impl GetByKey<Level, (Balance, FeeDistribution)> for LevelVolumeAndRewards {
 	}
 }
 
+pub struct TierRewardAsset;
+
+impl GetByKey<Level, Option<AssetId>> for TierRewardAsset {
+	fn get(level: &Level) -> Option<AssetId> {
+		TIER_REWARD_ASSET.with(|v| v.borrow().get(level).copied())
+	}
+}
+
 pub struct SeedAmount;
 
 impl Get<Balance> for SeedAmount {
@@ -135,13 +193,38 @@ impl Config for Test {
 	type Convert = AssetConvert;
 	type PriceProvider = ConversionPrice;
 	type RewardAsset = RewardAsset;
+	type RewardAssetForTier = TierRewardAsset;
 	type PalletId = RefarralPalletId;
 	type RegistrationFee = RegistrationFee;
+	type RewardPotShare = RewardPotShare;
+	type VanityFee = VanityFee;
+	type VanityLengthThreshold = VanityLengthThreshold;
 	type CodeLength = CodeLength;
 	type MinCodeLength = MinCodeLength;
 	type LevelVolumeAndRewardPercentages = LevelVolumeAndRewards;
 	type ExternalAccount = ExtAccount;
 	type SeedNativeAmount = SeedAmount;
+	type MaxLeaderboard = ConstU32<3>;
+	type MaxAliases = ConstU32<3>;
+	type TransactionFeeDiscount = TransactionFeeDiscount;
+	type BlockNumericCodes = ConstBool<true>;
+	type MaxRewardRate = MaxRewardRate;
+	type NormalizeCase = NormalizeCase;
+	type MaxReferralDepth = MaxReferralDepth;
+	type ReferralChainDecay = ReferralChainDecay;
+	type MinVolumeForReward = MinVolumeForReward;
+	type EmitLinkEvents = EmitLinkEvents;
+	type RewardCapPerPeriod = RewardCapPerPeriod;
+	type RewardPeriod = RewardPeriod;
+	type MaxAccruedVolume = MaxAccruedVolume;
+	type MaxDescLen = ConstU32<16>;
+	type UnlinkGracePeriod = ConstU64<10>;
+	type TransferOfferTtl = ConstU64<10>;
+	type RenewalExtension = ConstU64<100>;
+	type EmitVolumeEvents = EmitVolumeEvents;
+	type VolumeEventThreshold = VolumeEventThreshold;
+	type SnapshotPeriod = ConstU64<10>;
+	type MaxCodesPerSnapshot = ConstU32<2>;
 	type WeightInfo = ();
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -206,6 +289,10 @@ impl mock_amm::pallet::Config for Test {
 	type TradeHooks = AmmTrader;
 }
 
+pub fn to_code(code: &[u8]) -> ReferralCode<<Test as Config>::CodeLength> {
+	code.to_vec().try_into().unwrap()
+}
+
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
 	referrer_shares: Vec<(AccountId, Balance)>,
@@ -229,10 +316,46 @@ impl Default for ExtBuilder {
 		TIER_REWARDS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		TIER_REWARD_ASSET.with(|v| {
+			v.borrow_mut().clear();
+		});
 		EXTERNAL_ACCOUNT.with(|v| {
 			let mut c = v.borrow_mut();
 			*c = None;
 		});
+		NORMALIZE_CASE.with(|v| {
+			*v.borrow_mut() = CaseMode::Upper;
+		});
+		MAX_REFERRAL_DEPTH.with(|v| {
+			*v.borrow_mut() = 1;
+		});
+		MIN_VOLUME_FOR_REWARD.with(|v| {
+			*v.borrow_mut() = 0;
+		});
+		EMIT_LINK_EVENTS.with(|v| {
+			*v.borrow_mut() = true;
+		});
+		REWARD_CAP_PER_PERIOD.with(|v| {
+			*v.borrow_mut() = Balance::MAX;
+		});
+		MAX_ACCRUED_VOLUME.with(|v| {
+			*v.borrow_mut() = Balance::MAX;
+		});
+		REWARD_POT_SHARE.with(|v| {
+			*v.borrow_mut() = Permill::from_percent(0);
+		});
+		MIN_CODE_LENGTH.with(|v| {
+			*v.borrow_mut() = 4;
+		});
+		VANITY_LENGTH_THRESHOLD.with(|v| {
+			*v.borrow_mut() = 0;
+		});
+		EMIT_VOLUME_EVENTS.with(|v| {
+			*v.borrow_mut() = true;
+		});
+		VOLUME_EVENT_THRESHOLD.with(|v| {
+			*v.borrow_mut() = 0;
+		});
 
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, INITIAL_ALICE_BALANCE)],
@@ -298,6 +421,86 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_tier_reward_asset(self, level: Level, asset_id: AssetId) -> Self {
+		TIER_REWARD_ASSET.with(|v| {
+			v.borrow_mut().insert(level, asset_id);
+		});
+		self
+	}
+
+	pub fn with_normalize_case(self, mode: CaseMode) -> Self {
+		NORMALIZE_CASE.with(|v| {
+			*v.borrow_mut() = mode;
+		});
+		self
+	}
+
+	pub fn with_max_referral_depth(self, depth: u32) -> Self {
+		MAX_REFERRAL_DEPTH.with(|v| {
+			*v.borrow_mut() = depth;
+		});
+		self
+	}
+
+	pub fn with_min_volume_for_reward(self, amount: Balance) -> Self {
+		MIN_VOLUME_FOR_REWARD.with(|v| {
+			*v.borrow_mut() = amount;
+		});
+		self
+	}
+
+	pub fn with_link_events(self, emit: bool) -> Self {
+		EMIT_LINK_EVENTS.with(|v| {
+			*v.borrow_mut() = emit;
+		});
+		self
+	}
+
+	pub fn with_reward_cap_per_period(self, cap: Balance) -> Self {
+		REWARD_CAP_PER_PERIOD.with(|v| {
+			*v.borrow_mut() = cap;
+		});
+		self
+	}
+
+	pub fn with_max_accrued_volume(self, volume: Balance) -> Self {
+		MAX_ACCRUED_VOLUME.with(|v| {
+			*v.borrow_mut() = volume;
+		});
+		self
+	}
+
+	pub fn with_reward_pot_share(self, share: Permill) -> Self {
+		REWARD_POT_SHARE.with(|v| {
+			*v.borrow_mut() = share;
+		});
+		self
+	}
+
+	pub fn with_min_code_length(self, length: u32) -> Self {
+		MIN_CODE_LENGTH.with(|v| {
+			*v.borrow_mut() = length;
+		});
+		self
+	}
+
+	pub fn with_vanity_length_threshold(self, threshold: u32) -> Self {
+		VANITY_LENGTH_THRESHOLD.with(|v| {
+			*v.borrow_mut() = threshold;
+		});
+		self
+	}
+
+	pub fn with_volume_events(self, emit: bool, threshold: Balance) -> Self {
+		EMIT_VOLUME_EVENTS.with(|v| {
+			*v.borrow_mut() = emit;
+		});
+		VOLUME_EVENT_THRESHOLD.with(|v| {
+			*v.borrow_mut() = threshold;
+		});
+		self
+	}
+
 	pub fn with_external_account(self, acc: AccountId) -> Self {
 		EXTERNAL_ACCOUNT.with(|v| {
 			let mut m = v.borrow_mut();
@@ -0,0 +1,115 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn set_code_active_should_mark_code_inactive_when_disabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act
+		assert_ok!(Referrals::set_code_active(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			false
+		));
+		// Assert
+		assert!(InactiveCodes::<Test>::contains_key(to_code(b"BALLS69")));
+		expect_events(vec![Event::CodeActiveSet {
+			code: to_code(b"BALLS69"),
+			active: false,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn link_code_should_fail_when_code_is_inactive() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_active(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			false
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+			Error::<Test>::CodeInactive
+		);
+	});
+}
+
+#[test]
+fn link_code_should_work_when_code_is_enabled_again() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_active(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			false
+		));
+		// Act
+		assert_ok!(Referrals::set_code_active(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			true
+		));
+		// Assert
+		assert!(!InactiveCodes::<Test>::contains_key(to_code(b"BALLS69")));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		assert_eq!(Referrals::linked_referral_account(BOB), Some(ALICE));
+	});
+}
+
+#[test]
+fn set_code_active_should_fail_when_caller_is_not_code_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::set_code_active(RuntimeOrigin::signed(BOB), to_code(b"BALLS69"), false),
+			Error::<Test>::NotCodeOwner
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_should_work_when_code_is_inactive() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(ALICE, 5_000_000_000_000), (BOB, 15_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69")
+			));
+			assert_ok!(Referrals::set_code_active(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69"),
+				false
+			));
+			let balance_before = Tokens::free_balance(HDX, &ALICE);
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			// Assert
+			let balance = Tokens::free_balance(HDX, &ALICE);
+			assert_eq!(balance - balance_before, 5_000_000_000_000);
+		});
+}
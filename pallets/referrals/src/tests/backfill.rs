@@ -0,0 +1,114 @@
+use crate::migration::{backfill_account_codes, MigrateToV2};
+use crate::tests::*;
+use frame_support::traits::{Hooks, OnRuntimeUpgrade, StorageVersion};
+use frame_support::weights::Weight;
+use pretty_assertions::assert_eq;
+
+const OWNERS: u64 = 20;
+const ALIASES: u8 = 2;
+
+fn main_code(owner: AccountId) -> ReferralCode<<Test as Config>::CodeLength> {
+	to_code(format!("MAIN{owner:04}").as_bytes())
+}
+
+fn alias_code(owner: AccountId, index: u8) -> ReferralCode<<Test as Config>::CodeLength> {
+	to_code(format!("ALIAS{owner:04}{index}").as_bytes())
+}
+
+/// Codes registered before `AccountCodes` was introduced: the aliases are only in `ReferralCodes`.
+fn insert_codes_without_account_codes() {
+	StorageVersion::new(1).put::<Pallet<Test>>();
+	for owner in 100..100 + OWNERS {
+		ReferralCodes::<Test>::insert(main_code(owner), owner);
+		ReferralAccounts::<Test>::insert(owner, main_code(owner));
+		for index in 0..ALIASES {
+			ReferralCodes::<Test>::insert(alias_code(owner, index), owner);
+		}
+	}
+}
+
+fn code_weight() -> Weight {
+	<Test as Config>::WeightInfo::backfill_account_code()
+}
+
+#[test]
+fn backfill_should_populate_account_codes_over_several_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		insert_codes_without_account_codes();
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		// Act
+		let mut blocks = 0u64;
+		while AccountCodesMigrationCursor::<Test>::get().is_some() {
+			blocks += 1;
+			Referrals::on_idle(blocks, code_weight().saturating_mul(10));
+			assert!(blocks <= 10, "backfill did not complete");
+		}
+
+		// Assert
+		// 60 codes, 10 codes per block
+		assert!(blocks >= 6);
+		for owner in 100..100 + OWNERS {
+			let mut aliases = Referrals::account_codes(owner).into_inner();
+			aliases.sort();
+			assert_eq!(
+				aliases,
+				(0..ALIASES).map(|index| alias_code(owner, index)).collect::<Vec<_>>()
+			);
+		}
+	});
+}
+
+#[test]
+fn backfill_should_stop_when_weight_is_exhausted() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		insert_codes_without_account_codes();
+		MigrateToV2::<Test>::on_runtime_upgrade();
+		let cursor = AccountCodesMigrationCursor::<Test>::get();
+
+		// Act
+		let weight = backfill_account_codes::<Test>(code_weight().saturating_sub(Weight::from_parts(1, 0)));
+
+		// Assert
+		assert!(weight.all_lt(code_weight()));
+		assert_eq!(AccountCodesMigrationCursor::<Test>::get(), cursor);
+		assert_eq!(AccountCodes::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn backfill_should_not_add_main_code_or_duplicate_aliases() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		insert_codes_without_account_codes();
+		AccountCodes::<Test>::insert(100, BoundedVec::truncate_from(vec![alias_code(100, 0)]));
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		// Act
+		backfill_account_codes::<Test>(Weight::MAX);
+
+		// Assert
+		assert_eq!(AccountCodesMigrationCursor::<Test>::get(), None);
+		let aliases = Referrals::account_codes(100).into_inner();
+		assert_eq!(aliases.len(), ALIASES as usize);
+		assert!(aliases.contains(&alias_code(100, 0)));
+		assert!(aliases.contains(&alias_code(100, 1)));
+		assert!(!aliases.contains(&main_code(100)));
+	});
+}
+
+#[test]
+fn migration_should_not_restart_backfill_when_storage_version_is_current() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		StorageVersion::new(2).put::<Pallet<Test>>();
+
+		// Act
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		// Assert
+		assert_eq!(AccountCodesMigrationCursor::<Test>::get(), None);
+	});
+}
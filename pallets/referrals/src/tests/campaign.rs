@@ -0,0 +1,88 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn register_code_in_campaign_should_assign_campaign_to_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(Referrals::register_code_in_campaign(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"AAAA"),
+			1
+		));
+		// Assert
+		assert_eq!(Referrals::referral_account(to_code(b"AAAA")), Some(ALICE));
+		assert_eq!(Referrals::code_campaign(to_code(b"AAAA")), Some(1));
+		expect_events(vec![Event::CodeCampaignSet {
+			code: to_code(b"AAAA"),
+			campaign: 1,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn accrue_volume_should_aggregate_volume_of_codes_in_same_campaign() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 1_000 * ONE), (CHARLIE, HDX, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code_in_campaign(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"AAAA"),
+				1
+			));
+			assert_ok!(Referrals::register_code_in_campaign(
+				RuntimeOrigin::signed(BOB),
+				to_code(b"BBBB"),
+				1
+			));
+			assert_ok!(Referrals::register_code_in_campaign(
+				RuntimeOrigin::signed(CHARLIE),
+				to_code(b"CCCC"),
+				2
+			));
+			// Act
+			Referrals::accrue_volume(&ALICE, 1_000);
+			Referrals::accrue_volume(&BOB, 2_000);
+			Referrals::accrue_volume(&CHARLIE, 500);
+			// Assert
+			assert_eq!(Referrals::campaign_volume(1), 3_000);
+			assert_eq!(Referrals::campaign_volume(2), 500);
+			assert_eq!(Referrals::referrer_level(ALICE).unwrap().1, 1_000);
+			assert_eq!(Referrals::referrer_level(BOB).unwrap().1, 2_000);
+		});
+}
+
+#[test]
+fn accrue_volume_should_not_change_campaign_volume_when_code_has_no_campaign() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), to_code(b"AAAA")));
+		// Act
+		Referrals::accrue_volume(&ALICE, 1_000);
+		// Assert
+		assert_eq!(Referrals::code_campaign(to_code(b"AAAA")), None);
+		assert_eq!(Referrals::campaign_volume(0), 0);
+	});
+}
+
+#[test]
+fn deregister_code_should_remove_campaign_of_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code_in_campaign(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"AAAA"),
+			1
+		));
+		// Act
+		assert_ok!(Referrals::deregister_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"AAAA")
+		));
+		// Assert
+		assert_eq!(Referrals::code_campaign(to_code(b"AAAA")), None);
+	});
+}
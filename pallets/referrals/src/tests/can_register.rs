@@ -0,0 +1,115 @@
+use crate::tests::*;
+use frame_support::assert_storage_noop;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn can_register_should_return_ok_without_changing_state_when_all_checks_pass() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_storage_noop!(assert_eq!(Referrals::can_register(&ALICE, b"BALLS69".to_vec()), Ok(())));
+		// the registration succeeds as well
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+	});
+}
+
+#[test]
+fn can_register_should_return_too_long_when_code_exceeds_code_length() {
+	ExtBuilder::default().build().execute_with(|| {
+		let code = vec![b'x'; <Test as Config>::CodeLength::get() as usize + 1];
+		assert_eq!(Referrals::can_register(&ALICE, code), Err(RegistrationError::TooLong));
+	});
+}
+
+#[test]
+fn can_register_should_return_too_short_when_code_is_below_min_length() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			Referrals::can_register(&ALICE, vec![]),
+			Err(RegistrationError::TooShort)
+		);
+	});
+}
+
+#[test]
+fn can_register_should_return_invalid_character_when_code_is_not_alphanumeric() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			Referrals::can_register(&ALICE, b"BALLS-69".to_vec()),
+			Err(RegistrationError::InvalidCharacter)
+		);
+	});
+}
+
+#[test]
+fn can_register_should_return_numeric_code_reserved_when_code_is_digits_only() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			Referrals::can_register(&ALICE, b"1234567".to_vec()),
+			Err(RegistrationError::NumericCodeReserved)
+		);
+	});
+}
+
+#[test]
+fn can_register_should_return_pattern_mismatch_when_code_does_not_match_pattern() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::set_code_pattern(
+			RuntimeOrigin::root(),
+			Some(vec![CharClass::Letter; 7].try_into().unwrap())
+		));
+		// Assert
+		assert_eq!(
+			Referrals::can_register(&ALICE, b"BALLS69".to_vec()),
+			Err(RegistrationError::PatternMismatch)
+		);
+	});
+}
+
+#[test]
+fn can_register_should_return_already_registered_when_account_has_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Assert
+		assert_eq!(
+			Referrals::can_register(&ALICE, b"DUMMY42".to_vec()),
+			Err(RegistrationError::AlreadyRegistered)
+		);
+	});
+}
+
+#[test]
+fn can_register_should_return_already_exists_when_code_is_registered() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 2_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69")
+			));
+			// Assert
+			assert_eq!(
+				Referrals::can_register(&BOB, b"balls69".to_vec()),
+				Err(RegistrationError::AlreadyExists)
+			);
+		});
+}
+
+#[test]
+fn can_register_should_return_insufficient_balance_when_account_cannot_pay_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			Referrals::can_register(&BOB, b"BALLS69".to_vec()),
+			Err(RegistrationError::InsufficientBalance)
+		);
+		assert!(Referrals::register_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")).is_err());
+	});
+}
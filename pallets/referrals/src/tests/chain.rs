@@ -0,0 +1,240 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+const DAVE: AccountId = 4;
+
+fn default_rewards() -> Vec<(AssetId, Level, FeeDistribution)> {
+	vec![(
+		DAI,
+		Level::Tier0,
+		FeeDistribution {
+			referrer: Permill::from_percent(50),
+			trader: Permill::zero(),
+			external: Permill::zero(),
+		},
+	)]
+}
+
+/// Creates referral chain CHARLIE -> BOB -> ALICE -> DAVE.
+fn create_referral_chain() {
+	assert_ok!(Referrals::register_code(
+		RuntimeOrigin::signed(DAVE),
+		to_code(b"DAVE01")
+	));
+	assert_ok!(Referrals::register_code(
+		RuntimeOrigin::signed(ALICE),
+		to_code(b"ALICE01")
+	));
+	assert_ok!(Referrals::link_code(RuntimeOrigin::signed(ALICE), to_code(b"DAVE01")));
+	assert_ok!(Referrals::register_code(RuntimeOrigin::signed(BOB), to_code(b"BOB01")));
+	assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"ALICE01")));
+	assert_ok!(Referrals::link_code(RuntimeOrigin::signed(CHARLIE), to_code(b"BOB01")));
+}
+
+#[test]
+fn process_trade_fee_should_distribute_decayed_rewards_up_the_referral_chain() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, HDX, INITIAL_ALICE_BALANCE),
+			(DAVE, HDX, INITIAL_ALICE_BALANCE),
+			(CHARLIE, DAI, 2_000_000_000_000_000_000),
+		])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(default_rewards())
+		.with_max_referral_depth(3)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_referral_chain();
+			// Act
+			assert_ok!(MockAmm::trade(
+				RuntimeOrigin::signed(CHARLIE),
+				HDX,
+				DAI,
+				1_000_000_000_000,
+			));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 2_500_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 1_250_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(DAVE), 1_250_000_000);
+			assert_eq!(Referrals::total_shares(), 5_000_000_000);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_not_take_more_than_fee_when_whole_fee_is_distributed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, HDX, INITIAL_ALICE_BALANCE),
+			(DAVE, HDX, INITIAL_ALICE_BALANCE),
+			(CHARLIE, DAI, 2_000_000_000_000_000_000),
+		])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(vec![(
+			DAI,
+			Level::Tier0,
+			FeeDistribution {
+				referrer: Permill::from_percent(80),
+				trader: Permill::from_percent(20),
+				external: Permill::zero(),
+			},
+		)])
+		.with_max_referral_depth(3)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_referral_chain();
+			// Act
+			assert_ok!(MockAmm::trade(
+				RuntimeOrigin::signed(CHARLIE),
+				HDX,
+				DAI,
+				1_000_000_000_000,
+			));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 4_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 2_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(DAVE), 2_000_000_000);
+			assert_eq!(TraderShares::<Test>::get(CHARLIE), 2_000_000_000);
+			assert_eq!(Referrals::total_shares(), 10_000_000_000);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_not_reward_referrers_beyond_max_referral_depth() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, HDX, INITIAL_ALICE_BALANCE),
+			(DAVE, HDX, INITIAL_ALICE_BALANCE),
+			(CHARLIE, DAI, 2_000_000_000_000_000_000),
+		])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(default_rewards())
+		.with_max_referral_depth(2)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_referral_chain();
+			// Act
+			assert_ok!(MockAmm::trade(
+				RuntimeOrigin::signed(CHARLIE),
+				HDX,
+				DAI,
+				1_000_000_000_000,
+			));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 2_500_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 2_500_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(DAVE), 0);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_reward_only_direct_referrer_when_max_referral_depth_is_one() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, HDX, INITIAL_ALICE_BALANCE),
+			(DAVE, HDX, INITIAL_ALICE_BALANCE),
+			(CHARLIE, DAI, 2_000_000_000_000_000_000),
+		])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(default_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_referral_chain();
+			// Act
+			assert_ok!(MockAmm::trade(
+				RuntimeOrigin::signed(CHARLIE),
+				HDX,
+				DAI,
+				1_000_000_000_000,
+			));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 5_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 0);
+			assert_eq!(ReferrerShares::<Test>::get(DAVE), 0);
+		});
+}
+
+#[test]
+fn link_code_should_fail_when_link_creates_cycle() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, HDX, INITIAL_ALICE_BALANCE),
+			(CHARLIE, HDX, INITIAL_ALICE_BALANCE),
+		])
+		.with_max_referral_depth(3)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"ALICE01")
+			));
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(BOB), to_code(b"BOB01")));
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(CHARLIE),
+				to_code(b"CHARLIE01")
+			));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"ALICE01")));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(CHARLIE), to_code(b"BOB01")));
+			// Act & Assert
+			assert_noop!(
+				Referrals::link_code(RuntimeOrigin::signed(ALICE), to_code(b"BOB01")),
+				Error::<Test>::CycleDetected
+			);
+			assert_noop!(
+				Referrals::link_code(RuntimeOrigin::signed(ALICE), to_code(b"CHARLIE01")),
+				Error::<Test>::CycleDetected
+			);
+		});
+}
+
+#[test]
+fn referral_chain_should_return_all_referral_accounts_up_the_chain() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, HDX, INITIAL_ALICE_BALANCE),
+			(DAVE, HDX, INITIAL_ALICE_BALANCE),
+		])
+		.with_max_referral_depth(3)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_referral_chain();
+			// Act & Assert
+			assert_eq!(Referrals::referral_chain(&CHARLIE, 10), vec![BOB, ALICE, DAVE]);
+			assert_eq!(Referrals::referral_chain(&CHARLIE, 2), vec![BOB, ALICE]);
+			assert_eq!(Referrals::referral_chain(&DAVE, 10), vec![]);
+		});
+}
+
+#[test]
+fn referral_chain_should_terminate_when_chain_is_cyclic() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange - cycles are rejected when linking, so the links are inserted directly
+		LinkedAccounts::<Test>::insert(ALICE, BOB);
+		LinkedAccounts::<Test>::insert(BOB, CHARLIE);
+		LinkedAccounts::<Test>::insert(CHARLIE, ALICE);
+		// Act & Assert
+		assert_eq!(Referrals::referral_chain(&ALICE, u32::MAX), vec![BOB, CHARLIE]);
+		assert_eq!(Referrals::referral_chain(&BOB, u32::MAX), vec![CHARLIE, ALICE]);
+	});
+}
+
+#[test]
+fn referral_chain_should_be_capped_at_max_query_depth() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let depth = MAX_REFERRAL_CHAIN_QUERY_DEPTH as AccountId + 10;
+		for acc in 100..100 + depth {
+			LinkedAccounts::<Test>::insert(acc, acc + 1);
+		}
+		// Act & Assert
+		assert_eq!(
+			Referrals::referral_chain(&100, u32::MAX).len(),
+			MAX_REFERRAL_CHAIN_QUERY_DEPTH as usize
+		);
+	});
+}
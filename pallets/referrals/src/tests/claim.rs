@@ -307,3 +307,185 @@ fn claim_rewards_should_increase_referrer_level_directly_to_top_tier_when_limit_
 			assert_eq!(total, 15_000_000_000_000);
 		});
 }
+
+#[test]
+fn claim_rewards_should_fail_when_accrued_volume_is_below_min_volume() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_min_volume_for_reward(6_000_000_000_000)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			Referrer::<Test>::insert(BOB, (Level::Tier0, 0));
+			// Act & Assert
+			assert_noop!(
+				Referrals::claim_rewards(RuntimeOrigin::signed(BOB)),
+				Error::<Test>::VolumeBelowThreshold
+			);
+			let shares = ReferrerShares::<Test>::get(BOB);
+			assert_eq!(shares, 5_000_000_000_000);
+		});
+}
+
+#[test]
+fn claim_rewards_should_work_when_pending_rewards_reach_min_volume() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_min_volume_for_reward(5_000_000_000_000)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			Referrer::<Test>::insert(BOB, (Level::Tier0, 0));
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			let reserve = Tokens::free_balance(HDX, &BOB);
+			assert_eq!(reserve, 5_000_000_000_000);
+			let shares = ReferrerShares::<Test>::get(BOB);
+			assert_eq!(shares, 0);
+		});
+}
+
+#[test]
+fn claim_rewards_should_work_when_accrued_volume_reaches_min_volume() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_min_volume_for_reward(6_000_000_000_000)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			Referrer::<Test>::insert(BOB, (Level::Tier0, 1_000_000_000_000));
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			let reserve = Tokens::free_balance(HDX, &BOB);
+			assert_eq!(reserve, 5_000_000_000_000);
+			let (_, total) = Referrer::<Test>::get(BOB).unwrap();
+			assert_eq!(total, 6_000_000_000_000);
+		});
+}
+
+#[test]
+fn claim_rewards_should_not_apply_min_volume_when_account_is_not_referrer() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_trader_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_min_volume_for_reward(6_000_000_000_000)
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			let reserve = Tokens::free_balance(HDX, &BOB);
+			assert_eq!(reserve, 5_000_000_000_000);
+			let shares = TraderShares::<Test>::get(BOB);
+			assert_eq!(shares, 0);
+		});
+}
+
+#[test]
+fn claim_rewards_should_pay_all_rewards_when_within_reward_cap() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_reward_cap_per_period(10_000_000_000_000)
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 5_000_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 0);
+			assert_eq!(Referrals::rewards_claimed_in_period(BOB), (0, 5_000_000_000_000));
+			assert!(!System::events()
+				.iter()
+				.any(|r| matches!(r.event, RuntimeEvent::Referrals(Event::RewardDeferred { .. }))));
+		});
+}
+
+#[test]
+fn claim_rewards_should_defer_rewards_above_reward_cap_to_next_period() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_reward_cap_per_period(2_000_000_000_000)
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 2_000_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 3_000_000_000_000);
+			assert_eq!(TotalShares::<Test>::get(), 18_000_000_000_000);
+			expect_events(vec![
+				Event::RewardDeferred {
+					who: BOB,
+					amount: 3_000_000_000_000,
+				}
+				.into(),
+				Event::Claimed {
+					who: BOB,
+					referrer_rewards: 2_000_000_000_000,
+					trade_rewards: 0,
+				}
+				.into(),
+			]);
+
+			// Act - the cap is reached in the current period
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 2_000_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 3_000_000_000_000);
+
+			// Act - next reward period
+			System::set_block_number(10);
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 4_000_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 1_000_000_000_000);
+			assert_eq!(Referrals::rewards_claimed_in_period(BOB), (1, 2_000_000_000_000));
+
+			// Act - remaining rewards within the cap
+			System::set_block_number(20);
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 5_000_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 0);
+			assert_eq!(TotalShares::<Test>::get(), 15_000_000_000_000);
+		});
+}
+
+#[test]
+fn claim_rewards_should_transfer_rewards_to_payee_when_assigned() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			let alice_balance = Tokens::free_balance(HDX, &ALICE);
+			// Act
+			assert_ok!(Referrals::assign_rewards(RuntimeOrigin::signed(ALICE), CHARLIE));
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			// Assert
+			assert_eq!(Referrals::reward_payee(code), Some(CHARLIE));
+			assert_eq!(Tokens::free_balance(HDX, &CHARLIE), 15_000_000_000_000);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_balance);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 0);
+		});
+}
+
+#[test]
+fn assign_rewards_should_fail_when_signer_has_no_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::assign_rewards(RuntimeOrigin::signed(BOB), CHARLIE),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
@@ -0,0 +1,127 @@
+use crate::tests::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn code_count_should_increase_when_code_and_alias_are_registered() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69")
+			));
+			assert_ok!(Referrals::register_alias(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"ALIAS1")
+			));
+			assert_ok!(Referrals::force_register_code(
+				RuntimeOrigin::root(),
+				BOB,
+				to_code(b"12345")
+			));
+			// Assert
+			assert_eq!(Referrals::code_count(), 3);
+		});
+}
+
+#[test]
+fn code_count_should_not_change_when_registration_fails() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69")
+			));
+			// Act
+			assert_noop!(
+				Referrals::register_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+				Error::<Test>::AlreadyExists
+			);
+			// Assert
+			assert_eq!(Referrals::code_count(), 1);
+		});
+}
+
+#[test]
+fn code_count_should_decrease_when_code_is_deregistered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS1")
+		));
+		// Act
+		assert_ok!(Referrals::deregister_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS1")
+		));
+		// Assert
+		assert_eq!(Referrals::code_count(), 1);
+	});
+}
+
+#[test]
+fn code_count_should_decrease_by_all_codes_when_all_codes_are_deregistered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS1")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS2")
+		));
+		// Act
+		assert_ok!(Referrals::deregister_all(RuntimeOrigin::signed(ALICE)));
+		// Assert
+		assert_eq!(Referrals::code_count(), 0);
+	});
+}
+
+#[test]
+fn migration_should_initialize_code_count_from_registered_codes() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		ReferralCodes::<Test>::insert(to_code(b"BALLS69"), ALICE);
+		ReferralCodes::<Test>::insert(to_code(b"ALIAS1"), ALICE);
+		ReferralCodes::<Test>::insert(to_code(b"CODE2"), BOB);
+		StorageVersion::new(0).put::<Referrals>();
+		// Act
+		migration::MigrateToV1::<Test>::on_runtime_upgrade();
+		// Assert
+		assert_eq!(Referrals::code_count(), 3);
+		assert_eq!(Referrals::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
+
+#[test]
+fn migration_should_not_change_code_count_when_already_migrated() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		StorageVersion::new(1).put::<Referrals>();
+		ReferralCodes::<Test>::insert(to_code(b"CODE2"), BOB);
+		// Act
+		migration::MigrateToV1::<Test>::on_runtime_upgrade();
+		// Assert
+		assert_eq!(Referrals::code_count(), 1);
+	});
+}
@@ -0,0 +1,91 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn code_info_should_return_full_profile_of_registered_code() {
+	let mut volumes = HashMap::new();
+	volumes.insert(Level::Tier0, Some(0));
+	volumes.insert(Level::Tier1, Some(10_000_000_000_000));
+	volumes.insert(Level::Tier2, Some(20_000_000_000_000));
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_tier_volumes(volumes)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			System::set_block_number(10);
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			// Act
+			let info = Referrals::code_info(b"BALLS69".to_vec());
+			// Assert
+			assert_eq!(
+				info,
+				Some(CodeInfo {
+					owner: ALICE,
+					level: Level::Tier1,
+					volume: 15_000_000_000_000,
+					registered_at: Some(10),
+					display: Some(b"BALLS69".to_vec()),
+					description: None,
+					referral_count: 1,
+					expires_at: None,
+					auto_renew: false,
+				})
+			);
+		});
+}
+
+#[test]
+fn code_info_should_return_expiry_of_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		assert_ok!(Referrals::set_code_expiry(RuntimeOrigin::root(), code, Some(50), true));
+		// Act
+		let info = Referrals::code_info(b"BALLS69".to_vec()).unwrap();
+		// Assert
+		assert_eq!(info.expires_at, Some(50));
+		assert!(info.auto_renew);
+	});
+}
+
+#[test]
+fn code_info_should_normalize_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+		// Act
+		let info = Referrals::code_info(b"balls69".to_vec());
+		// Assert
+		assert_eq!(info.map(|info| info.owner), Some(ALICE));
+	});
+}
+
+#[test]
+fn code_info_should_return_none_when_code_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Referrals::code_info(b"BALLS69".to_vec()), None);
+		assert_eq!(Referrals::code_info(b"TOOLONGCODE123".to_vec()), None);
+	});
+}
+
+#[test]
+fn code_info_should_return_none_when_code_is_deregistered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// Act
+		assert_ok!(Referrals::deregister_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// Assert
+		assert_eq!(Referrals::code_info(code.to_vec()), None);
+		assert_eq!(Referrals::code_registered_at(code), None);
+	});
+}
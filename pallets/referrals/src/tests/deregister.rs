@@ -0,0 +1,310 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn register_alias_should_work_when_account_has_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"alias1")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_account(to_code(b"ALIAS1")), Some(ALICE));
+		assert_eq!(Referrals::referral_code(ALICE), Some(to_code(b"BALLS69")));
+		assert_eq!(Referrals::account_codes(ALICE).into_inner(), vec![to_code(b"ALIAS1")]);
+		assert_balance!(ALICE, HDX, INITIAL_ALICE_BALANCE - 2 * 222 * ONE);
+		expect_events(vec![Event::CodeRegistered {
+			code: to_code(b"ALIAS1"),
+			account: ALICE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn register_alias_should_fail_when_account_has_no_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::register_alias(RuntimeOrigin::signed(ALICE), to_code(b"ALIAS1")),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
+
+#[test]
+fn register_alias_should_fail_when_max_aliases_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS1")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS2")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS3")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::register_alias(RuntimeOrigin::signed(ALICE), to_code(b"ALIAS4")),
+			Error::<Test>::TooManyAliases
+		);
+	});
+}
+
+#[test]
+fn link_code_should_link_to_referrer_when_alias_is_used() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS1")
+		));
+		// Act
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"ALIAS1")));
+		// Assert
+		assert_eq!(Referrals::linked_referral_account(BOB), Some(ALICE));
+	});
+}
+
+#[test]
+fn deregister_code_should_remove_alias() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS1")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS2")
+		));
+		// Act
+		assert_ok!(Referrals::deregister_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"alias1")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_account(to_code(b"ALIAS1")), None);
+		assert_eq!(Referrals::referral_code(ALICE), Some(to_code(b"BALLS69")));
+		assert_eq!(Referrals::account_codes(ALICE).into_inner(), vec![to_code(b"ALIAS2")]);
+		expect_events(vec![Event::CodeDeregistered {
+			code: to_code(b"ALIAS1"),
+			account: ALICE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn deregister_code_should_promote_oldest_alias_when_main_code_is_removed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS1")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS2")
+		));
+		Referrals::accrue_volume(&ALICE, 1_000);
+		// Act
+		assert_ok!(Referrals::deregister_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_account(to_code(b"BALLS69")), None);
+		assert_eq!(Referrals::referral_code(ALICE), Some(to_code(b"ALIAS1")));
+		assert_eq!(Referrals::account_codes(ALICE).into_inner(), vec![to_code(b"ALIAS2")]);
+		assert_eq!(Referrals::leaderboard().into_inner(), vec![(to_code(b"ALIAS1"), 1_000)]);
+	});
+}
+
+#[test]
+fn deregister_code_should_remove_referral_account_when_last_code_is_removed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		Referrals::accrue_volume(&ALICE, 1_000);
+		// Act
+		assert_ok!(Referrals::deregister_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_code(ALICE), None);
+		assert!(Referrals::leaderboard().is_empty());
+		// linked accounts and referrer details are kept
+		assert_eq!(Referrals::linked_referral_account(BOB), Some(ALICE));
+		assert_eq!(Referrals::referrer_level(ALICE), Some((Level::Tier4, 1_000)));
+	});
+}
+
+#[test]
+fn deregister_code_should_fail_when_code_is_not_owned_by_signer() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::deregister_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+			Error::<Test>::NotCodeOwner
+		);
+	});
+}
+
+#[test]
+fn deregister_code_should_fail_when_code_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::deregister_code(RuntimeOrigin::signed(ALICE), to_code(b"BALLS69")),
+			Error::<Test>::InvalidCode
+		);
+	});
+}
+
+#[test]
+fn register_code_should_keep_referrer_level_when_registered_again() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		Referrals::accrue_volume(&ALICE, 1_000);
+		assert_ok!(Referrals::deregister_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS70")
+		));
+		// Assert
+		assert_eq!(Referrals::referrer_level(ALICE), Some((Level::Tier4, 1_000)));
+	});
+}
+
+#[test]
+fn deregister_all_should_remove_code_and_all_aliases() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS1")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS2")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS3")
+		));
+		Referrals::accrue_volume(&ALICE, 1_000);
+		// Act
+		assert_ok!(Referrals::deregister_all(RuntimeOrigin::signed(ALICE)));
+		// Assert
+		for code in [b"BALLS69".as_slice(), b"ALIAS1", b"ALIAS2", b"ALIAS3"] {
+			assert_eq!(Referrals::referral_account(to_code(code)), None);
+		}
+		assert_eq!(Referrals::referral_code(ALICE), None);
+		assert!(Referrals::account_codes(ALICE).is_empty());
+		assert!(!AccountCodes::<Test>::contains_key(ALICE));
+		assert!(Referrals::leaderboard().is_empty());
+		assert_eq!(Referrals::referrer_level(ALICE), Some((Level::Tier4, 1_000)));
+		expect_events(vec![
+			Event::CodeDeregistered {
+				code: to_code(b"BALLS69"),
+				account: ALICE,
+			}
+			.into(),
+			Event::CodeDeregistered {
+				code: to_code(b"ALIAS1"),
+				account: ALICE,
+			}
+			.into(),
+			Event::CodeDeregistered {
+				code: to_code(b"ALIAS2"),
+				account: ALICE,
+			}
+			.into(),
+			Event::CodeDeregistered {
+				code: to_code(b"ALIAS3"),
+				account: ALICE,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn deregister_all_should_allow_codes_to_be_registered_by_other_account() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69")
+			));
+			assert_ok!(Referrals::register_alias(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"ALIAS1")
+			));
+			assert_ok!(Referrals::deregister_all(RuntimeOrigin::signed(ALICE)));
+			// Act & Assert
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(BOB), to_code(b"ALIAS1")));
+			assert_eq!(Referrals::referral_account(to_code(b"ALIAS1")), Some(BOB));
+		});
+}
+
+#[test]
+fn deregister_all_should_fail_when_account_has_no_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::deregister_all(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
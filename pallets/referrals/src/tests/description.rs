@@ -0,0 +1,104 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn set_description_should_store_description_when_signer_is_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act
+		assert_ok!(Referrals::set_description(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			b"@alice".to_vec()
+		));
+		// Assert
+		assert_eq!(
+			Referrals::code_info(b"BALLS69".to_vec()).and_then(|info| info.description),
+			Some(b"@alice".to_vec())
+		);
+		expect_events(vec![Event::CodeDescriptionSet {
+			code: to_code(b"BALLS69"),
+			description: b"@alice".to_vec().try_into().unwrap(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_description_should_replace_existing_description() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_description(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			b"@alice".to_vec()
+		));
+		// Act
+		assert_ok!(Referrals::set_description(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			b"alice on x".to_vec()
+		));
+		// Assert
+		assert_eq!(
+			Referrals::code_description(to_code(b"BALLS69")).map(|d| d.into_inner()),
+			Some(b"alice on x".to_vec())
+		);
+	});
+}
+
+#[test]
+fn set_description_should_fail_when_description_is_too_long() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::set_description(RuntimeOrigin::signed(ALICE), to_code(b"BALLS69"), vec![b'a'; 17]),
+			Error::<Test>::DescriptionTooLong
+		);
+	});
+}
+
+#[test]
+fn set_description_should_fail_when_description_is_not_printable_ascii() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::set_description(RuntimeOrigin::signed(ALICE), to_code(b"BALLS69"), b"@alice\n".to_vec()),
+			Error::<Test>::InvalidDescription
+		);
+	});
+}
+
+#[test]
+fn set_description_should_fail_when_signer_is_not_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::set_description(RuntimeOrigin::signed(BOB), to_code(b"BALLS69"), b"@bob".to_vec()),
+			Error::<Test>::NotCodeOwner
+		);
+	});
+}
@@ -0,0 +1,327 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn set_code_expiry_should_store_expiry_and_auto_renew_flag() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			Some(50),
+			true
+		));
+		// Assert
+		assert_eq!(Referrals::code_expiry(to_code(b"BALLS69")), Some(50));
+		assert!(AutoRenew::<Test>::contains_key(to_code(b"BALLS69")));
+		expect_events(vec![Event::CodeExpirySet {
+			code: to_code(b"BALLS69"),
+			expires_at: Some(50),
+			auto_renew: true,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_code_expiry_should_remove_expiry_and_auto_renew_flag_when_expiry_is_not_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			Some(50),
+			true
+		));
+		// Act
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			None,
+			true
+		));
+		// Assert
+		assert_eq!(Referrals::code_expiry(to_code(b"BALLS69")), None);
+		assert!(!AutoRenew::<Test>::contains_key(to_code(b"BALLS69")));
+	});
+}
+
+#[test]
+fn set_code_expiry_should_fail_when_expiry_is_not_after_current_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		System::set_block_number(10);
+		// Act & Assert
+		assert_noop!(
+			Referrals::set_code_expiry(RuntimeOrigin::root(), to_code(b"BALLS69"), Some(10), false),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn set_code_expiry_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::set_code_expiry(RuntimeOrigin::signed(ALICE), to_code(b"BALLS69"), Some(50), false),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn link_code_should_fail_when_code_has_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			Some(50),
+			false
+		));
+		System::set_block_number(50);
+		// Act & Assert
+		assert_noop!(
+			Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+			Error::<Test>::CodeExpired
+		);
+	});
+}
+
+#[test]
+fn link_code_should_not_extend_expiry_when_code_is_not_auto_renew() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			Some(50),
+			false
+		));
+		System::set_block_number(20);
+		// Act
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		// Assert
+		assert_eq!(Referrals::code_expiry(to_code(b"BALLS69")), Some(50));
+	});
+}
+
+#[test]
+fn link_code_should_extend_expiry_when_code_is_auto_renew() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			Some(50),
+			true
+		));
+		System::set_block_number(20);
+		// Act
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		// Assert
+		assert_eq!(Referrals::code_expiry(to_code(b"BALLS69")), Some(120));
+	});
+}
+
+#[test]
+fn link_code_should_not_shorten_expiry_when_code_is_auto_renew() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			Some(500),
+			true
+		));
+		System::set_block_number(20);
+		// Act
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		// Assert
+		assert_eq!(Referrals::code_expiry(to_code(b"BALLS69")), Some(500));
+	});
+}
+
+#[test]
+fn accrue_volume_should_extend_expiry_when_code_is_auto_renew() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			Some(50),
+			true
+		));
+		System::set_block_number(30);
+		// Act
+		Referrals::accrue_volume(&ALICE, 1_000);
+		// Assert
+		assert_eq!(Referrals::code_expiry(to_code(b"BALLS69")), Some(130));
+	});
+}
+
+#[test]
+fn accrue_volume_should_not_extend_expiry_of_alias() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS69")
+		));
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"ALIAS69"),
+			Some(50),
+			true
+		));
+		System::set_block_number(30);
+		// Act
+		Referrals::accrue_volume(&ALICE, 1_000);
+		// Assert
+		assert_eq!(Referrals::code_expiry(to_code(b"ALIAS69")), Some(50));
+	});
+}
+
+#[test]
+fn auto_renew_code_should_expire_when_not_used_within_renewal_extension() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			Some(50),
+			true
+		));
+		System::set_block_number(20);
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		// Act
+		System::set_block_number(120);
+		Referrals::accrue_volume(&ALICE, 1_000);
+		// Assert
+		assert_eq!(Referrals::code_expiry(to_code(b"BALLS69")), Some(120));
+		assert_noop!(
+			Referrals::link_code(RuntimeOrigin::signed(CHARLIE), to_code(b"BALLS69")),
+			Error::<Test>::CodeExpired
+		);
+	});
+}
+
+#[test]
+fn remove_expired_code_should_remove_code_when_auto_renew_code_has_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			Some(50),
+			true
+		));
+		System::set_block_number(50);
+		// Act
+		assert_ok!(Referrals::remove_expired_code(
+			RuntimeOrigin::signed(BOB),
+			to_code(b"BALLS69")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_account(to_code(b"BALLS69")), None);
+		assert_eq!(Referrals::referral_code(ALICE), None);
+		assert_eq!(Referrals::code_expiry(to_code(b"BALLS69")), None);
+		assert!(!AutoRenew::<Test>::contains_key(to_code(b"BALLS69")));
+		expect_events(vec![Event::CodeDeregistered {
+			code: to_code(b"BALLS69"),
+			account: ALICE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_expired_code_should_fail_when_code_has_not_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_expiry(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			Some(50),
+			true
+		));
+		System::set_block_number(49);
+		// Act & Assert
+		assert_noop!(
+			Referrals::remove_expired_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+			Error::<Test>::CodeNotExpired
+		);
+	});
+}
+
+#[test]
+fn remove_expired_code_should_fail_when_code_has_no_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::remove_expired_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+			Error::<Test>::CodeNotExpired
+		);
+	});
+}
@@ -0,0 +1,120 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+const DAVE: AccountId = 4;
+
+fn register(who: AccountId, code: &[u8]) -> ReferralCode<<Test as Config>::CodeLength> {
+	let code: ReferralCode<<Test as Config>::CodeLength> = code.to_vec().try_into().unwrap();
+	assert_ok!(Referrals::register_code(RuntimeOrigin::signed(who), code.clone()));
+	code
+}
+
+#[test]
+fn accrue_volume_should_add_code_to_leaderboard() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code = register(ALICE, b"AAAA");
+		// Act
+		Referrals::accrue_volume(&ALICE, 1_000);
+		// Assert
+		assert_eq!(Referrals::leaderboard().into_inner(), vec![(code, 1_000)]);
+	});
+}
+
+#[test]
+fn accrue_volume_should_not_add_code_to_leaderboard_when_amount_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		register(ALICE, b"AAAA");
+		// Act
+		Referrals::accrue_volume(&ALICE, 0);
+		// Assert
+		assert!(Referrals::leaderboard().is_empty());
+	});
+}
+
+#[test]
+fn accrue_volume_should_reorder_leaderboard_when_volume_increases() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 1_000 * ONE), (CHARLIE, HDX, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code_a = register(ALICE, b"AAAA");
+			let code_b = register(BOB, b"BBBB");
+			let code_c = register(CHARLIE, b"CCCC");
+			Referrals::accrue_volume(&ALICE, 10);
+			Referrals::accrue_volume(&BOB, 20);
+			Referrals::accrue_volume(&CHARLIE, 30);
+			assert_eq!(
+				Referrals::leaderboard().into_inner(),
+				vec![(code_c.clone(), 30), (code_b.clone(), 20), (code_a.clone(), 10)]
+			);
+
+			// Act
+			Referrals::accrue_volume(&ALICE, 25);
+
+			// Assert
+			assert_eq!(
+				Referrals::leaderboard().into_inner(),
+				vec![(code_a, 35), (code_c, 30), (code_b, 20)]
+			);
+		});
+}
+
+#[test]
+fn accrue_volume_should_respect_leaderboard_bound() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, HDX, 1_000 * ONE),
+			(CHARLIE, HDX, 1_000 * ONE),
+			(DAVE, HDX, 1_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code_a = register(ALICE, b"AAAA");
+			let code_b = register(BOB, b"BBBB");
+			let code_c = register(CHARLIE, b"CCCC");
+			let code_d = register(DAVE, b"DDDD");
+			Referrals::accrue_volume(&ALICE, 35);
+			Referrals::accrue_volume(&BOB, 20);
+			Referrals::accrue_volume(&CHARLIE, 30);
+
+			// Act - volume lower than the lowest entry does not enter the leaderboard
+			Referrals::accrue_volume(&DAVE, 5);
+
+			// Assert
+			assert_eq!(
+				Referrals::leaderboard().into_inner(),
+				vec![(code_a.clone(), 35), (code_c.clone(), 30), (code_b, 20)]
+			);
+
+			// Act - volume higher than the lowest entry replaces it
+			Referrals::accrue_volume(&DAVE, 30);
+
+			// Assert
+			let board = Referrals::leaderboard();
+			assert_eq!(board.len(), <Test as Config>::MaxLeaderboard::get() as usize);
+			assert_eq!(board.into_inner(), vec![(code_a, 35), (code_d, 35), (code_c, 30)]);
+		});
+}
+
+#[test]
+fn claim_rewards_should_update_leaderboard_with_referrer_rewards() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, HDX, 1_000 * ONE),
+			(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000),
+		])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code = register(BOB, b"BBBB");
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_eq!(Referrals::leaderboard().into_inner(), vec![(code, 5_000_000_000_000)]);
+		});
+}
@@ -1,4 +1,5 @@
 use crate::tests::*;
+use frame_support::traits::OnFinalize;
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -100,7 +101,129 @@ fn link_code_should_emit_event_when_successful() {
 			account: BOB,
 			code,
 			referral_account: ALICE,
+			forced: false,
 		}
 		.into()]);
 	});
 }
+
+#[test]
+fn fee_discount_for_should_return_discount_when_account_is_linked() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+
+		// Act & Assert
+		assert_eq!(Referrals::fee_discount_for(&BOB), Permill::from_percent(10));
+	});
+}
+
+#[test]
+fn fee_discount_for_should_return_zero_when_account_is_not_linked() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+
+		// Act & Assert
+		assert_eq!(Referrals::fee_discount_for(&ALICE), Permill::zero());
+		assert_eq!(Referrals::fee_discount_for(&BOB), Permill::zero());
+	});
+}
+
+#[test]
+fn link_code_should_not_count_links_when_link_events_are_emitted() {
+	ExtBuilder::default().with_link_events(true).build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+
+		// Act
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code.clone()));
+		<Referrals as OnFinalize<BlockNumberFor<Test>>>::on_finalize(1);
+
+		// Assert
+		expect_events(vec![Event::CodeLinked {
+			account: BOB,
+			code,
+			referral_account: ALICE,
+			forced: false,
+		}
+		.into()]);
+		assert_eq!(Referrals::links_in_period(), 0);
+		assert!(!System::events()
+			.iter()
+			.any(|r| matches!(r.event, RuntimeEvent::Referrals(Event::LinksBatched { .. }))));
+	});
+}
+
+#[test]
+fn link_code_should_batch_links_when_link_events_are_not_emitted() {
+	ExtBuilder::default().with_link_events(false).build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+
+		// Act
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code.clone()));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(CHARLIE), code));
+
+		// Assert
+		assert_eq!(Pallet::<Test>::linked_referral_account::<AccountId>(BOB), Some(ALICE));
+		assert_eq!(
+			Pallet::<Test>::linked_referral_account::<AccountId>(CHARLIE),
+			Some(ALICE)
+		);
+		assert_eq!(Referrals::links_in_period(), 2);
+		assert!(!System::events()
+			.iter()
+			.any(|r| matches!(r.event, RuntimeEvent::Referrals(Event::CodeLinked { .. }))));
+
+		<Referrals as OnFinalize<BlockNumberFor<Test>>>::on_finalize(1);
+
+		expect_events(vec![Event::LinksBatched { count: 2 }.into()]);
+		assert_eq!(Referrals::links_in_period(), 0);
+	});
+}
+
+#[test]
+fn force_link_should_link_account_when_account_already_traded() {
+	ExtBuilder::default()
+		.with_trader_shares(vec![(BOB, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+
+			// Act
+			assert_ok!(Referrals::force_link(RuntimeOrigin::root(), BOB, code.clone()));
+
+			// Assert
+			assert_eq!(Pallet::<Test>::linked_referral_account::<AccountId>(BOB), Some(ALICE));
+			expect_events(vec![Event::CodeLinked {
+				account: BOB,
+				code,
+				referral_account: ALICE,
+				forced: true,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn force_link_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+
+		// Act & Assert
+		assert_noop!(
+			Referrals::force_link(RuntimeOrigin::signed(CHARLIE), BOB, code),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
@@ -0,0 +1,158 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn set_code_manager_should_store_manager_when_signer_is_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act
+		assert_ok!(Referrals::set_code_manager(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"balls69"),
+			Some(CHARLIE)
+		));
+		// Assert
+		assert_eq!(Referrals::code_manager(to_code(b"BALLS69")), Some(CHARLIE));
+		expect_events(vec![Event::CodeManagerSet {
+			code: to_code(b"BALLS69"),
+			manager: Some(CHARLIE),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_code_manager_should_fail_when_signer_is_not_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::set_code_manager(RuntimeOrigin::signed(BOB), to_code(b"BALLS69"), Some(BOB)),
+			Error::<Test>::NotCodeOwner
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_for_should_pay_rewards_to_owner_when_called_by_manager() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69")
+			));
+			assert_ok!(Referrals::set_code_manager(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69"),
+				Some(CHARLIE)
+			));
+			let alice_balance = Tokens::free_balance(HDX, &ALICE);
+			// Act
+			assert_ok!(Referrals::claim_rewards_for(
+				RuntimeOrigin::signed(CHARLIE),
+				to_code(b"BALLS69")
+			));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_balance + 15_000_000_000_000);
+			assert_eq!(Tokens::free_balance(HDX, &CHARLIE), 0);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 0);
+			expect_events(vec![Event::Claimed {
+				who: ALICE,
+				referrer_rewards: 15_000_000_000_000,
+				trade_rewards: 0,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn claim_rewards_for_should_pay_rewards_to_payee_when_assigned() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69")
+			));
+			assert_ok!(Referrals::assign_rewards(RuntimeOrigin::signed(ALICE), BOB));
+			assert_ok!(Referrals::set_code_manager(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69"),
+				Some(CHARLIE)
+			));
+			// Act
+			assert_ok!(Referrals::claim_rewards_for(
+				RuntimeOrigin::signed(CHARLIE),
+				to_code(b"BALLS69")
+			));
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &BOB), 15_000_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 5_000_000_000_000);
+		});
+}
+
+#[test]
+fn claim_rewards_for_should_fail_when_signer_is_not_manager() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(ALICE, 15_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69")
+			));
+			assert_ok!(Referrals::set_code_manager(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69"),
+				Some(CHARLIE)
+			));
+			// Act & Assert
+			assert_noop!(
+				Referrals::claim_rewards_for(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+				Error::<Test>::NotManager
+			);
+		});
+}
+
+#[test]
+fn claim_rewards_for_should_fail_when_manager_was_removed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::set_code_manager(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			Some(CHARLIE)
+		));
+		assert_ok!(Referrals::set_code_manager(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			None
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::claim_rewards_for(RuntimeOrigin::signed(CHARLIE), to_code(b"BALLS69")),
+			Error::<Test>::NotManager
+		);
+	});
+}
@@ -0,0 +1,161 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn migrate_code_should_keep_volume_level_and_links_of_the_code() {
+	let mut volumes = HashMap::new();
+	volumes.insert(Level::Tier0, Some(0));
+	volumes.insert(Level::Tier1, Some(10_000_000_000_000));
+	volumes.insert(Level::Tier2, Some(20_000_000_000_000));
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000),
+			(CHARLIE, HDX, INITIAL_ALICE_BALANCE),
+		])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_tier_volumes(volumes)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			System::set_block_number(10);
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69")
+			));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			// Act
+			assert_ok!(Referrals::migrate_code(
+				RuntimeOrigin::root(),
+				to_code(b"BALLS69"),
+				to_code(b"RENAMED")
+			));
+			// Assert
+			assert_eq!(
+				Referrals::code_info(b"RENAMED".to_vec()),
+				Some(CodeInfo {
+					owner: ALICE,
+					level: Level::Tier1,
+					volume: 15_000_000_000_000,
+					registered_at: Some(10),
+					display: Some(b"RENAMED".to_vec()),
+					description: None,
+					referral_count: 1,
+					expires_at: None,
+					auto_renew: false,
+				})
+			);
+			assert_eq!(Referrals::referral_code(ALICE), Some(to_code(b"RENAMED")));
+			assert_eq!(Referrals::linked_referral_account(BOB), Some(ALICE));
+			assert_eq!(Referrals::referral_account(to_code(b"BALLS69")), None);
+			assert_eq!(Referrals::code_info(b"BALLS69".to_vec()), None);
+			expect_events(vec![Event::CodeMigrated {
+				old: to_code(b"BALLS69"),
+				new: to_code(b"RENAMED"),
+			}
+			.into()]);
+			// the old code is free to register
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(CHARLIE),
+				to_code(b"BALLS69")
+			));
+		});
+}
+
+#[test]
+fn migrate_code_should_rename_alias() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS1")
+		));
+		// Act
+		assert_ok!(Referrals::migrate_code(
+			RuntimeOrigin::root(),
+			to_code(b"ALIAS1"),
+			to_code(b"ALIAS2")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_code(ALICE), Some(to_code(b"BALLS69")));
+		assert_eq!(Referrals::account_codes(ALICE).into_inner(), vec![to_code(b"ALIAS2")]);
+		assert_eq!(Referrals::referral_account(to_code(b"ALIAS2")), Some(ALICE));
+		assert_eq!(Referrals::referral_account(to_code(b"ALIAS1")), None);
+		assert_eq!(Referrals::code_count(), 2);
+	});
+}
+
+#[test]
+fn migrate_code_should_fail_when_new_code_is_registered() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"BALLS69")
+			));
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(BOB), to_code(b"TAKEN1")));
+			// Act & Assert
+			assert_noop!(
+				Referrals::migrate_code(RuntimeOrigin::root(), to_code(b"BALLS69"), to_code(b"TAKEN1")),
+				Error::<Test>::AlreadyExists
+			);
+		});
+}
+
+#[test]
+fn migrate_code_should_fail_when_old_code_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::migrate_code(RuntimeOrigin::root(), to_code(b"BALLS69"), to_code(b"RENAMED")),
+			Error::<Test>::InvalidCode
+		);
+	});
+}
+
+#[test]
+fn migrate_code_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::migrate_code(RuntimeOrigin::signed(ALICE), to_code(b"BALLS69"), to_code(b"RENAMED")),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn migrate_code_should_store_submitted_new_code_for_display_under_normalized_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"OldCode")
+		));
+		let new_code = to_code(b"NewCode");
+		// Act
+		assert_ok!(Referrals::migrate_code(
+			RuntimeOrigin::root(),
+			to_code(b"OLDCODE"),
+			new_code.clone()
+		));
+		// Assert
+		assert_eq!(Pallet::<Test>::code_display(to_code(b"NEWCODE")), Some(new_code));
+		assert_eq!(Pallet::<Test>::code_display(to_code(b"OLDCODE")), None);
+		let info = Referrals::code_info(b"NewCode".to_vec()).unwrap();
+		assert_eq!(info.display, Some(b"NewCode".to_vec()));
+	});
+}
@@ -0,0 +1,110 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn register_code_should_fail_when_code_differs_only_in_case_and_mode_is_upper() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, INITIAL_ALICE_BALANCE)])
+		.with_normalize_case(CaseMode::Upper)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"Balls69")
+			));
+			// Act & Assert
+			assert_eq!(Referrals::referral_code(ALICE), Some(to_code(b"BALLS69")));
+			assert_noop!(
+				Referrals::register_code(RuntimeOrigin::signed(BOB), to_code(b"bAlls69")),
+				Error::<Test>::AlreadyExists
+			);
+		});
+}
+
+#[test]
+fn register_code_should_fail_when_code_differs_only_in_case_and_mode_is_lower() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, INITIAL_ALICE_BALANCE)])
+		.with_normalize_case(CaseMode::Lower)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"Balls69")
+			));
+			// Act & Assert
+			assert_eq!(Referrals::referral_code(ALICE), Some(to_code(b"balls69")));
+			assert_noop!(
+				Referrals::register_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+				Error::<Test>::AlreadyExists
+			);
+			expect_events(vec![Event::CodeRegistered {
+				code: to_code(b"balls69"),
+				account: ALICE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn register_code_should_work_when_code_differs_only_in_case_and_mode_is_none() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, INITIAL_ALICE_BALANCE)])
+		.with_normalize_case(CaseMode::None)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"Balls69")
+			));
+			// Act
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(BOB),
+				to_code(b"bAlls69")
+			));
+			// Assert
+			assert_eq!(Referrals::referral_code(ALICE), Some(to_code(b"Balls69")));
+			assert_eq!(Referrals::referral_code(BOB), Some(to_code(b"bAlls69")));
+		});
+}
+
+#[test]
+fn register_code_should_fail_when_code_is_exact_match_and_mode_is_none() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, INITIAL_ALICE_BALANCE)])
+		.with_normalize_case(CaseMode::None)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"Balls69")
+			));
+			// Act & Assert
+			assert_noop!(
+				Referrals::register_code(RuntimeOrigin::signed(BOB), to_code(b"Balls69")),
+				Error::<Test>::AlreadyExists
+			);
+		});
+}
+
+#[test]
+fn link_code_should_normalize_code_when_mode_is_lower() {
+	ExtBuilder::default()
+		.with_normalize_case(CaseMode::Lower)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"balls69")
+			));
+			// Act
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+			// Assert
+			assert_eq!(Referrals::linked_referral_account::<AccountId>(BOB), Some(ALICE));
+		});
+}
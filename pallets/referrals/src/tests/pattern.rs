@@ -0,0 +1,96 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn three_letters_and_four_digits() -> ReferralCodePattern<<Test as Config>::CodeLength> {
+	vec![
+		CharClass::Letter,
+		CharClass::Letter,
+		CharClass::Letter,
+		CharClass::Digit,
+		CharClass::Digit,
+		CharClass::Digit,
+		CharClass::Digit,
+	]
+	.try_into()
+	.unwrap()
+}
+
+#[test]
+fn set_code_pattern_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(Referrals::set_code_pattern(
+			RuntimeOrigin::root(),
+			Some(three_letters_and_four_digits())
+		));
+		// Assert
+		assert_eq!(Referrals::code_pattern(), Some(three_letters_and_four_digits()));
+		expect_events(vec![Event::CodePatternSet {
+			pattern: Some(three_letters_and_four_digits()),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_code_pattern_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_code_pattern(RuntimeOrigin::signed(ALICE), Some(three_letters_and_four_digits())),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn register_code_should_work_when_code_matches_pattern() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::set_code_pattern(
+			RuntimeOrigin::root(),
+			Some(three_letters_and_four_digits())
+		));
+		// Act
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			b"ABC1234".to_vec().try_into().unwrap()
+		));
+		// Assert
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"ABC1234".to_vec().try_into().unwrap();
+		assert_eq!(Referrals::referral_account(code), Some(ALICE));
+	});
+}
+
+#[test]
+fn register_code_should_fail_when_code_does_not_match_pattern() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::set_code_pattern(
+			RuntimeOrigin::root(),
+			Some(three_letters_and_four_digits())
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::register_code(RuntimeOrigin::signed(ALICE), b"ABCD123".to_vec().try_into().unwrap()),
+			Error::<Test>::PatternMismatch
+		);
+	});
+}
+
+#[test]
+fn register_code_should_work_when_pattern_is_removed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::set_code_pattern(
+			RuntimeOrigin::root(),
+			Some(three_letters_and_four_digits())
+		));
+		assert_ok!(Referrals::set_code_pattern(RuntimeOrigin::root(), None));
+		// Act & Assert
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			b"ABCD123".to_vec().try_into().unwrap()
+		));
+	});
+}
@@ -0,0 +1,102 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+const DAVE: AccountId = 4;
+
+#[test]
+fn referral_count_should_be_zero_when_code_is_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 0);
+		assert_eq!(Referrals::code_referral_count(b"BALLS69".to_vec()), 0);
+	});
+}
+
+#[test]
+fn referral_count_should_be_increased_when_accounts_are_linked() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		assert_ok!(Referrals::link_code(
+			RuntimeOrigin::signed(CHARLIE),
+			to_code(b"balls69")
+		));
+		assert_ok!(Referrals::force_link(RuntimeOrigin::root(), DAVE, to_code(b"BALLS69")));
+		// Assert
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 3);
+		assert_eq!(Referrals::code_referral_count(b"balls69".to_vec()), 3);
+		assert_eq!(
+			Referrals::code_info(b"BALLS69".to_vec()).map(|info| info.referral_count),
+			Some(3)
+		);
+	});
+}
+
+#[test]
+fn referral_count_should_not_change_when_link_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		// Act
+		assert_noop!(
+			Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+			Error::<Test>::AlreadyLinked
+		);
+		// Assert
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 1);
+	});
+}
+
+#[test]
+fn referral_count_should_be_removed_when_code_is_deregistered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		// Act
+		assert_ok!(Referrals::deregister_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 0);
+	});
+}
+
+#[test]
+fn referral_count_should_be_moved_when_code_is_migrated() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		// Act
+		assert_ok!(Referrals::migrate_code(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			to_code(b"RENAMED")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 0);
+		assert_eq!(Referrals::referral_count(to_code(b"RENAMED")), 1);
+	});
+}
@@ -1,6 +1,7 @@
 use crate::tests::*;
 use pretty_assertions::assert_eq;
 use sp_runtime::traits::Zero;
+use sp_runtime::DispatchError::BadOrigin;
 
 #[test]
 fn register_code_should_work_when_code_is_max_length() {
@@ -107,6 +108,22 @@ fn register_code_should_convert_to_upper_case_when_code_is_lower_case() {
 	});
 }
 
+#[test]
+fn register_code_should_store_submitted_code_for_display_under_normalized_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"MyCode".to_vec().try_into().unwrap();
+		// Act
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// Assert
+		let normalized: ReferralCode<CodeLength> = b"MYCODE".to_vec().try_into().unwrap();
+		assert_eq!(Pallet::<Test>::referral_account(normalized.clone()), Some(ALICE));
+		assert_eq!(Pallet::<Test>::code_display(normalized), Some(code));
+		let info = Referrals::code_info(b"MyCode".to_vec()).unwrap();
+		assert_eq!(info.display, Some(b"MyCode".to_vec()));
+	});
+}
+
 #[test]
 fn register_code_should_emit_event_when_successful() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -133,6 +150,84 @@ fn signer_should_pay_the_registration_fee() {
 	});
 }
 
+#[test]
+fn registration_fee_should_be_split_between_reward_pot_and_fee_collector_when_reward_pot_share_is_set() {
+	ExtBuilder::default()
+		.with_reward_pot_share(Permill::from_percent(30))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			let (fee_asset, amount, beneficiary) = RegistrationFee::get();
+			let pot_balance = Tokens::free_balance(fee_asset, &Referrals::pot_account_id());
+			// Act
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			// Assert
+			let reward_pot_amount = Permill::from_percent(30).mul_floor(amount);
+			let fee_collector_amount = amount - reward_pot_amount;
+			assert_balance!(ALICE, fee_asset, INITIAL_ALICE_BALANCE - amount);
+			assert_balance!(Referrals::pot_account_id(), fee_asset, pot_balance + reward_pot_amount);
+			assert_balance!(beneficiary, fee_asset, fee_collector_amount);
+			expect_events(vec![Event::RegistrationFeePaid {
+				account: ALICE,
+				fee: amount,
+				reward_pot_amount,
+				fee_collector_amount,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn vanity_code_should_pay_vanity_fee_when_shorter_than_threshold() {
+	ExtBuilder::default()
+		.with_min_code_length(3)
+		.with_vanity_length_threshold(5)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"ABC".to_vec().try_into().unwrap();
+			let (fee_asset, _, beneficiary) = RegistrationFee::get();
+			// Act
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			// Assert
+			assert_balance!(ALICE, fee_asset, INITIAL_ALICE_BALANCE - VanityFee::get());
+			assert_balance!(beneficiary, fee_asset, VanityFee::get());
+			expect_events(vec![Event::RegistrationFeePaid {
+				account: ALICE,
+				fee: VanityFee::get(),
+				reward_pot_amount: 0,
+				fee_collector_amount: VanityFee::get(),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn code_should_pay_base_fee_when_not_shorter_than_vanity_threshold() {
+	ExtBuilder::default()
+		.with_min_code_length(3)
+		.with_vanity_length_threshold(5)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"ABCDEFGHIJ".to_vec().try_into().unwrap();
+			let (fee_asset, amount, beneficiary) = RegistrationFee::get();
+			// Act
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			// Assert
+			assert_balance!(ALICE, fee_asset, INITIAL_ALICE_BALANCE - amount);
+			assert_balance!(beneficiary, fee_asset, amount);
+			expect_events(vec![Event::RegistrationFeePaid {
+				account: ALICE,
+				fee: amount,
+				reward_pot_amount: 0,
+				fee_collector_amount: amount,
+			}
+			.into()]);
+		});
+}
+
 #[test]
 fn singer_should_set_default_level_for_referrer() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -159,3 +254,63 @@ fn register_code_should_fail_when_account_has_already_code_registered() {
 		);
 	});
 }
+
+#[test]
+fn register_code_should_fail_when_code_is_numeric() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"123456".to_vec().try_into().unwrap();
+		// Act & Assert
+		assert_noop!(
+			Referrals::register_code(RuntimeOrigin::signed(ALICE), code),
+			Error::<Test>::NumericCodeReserved
+		);
+	});
+}
+
+#[test]
+fn register_code_should_work_when_code_contains_digits_and_letters() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"ABC123".to_vec().try_into().unwrap();
+		// Act
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// Assert
+		let entry = Pallet::<Test>::referral_account::<ReferralCode<<Test as Config>::CodeLength>>(code);
+		assert_eq!(entry, Some(ALICE));
+	});
+}
+
+#[test]
+fn force_register_code_should_register_numeric_code_without_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"123456".to_vec().try_into().unwrap();
+		let (fee_asset, _, beneficiary) = RegistrationFee::get();
+		let beneficiary_balance = Tokens::free_balance(fee_asset, &beneficiary);
+		// Act
+		assert_ok!(Referrals::force_register_code(
+			RuntimeOrigin::root(),
+			ALICE,
+			code.clone()
+		));
+		// Assert
+		let entry = Pallet::<Test>::referral_account::<ReferralCode<<Test as Config>::CodeLength>>(code.clone());
+		assert_eq!(entry, Some(ALICE));
+		assert_eq!(Pallet::<Test>::referral_code(ALICE), Some(code));
+		assert_balance!(beneficiary, fee_asset, beneficiary_balance);
+	});
+}
+
+#[test]
+fn force_register_code_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"123456".to_vec().try_into().unwrap();
+		// Act & Assert
+		assert_noop!(
+			Referrals::force_register_code(RuntimeOrigin::signed(ALICE), ALICE, code),
+			BadOrigin
+		);
+	});
+}
@@ -0,0 +1,85 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn claim_rewards_should_pay_rewards_in_reward_asset_of_the_tier() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (CHARLIE, 15_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1, 2))
+		.with_tier_reward_asset(Level::Tier4, DAI)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::force_register_code(
+				RuntimeOrigin::root(),
+				BOB,
+				to_code(b"ELITE69")
+			));
+			assert_ok!(Referrals::force_register_code(
+				RuntimeOrigin::root(),
+				CHARLIE,
+				to_code(b"BASIC69")
+			));
+			Referrer::<Test>::insert(BOB, (Level::Tier4, 0));
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(CHARLIE)));
+			// Assert
+			assert_balance!(BOB, DAI, 10_000_000_000_000);
+			assert_balance!(BOB, HDX, 0);
+			assert_balance!(CHARLIE, HDX, 15_000_000_000_000);
+			assert_balance!(CHARLIE, DAI, 0);
+			expect_events(vec![Event::Converted {
+				from: AssetAmount::new(HDX, 5_000_000_000_000),
+				to: AssetAmount::new(DAI, 10_000_000_000_000),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn claim_rewards_should_pay_rewards_in_reward_asset_when_tier_has_no_reward_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (CHARLIE, 15_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1, 2))
+		.with_tier_reward_asset(Level::Tier4, DAI)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::force_register_code(
+				RuntimeOrigin::root(),
+				BOB,
+				to_code(b"BASIC69")
+			));
+			// Act
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(BOB)));
+			// Assert
+			assert_balance!(BOB, HDX, 5_000_000_000_000);
+			assert_balance!(BOB, DAI, 0);
+		});
+}
+
+#[test]
+fn claim_rewards_should_fail_when_conversion_to_reward_asset_of_the_tier_fails() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (CHARLIE, 15_000_000_000_000)])
+		.with_tier_reward_asset(Level::Tier4, DAI)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::force_register_code(
+				RuntimeOrigin::root(),
+				BOB,
+				to_code(b"ELITE69")
+			));
+			Referrer::<Test>::insert(BOB, (Level::Tier4, 0));
+			// Act & Assert
+			assert_noop!(
+				Referrals::claim_rewards(RuntimeOrigin::signed(BOB)),
+				Error::<Test>::ConversionMinTradingAmountNotReached
+			);
+		});
+}
@@ -0,0 +1,172 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn default_rewards() -> Vec<(AssetId, Level, FeeDistribution)> {
+	vec![(
+		DAI,
+		Level::Tier0,
+		FeeDistribution {
+			referrer: Permill::from_percent(50),
+			trader: Permill::zero(),
+			external: Permill::zero(),
+		},
+	)]
+}
+
+#[test]
+fn set_code_reward_override_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// Act
+		assert_ok!(Referrals::set_code_reward_override(
+			RuntimeOrigin::root(),
+			code.clone(),
+			Some(Permill::from_percent(70))
+		));
+		// Assert
+		assert_eq!(
+			Referrals::code_reward_override::<ReferralCode<<Test as Config>::CodeLength>>(code.clone()),
+			Some(Permill::from_percent(70))
+		);
+		expect_events(vec![Event::CodeRewardOverrideUpdated {
+			code,
+			rate: Some(Permill::from_percent(70)),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_code_reward_override_should_fail_when_rate_exceeds_max_reward_rate() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// Act & Assert
+		assert_noop!(
+			Referrals::set_code_reward_override(RuntimeOrigin::root(), code, Some(Permill::from_percent(81))),
+			Error::<Test>::RewardRateTooHigh
+		);
+	});
+}
+
+#[test]
+fn set_code_reward_override_should_fail_when_code_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_noop!(
+			Referrals::set_code_reward_override(RuntimeOrigin::root(), code, Some(Permill::from_percent(70))),
+			Error::<Test>::InvalidCode
+		);
+	});
+}
+
+#[test]
+fn set_code_reward_override_should_fail_when_code_is_alias() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		let alias: ReferralCode<<Test as Config>::CodeLength> = b"ALIAS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+		assert_ok!(Referrals::register_alias(RuntimeOrigin::signed(ALICE), alias.clone()));
+		// Act & Assert
+		assert_noop!(
+			Referrals::set_code_reward_override(RuntimeOrigin::root(), alias, Some(Permill::from_percent(70))),
+			Error::<Test>::NotMainCode
+		);
+	});
+}
+
+#[test]
+fn set_code_reward_override_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		assert_noop!(
+			Referrals::set_code_reward_override(RuntimeOrigin::signed(ALICE), code, Some(Permill::from_percent(70))),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn process_trade_fee_should_use_override_when_code_has_custom_rate() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(default_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code.clone()));
+			assert_ok!(Referrals::set_code_reward_override(
+				RuntimeOrigin::root(),
+				code,
+				Some(Permill::from_percent(70))
+			));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			let shares = ReferrerShares::<Test>::get(ALICE);
+			assert_eq!(shares, 7_000_000_000);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_use_default_rate_when_code_has_no_override() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, DAI, 2_000_000_000_000_000_000),
+			(CHARLIE, HDX, INITIAL_ALICE_BALANCE),
+		])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(default_rewards())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::set_code_reward_override(
+				RuntimeOrigin::root(),
+				code,
+				Some(Permill::from_percent(70))
+			));
+			let other_code: ReferralCode<<Test as Config>::CodeLength> = b"OTHER".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(CHARLIE),
+				other_code.clone()
+			));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), other_code));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			let shares = ReferrerShares::<Test>::get(CHARLIE);
+			assert_eq!(shares, 5_000_000_000);
+		});
+}
+
+#[test]
+fn deregister_code_should_remove_reward_override() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		assert_ok!(Referrals::set_code_reward_override(
+			RuntimeOrigin::root(),
+			code.clone(),
+			Some(Permill::from_percent(70))
+		));
+		// Act
+		assert_ok!(Referrals::deregister_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// Assert
+		assert_eq!(
+			Referrals::code_reward_override::<ReferralCode<<Test as Config>::CodeLength>>(code),
+			None
+		);
+	});
+}
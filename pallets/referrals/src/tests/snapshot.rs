@@ -0,0 +1,105 @@
+use crate::tests::*;
+use frame_support::traits::Hooks;
+use pretty_assertions::assert_eq;
+
+fn on_initialize(n: BlockNumberFor<Test>) {
+	<Referrals as Hooks<BlockNumberFor<Test>>>::on_initialize(n);
+}
+
+#[test]
+fn on_initialize_should_snapshot_code_stats_at_period_boundary() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), to_code(b"AAAA")));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"AAAA")));
+		Referrals::accrue_volume(&ALICE, 1_000);
+		// Act
+		on_initialize(9);
+		// Assert
+		assert_eq!(Referrals::code_snapshot(0, to_code(b"AAAA")), None);
+		// Act
+		on_initialize(10);
+		// Assert
+		assert_eq!(
+			Referrals::code_snapshot(0, to_code(b"AAAA")),
+			Some(CodeStats {
+				volume: 1_000,
+				referral_count: 1,
+			})
+		);
+		assert!(SnapshotCursor::<Test>::get().is_none());
+	});
+}
+
+#[test]
+fn snapshot_should_reflect_stats_at_period_boundary() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), to_code(b"AAAA")));
+		Referrals::accrue_volume(&ALICE, 1_000);
+		on_initialize(10);
+		// Act
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"AAAA")));
+		Referrals::accrue_volume(&ALICE, 2_000);
+		on_initialize(15);
+		on_initialize(20);
+		// Assert
+		assert_eq!(
+			Referrals::code_snapshot(0, to_code(b"AAAA")),
+			Some(CodeStats {
+				volume: 1_000,
+				referral_count: 0,
+			})
+		);
+		assert_eq!(
+			Referrals::code_snapshot(1, to_code(b"AAAA")),
+			Some(CodeStats {
+				volume: 3_000,
+				referral_count: 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn snapshot_should_continue_from_cursor_when_codes_exceed_max_codes_per_snapshot() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 1_000 * ONE), (CHARLIE, HDX, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), to_code(b"AAAA")));
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(BOB), to_code(b"BBBB")));
+			assert_ok!(Referrals::register_code(
+				RuntimeOrigin::signed(CHARLIE),
+				to_code(b"CCCC")
+			));
+			// Act
+			on_initialize(10);
+			// Assert
+			assert_eq!(CodeSnapshots::<Test>::iter_prefix(0).count(), 2);
+			assert_eq!(SnapshotCursor::<Test>::get().map(|(index, _)| index), Some(0));
+			// Act
+			on_initialize(11);
+			// Assert
+			assert_eq!(CodeSnapshots::<Test>::iter_prefix(0).count(), 3);
+			assert!(SnapshotCursor::<Test>::get().is_none());
+			for code in [b"AAAA", b"BBBB", b"CCCC"] {
+				assert_eq!(Referrals::code_snapshot(0, to_code(code)), Some(CodeStats::default()));
+			}
+		});
+}
+
+#[test]
+fn on_initialize_should_not_snapshot_when_not_at_period_boundary() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), to_code(b"AAAA")));
+		// Act
+		on_initialize(5);
+		on_initialize(0);
+		// Assert
+		assert_eq!(CodeSnapshots::<Test>::iter().count(), 0);
+		assert!(SnapshotCursor::<Test>::get().is_none());
+	});
+}
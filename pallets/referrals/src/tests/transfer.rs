@@ -0,0 +1,263 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn offer_code_transfer_should_store_offer_when_signer_is_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act
+		assert_ok!(Referrals::offer_code_transfer(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"balls69"),
+			BOB
+		));
+		// Assert
+		assert_eq!(Referrals::code_transfer_offer(to_code(b"BALLS69")), Some((BOB, 11)));
+		expect_events(vec![Event::CodeTransferOffered {
+			code: to_code(b"BALLS69"),
+			from: ALICE,
+			to: BOB,
+			expires_at: 11,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn offer_code_transfer_should_fail_when_signer_is_not_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::offer_code_transfer(RuntimeOrigin::signed(BOB), to_code(b"BALLS69"), BOB),
+			Error::<Test>::NotCodeOwner
+		);
+	});
+}
+
+#[test]
+fn offer_code_transfer_should_fail_when_recipient_is_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::offer_code_transfer(RuntimeOrigin::signed(ALICE), to_code(b"BALLS69"), ALICE),
+			Error::<Test>::SelfTransferNotAllowed
+		);
+	});
+}
+
+#[test]
+fn accept_code_transfer_should_transfer_code_to_recipient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::offer_code_transfer(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			BOB
+		));
+		// Act
+		assert_ok!(Referrals::accept_code_transfer(
+			RuntimeOrigin::signed(BOB),
+			to_code(b"BALLS69")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_account(to_code(b"BALLS69")), Some(BOB));
+		assert_eq!(Referrals::referral_code(BOB), Some(to_code(b"BALLS69")));
+		assert_eq!(Referrals::referral_code(ALICE), None);
+		assert_eq!(Referrals::code_transfer_offer(to_code(b"BALLS69")), None);
+		assert!(Referrals::referrer_level(BOB).is_some());
+		expect_events(vec![Event::CodeTransferred {
+			code: to_code(b"BALLS69"),
+			from: ALICE,
+			to: BOB,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn accept_code_transfer_should_add_code_as_alias_when_recipient_has_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::register_alias(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"ALIAS69")
+		));
+		assert_ok!(Referrals::force_register_code(
+			RuntimeOrigin::root(),
+			BOB,
+			to_code(b"OTHER69")
+		));
+		assert_ok!(Referrals::offer_code_transfer(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			BOB
+		));
+		// Act
+		assert_ok!(Referrals::accept_code_transfer(
+			RuntimeOrigin::signed(BOB),
+			to_code(b"BALLS69")
+		));
+		// Assert
+		assert_eq!(Referrals::referral_code(BOB), Some(to_code(b"OTHER69")));
+		assert_eq!(Referrals::account_codes(BOB).into_inner(), vec![to_code(b"BALLS69")]);
+		assert_eq!(Referrals::referral_code(ALICE), Some(to_code(b"ALIAS69")));
+		assert!(Referrals::account_codes(ALICE).is_empty());
+	});
+}
+
+#[test]
+fn accept_code_transfer_should_fail_when_signer_is_not_recipient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::offer_code_transfer(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			BOB
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::accept_code_transfer(RuntimeOrigin::signed(CHARLIE), to_code(b"BALLS69")),
+			Error::<Test>::NotOfferRecipient
+		);
+	});
+}
+
+#[test]
+fn accept_code_transfer_should_fail_when_offer_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::offer_code_transfer(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			BOB
+		));
+		System::set_block_number(11);
+		// Act & Assert
+		assert_noop!(
+			Referrals::accept_code_transfer(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+			Error::<Test>::TransferOfferExpired
+		);
+		assert_eq!(Referrals::referral_account(to_code(b"BALLS69")), Some(ALICE));
+	});
+}
+
+#[test]
+fn accept_code_transfer_should_fail_when_there_is_no_offer() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::accept_code_transfer(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+			Error::<Test>::TransferOfferNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_code_transfer_should_remove_offer_when_signer_is_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::offer_code_transfer(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			BOB
+		));
+		// Act
+		assert_ok!(Referrals::cancel_code_transfer(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		// Assert
+		assert_eq!(Referrals::code_transfer_offer(to_code(b"BALLS69")), None);
+		expect_events(vec![Event::CodeTransferCancelled {
+			code: to_code(b"BALLS69"),
+		}
+		.into()]);
+		assert_noop!(
+			Referrals::accept_code_transfer(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")),
+			Error::<Test>::TransferOfferNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_code_transfer_should_remove_offer_when_signer_is_recipient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::offer_code_transfer(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			BOB
+		));
+		// Act
+		assert_ok!(Referrals::cancel_code_transfer(
+			RuntimeOrigin::signed(BOB),
+			to_code(b"BALLS69")
+		));
+		// Assert
+		assert_eq!(Referrals::code_transfer_offer(to_code(b"BALLS69")), None);
+	});
+}
+
+#[test]
+fn cancel_code_transfer_should_fail_when_signer_is_not_party_of_offer() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::offer_code_transfer(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			BOB
+		));
+		// Act & Assert
+		assert_noop!(
+			Referrals::cancel_code_transfer(RuntimeOrigin::signed(CHARLIE), to_code(b"BALLS69")),
+			Error::<Test>::NotOfferParty
+		);
+	});
+}
@@ -0,0 +1,201 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+const DAVE: AccountId = 4;
+
+#[test]
+fn unlink_code_should_remove_link_when_within_grace_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		System::set_block_number(10);
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		System::set_block_number(20);
+		// Act
+		assert_ok!(Referrals::unlink_code(RuntimeOrigin::signed(BOB)));
+		// Assert
+		assert_eq!(Referrals::linked_referral_account(BOB), None);
+		assert_eq!(Referrals::linked_at(BOB), None);
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 0);
+		expect_events(vec![Event::CodeUnlinked {
+			account: BOB,
+			code: to_code(b"BALLS69"),
+			referral_account: ALICE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn unlink_code_should_allow_linking_again() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::force_register_code(
+			RuntimeOrigin::root(),
+			CHARLIE,
+			to_code(b"OTHER69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		assert_ok!(Referrals::unlink_code(RuntimeOrigin::signed(BOB)));
+		// Act
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"OTHER69")));
+		// Assert
+		assert_eq!(Referrals::linked_referral_account(BOB), Some(CHARLIE));
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 0);
+		assert_eq!(Referrals::referral_count(to_code(b"OTHER69")), 1);
+	});
+}
+
+#[test]
+fn unlink_code_should_decrease_referral_count_of_linked_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		assert_ok!(Referrals::link_code(
+			RuntimeOrigin::signed(CHARLIE),
+			to_code(b"BALLS69")
+		));
+		// Act
+		assert_ok!(Referrals::unlink_code(RuntimeOrigin::signed(BOB)));
+		// Assert
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 1);
+		assert_eq!(Referrals::linked_referral_account(CHARLIE), Some(ALICE));
+	});
+}
+
+#[test]
+fn unlink_code_should_fail_when_grace_period_has_passed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		System::set_block_number(10);
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		System::set_block_number(21);
+		// Act & Assert
+		assert_noop!(
+			Referrals::unlink_code(RuntimeOrigin::signed(BOB)),
+			Error::<Test>::UnlinkWindowClosed
+		);
+	});
+}
+
+#[test]
+fn unlink_code_should_fail_when_account_is_not_linked() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::unlink_code(RuntimeOrigin::signed(BOB)),
+			Error::<Test>::NotLinked
+		);
+	});
+}
+
+#[test]
+fn unlink_code_should_decrease_referral_count_of_renamed_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		assert_ok!(Referrals::link_code(
+			RuntimeOrigin::signed(CHARLIE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::migrate_code(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			to_code(b"RENAMED")
+		));
+		// Act
+		assert_ok!(Referrals::unlink_code(RuntimeOrigin::signed(BOB)));
+		// Assert
+		assert_eq!(Referrals::referral_count(to_code(b"RENAMED")), 1);
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 0);
+		expect_events(vec![Event::CodeUnlinked {
+			account: BOB,
+			code: to_code(b"RENAMED"),
+			referral_account: ALICE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn unlink_code_should_decrease_referral_count_of_code_renamed_multiple_times() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		assert_ok!(Referrals::migrate_code(
+			RuntimeOrigin::root(),
+			to_code(b"BALLS69"),
+			to_code(b"RENAMED")
+		));
+		assert_ok!(Referrals::migrate_code(
+			RuntimeOrigin::root(),
+			to_code(b"RENAMED"),
+			to_code(b"RENAMED2")
+		));
+		// the old code is registered again by another account
+		assert_ok!(Referrals::force_register_code(
+			RuntimeOrigin::root(),
+			DAVE,
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(
+			RuntimeOrigin::signed(CHARLIE),
+			to_code(b"BALLS69")
+		));
+		// Act
+		assert_ok!(Referrals::unlink_code(RuntimeOrigin::signed(BOB)));
+		// Assert
+		assert_eq!(Referrals::referral_count(to_code(b"RENAMED2")), 0);
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 1);
+	});
+}
+
+#[test]
+fn unlink_code_should_not_decrease_referral_count_of_transferred_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), to_code(b"BALLS69")));
+		assert_ok!(Referrals::offer_code_transfer(
+			RuntimeOrigin::signed(ALICE),
+			to_code(b"BALLS69"),
+			CHARLIE
+		));
+		assert_ok!(Referrals::accept_code_transfer(
+			RuntimeOrigin::signed(CHARLIE),
+			to_code(b"BALLS69")
+		));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(DAVE), to_code(b"BALLS69")));
+		// Act
+		assert_ok!(Referrals::unlink_code(RuntimeOrigin::signed(BOB)));
+		// Assert
+		assert_eq!(Referrals::linked_referral_account(BOB), None);
+		assert_eq!(Referrals::linked_referral_account(DAVE), Some(CHARLIE));
+		assert_eq!(Referrals::referral_count(to_code(b"BALLS69")), 1);
+	});
+}
@@ -0,0 +1,49 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+fn volume_cap_reached_events(who: AccountId) -> usize {
+	frame_system::Pallet::<Test>::events()
+		.into_iter()
+		.filter(|r| r.event == Event::VolumeCapReached { who }.into())
+		.count()
+}
+
+#[test]
+fn accrue_volume_should_saturate_at_max_accrued_volume() {
+	ExtBuilder::default()
+		.with_max_accrued_volume(1_500)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code_in_campaign(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"AAAA"),
+				1
+			));
+			// Act
+			Referrals::accrue_volume(&ALICE, 1_000);
+			Referrals::accrue_volume(&ALICE, 1_000);
+			Referrals::accrue_volume(&ALICE, 1_000);
+			// Assert
+			assert_eq!(Referrals::referrer_level(ALICE).unwrap().1, 1_500);
+			assert_eq!(Referrals::campaign_volume(1), 1_500);
+		});
+}
+
+#[test]
+fn accrue_volume_should_emit_volume_cap_reached_event_once() {
+	ExtBuilder::default()
+		.with_max_accrued_volume(1_500)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), to_code(b"AAAA")));
+			// Act
+			Referrals::accrue_volume(&ALICE, 1_000);
+			assert_eq!(volume_cap_reached_events(ALICE), 0);
+			Referrals::accrue_volume(&ALICE, 1_000);
+			Referrals::accrue_volume(&ALICE, 1_000);
+			// Assert
+			assert_eq!(volume_cap_reached_events(ALICE), 1);
+		});
+}
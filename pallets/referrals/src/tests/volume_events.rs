@@ -0,0 +1,97 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+fn volume_accrued_events() -> Vec<(Balance, Balance)> {
+	frame_system::Pallet::<Test>::events()
+		.into_iter()
+		.filter_map(|r| match r.event {
+			RuntimeEvent::Referrals(Event::VolumeAccrued { amount, total, .. }) => Some((amount, total)),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn accrue_volume_should_emit_event_for_every_accrual_when_volume_events_are_enabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), to_code(b"AAAA")));
+		// Act
+		Referrals::accrue_volume(&ALICE, 1_000);
+		Referrals::accrue_volume(&ALICE, 500);
+		// Assert
+		expect_events(vec![Event::VolumeAccrued {
+			code: to_code(b"AAAA"),
+			amount: 500,
+			total: 1_500,
+		}
+		.into()]);
+		assert_eq!(volume_accrued_events(), vec![(1_000, 1_000), (500, 1_500)]);
+		assert_eq!(Referrals::volume_since_last_event(to_code(b"AAAA")), 0);
+	});
+}
+
+#[test]
+fn accrue_volume_should_accumulate_volume_below_threshold_when_volume_events_are_disabled() {
+	ExtBuilder::default()
+		.with_volume_events(false, 2_000)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), to_code(b"AAAA")));
+			// Act
+			Referrals::accrue_volume(&ALICE, 1_000);
+			Referrals::accrue_volume(&ALICE, 500);
+			// Assert
+			assert!(volume_accrued_events().is_empty());
+			assert_eq!(Referrals::volume_since_last_event(to_code(b"AAAA")), 1_500);
+			assert_eq!(Referrals::referrer_level(ALICE).unwrap().1, 1_500);
+		});
+}
+
+#[test]
+fn accrue_volume_should_emit_accumulated_volume_when_threshold_is_reached() {
+	ExtBuilder::default()
+		.with_volume_events(false, 2_000)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), to_code(b"AAAA")));
+			Referrals::accrue_volume(&ALICE, 1_500);
+			// Act
+			Referrals::accrue_volume(&ALICE, 1_000);
+			// Assert
+			expect_events(vec![Event::VolumeAccrued {
+				code: to_code(b"AAAA"),
+				amount: 2_500,
+				total: 2_500,
+			}
+			.into()]);
+			assert_eq!(Referrals::volume_since_last_event(to_code(b"AAAA")), 0);
+
+			// Act - accumulation starts again after the event
+			Referrals::accrue_volume(&ALICE, 1_000);
+			// Assert
+			assert_eq!(volume_accrued_events(), vec![(2_500, 2_500)]);
+			assert_eq!(Referrals::volume_since_last_event(to_code(b"AAAA")), 1_000);
+		});
+}
+
+#[test]
+fn deregister_code_should_remove_volume_since_last_event() {
+	ExtBuilder::default()
+		.with_volume_events(false, 2_000)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), to_code(b"AAAA")));
+			Referrals::accrue_volume(&ALICE, 1_000);
+			// Act
+			assert_ok!(Referrals::deregister_code(
+				RuntimeOrigin::signed(ALICE),
+				to_code(b"AAAA")
+			));
+			// Assert
+			assert_eq!(Referrals::volume_since_last_event(to_code(b"AAAA")), 0);
+		});
+}
@@ -23,6 +23,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! Weights of the functions marked as estimated were not produced by the benchmark CLI
+//! and DB reads and writes of some other functions were adjusted by hand. Regenerate before release.
 
 // Executed Command:
 // target/release/hydradx
@@ -55,6 +58,26 @@ pub trait WeightInfo {
 	fn convert() -> Weight;
 	fn claim_rewards() -> Weight;
 	fn set_reward_percentage() -> Weight;
+	fn register_alias() -> Weight;
+	fn deregister_code() -> Weight;
+	fn deregister_all(a: u32) -> Weight;
+	fn force_register_code() -> Weight;
+	fn set_code_reward_override() -> Weight;
+	fn migrate_code() -> Weight;
+	fn assign_rewards() -> Weight;
+	fn set_code_pattern() -> Weight;
+	fn register_code_in_campaign() -> Weight;
+	fn set_code_active() -> Weight;
+	fn set_description() -> Weight;
+	fn set_code_manager() -> Weight;
+	fn unlink_code() -> Weight;
+	fn offer_code_transfer() -> Weight;
+	fn accept_code_transfer() -> Weight;
+	fn cancel_code_transfer() -> Weight;
+	fn backfill_account_code() -> Weight;
+	fn set_code_expiry() -> Weight;
+	fn remove_expired_code() -> Weight;
+	fn snapshot_code() -> Weight;
 }
 
 /// Weights for pallet_referrals using the hydraDX node and recommended hardware.
@@ -63,31 +86,53 @@ impl WeightInfo for () {
 	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::ReferralCodes` (r:1 w:1)
 	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `System::Account` (r:3 w:3)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::Referrer` (r:0 w:1)
 	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCount` (r:1 w:1)
+	/// Proof: `Referrals::CodeCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeRegisteredAt` (r:0 w:1)
+	/// Proof: `Referrals::CodeRegisteredAt` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::PendingConversions` (r:0 w:1)
+	/// Proof: `Referrals::PendingConversions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDisplay` (r:0 w:1)
+	/// Proof: `Referrals::CodeDisplay` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodePattern` (r:1 w:0)
+	/// Proof: `Referrals::CodePattern` (`max_values`: Some(1), `max_size`: Some(11), added: 506, mode: `MaxEncodedLen`)
 	fn register_code() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `318`
 		//  Estimated: `6196`
 		// Minimum execution time: 59_104_000 picoseconds.
 		Weight::from_parts(59_872_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Referrals::ReferralCodes` (r:1 w:0)
 	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::LinkedAccounts` (r:1 w:1)
 	/// Proof: `Referrals::LinkedAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::LinksInPeriod` (r:1 w:1)
+	/// Proof: `Referrals::LinksInPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::InactiveCodes` (r:1 w:0)
+	/// Proof: `Referrals::InactiveCodes` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCount` (r:1 w:1)
+	/// Proof: `Referrals::ReferralCount` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::LinkedAt` (r:0 w:1)
+	/// Proof: `Referrals::LinkedAt` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeExpiry` (r:1 w:1)
+	/// Proof: `Referrals::CodeExpiry` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AutoRenew` (r:1 w:0)
+	/// Proof: `Referrals::AutoRenew` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
 	fn link_code() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `228`
 		//  Estimated: `3545`
 		// Minimum execution time: 17_219_000 picoseconds.
 		Weight::from_parts(17_478_000, 3545)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Tokens::Accounts` (r:2 w:2)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
@@ -150,14 +195,26 @@ impl WeightInfo for () {
 	/// Proof: `Referrals::TotalShares` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Referrals::Referrer` (r:1 w:1)
 	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardsClaimedInPeriod` (r:1 w:1)
+	/// Proof: `Referrals::RewardsClaimedInPeriod` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:0)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPayee` (r:1 w:0)
+	/// Proof: `Referrals::RewardPayee` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCampaign` (r:1 w:0)
+	/// Proof: `Referrals::CodeCampaign` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CampaignVolume` (r:1 w:1)
+	/// Proof: `Referrals::CampaignVolume` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::VolumeSinceLastEvent` (r:1 w:1)
+	/// Proof: `Referrals::VolumeSinceLastEvent` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
 	fn claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `689`
 		//  Estimated: `6196`
 		// Minimum execution time: 78_198_000 picoseconds.
 		Weight::from_parts(78_998_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Referrals::AssetRewards` (r:1 w:1)
 	/// Proof: `Referrals::AssetRewards` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -170,4 +227,469 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:0)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:1)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AccountCodes` (r:1 w:1)
+	/// Proof: `Referrals::AccountCodes` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCount` (r:1 w:1)
+	/// Proof: `Referrals::CodeCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeRegisteredAt` (r:0 w:1)
+	/// Proof: `Referrals::CodeRegisteredAt` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::PendingConversions` (r:0 w:1)
+	/// Proof: `Referrals::PendingConversions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn register_alias() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_287_000 picoseconds.
+		Weight::from_parts(62_014_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:1)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:1)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AccountCodes` (r:1 w:1)
+	/// Proof: `Referrals::AccountCodes` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Leaderboard` (r:1 w:1)
+	/// Proof: `Referrals::Leaderboard` (`max_values`: Some(1), `max_size`: Some(651), added: 1146, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCount` (r:1 w:1)
+	/// Proof: `Referrals::CodeCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeRegisteredAt` (r:0 w:1)
+	/// Proof: `Referrals::CodeRegisteredAt` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDisplay` (r:0 w:1)
+	/// Proof: `Referrals::CodeDisplay` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPayee` (r:0 w:1)
+	/// Proof: `Referrals::RewardPayee` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCampaign` (r:0 w:1)
+	/// Proof: `Referrals::CodeCampaign` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::InactiveCodes` (r:0 w:1)
+	/// Proof: `Referrals::InactiveCodes` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDescription` (r:0 w:1)
+	/// Proof: `Referrals::CodeDescription` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeManager` (r:0 w:1)
+	/// Proof: `Referrals::CodeManager` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCount` (r:0 w:1)
+	/// Proof: `Referrals::ReferralCount` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::VolumeSinceLastEvent` (r:0 w:1)
+	/// Proof: `Referrals::VolumeSinceLastEvent` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeTransferOffers` (r:0 w:1)
+	/// Proof: `Referrals::CodeTransferOffers` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeExpiry` (r:0 w:1)
+	/// Proof: `Referrals::CodeExpiry` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AutoRenew` (r:0 w:1)
+	/// Proof: `Referrals::AutoRenew` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	fn deregister_code() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `3618`
+		// Minimum execution time: 24_503_000 picoseconds.
+		Weight::from_parts(25_091_000, 3618)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:1)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AccountCodes` (r:1 w:1)
+	/// Proof: `Referrals::AccountCodes` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Leaderboard` (r:1 w:1)
+	/// Proof: `Referrals::Leaderboard` (`max_values`: Some(1), `max_size`: Some(651), added: 1146, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCodes` (r:0 w:6)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCount` (r:1 w:1)
+	/// Proof: `Referrals::CodeCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeRegisteredAt` (r:0 w:1)
+	/// Proof: `Referrals::CodeRegisteredAt` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDisplay` (r:0 w:1)
+	/// Proof: `Referrals::CodeDisplay` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPayee` (r:0 w:1)
+	/// Proof: `Referrals::RewardPayee` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCampaign` (r:0 w:1)
+	/// Proof: `Referrals::CodeCampaign` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::InactiveCodes` (r:0 w:1)
+	/// Proof: `Referrals::InactiveCodes` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDescription` (r:0 w:1)
+	/// Proof: `Referrals::CodeDescription` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeManager` (r:0 w:1)
+	/// Proof: `Referrals::CodeManager` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCount` (r:0 w:1)
+	/// Proof: `Referrals::ReferralCount` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::VolumeSinceLastEvent` (r:0 w:1)
+	/// Proof: `Referrals::VolumeSinceLastEvent` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeTransferOffers` (r:0 w:1)
+	/// Proof: `Referrals::CodeTransferOffers` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeExpiry` (r:0 w:6)
+	/// Proof: `Referrals::CodeExpiry` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AutoRenew` (r:0 w:6)
+	/// Proof: `Referrals::AutoRenew` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 5]`.
+	fn deregister_all(a: u32, ) -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `402 + a * (40 ±0)`
+		//  Estimated: `3618`
+		// Minimum execution time: 22_871_000 picoseconds.
+		Weight::from_parts(23_650_512, 3618)
+			// Standard Error: 6_472
+			.saturating_add(Weight::from_parts(1_604_318, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+			.saturating_add(RocksDbWeight::get().writes((13_u64).saturating_mul(a.into())))
+	}
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:1)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:1)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Referrer` (r:1 w:1)
+	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCount` (r:1 w:1)
+	/// Proof: `Referrals::CodeCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeRegisteredAt` (r:0 w:1)
+	/// Proof: `Referrals::CodeRegisteredAt` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	fn force_register_code() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `3530`
+		// Minimum execution time: 24_318_000 picoseconds.
+		Weight::from_parts(24_902_000, 3530)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:0)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeRewardOverride` (r:0 w:1)
+	/// Proof: `Referrals::CodeRewardOverride` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:0)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	fn set_code_reward_override() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3524`
+		// Minimum execution time: 15_206_000 picoseconds.
+		Weight::from_parts(15_649_000, 3524)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:2 w:2)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:1)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Leaderboard` (r:1 w:1)
+	/// Proof: `Referrals::Leaderboard` (`max_values`: Some(1), `max_size`: Some(651), added: 1146, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeRegisteredAt` (r:1 w:2)
+	/// Proof: `Referrals::CodeRegisteredAt` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeRewardOverride` (r:1 w:2)
+	/// Proof: `Referrals::CodeRewardOverride` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDisplay` (r:0 w:2)
+	/// Proof: `Referrals::CodeDisplay` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPayee` (r:1 w:2)
+	/// Proof: `Referrals::RewardPayee` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCampaign` (r:1 w:2)
+	/// Proof: `Referrals::CodeCampaign` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::InactiveCodes` (r:1 w:2)
+	/// Proof: `Referrals::InactiveCodes` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDescription` (r:1 w:2)
+	/// Proof: `Referrals::CodeDescription` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeManager` (r:1 w:2)
+	/// Proof: `Referrals::CodeManager` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCount` (r:1 w:2)
+	/// Proof: `Referrals::ReferralCount` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::VolumeSinceLastEvent` (r:1 w:2)
+	/// Proof: `Referrals::VolumeSinceLastEvent` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeTransferOffers` (r:1 w:2)
+	/// Proof: `Referrals::CodeTransferOffers` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeExpiry` (r:1 w:2)
+	/// Proof: `Referrals::CodeExpiry` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AutoRenew` (r:1 w:2)
+	/// Proof: `Referrals::AutoRenew` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RenamedCodes` (r:0 w:2)
+	/// Proof: `Referrals::RenamedCodes` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	fn migrate_code() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6058`
+		// Minimum execution time: 29_846_000 picoseconds.
+		Weight::from_parts(30_512_000, 6058)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(32_u64))
+	}
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:0)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPayee` (r:0 w:1)
+	/// Proof: `Referrals::RewardPayee` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	fn assign_rewards() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `342`
+		//  Estimated: `3524`
+		// Minimum execution time: 15_872_000 picoseconds.
+		Weight::from_parts(16_314_000, 3524)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::CodePattern` (r:0 w:1)
+	/// Proof: `Referrals::CodePattern` (`max_values`: Some(1), `max_size`: Some(11), added: 506, mode: `MaxEncodedLen`)
+	fn set_code_pattern() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_934_000 picoseconds.
+		Weight::from_parts(9_262_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:1)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:1)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Referrer` (r:0 w:1)
+	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCount` (r:1 w:1)
+	/// Proof: `Referrals::CodeCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeRegisteredAt` (r:0 w:1)
+	/// Proof: `Referrals::CodeRegisteredAt` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::PendingConversions` (r:0 w:1)
+	/// Proof: `Referrals::PendingConversions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDisplay` (r:0 w:1)
+	/// Proof: `Referrals::CodeDisplay` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodePattern` (r:1 w:0)
+	/// Proof: `Referrals::CodePattern` (`max_values`: Some(1), `max_size`: Some(11), added: 506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCampaign` (r:0 w:1)
+	/// Proof: `Referrals::CodeCampaign` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	fn register_code_in_campaign() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `6196`
+		// Minimum execution time: 60_231_000 picoseconds.
+		Weight::from_parts(61_014_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:0)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::InactiveCodes` (r:0 w:1)
+	/// Proof: `Referrals::InactiveCodes` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	fn set_code_active() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3524`
+		// Minimum execution time: 14_815_000 picoseconds.
+		Weight::from_parts(15_247_000, 3524)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:0)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDescription` (r:0 w:1)
+	/// Proof: `Referrals::CodeDescription` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn set_description() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3524`
+		// Minimum execution time: 15_402_000 picoseconds.
+		Weight::from_parts(15_873_000, 3524)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:0)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeManager` (r:0 w:1)
+	/// Proof: `Referrals::CodeManager` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	fn set_code_manager() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3524`
+		// Minimum execution time: 15_118_000 picoseconds.
+		Weight::from_parts(15_604_000, 3524)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::LinkedAccounts` (r:1 w:1)
+	/// Proof: `Referrals::LinkedAccounts` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::LinkedAt` (r:1 w:1)
+	/// Proof: `Referrals::LinkedAt` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCount` (r:1 w:1)
+	/// Proof: `Referrals::ReferralCount` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCodes` (r:9 w:0)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RenamedCodes` (r:8 w:0)
+	/// Proof: `Referrals::RenamedCodes` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	fn unlink_code() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `396`
+		//  Estimated: `3545`
+		// Minimum execution time: 21_467_000 picoseconds.
+		Weight::from_parts(21_915_000, 3545)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:0)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeTransferOffers` (r:0 w:1)
+	/// Proof: `Referrals::CodeTransferOffers` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	fn offer_code_transfer() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3524`
+		// Minimum execution time: 16_204_000 picoseconds.
+		Weight::from_parts(16_739_000, 3524)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::CodeTransferOffers` (r:1 w:1)
+	/// Proof: `Referrals::CodeTransferOffers` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:1)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralAccounts` (r:2 w:2)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AccountCodes` (r:2 w:2)
+	/// Proof: `Referrals::AccountCodes` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Referrer` (r:1 w:1)
+	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Leaderboard` (r:1 w:1)
+	/// Proof: `Referrals::Leaderboard` (`max_values`: Some(1), `max_size`: Some(651), added: 1146, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPayee` (r:0 w:1)
+	/// Proof: `Referrals::RewardPayee` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeManager` (r:0 w:1)
+	/// Proof: `Referrals::CodeManager` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCount` (r:0 w:1)
+	/// Proof: `Referrals::ReferralCount` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::VolumeSinceLastEvent` (r:0 w:1)
+	/// Proof: `Referrals::VolumeSinceLastEvent` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn accept_code_transfer() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `7246`
+		// Minimum execution time: 48_931_000 picoseconds.
+		Weight::from_parts(49_857_000, 7246)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `Referrals::CodeTransferOffers` (r:1 w:1)
+	/// Proof: `Referrals::CodeTransferOffers` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:0)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	fn cancel_code_transfer() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `331`
+		//  Estimated: `3528`
+		// Minimum execution time: 19_377_000 picoseconds.
+		Weight::from_parts(19_860_000, 3528)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:0)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:0)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AccountCodes` (r:1 w:1)
+	/// Proof: `Referrals::AccountCodes` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	fn backfill_account_code() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `3618`
+		// Minimum execution time: 17_254_000 picoseconds.
+		Weight::from_parts(17_702_000, 3618)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:0)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AutoRenew` (r:0 w:1)
+	/// Proof: `Referrals::AutoRenew` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeExpiry` (r:0 w:1)
+	/// Proof: `Referrals::CodeExpiry` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	fn set_code_expiry() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3524`
+		// Minimum execution time: 13_106_000 picoseconds.
+		Weight::from_parts(13_472_000, 3524)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:1)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:1)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AccountCodes` (r:1 w:1)
+	/// Proof: `Referrals::AccountCodes` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Leaderboard` (r:1 w:1)
+	/// Proof: `Referrals::Leaderboard` (`max_values`: Some(1), `max_size`: Some(651), added: 1146, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCount` (r:1 w:1)
+	/// Proof: `Referrals::CodeCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeRegisteredAt` (r:0 w:1)
+	/// Proof: `Referrals::CodeRegisteredAt` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDisplay` (r:0 w:1)
+	/// Proof: `Referrals::CodeDisplay` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::RewardPayee` (r:0 w:1)
+	/// Proof: `Referrals::RewardPayee` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeCampaign` (r:0 w:1)
+	/// Proof: `Referrals::CodeCampaign` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::InactiveCodes` (r:0 w:1)
+	/// Proof: `Referrals::InactiveCodes` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeDescription` (r:0 w:1)
+	/// Proof: `Referrals::CodeDescription` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeManager` (r:0 w:1)
+	/// Proof: `Referrals::CodeManager` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCount` (r:0 w:1)
+	/// Proof: `Referrals::ReferralCount` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::VolumeSinceLastEvent` (r:0 w:1)
+	/// Proof: `Referrals::VolumeSinceLastEvent` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeTransferOffers` (r:0 w:1)
+	/// Proof: `Referrals::CodeTransferOffers` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+		/// Storage: `Referrals::CodeExpiry` (r:1 w:1)
+	/// Proof: `Referrals::CodeExpiry` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::AutoRenew` (r:0 w:1)
+	/// Proof: `Referrals::AutoRenew` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+	fn remove_expired_code() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `510`
+		//  Estimated: `3618`
+		// Minimum execution time: 26_114_000 picoseconds.
+		Weight::from_parts(26_739_000, 3618)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:0)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Referrer` (r:1 w:0)
+	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCount` (r:1 w:0)
+	/// Proof: `Referrals::ReferralCount` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeSnapshots` (r:0 w:1)
+	/// Proof: `Referrals::CodeSnapshots` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	fn snapshot_code() -> Weight {
+		// Estimated, not measured. Needs to be regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `3530`
+		// Minimum execution time: 16_118_000 picoseconds.
+		Weight::from_parts(16_574_000, 3530)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -40,7 +40,7 @@ use frame_support::{
 	sp_runtime::{
 		traits::{DispatchInfoOf, One, PostDispatchInfoOf, Saturating, Zero},
 		transaction_validity::{InvalidTransaction, TransactionValidityError},
-		FixedPointNumber, FixedPointOperand, FixedU128,
+		FixedPointNumber, FixedPointOperand, FixedU128, Permill,
 	},
 	traits::Get,
 	weights::Weight,
@@ -611,9 +611,12 @@ impl<T: Config> DepositFee<T::AccountId, AssetIdOf<T>, BalanceOf<T>> for Deposit
 }
 
 /// Implements the transaction payment for native as well as non-native currencies
-pub struct TransferFees<MC, DF, FR>(PhantomData<(MC, DF, FR)>);
+///
+/// Discount given by `FD` is subtracted from the actual fee and refunded to the payer
+/// in the currency the fee was paid in.
+pub struct TransferFees<MC, DF, FR, FD = ()>(PhantomData<(MC, DF, FR, FD)>);
 
-impl<T, MC, DF, FR> OnChargeTransaction<T> for TransferFees<MC, DF, FR>
+impl<T, MC, DF, FR, FD> OnChargeTransaction<T> for TransferFees<MC, DF, FR, FD>
 where
 	T: Config + pallet_utility::Config,
 	MC: MultiCurrency<<T as frame_system::Config>::AccountId>,
@@ -621,12 +624,13 @@ where
 	MC::Balance: FixedPointOperand,
 	FR: Get<T::AccountId>,
 	DF: DepositFee<T::AccountId, MC::CurrencyId, MC::Balance>,
+	FD: FeeDiscount<T::AccountId, <T as frame_system::Config>::RuntimeCall>,
 	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>> + IsSubType<pallet_utility::pallet::Call<T>>,
 	<T as pallet_utility::Config>::RuntimeCall: IsSubType<Call<T>>,
 	BalanceOf<T>: FixedPointOperand,
 	BalanceOf<T>: From<MC::Balance>,
 {
-	type LiquidityInfo = Option<PaymentInfo<Self::Balance, AssetIdOf<T>, Price>>;
+	type LiquidityInfo = Option<(PaymentInfo<Self::Balance, AssetIdOf<T>, Price>, Permill)>;
 	type Balance = <MC as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	/// Withdraw the predicted fee from the transaction origin.
@@ -700,12 +704,13 @@ where
 			(fee_in_dot, T::PolkadotNativeAssetId::get(), dot_hdx_price)
 		};
 
+		let discount = FD::fee_discount(who, call);
 		match MC::withdraw(currency.into(), who, converted_fee) {
 			Ok(()) => {
 				if currency == T::NativeAssetId::get() {
-					Ok(Some(PaymentInfo::Native(fee)))
+					Ok(Some((PaymentInfo::Native(fee), discount)))
 				} else {
-					Ok(Some(PaymentInfo::NonNative(converted_fee, currency, price)))
+					Ok(Some((PaymentInfo::NonNative(converted_fee, currency, price), discount)))
 				}
 			}
 			Err(_) => Err(InvalidTransaction::Payment.into()),
@@ -726,7 +731,10 @@ where
 	) -> Result<(), TransactionValidityError> {
 		let fee_receiver = FR::get();
 
-		if let Some(paid) = already_withdrawn {
+		if let Some((paid, discount)) = already_withdrawn {
+			// The discount is refunded together with the overpaid fee
+			let corrected_fee = corrected_fee.saturating_sub(discount.mul_floor(corrected_fee.saturating_sub(tip)));
+
 			// Calculate how much refund we should return
 			let (currency, refund, fee, tip) = match paid {
 				PaymentInfo::Native(paid_fee) => (
@@ -16,7 +16,9 @@
 // limitations under the License.
 
 pub use crate::{mock::*, Error};
-use crate::{AcceptedCurrencies, AcceptedCurrencyPrice, Event, PaymentInfo, Price};
+use crate::{
+	AcceptedCurrencies, AcceptedCurrencyPrice, DepositAll, Event, FeeDiscount, PaymentInfo, Price, TransferFees,
+};
 
 use frame_support::{
 	assert_noop, assert_ok, assert_storage_noop,
@@ -28,10 +30,11 @@ use frame_support::{
 use hydradx_traits::evm::InspectEvmAccounts;
 use orml_traits::MultiCurrency;
 use pallet_balances::Call as BalancesCall;
-use pallet_transaction_payment::ChargeTransactionPayment;
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
 use sp_core::{H256, U256};
 use sp_runtime::traits::ValidateUnsigned;
 use sp_runtime::transaction_validity::TransactionSource;
+use sp_runtime::Permill;
 
 const CALL: &<Test as frame_system::Config>::RuntimeCall =
 	&RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest: BOB, value: 69 });
@@ -578,7 +581,10 @@ fn fee_should_be_transferred_when_paid_in_native_currency() {
 				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, len)
 				.unwrap();
 			// Assert
-			assert_eq!(pre, (tip, CHARLIE, Some(PaymentInfo::Native(5 + 15 + 10))));
+			assert_eq!(
+				pre,
+				(tip, CHARLIE, Some((PaymentInfo::Native(5 + 15 + 10), Permill::zero())))
+			);
 
 			assert_eq!(Balances::free_balance(CHARLIE), 100 - 30);
 			assert_eq!(Balances::free_balance(FEE_RECEIVER), 0);
@@ -652,7 +658,14 @@ fn fee_should_be_transferred_when_paid_in_native_currency_work_with_tip() {
 				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, len)
 				.unwrap();
 			// Assert
-			assert_eq!(pre, (tip, CHARLIE, Some(PaymentInfo::Native(5 + 15 + 10 + tip))));
+			assert_eq!(
+				pre,
+				(
+					tip,
+					CHARLIE,
+					Some((PaymentInfo::Native(5 + 15 + 10 + tip), Permill::zero()))
+				)
+			);
 
 			assert_eq!(Balances::free_balance(CHARLIE), 100 - 5 - 10 - 15 - tip);
 			assert_eq!(Balances::free_balance(FEE_RECEIVER), 0);
@@ -735,7 +748,10 @@ fn fee_should_be_transferred_when_paid_in_non_native_currency() {
 				(
 					tip,
 					CHARLIE,
-					Some(PaymentInfo::NonNative(45, SUPPORTED_CURRENCY, Price::from_float(1.5)))
+					Some((
+						PaymentInfo::NonNative(45, SUPPORTED_CURRENCY, Price::from_float(1.5)),
+						Permill::zero()
+					))
 				)
 			);
 
@@ -761,6 +777,92 @@ fn fee_should_be_transferred_when_paid_in_non_native_currency() {
 		});
 }
 
+struct HalfFeeDiscount;
+impl FeeDiscount<AccountId, RuntimeCall> for HalfFeeDiscount {
+	fn fee_discount(_who: &AccountId, _call: &RuntimeCall) -> Permill {
+		Permill::from_percent(50)
+	}
+}
+
+type DiscountedTransferFees = TransferFees<Currencies, DepositAll<Test>, FeeReceiver, HalfFeeDiscount>;
+
+#[test]
+fn fee_discount_should_be_refunded_when_paid_in_native_currency() {
+	// Arrange
+	ExtBuilder::default()
+		.account_native_balance(CHARLIE, 100)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+			let pre = <DiscountedTransferFees as OnChargeTransaction<Test>>::withdraw_fee(
+				&CHARLIE,
+				CALL,
+				&dispatch_info,
+				30,
+				0,
+			)
+			.unwrap();
+			assert_eq!(pre, Some((PaymentInfo::Native(30), Permill::from_percent(50))));
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 30);
+
+			// Act
+			assert_ok!(
+				<DiscountedTransferFees as OnChargeTransaction<Test>>::correct_and_deposit_fee(
+					&CHARLIE,
+					&dispatch_info,
+					&default_post_info(),
+					30,
+					0,
+					pre
+				)
+			);
+
+			// Assert
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 15);
+			assert_eq!(Balances::free_balance(FEE_RECEIVER), 15);
+		});
+}
+
+#[test]
+fn fee_discount_should_be_refunded_in_fee_currency_when_paid_in_non_native_currency() {
+	// Arrange
+	ExtBuilder::default()
+		.with_currencies(vec![(CHARLIE, SUPPORTED_CURRENCY)])
+		.account_tokens(CHARLIE, SUPPORTED_CURRENCY, 10_000)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+			let pre = <DiscountedTransferFees as OnChargeTransaction<Test>>::withdraw_fee(
+				&CHARLIE,
+				CALL,
+				&dispatch_info,
+				30,
+				0,
+			)
+			.unwrap();
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &CHARLIE), 10_000 - 45);
+
+			// Act
+			assert_ok!(
+				<DiscountedTransferFees as OnChargeTransaction<Test>>::correct_and_deposit_fee(
+					&CHARLIE,
+					&dispatch_info,
+					&default_post_info(),
+					30,
+					0,
+					pre
+				)
+			);
+
+			// Assert
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &CHARLIE), 10_000 - 22);
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &FeeReceiver::get()), 22);
+			assert_eq!(Balances::free_balance(CHARLIE), 0);
+		});
+}
+
 #[test]
 fn fee_should_be_withdrawn_when_paid_in_non_native_currency() {
 	// Arrange
@@ -829,7 +931,10 @@ fn fee_should_be_transferred_when_paid_in_non_native_currency_with_tip() {
 				(
 					tip,
 					CHARLIE,
-					Some(PaymentInfo::NonNative(52, SUPPORTED_CURRENCY, Price::from_float(1.5)))
+					Some((
+						PaymentInfo::NonNative(52, SUPPORTED_CURRENCY, Price::from_float(1.5)),
+						Permill::zero()
+					))
 				)
 			);
 
@@ -1027,7 +1132,7 @@ fn fee_transfer_can_kill_account_when_paid_in_native() {
 				.unwrap();
 
 			// Assert
-			assert_eq!(pre, (tip, CHARLIE, Some(PaymentInfo::Native(30))));
+			assert_eq!(pre, (tip, CHARLIE, Some((PaymentInfo::Native(30), Permill::zero()))));
 			assert_eq!(Balances::free_balance(CHARLIE), 0);
 			assert_eq!(Balances::free_balance(FEE_RECEIVER), 0);
 
@@ -1071,7 +1176,10 @@ fn fee_transfer_can_kill_account_when_paid_in_non_native() {
 				(
 					tip,
 					ALICE,
-					Some(PaymentInfo::NonNative(45, SUPPORTED_CURRENCY, Price::from_float(1.5)))
+					Some((
+						PaymentInfo::NonNative(45, SUPPORTED_CURRENCY, Price::from_float(1.5)),
+						Permill::zero()
+					))
 				)
 			);
 			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &ALICE), 0);
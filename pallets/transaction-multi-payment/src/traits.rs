@@ -1,7 +1,7 @@
 use crate::{Config, Error, PhantomData};
 use frame_support::dispatch::PostDispatchInfo;
 use frame_support::pallet_prelude::DispatchResultWithPostInfo;
-use frame_support::sp_runtime::DispatchResult;
+use frame_support::sp_runtime::{DispatchResult, Permill};
 use frame_support::weights::Weight;
 use sp_core::{H160, H256, U256};
 use sp_std::vec::Vec;
@@ -17,6 +17,18 @@ pub trait DepositFee<AccountId, AssetId, Balance> {
 	fn deposit_fee(who: &AccountId, currency: AssetId, amount: Balance) -> DispatchResult;
 }
 
+/// Discount of the transaction fee of an account for a call.
+/// The discount applies to the fee without the tip.
+pub trait FeeDiscount<AccountId, Call> {
+	fn fee_discount(who: &AccountId, call: &Call) -> Permill;
+}
+
+impl<AccountId, Call> FeeDiscount<AccountId, Call> for () {
+	fn fee_discount(_who: &AccountId, _call: &Call) -> Permill {
+		Permill::zero()
+	}
+}
+
 pub trait EVMPermit {
 	#![allow(clippy::too_many_arguments)]
 	fn validate_permit(
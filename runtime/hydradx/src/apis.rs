@@ -446,6 +446,71 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_referrals_rpc_runtime_api::ReferralsApi<Block, AccountId, Balance, pallet_referrals::CodeInfo<AccountId, BlockNumber>, pallet_referrals::CampaignId, pallet_referrals::RegistrationError> for Runtime {
+		fn leaderboard() -> Vec<(Vec<u8>, Balance)> {
+			Referrals::leaderboard()
+				.into_iter()
+				.map(|(code, volume)| (code.into_inner(), volume))
+				.collect()
+		}
+
+		fn code_count() -> u32 {
+			Referrals::code_count()
+		}
+
+		fn code_info(code: Vec<u8>) -> Option<pallet_referrals::CodeInfo<AccountId, BlockNumber>> {
+			Referrals::code_info(code)
+		}
+
+		fn referral_count(code: Vec<u8>) -> u32 {
+			Referrals::code_referral_count(code)
+		}
+
+		fn campaign_volume(campaign: pallet_referrals::CampaignId) -> Balance {
+			Referrals::campaign_volume(campaign)
+		}
+
+		fn can_register(account: AccountId, code: Vec<u8>) -> Result<(), pallet_referrals::RegistrationError> {
+			Referrals::can_register(&account, code)
+		}
+
+		fn referral_chain(account: AccountId, max_depth: u32) -> Vec<AccountId> {
+			Referrals::referral_chain(&account, max_depth)
+		}
+	}
+
+	impl pallet_bonds_rpc_runtime_api::BondsApi<Block, AssetId, primitives::Moment, Balance> for Runtime {
+		fn metadata_uri(bond_id: AssetId) -> Option<Vec<u8>> {
+			Bonds::bond_metadata_uri(bond_id).map(|uri| uri.into_inner())
+		}
+
+		fn weighted_average_maturity(asset_id: AssetId) -> Option<primitives::Moment> {
+			Bonds::weighted_average_maturity(asset_id)
+		}
+
+		fn cumulative_fees(asset_id: AssetId) -> Balance {
+			Bonds::cumulative_fees(asset_id)
+		}
+
+		fn bonds_of_asset(asset_id: AssetId) -> Vec<AssetId> {
+			Bonds::bonds_of_asset(asset_id).into_inner()
+		}
+	}
+
+	impl pallet_otc_settlements_rpc_runtime_api::OtcSettlementsApi<Block, pallet_otc::OrderId, Balance, pallet_otc_settlements::SettlementPreview<AssetId>, pallet_otc_settlements::SettlementOutcome> for Runtime {
+		fn preview_settlement(otc_id: pallet_otc::OrderId, amount: Balance) -> Option<pallet_otc_settlements::SettlementPreview<AssetId>> {
+			OtcSettlements::preview_settlement(otc_id, amount)
+		}
+
+		fn settleable_orders(limit: u32) -> Vec<(pallet_otc::OrderId, Balance)> {
+			OtcSettlements::settleable_orders(limit)
+		}
+
+		fn simulate_batch(order_ids: Vec<pallet_otc::OrderId>) -> Vec<(pallet_otc::OrderId, pallet_otc_settlements::SettlementOutcome)> {
+			OtcSettlements::simulate_batch(order_ids)
+		}
+	}
+
 	impl xcm_fee_payment_runtime_api::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: polkadot_xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
 			if !matches!(xcm_version, 3 | 4) {
@@ -51,8 +51,8 @@ use frame_support::{
 	sp_runtime::traits::{One, PhantomData},
 	sp_runtime::{FixedU128, Perbill, Permill},
 	traits::{
-		AsEnsureOriginWithArg, ConstU32, Contains, Currency, Defensive, EnsureOrigin, Imbalance, LockIdentifier,
-		NeverEnsureOrigin, OnUnbalanced,
+		AsEnsureOriginWithArg, ConstBool, ConstU32, Contains, Currency, Defensive, EnsureOrigin, Imbalance,
+		LockIdentifier, NeverEnsureOrigin, OnUnbalanced,
 	},
 	BoundedVec, PalletId,
 };
@@ -67,6 +67,7 @@ use pallet_lbp::weights::WeightInfo as LbpWeights;
 use pallet_route_executor::{weights::WeightInfo as RouterWeights, AmmTradeWeights, MAX_NUMBER_OF_TRADES};
 use pallet_staking::{
 	types::{Action, Point},
+	weights::WeightInfo as StakingWeights,
 	SigmoidPercentage,
 };
 use pallet_xyk::weights::WeightInfo as XykWeights;
@@ -115,7 +116,7 @@ impl MutationHooks<AccountId, AssetId, Balance> for CurrencyHooks {
 	type OnSlash = ();
 	type PreDeposit = SufficiencyCheck;
 	type PostDeposit = ();
-	type PreTransfer = SufficiencyCheck;
+	type PreTransfer = (SufficiencyCheck, Bonds);
 	type PostTransfer = ();
 	type OnNewTokenAccount = AddTxAssetOnAccount<Runtime>;
 	type OnKilledTokenAccount = (RemoveTxAssetOnKilled<Runtime>, OnKilledTokenAccount);
@@ -1213,6 +1214,28 @@ parameter_types! {
 	pub const ExistentialDepositMultiplier: u8 = 5;
 	pub const PricePrecision: FixedU128 = FixedU128::from_rational(1, 100);
 	pub MinProfitPercentage: Perbill = Perbill::from_rational(1u32, 100_000_u32); // 0.001%
+	// all the profit goes to the treasury
+	pub const OtcSettlementsKeeperBounty: Permill = Permill::zero();
+	// spot price of the route is not cross-checked against the oracle price
+	pub const OtcSettlementsMaxOracleDeviation: Option<Permill> = None;
+	// all sorted orders are scanned by the offchain worker in every block
+	pub const OtcSettlementsMaxOrdersScannedPerBlock: u32 = u32::MAX;
+	// failing orders are not skipped by the offchain worker
+	pub const OtcSettlementsFailuresBeforeBackoff: u32 = u32::MAX;
+	// settlements are not capped by the liquidity of the pools
+	pub const OtcSettlementsMaxPoolDrain: Permill = Permill::from_percent(100);
+	// orders can be settled in the block they are placed
+	pub const OtcSettlementsMinOrderAge: BlockNumber = 0;
+	// orders don't expire
+	pub const OtcSettlementsOrderTtl: BlockNumber = 0;
+	// profit of the settlements in a block is not capped
+	pub const OtcSettlementsMaxProfitPerBlock: Balance = Balance::MAX;
+	// profit is transferred in the asset it was made in
+	pub const OtcSettlementsConvertProfitToNative: bool = false;
+	// settlements are only required to be profitable
+	pub const OtcSettlementsMinPriceImprovement: Permill = Permill::zero();
+	// failed settlements don't halt the settlement
+	pub const OtcSettlementsFailureBreakerThreshold: u32 = 0;
 	pub OtcFee: Permill = Permill::from_rational(1u32, 1_000_u32); // 0.1%
 }
 
@@ -1235,11 +1258,35 @@ impl pallet_otc_settlements::Config for Runtime {
 	type Router = Router;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Router = pallet_route_executor::DummyRouter<Runtime>;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type ProfitReceiver = TreasuryAccount;
+	type MaxProfitBeneficiaries = ConstU32<5>;
+	type KeeperBounty = OtcSettlementsKeeperBounty;
+	type PriceOracle = OraclePriceProviderUsingRoute<
+		Router,
+		OraclePriceProvider<AssetId, EmaOracle, LRNA>,
+		RouteValidationOraclePeriod,
+	>;
+	type MaxOracleDeviation = OtcSettlementsMaxOracleDeviation;
+	type AMM = (Omnipool, Stableswap, XYK, LBP);
+	type MaxPoolDrain = OtcSettlementsMaxPoolDrain;
 	type MinProfitPercentage = MinProfitPercentage;
+	type MinPriceImprovement = OtcSettlementsMinPriceImprovement;
 	type PricePrecision = PricePrecision;
 	type MinTradingLimit = MinTradingLimit;
 	type MaxIterations = ConstU32<40>;
+	type MaxOrdersScannedPerBlock = OtcSettlementsMaxOrdersScannedPerBlock;
+	type FailuresBeforeBackoff = OtcSettlementsFailuresBeforeBackoff;
+	type MaxBackoffScans = ConstU32<64>;
+	type FailureBreakerThreshold = OtcSettlementsFailureBreakerThreshold;
+	type ReservationPeriod = ConstU32<3>;
+	type ReservationCooldown = ConstU32<10>;
+	type MinOrderAge = OtcSettlementsMinOrderAge;
+	type OrderTtl = OtcSettlementsOrderTtl;
+	type MaxProfitPerBlock = OtcSettlementsMaxProfitPerBlock;
+	type NativeAssetId = NativeAssetId;
+	type ConvertProfitToNative = OtcSettlementsConvertProfitToNative;
+	type WeightToFee = crate::system::WeightToFee;
 	type WeightInfo = weights::pallet_otc_settlements::HydraWeight<Runtime>;
 	type RouterWeightInfo = RouterWeightInfo;
 }
@@ -1395,6 +1442,24 @@ impl pallet_stableswap::Config for Runtime {
 parameter_types! {
 	pub ProtocolFee: Permill = Permill::from_percent(2);
 	pub const BondsPalletId: PalletId = PalletId(*b"pltbonds");
+	pub const BondsPostMaturityGrace: primitives::Moment = primitives::constants::time::unix_time::DAY;
+	// maturities of already registered bonds are not aligned, rounding is disabled
+	pub const BondsMaturityGranularity: primitives::Moment = 1;
+	// issuance of bonds is not limited per issuer
+	pub const BondsMaxIssuancePerIssuer: Balance = Balance::MAX;
+	// maturity warnings are disabled
+	pub const BondsMaturityWarningWindow: primitives::Moment = 0;
+	// outstanding supply events are disabled
+	pub const BondsGaugeInterval: BlockNumber = 0;
+	// dust remainders of partial redemptions are kept by the holders
+	pub const BondsDustRedemption: bool = false;
+	pub const BondsDustThreshold: Balance = 0;
+	// redemptions of any amount are allowed
+	pub const BondsMinRedemptionAmount: Balance = 0;
+	// redemptions of bonds with an underlying asset removed from the registry fail
+	pub const BondsUnderlyingFallbackAsset: Option<AssetId> = None;
+	// redemption conditions are checked against the daily oracle price
+	pub const BondsConditionOraclePeriod: OraclePeriod = OraclePeriod::Day;
 }
 
 pub struct AssetTypeWhitelist;
@@ -1404,6 +1469,55 @@ impl Contains<AssetKind> for AssetTypeWhitelist {
 	}
 }
 
+/// Redemption condition of bonds: the oracle price of `asset_id` in `quote_asset_id` is at least `min_price`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, sp_core::RuntimeDebug, scale_info::TypeInfo, codec::MaxEncodedLen)]
+pub struct BondPriceCondition {
+	pub asset_id: AssetId,
+	pub quote_asset_id: AssetId,
+	pub min_price: FixedU128,
+}
+
+type BondsConditionPriceProvider =
+	OraclePriceProviderUsingRoute<Router, OraclePriceProvider<AssetId, EmaOracle, LRNA>, BondsConditionOraclePeriod>;
+
+/// Checks the redemption conditions of bonds against the price of the EMA oracle over `BondsConditionOraclePeriod`.
+/// The condition is not met if the oracle price is not available.
+pub struct BondsConditionOracle;
+impl pallet_bonds::RedemptionConditionOracle<BondPriceCondition> for BondsConditionOracle {
+	fn is_met(condition: &BondPriceCondition) -> bool {
+		BondsConditionPriceProvider::get_price(condition.asset_id, condition.quote_asset_id)
+			.and_then(|price| FixedU128::checked_from_rational(price.n, price.d))
+			.is_some_and(|price| price >= condition.min_price)
+	}
+
+	fn is_met_weight() -> Weight {
+		// route of the oracle price and oracle entry of each trade
+		RouterWeightInfo::get_route_weight()
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(MAX_NUMBER_OF_TRADES.into()))
+	}
+}
+
+/// Stakes the redeemed native asset to the existing staking position of the account or creates a new one.
+pub struct BondsStaking;
+impl pallet_bonds::StakeHandler<AccountId, Balance> for BondsStaking {
+	fn stakeable_asset() -> AssetId {
+		NativeAssetId::get()
+	}
+
+	fn stake(who: &AccountId, amount: Balance) -> DispatchResult {
+		let origin = RuntimeOrigin::signed(who.clone());
+		match Staking::get_user_position_id(who)? {
+			Some(position_id) => Staking::increase_stake(origin, position_id, amount),
+			None => Staking::stake(origin, amount),
+		}
+	}
+
+	fn stake_weight() -> Weight {
+		weights::pallet_staking::HydraWeight::<Runtime>::stake()
+			.max(weights::pallet_staking::HydraWeight::<Runtime>::increase_stake())
+	}
+}
+
 impl pallet_bonds::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -1413,10 +1527,36 @@ impl pallet_bonds::Config for Runtime {
 	type TimestampProvider = Timestamp;
 	type PalletId = BondsPalletId;
 	type IssueOrigin = EnsureSigned<AccountId>;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type ClawbackOrigin = EnsureRoot<AccountId>;
+	type PostMaturityGrace = BondsPostMaturityGrace;
+	type MaturityGranularity = BondsMaturityGranularity;
+	type MaxBatch = ConstU32<10>;
+	type MaxQueuedRedemptionsPerBlock = ConstU32<10>;
+	type MaturityWarningWindow = BondsMaturityWarningWindow;
+	type MaxMaturityChecksPerBlock = ConstU32<10>;
+	type GaugeInterval = BondsGaugeInterval;
+	type MaxAssetsPerGauge = ConstU32<50>;
+	type MaxIssuancePerIssuer = BondsMaxIssuancePerIssuer;
+	type MaxUriLen = ConstU32<256>;
+	type MaxBidsPerBook = ConstU32<20>;
+	type DustRedemption = BondsDustRedemption;
+	type BondDustThreshold = BondsDustThreshold;
+	type MinRedemptionAmount = BondsMinRedemptionAmount;
+	type UnderlyingFallbackAsset = BondsUnderlyingFallbackAsset;
 	type AssetTypeWhitelist = AssetTypeWhitelist;
 	type ProtocolFee = ProtocolFee;
 	type FeeReceiver = TreasuryAccount;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Router = Router;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Router = pallet_route_executor::DummyRouter<Runtime>;
+	type Staking = BondsStaking;
+	type RedemptionCondition = BondPriceCondition;
+	type ConditionOracle = BondsConditionOracle;
+	type MaxBondsPerAsset = ConstU32<256>;
 	type WeightInfo = weights::pallet_bonds::HydraWeight<Runtime>;
+	type RouterWeightInfo = RouterWeightInfo;
 }
 
 // Staking
@@ -1545,11 +1685,44 @@ impl pallet_xyk::Config for Runtime {
 parameter_types! {
 	pub const ReferralsPalletId: PalletId = PalletId(*b"referral");
 	pub RegistrationFee: (AssetId,Balance, AccountId)= (NativeAssetId::get(), 222_000_000_000_000, TreasuryAccount::get());
+	// all of the registration fee goes to the treasury
+	pub const ReferralsRewardPotShare: Permill = Permill::zero();
+	// no code is shorter than the threshold, so all codes pay the registration fee
+	pub const ReferralsVanityLengthThreshold: u32 = 0;
+	pub const ReferralsVanityFee: Balance = 222_000_000_000_000;
 	pub const MaxCodeLength: u32 = 10;
 	pub const MinCodeLength: u32 = 4;
 	pub const ReferralsOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
 	pub const ReferralsSeedAmount: Balance = 10_000_000_000_000;
 	pub ReferralsExternalRewardAccount: Option<AccountId> = Some(StakingPalletId::get().into_account_truncating());
+	pub const ReferralsMaxLeaderboard: u32 = 10;
+	pub const ReferralsMaxAliases: u32 = 5;
+	// transaction fees of linked accounts are not discounted unless enabled by governance via `set_storage`
+	pub storage ReferralsTransactionFeeDiscount: Permill = Permill::zero();
+	pub const ReferralsMaxRewardRate: Permill = Permill::from_percent(50);
+	pub const ReferralsNormalizeCase: pallet_referrals::CaseMode = pallet_referrals::CaseMode::Upper;
+	// only the direct referrer is rewarded
+	pub const ReferralsMaxReferralDepth: u32 = 1;
+	pub const ReferralsChainDecay: Permill = Permill::from_percent(50);
+	// rewards of any amount can be claimed
+	pub const ReferralsMinVolumeForReward: Balance = 0;
+	// every link is reported with its own event
+	pub const ReferralsEmitLinkEvents: bool = true;
+	// claimed rewards are not capped
+	pub const ReferralsRewardCapPerPeriod: Balance = Balance::MAX;
+	pub const ReferralsRewardPeriod: BlockNumber = DAYS;
+	// accrued volume is not capped
+	pub const ReferralsMaxAccruedVolume: Balance = Balance::MAX;
+	pub const ReferralsMaxDescLen: u32 = 64;
+	pub const ReferralsUnlinkGracePeriod: BlockNumber = DAYS;
+	pub const ReferralsTransferOfferTtl: BlockNumber = 3 * DAYS;
+	pub const ReferralsRenewalExtension: BlockNumber = 30 * DAYS;
+	// every accrual of volume is reported with its own event
+	pub const ReferralsEmitVolumeEvents: bool = true;
+	pub const ReferralsVolumeEventThreshold: Balance = 0;
+	// stats of the codes are snapshotted every week
+	pub const ReferralsSnapshotPeriod: BlockNumber = 7 * DAYS;
+	pub const ReferralsMaxCodesPerSnapshot: u32 = 100;
 }
 
 impl pallet_referrals::Config for Runtime {
@@ -1564,13 +1737,38 @@ impl pallet_referrals::Config for Runtime {
 	#[cfg(feature = "runtime-benchmarks")]
 	type PriceProvider = ReferralsDummyPriceProvider;
 	type RewardAsset = NativeAssetId;
+	type RewardAssetForTier = ReferralsRewardAssetForTier;
 	type PalletId = ReferralsPalletId;
 	type RegistrationFee = RegistrationFee;
+	type RewardPotShare = ReferralsRewardPotShare;
+	type VanityFee = ReferralsVanityFee;
+	type VanityLengthThreshold = ReferralsVanityLengthThreshold;
 	type CodeLength = MaxCodeLength;
 	type MinCodeLength = MinCodeLength;
 	type LevelVolumeAndRewardPercentages = ReferralsLevelVolumeAndRewards;
 	type ExternalAccount = ReferralsExternalRewardAccount;
 	type SeedNativeAmount = ReferralsSeedAmount;
+	type MaxLeaderboard = ReferralsMaxLeaderboard;
+	type MaxAliases = ReferralsMaxAliases;
+	type TransactionFeeDiscount = ReferralsTransactionFeeDiscount;
+	type BlockNumericCodes = ConstBool<true>;
+	type MaxRewardRate = ReferralsMaxRewardRate;
+	type NormalizeCase = ReferralsNormalizeCase;
+	type MaxReferralDepth = ReferralsMaxReferralDepth;
+	type ReferralChainDecay = ReferralsChainDecay;
+	type MinVolumeForReward = ReferralsMinVolumeForReward;
+	type EmitLinkEvents = ReferralsEmitLinkEvents;
+	type RewardCapPerPeriod = ReferralsRewardCapPerPeriod;
+	type RewardPeriod = ReferralsRewardPeriod;
+	type MaxAccruedVolume = ReferralsMaxAccruedVolume;
+	type MaxDescLen = ReferralsMaxDescLen;
+	type UnlinkGracePeriod = ReferralsUnlinkGracePeriod;
+	type TransferOfferTtl = ReferralsTransferOfferTtl;
+	type RenewalExtension = ReferralsRenewalExtension;
+	type EmitVolumeEvents = ReferralsEmitVolumeEvents;
+	type VolumeEventThreshold = ReferralsVolumeEventThreshold;
+	type SnapshotPeriod = ReferralsSnapshotPeriod;
+	type MaxCodesPerSnapshot = ReferralsMaxCodesPerSnapshot;
 	type WeightInfo = weights::pallet_referrals::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ReferralsBenchmarkHelper;
@@ -1664,6 +1862,15 @@ impl GetByKey<Level, (Balance, FeeDistribution)> for ReferralsLevelVolumeAndRewa
 	}
 }
 
+pub struct ReferralsRewardAssetForTier;
+
+impl GetByKey<Level, Option<AssetId>> for ReferralsRewardAssetForTier {
+	fn get(_k: &Level) -> Option<AssetId> {
+		// all levels are paid in the native asset
+		None
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 use pallet_referrals::BenchmarkHelper as RefBenchmarkHelper;
 use pallet_xyk::types::AssetPair;
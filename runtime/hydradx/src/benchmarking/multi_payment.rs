@@ -18,8 +18,8 @@
 
 use super::*;
 use crate::{
-	AccountId, AssetId, Balance, Currencies, EmaOracle, InsufficientEDinHDX, Runtime, RuntimeCall, System,
-	TreasuryAccount,
+	AccountId, AssetId, Balance, Currencies, EmaOracle, InsufficientEDinHDX, ReferralFeeDiscount, Runtime, RuntimeCall,
+	System, TreasuryAccount,
 };
 use frame_benchmarking::account;
 use frame_benchmarking::BenchmarkError;
@@ -39,7 +39,7 @@ use primitives::{BlockNumber, Price};
 use sp_core::Get;
 use sp_runtime::traits::SaturatedConversion;
 use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
-use sp_runtime::FixedU128;
+use sp_runtime::{FixedU128, Permill};
 
 type MultiPaymentPallet<T> = pallet_transaction_multi_payment::Pallet<T>;
 type XykPallet<T> = pallet_xyk::Pallet<T>;
@@ -193,9 +193,9 @@ runtime_benchmarks! {
 		let info = call.get_dispatch_info();
 		let fee= 295599811918u128;
 		let tip = 0;
-		let mut tx_result : Result<Option<PaymentInfo<Balance, pallet_transaction_multi_payment::AssetIdOf<Runtime>, Price>>, TransactionValidityError> = Err(TransactionValidityError::Invalid(InvalidTransaction::Payment));
+		let mut tx_result : Result<Option<(PaymentInfo<Balance, pallet_transaction_multi_payment::AssetIdOf<Runtime>, Price>, Permill)>, TransactionValidityError> = Err(TransactionValidityError::Invalid(InvalidTransaction::Payment));
 	}: {
-		tx_result = <TransferFees<Currencies, DepositAll<Runtime>, TreasuryAccount> as OnChargeTransaction<Runtime>>::withdraw_fee(&from, &call, &info, fee, tip);
+		tx_result = <TransferFees<Currencies, DepositAll<Runtime>, TreasuryAccount, ReferralFeeDiscount> as OnChargeTransaction<Runtime>>::withdraw_fee(&from, &call, &info, fee, tip);
 	}
 	verify {
 		assert!(tx_result.is_ok());
@@ -108,10 +108,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 261,
+	spec_version: 357,
 	impl_version: 0,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -252,6 +252,7 @@ pub type SignedExtra = (
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	pallet_claims::ValidateClaim<Runtime>,
+	LinkReferralCode,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
@@ -266,7 +267,12 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	(pallet_collator_selection::migration::v2::MigrationToV2<Runtime>,),
+	(
+		pallet_collator_selection::migration::v2::MigrationToV2<Runtime>,
+		pallet_referrals::migration::MigrateToV1<Runtime>,
+		pallet_referrals::migration::MigrateToV2<Runtime>,
+		pallet_bonds::migration::MigrateToV1<Runtime>,
+	),
 >;
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
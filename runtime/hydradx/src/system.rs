@@ -17,7 +17,7 @@
 
 use super::*;
 
-use pallet_transaction_multi_payment::{DepositAll, TransferFees, WeightInfo};
+use pallet_transaction_multi_payment::{DepositAll, FeeDiscount, TransferFees, WeightInfo};
 use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 use primitives::constants::{
 	chain::{CORE_ASSET_ID, MAXIMUM_BLOCK_WEIGHT},
@@ -30,8 +30,9 @@ use frame_support::{
 	dispatch::DispatchClass,
 	parameter_types,
 	sp_runtime::{
-		traits::{ConstU32, ConstU64, IdentityLookup},
-		FixedPointNumber, Perbill, Perquintill, RuntimeDebug,
+		traits::{ConstU32, ConstU64, DispatchInfoOf, IdentityLookup, SignedExtension},
+		transaction_validity::TransactionValidityError,
+		FixedPointNumber, Perbill, Permill, Perquintill, RuntimeDebug,
 	},
 	traits::{ConstBool, Contains, InstanceFilter, SortedMembers},
 	weights::{
@@ -42,6 +43,7 @@ use frame_support::{
 };
 use frame_system::EnsureRoot;
 use hydradx_adapters::{OraclePriceProvider, RelayChainBlockNumberProvider};
+use pallet_referrals::ReferralCode;
 use scale_info::TypeInfo;
 
 pub struct CallFilter;
@@ -498,13 +500,99 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = TransferFees<Currencies, DepositAll<Runtime>, TreasuryAccount>;
+	type OnChargeTransaction = TransferFees<Currencies, DepositAll<Runtime>, TreasuryAccount, ReferralFeeDiscount>;
 	type OperationalFeeMultiplier = ();
 	type WeightToFee = WeightToFee;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
 }
 
+/// Referral discount of the transaction fee, applied by `TransferFees` when the fee is charged.
+///
+/// Discount given by `Referrals::fee_discount_for` is subtracted from the actual fee of trading calls
+/// and refunded to the signer in the currency the fee was paid in.
+/// There is no discount for non-trading calls and for accounts that are not linked to a referral account.
+pub struct ReferralFeeDiscount;
+
+impl ReferralFeeDiscount {
+	fn is_trading_call(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Omnipool(pallet_omnipool::Call::sell { .. })
+				| RuntimeCall::Omnipool(pallet_omnipool::Call::buy { .. })
+				| RuntimeCall::Router(pallet_route_executor::Call::sell { .. })
+				| RuntimeCall::Router(pallet_route_executor::Call::buy { .. })
+				| RuntimeCall::Router(pallet_route_executor::Call::sell_all { .. })
+				| RuntimeCall::Stableswap(pallet_stableswap::Call::sell { .. })
+				| RuntimeCall::Stableswap(pallet_stableswap::Call::buy { .. })
+				| RuntimeCall::XYK(pallet_xyk::Call::sell { .. })
+				| RuntimeCall::XYK(pallet_xyk::Call::buy { .. })
+				| RuntimeCall::LBP(pallet_lbp::Call::sell { .. })
+				| RuntimeCall::LBP(pallet_lbp::Call::buy { .. })
+		)
+	}
+}
+
+impl FeeDiscount<AccountId, RuntimeCall> for ReferralFeeDiscount {
+	fn fee_discount(who: &AccountId, call: &RuntimeCall) -> Permill {
+		if !Self::is_trading_call(call) {
+			return Permill::zero();
+		}
+		Referrals::fee_discount_for(who)
+	}
+}
+
+/// Signed extension that links the signer to a referral account at trade time.
+///
+/// When the extension carries a referral code and the call is a trading call, the code is linked
+/// to the signer before the dispatch. Accounts that are already linked are not relinked and
+/// a code that can't be linked doesn't reject the transaction.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+pub struct LinkReferralCode(pub Option<ReferralCode<<Runtime as pallet_referrals::Config>::CodeLength>>);
+
+impl sp_std::fmt::Debug for LinkReferralCode {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "LinkReferralCode")
+	}
+}
+
+impl SignedExtension for LinkReferralCode {
+	const IDENTIFIER: &'static str = "LinkReferralCode";
+	type AccountId = AccountId;
+	type Call = RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let Some(code) = self.0 else {
+			return Ok(());
+		};
+
+		if !ReferralFeeDiscount::is_trading_call(call) || Referrals::linked_referral_account(who).is_some() {
+			return Ok(());
+		}
+
+		frame_system::Pallet::<Runtime>::register_extra_weight_unchecked(
+			<<Runtime as pallet_referrals::Config>::WeightInfo as pallet_referrals::WeightInfo>::link_code(),
+			DispatchClass::Normal,
+		);
+		// Invalid code doesn't reject the trade, the account just stays unlinked.
+		let _ = Referrals::link_code(RuntimeOrigin::signed(who.clone()), code);
+
+		Ok(())
+	}
+}
+
 impl pallet_transaction_multi_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AcceptedCurrencyOrigin = SuperMajorityTechCommittee;
@@ -61,6 +61,8 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `OTC::Orders` (r:0 w:1)
 	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderPlacedAt` (r:0 w:1)
+	/// Proof: `OTC::OrderPlacedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	fn place_order() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `963`
@@ -68,7 +70,7 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 46_446_000 picoseconds.
 		Weight::from_parts(47_816_000, 6190)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `OTC::Orders` (r:1 w:1)
 	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
@@ -119,6 +121,8 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:1 w:0)
 	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderPlacedAt` (r:0 w:1)
+	/// Proof: `OTC::OrderPlacedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	fn fill_order() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2436`
@@ -126,7 +130,7 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 161_252_000 picoseconds.
 		Weight::from_parts(162_540_000, 13905)
 			.saturating_add(T::DbWeight::get().reads(19_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `OTC::Orders` (r:1 w:1)
 	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
@@ -136,6 +140,8 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderPlacedAt` (r:0 w:1)
+	/// Proof: `OTC::OrderPlacedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	fn cancel_order() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1197`
@@ -143,6 +149,6 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 42_576_000 picoseconds.
 		Weight::from_parts(42_962_000, 4726)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 }
\ No newline at end of file